@@ -103,7 +103,8 @@ func (ec *EscrowContract) LockFunds(ctx context.Context, depositorAddress string
 		return nil, fmt.Errorf("failed to encode deadline: %w", err)
 	}
 
-	args := []xdr.ScVal{depositorVal, bountyIDVal, amountVal, deadlineVal}
+	// No lock memo
+	args := []xdr.ScVal{depositorVal, bountyIDVal, amountVal, deadlineVal, EncodeScValVoid()}
 
 	// Build InvokeHostFunction operation
 	op, err := BuildInvokeHostFunctionOp(contractAddr, "lock_funds", args)
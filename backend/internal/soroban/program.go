@@ -91,7 +91,8 @@ func (pec *ProgramEscrowContract) LockProgramFunds(ctx context.Context, amount i
 		return nil, fmt.Errorf("failed to encode amount: %w", err)
 	}
 
-	args := []xdr.ScVal{amountVal}
+	// No lock memo
+	args := []xdr.ScVal{amountVal, EncodeScValVoid()}
 
 	// Build InvokeHostFunction operation
 	op, err := BuildInvokeHostFunctionOp(contractAddr, "lock_program_funds", args)
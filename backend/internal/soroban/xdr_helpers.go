@@ -70,6 +70,11 @@ func EncodeScValAddress(addrStr string) (xdr.ScVal, error) {
 	return xdr.ScVal{}, fmt.Errorf("invalid address format: %s", addrStr)
 }
 
+// EncodeScValVoid encodes a unit value, which contracts read as Option::None
+func EncodeScValVoid() xdr.ScVal {
+	return xdr.ScVal{Type: xdr.ScValTypeScvVoid}
+}
+
 // EncodeScValVec encodes a slice of ScVal as ScVal vector
 func EncodeScValVec(vals []xdr.ScVal) (xdr.ScVal, error) {
 	vec := xdr.ScVec(vals)
@@ -28,7 +28,7 @@ fn setup(env: &Env, initial_amount: i128) -> (ProgramEscrowContractClient, Addre
     client.init_program(&program_id, &admin, &token_id);
     if initial_amount > 0 {
         token::StellarAssetClient::new(env, &token_id).mint(&client.address, &initial_amount);
-        client.lock_program_funds(&initial_amount);
+        client.lock_program_funds(&initial_amount, &None);
     }
     (client, admin, token_client)
 }
@@ -52,7 +52,7 @@ fn benchmark_batch_payouts() {
         }
         let start_gas = env.remaining_gas();
         let start_time = std::time::Instant::now();
-        client.batch_payout(&recipients_vec, &amounts);
+        client.batch_payout(&recipients_vec, &amounts, &false, &false);
         let elapsed = start_time.elapsed().as_micros();
         let used_gas = start_gas - env.remaining_gas();
         println!("Batch size: {} | Gas: {} | Time: {}μs", batch_size, used_gas, elapsed);
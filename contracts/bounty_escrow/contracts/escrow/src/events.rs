@@ -1,5 +1,5 @@
 use crate::CapabilityAction;
-use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String};
 
 pub const EVENT_VERSION_V2: u32 = 2;
 
@@ -62,6 +62,106 @@ pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
     env.events().publish(topics, event.clone());
 }
 
+/// Emitted alongside `FundsRefunded` when a cancellation fee was withheld;
+/// `FundsRefunded.amount` is the gross amount taken from the escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundFeeCharged {
+    pub bounty_id: u64,
+    pub refund_to: Address,
+    pub net_amount: i128,
+    pub fee: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_fee_charged(env: &Env, event: RefundFeeCharged) {
+    let topics = (symbol_short!("ref_fee"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockMemo {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub memo: String,
+    pub timestamp: u64,
+}
+
+pub fn emit_lock_memo(env: &Env, event: LockMemo) {
+    let topics = (symbol_short!("lock_memo"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundMadeClaimable {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_made_claimable(env: &Env, event: RefundMadeClaimable) {
+    let topics = (symbol_short!("ref_clm"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimableRefundWithdrawn {
+    pub recipient: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_claimable_refund_withdrawn(env: &Env, event: ClaimableRefundWithdrawn) {
+    let topics = (symbol_short!("ref_wdr"), event.recipient.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRaised {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub contributor: Address,
+    pub dispute_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_dispute_raised(env: &Env, event: DisputeRaised) {
+    let topics = (symbol_short!("dispute"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorAutoBlocklisted {
+    pub contributor: Address,
+    pub dispute_count: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_contributor_auto_blocklisted(env: &Env, event: ContributorAutoBlocklisted) {
+    let topics = (symbol_short!("auto_blk"), event.contributor.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundImminent {
+    pub bounty_id: u64,
+    pub deadline: u64,
+    pub seconds_remaining: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_refund_imminent(env: &Env, event: RefundImminent) {
+    let topics = (symbol_short!("ref_warn"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FeeOperationType {
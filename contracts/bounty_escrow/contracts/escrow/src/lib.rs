@@ -39,7 +39,7 @@ use events::{
 use soroban_sdk::xdr::ToXdr;
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Bytes,
-    BytesN, Env, String, Symbol, Vec,
+    BytesN, Env, Map, String, Symbol, Vec,
 };
 
 // ============================================================================
@@ -285,7 +285,8 @@ mod monitoring {
 }
 
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use soroban_sdk::xdr::ToXdr;
+    use soroban_sdk::{contracttype, symbol_short, Address, Bytes, BytesN, Env};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -311,6 +312,11 @@ mod anti_abuse {
         Whitelist(Address),
         Blocklist(Address),
         Admin,
+        AutoWhitelistOnLock,
+        /// ed25519 public key of the anti-abuse admin for exemption tokens
+        ExemptionSigner,
+        /// address -> (expiry, signature, signer key) exemption token presented for it
+        Exemption(Address),
     }
 
     pub fn get_config(env: &Env) -> AntiAbuseConfig {
@@ -369,15 +375,103 @@ mod anti_abuse {
         env.storage().instance().get(&AntiAbuseKey::Admin)
     }
 
+    pub fn set_auto_whitelist_on_lock(env: &Env, enabled: bool) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::AutoWhitelistOnLock, &enabled);
+    }
+
+    pub fn get_auto_whitelist_on_lock(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&AntiAbuseKey::AutoWhitelistOnLock)
+            .unwrap_or(false)
+    }
+
+    /// Whitelist `address` after its first successful lock when enabled.
+    pub fn record_successful_lock(env: &Env, address: Address) {
+        if get_auto_whitelist_on_lock(env) && !is_whitelisted(env, address.clone()) {
+            set_whitelist(env, address, true);
+        }
+    }
+
     pub fn set_admin(env: &Env, admin: Address) {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    pub fn get_state(env: &Env, address: Address) -> Option<AddressState> {
+        env.storage()
+            .persistent()
+            .get(&AntiAbuseKey::State(address))
+    }
+
+    pub fn set_exemption_signer(env: &Env, public_key: BytesN<32>) {
+        env.storage()
+            .instance()
+            .set(&AntiAbuseKey::ExemptionSigner, &public_key);
+    }
+
+    pub fn get_exemption_signer(env: &Env) -> Option<BytesN<32>> {
+        env.storage().instance().get(&AntiAbuseKey::ExemptionSigner)
+    }
+
+    /// The message the anti-abuse admin signs off-chain to exempt `address`
+    /// from rate limiting until `expiry`.
+    pub fn exemption_payload(env: &Env, address: Address, expiry: u64) -> Bytes {
+        (env.current_contract_address(), address, expiry).to_xdr(env)
+    }
+
+    /// Panics unless `signature` is the exemption signer's signature over the
+    /// exemption payload.
+    pub fn verify_exemption(env: &Env, address: Address, expiry: u64, signature: &BytesN<64>) {
+        let signer =
+            get_exemption_signer(env).unwrap_or_else(|| panic!("Exemption signer not set"));
+        let payload = exemption_payload(env, address, expiry);
+        env.crypto().ed25519_verify(&signer, &payload, signature);
+    }
+
+    /// Store a verified exemption token together with the signer key it was
+    /// checked against.
+    pub fn set_exemption(env: &Env, address: Address, expiry: u64, signature: BytesN<64>) {
+        let signer =
+            get_exemption_signer(env).unwrap_or_else(|| panic!("Exemption signer not set"));
+        env.storage().persistent().set(
+            &AntiAbuseKey::Exemption(address),
+            &(expiry, signature, signer),
+        );
+    }
+
+    /// The exemption token stored for `address`, if it was issued by the
+    /// current exemption signer. Rotating the signer voids earlier tokens.
+    pub fn get_exemption(env: &Env, address: Address) -> Option<(u64, BytesN<64>)> {
+        let (expiry, signature, signer): (u64, BytesN<64>, BytesN<32>) = env
+            .storage()
+            .persistent()
+            .get(&AntiAbuseKey::Exemption(address))?;
+        if Some(signer) != get_exemption_signer(env) {
+            return None;
+        }
+        Some((expiry, signature))
+    }
+
+    /// Enforce the cooldown and window limits for `address`. A valid, unexpired
+    /// exemption token bypasses both; an expired one is ignored.
+    pub fn check_rate_limit(env: &Env, address: Address, exemption: Option<(u64, BytesN<64>)>) {
         if is_whitelisted(env, address.clone()) {
             return;
         }
 
+        if let Some((expiry, signature)) = exemption {
+            if env.ledger().timestamp() < expiry {
+                verify_exemption(env, address.clone(), expiry, &signature);
+                env.events().publish(
+                    (symbol_short!("abuse"), symbol_short!("exempt")),
+                    (address, expiry),
+                );
+                return;
+            }
+        }
+
         let config = get_config(env);
         let now = env.ledger().timestamp();
         let key = AntiAbuseKey::State(address.clone());
@@ -439,6 +533,8 @@ mod anti_abuse {
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 5_000; // 50% max fee
 const MAX_BATCH_SIZE: u32 = 20;
+/// Maximum length in bytes of a depositor lock memo
+const MAX_LOCK_MEMO_LEN: u32 = 128;
 
 extern crate grainlify_core;
 use grainlify_core::asset;
@@ -536,6 +632,25 @@ pub enum Error {
     InvalidSelectionInput = 42,
     /// Returned when an upgrade safety pre-check fails
     UpgradeSafetyCheckFailed = 43,
+    /// Returned when an escrow requires contributor acknowledgment and none was recorded
+    AcknowledgmentRequired = 44,
+    /// Returned when a multisig config has no required signers or repeats a signer
+    InvalidMultisigConfig = 45,
+    /// Returned when a release above the multisig threshold lacks enough unexpired approvals
+    InsufficientApprovals = 46,
+    /// Returned when a token is not on the active approved-token allowlist
+    TokenNotApproved = 47,
+    /// Returned when a refund warning is requested outside the configured warning
+    /// window or was already emitted for the escrow
+    RefundWarningNotDue = 48,
+    /// Returned when a lock memo exceeds `MAX_LOCK_MEMO_LEN` bytes
+    MemoTooLong = 50,
+    /// Returned when settlement is attempted while a dispute is open
+    EscrowDisputed = 51,
+    /// Returned when `settle` is called on an escrow without a pre-authorized contributor
+    AutoReleaseNotSet = 52,
+    /// Returned when a deadline refund is blocked by a pre-authorized auto-release
+    AutoReleasePending = 53,
 }
 
 pub const RISK_FLAG_HIGH_RISK: u32 = 1 << 0;
@@ -560,6 +675,8 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    /// Funds frozen while a dispute raised by the depositor is open
+    Disputed,
 }
 
 #[contracttype]
@@ -682,6 +799,45 @@ pub enum DataKey {
     NetworkId,
 
     MaintenanceMode, // bool flag
+
+    /// Seconds added to `now` when `lock_funds` is called with a zero deadline
+    DefaultLockDuration,
+
+    /// Token in which lock/release fees are charged instead of skimming the escrowed asset
+    FeeToken,
+
+    /// Release and claim require the contributor's acknowledgment of terms
+    RequireAck(u64), // bounty_id -> bool
+    /// A contributor has acknowledged the escrow's terms
+    Acknowledged(u64, Address), // (bounty_id, contributor) -> bool
+    /// Address that receives a pending claim's funds instead of the claimant
+    ClaimDelegate(u64), // bounty_id -> Address
+    /// Seconds a large-release approval stays valid; unset means approvals never expire
+    ApprovalValidity,
+    /// Expiry of each signer's large-release approval
+    ApprovalExpiry(u64), // bounty_id -> Map<Address, u64>
+    /// Approved-token allowlist; empty means every token is accepted
+    ApprovedTokens, // Vec<Address>
+    /// Fee withheld from refunds, in basis points
+    CancellationFeeRate, // i128
+    /// Seconds before the deadline in which a refund warning may be emitted
+    RefundWarningWindow, // u64
+    /// Marks that the refund warning for an escrow has been emitted
+    RefundWarned(u64), // bounty_id -> bool
+    /// Disputes raised against a contributor
+    DisputeCount(Address), // contributor -> u32
+    /// Dispute count at which a contributor is blocklisted; unset or zero disables
+    AutoBlacklistThreshold, // u32
+    /// Whether a failed refund push is kept as a claimable balance instead of reverting
+    RefundClaimableFallback, // bool
+    /// Refunds awaiting withdrawal by a recipient that could not receive a push
+    ClaimableRefund(Address), // recipient -> i128
+    /// Sum of all outstanding claimable refunds
+    ClaimableRefundTotal, // i128
+    /// Contributor paid by `settle` once the deadline passes undisputed
+    AutoReleaseContributor(u64), // bounty_id -> Address
+    /// Depositor note attached at lock time (e.g. an invoice number)
+    LockMemo(u64), // bounty_id -> String
 }
 
 #[contracttype]
@@ -731,6 +887,16 @@ pub struct AntiAbuseConfigView {
     pub cooldown_period: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+/// Current rate limit usage for one address.
+pub struct RateLimitStatus {
+    pub operations_in_window: u32,
+    pub max_operations: u32,
+    pub cooldown_remaining: u64,
+    pub is_whitelisted: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct FeeConfig {
@@ -842,6 +1008,15 @@ pub struct RefundApproval {
     pub approved_at: u64,
 }
 
+/// What a refund would pay out, as computed by `preview_refund`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPreview {
+    pub to_depositor: i128,
+    pub fee: i128,
+    pub remaining_after: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RefundRecord {
@@ -1034,6 +1209,106 @@ impl BountyEscrowContract {
             })
     }
 
+    /// Charge lock/release fees in a separate token (admin only).
+    ///
+    /// When set, fees are pulled from the depositor (on lock) or the admin
+    /// (on release) with `transfer_from`, so the payer must have approved this
+    /// contract on the fee token. Fee amounts are computed from the escrowed
+    /// amount at the configured rates. Pass `None` to go back to deducting
+    /// fees from the escrowed amount.
+    pub fn set_fee_token(env: Env, token: Option<Address>) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        match token {
+            Some(token) => {
+                if !Self::is_token_approved(env.clone(), token.clone()) {
+                    return Err(Error::TokenNotApproved);
+                }
+                env.storage().instance().set(&DataKey::FeeToken, &token)
+            }
+            None => env.storage().instance().remove(&DataKey::FeeToken),
+        }
+        Ok(())
+    }
+
+    /// Add or remove a token from the approved-token allowlist (admin only).
+    /// While the list is empty every token is accepted.
+    ///
+    /// The allowlist covers every token the contract moves: the escrowed
+    /// token, the separate fee token and any per-token fee config. A change
+    /// that would leave the escrowed token or the active fee token
+    /// unapproved is rejected with `TokenNotApproved`.
+    pub fn set_token_approval(env: Env, token: Address, approved: bool) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut tokens = Self::get_approved_tokens(env.clone());
+        match (tokens.first_index_of(&token), approved) {
+            (None, true) => tokens.push_back(token),
+            (Some(index), false) => {
+                tokens.remove(index);
+            }
+            _ => {}
+        }
+
+        if !tokens.is_empty() {
+            let escrow_token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            if !tokens.contains(&escrow_token) {
+                return Err(Error::TokenNotApproved);
+            }
+            if let Some(fee_token) = Self::get_fee_token(env.clone()) {
+                if !tokens.contains(&fee_token) {
+                    return Err(Error::TokenNotApproved);
+                }
+            }
+        }
+
+        env.storage()
+            .instance()
+            .set(&DataKey::ApprovedTokens, &tokens);
+        Ok(())
+    }
+
+    pub fn get_approved_tokens(env: Env) -> Vec<Address> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ApprovedTokens)
+            .unwrap_or(vec![&env])
+    }
+
+    pub fn is_token_approved(env: Env, token: Address) -> bool {
+        let tokens = Self::get_approved_tokens(env);
+        tokens.is_empty() || tokens.contains(&token)
+    }
+
+    /// Get the token fees are charged in, if separate from the escrowed asset.
+    pub fn get_fee_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::FeeToken)
+    }
+
+    fn has_fee_token(env: &Env) -> bool {
+        env.storage().instance().has(&DataKey::FeeToken)
+    }
+
+    /// Pull a fee in the fee token from `payer` using this contract's allowance.
+    fn collect_fee_in_fee_token(
+        env: &Env,
+        fee_token: &Address,
+        payer: &Address,
+        recipient: &Address,
+        amount: i128,
+    ) {
+        let client = token::Client::new(env, fee_token);
+        client.transfer_from(&env.current_contract_address(), payer, recipient, &amount);
+    }
+
     /// Update fee configuration (admin only)
     pub fn update_fee_config(
         env: Env,
@@ -1091,6 +1366,34 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Set the cancellation fee withheld from refunds, in basis points (admin only).
+    ///
+    /// # Errors
+    /// * `NotInitialized`  – contract not yet initialised
+    /// * `InvalidFeeRate`  – rate is outside `[0, MAX_FEE_RATE]`
+    pub fn set_cancellation_fee_rate(env: Env, rate: i128) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if !(0..=MAX_FEE_RATE).contains(&rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::CancellationFeeRate, &rate);
+        Ok(())
+    }
+
+    pub fn get_cancellation_fee_rate(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::CancellationFeeRate)
+            .unwrap_or(0)
+    }
+
     /// Updates the granular pause state and metadata for the contract.
     ///
     /// # Arguments
@@ -1265,6 +1568,28 @@ impl BountyEscrowContract {
         }
     }
 
+    /// Index of the first batch item whose depositor fails the participant
+    /// filter, with the error `lock_funds` would return for it.
+    fn first_disallowed_depositor(env: &Env, items: &Vec<LockFundsItem>) -> Option<(u32, Error)> {
+        if Self::get_participant_filter_mode(env) == ParticipantFilterMode::Disabled {
+            return None;
+        }
+        for (i, item) in items.iter().enumerate() {
+            if let Err(err) = Self::check_participant_filter(env, item.depositor) {
+                return Some((i as u32, err));
+            }
+        }
+        None
+    }
+
+    /// View: index of the first item in a `batch_lock_funds` batch whose
+    /// depositor the current filter mode disallows, or `None` if every
+    /// depositor may participate. `batch_lock_funds` itself only returns the
+    /// filter error, so this is how callers find the offending item.
+    pub fn check_batch_participants(env: Env, items: Vec<LockFundsItem>) -> Option<u32> {
+        Self::first_disallowed_depositor(&env, &items).map(|(index, _)| index)
+    }
+
     /// Set deprecation (kill switch) and optional migration target. Admin only.
     /// When deprecated is true: new lock_funds and batch_lock_funds are blocked; existing escrows
     /// can still release, refund, or be migrated off-chain. Emits DeprecationStateChanged.
@@ -1737,6 +2062,7 @@ impl BountyEscrowContract {
     /// # Errors
     /// * `NotInitialized`  – contract not yet initialised
     /// * `InvalidFeeRate`  – any rate is outside `[0, MAX_FEE_RATE]`
+    /// * `TokenNotApproved` – `token` is not on the active allowlist
     pub fn set_token_fee_config(
         env: Env,
         token: Address,
@@ -1757,6 +2083,9 @@ impl BountyEscrowContract {
         if !(0..=MAX_FEE_RATE).contains(&release_fee_rate) {
             return Err(Error::InvalidFeeRate);
         }
+        if !Self::is_token_approved(env.clone(), token.clone()) {
+            return Err(Error::TokenNotApproved);
+        }
 
         let config = TokenFeeConfig {
             lock_fee_rate,
@@ -1826,6 +2155,18 @@ impl BountyEscrowContract {
         if required_signatures > signers.len() {
             return Err(Error::InvalidAmount);
         }
+        // A live threshold with zero required signatures would silently disable multisig
+        if required_signatures == 0 && threshold_amount < i128::MAX {
+            return Err(Error::InvalidMultisigConfig);
+        }
+        for i in 0..signers.len() {
+            let signer = signers.get(i).unwrap();
+            for j in (i + 1)..signers.len() {
+                if signers.get(j).unwrap() == signer {
+                    return Err(Error::InvalidMultisigConfig);
+                }
+            }
+        }
 
         let config = MultisigConfig {
             threshold_amount,
@@ -1852,6 +2193,57 @@ impl BountyEscrowContract {
             })
     }
 
+    /// Set how long a large-release approval stays valid (admin only).
+    /// Approvals given before the change keep their original expiry.
+    pub fn set_approval_validity(env: Env, seconds: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if seconds == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::ApprovalValidity, &seconds);
+        Ok(())
+    }
+
+    pub fn get_approval_validity(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::ApprovalValidity)
+    }
+
+    /// Count the unexpired approvals recorded for releasing `bounty_id` to `contributor`.
+    fn count_valid_approvals(env: &Env, bounty_id: u64, contributor: &Address) -> u32 {
+        let approval: ReleaseApproval = match env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReleaseApproval(bounty_id))
+        {
+            Some(approval) => approval,
+            None => return 0,
+        };
+        if approval.contributor != *contributor {
+            return 0;
+        }
+
+        let expiries: Map<Address, u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ApprovalExpiry(bounty_id))
+            .unwrap_or(Map::new(env));
+        let now = env.ledger().timestamp();
+        let mut count = 0u32;
+        for approver in approval.approvals.iter() {
+            if expiries.get(approver).unwrap_or(u64::MAX) > now {
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Approve release for large amount (requires multisig)
     pub fn approve_large_release(
         env: Env,
@@ -1890,10 +2282,22 @@ impl BountyEscrowContract {
                 approvals: vec![&env],
             });
 
-        for existing in approval.approvals.iter() {
-            if existing == approver {
-                return Ok(());
-            }
+        // Approving again refreshes an existing (possibly expired) approval
+        let expiry_key = DataKey::ApprovalExpiry(bounty_id);
+        let mut expiries: Map<Address, u64> = env
+            .storage()
+            .persistent()
+            .get(&expiry_key)
+            .unwrap_or(Map::new(&env));
+        let expires_at = match Self::get_approval_validity(env.clone()) {
+            Some(validity) => env.ledger().timestamp().saturating_add(validity),
+            None => u64::MAX,
+        };
+        expiries.set(approver.clone(), expires_at);
+        env.storage().persistent().set(&expiry_key, &expiries);
+
+        if approval.approvals.contains(&approver) {
+            return Ok(());
         }
 
         approval.approvals.push_back(approver.clone());
@@ -1917,15 +2321,26 @@ impl BountyEscrowContract {
     /// as using soulbound/non-transferable tokens; the token contract must disallow further
     /// transfers after the recipient claims. Claim and release still perform a single transfer
     /// from the contract to the recipient; no further transfers are required.
+    ///
+    /// `memo` is an optional depositor note (e.g. an invoice number) of at
+    /// most `MAX_LOCK_MEMO_LEN` bytes, emitted in a `LockMemo` event and
+    /// returned by `get_lock_memo`.
     pub fn lock_funds(
         env: Env,
         depositor: Address,
         bounty_id: u64,
         amount: i128,
         deadline: u64,
+        memo: Option<String>,
     ) -> Result<(), Error> {
-        let res =
-            Self::lock_funds_logic(env.clone(), depositor.clone(), bounty_id, amount, deadline);
+        let res = Self::lock_funds_logic(
+            env.clone(),
+            depositor.clone(),
+            bounty_id,
+            amount,
+            deadline,
+            memo,
+        );
         monitoring::track_operation(&env, symbol_short!("lock"), depositor, res.is_ok());
         res
     }
@@ -1936,6 +2351,7 @@ impl BountyEscrowContract {
         bounty_id: u64,
         amount: i128,
         deadline: u64,
+        memo: Option<String>,
     ) -> Result<(), Error> {
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
@@ -1970,7 +2386,8 @@ impl BountyEscrowContract {
         // 4. Participant filtering and rate limiting
         Self::check_participant_filter(&env, depositor.clone())?;
         soroban_sdk::log!(&env, "start lock_funds");
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        let exemption = anti_abuse::get_exemption(&env, depositor.clone());
+        anti_abuse::check_rate_limit(&env, depositor.clone(), exemption);
         soroban_sdk::log!(&env, "rate limit ok");
 
         let _start = env.ledger().timestamp();
@@ -1996,8 +2413,33 @@ impl BountyEscrowContract {
                 return Err(Error::AmountAboveMaximum);
             }
         }
+        if let Some(ref memo) = memo {
+            if memo.len() > MAX_LOCK_MEMO_LEN {
+                reentrancy_guard::release(&env);
+                return Err(Error::MemoTooLong);
+            }
+        }
         soroban_sdk::log!(&env, "amount policy ok");
 
+        // A zero deadline is treated as "unset": substitute the configured
+        // default lock duration, or reject rather than lock an escrow that is
+        // refundable immediately.
+        let deadline = if deadline == 0 {
+            match env
+                .storage()
+                .instance()
+                .get::<DataKey, u64>(&DataKey::DefaultLockDuration)
+            {
+                Some(duration) => env.ledger().timestamp().saturating_add(duration),
+                None => {
+                    reentrancy_guard::release(&env);
+                    return Err(Error::InvalidDeadline);
+                }
+            }
+        } else {
+            deadline
+        };
+
         // 7. Business logic: bounty must not already exist
         if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             reentrancy_guard::release(&env);
@@ -2026,9 +2468,15 @@ impl BountyEscrowContract {
             0
         };
 
-        // Net amount stored in escrow after fee.
+        // Net amount stored in escrow after fee. With a fee token configured
+        // the fee is charged separately and the full amount stays escrowed.
         // Fee must never exceed the deposit; guard against misconfiguration.
-        let net_amount = amount.checked_sub(fee_amount).unwrap_or(amount);
+        let fee_token = Self::get_fee_token(env.clone());
+        let net_amount = if fee_token.is_some() {
+            amount
+        } else {
+            amount.checked_sub(fee_amount).unwrap_or(amount)
+        };
         if net_amount <= 0 {
             return Err(Error::InvalidAmount);
         }
@@ -2036,7 +2484,18 @@ impl BountyEscrowContract {
         // Transfer fee to recipient immediately (separate transfer so it is
         // visible as a distinct on-chain operation).
         if fee_amount > 0 {
-            client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount);
+            match &fee_token {
+                Some(fee_token) => Self::collect_fee_in_fee_token(
+                    &env,
+                    fee_token,
+                    &depositor,
+                    &fee_recipient,
+                    fee_amount,
+                ),
+                None => {
+                    client.transfer(&env.current_contract_address(), &fee_recipient, &fee_amount)
+                }
+            }
             events::emit_fee_collected(
                 &env,
                 events::FeeCollected {
@@ -2098,10 +2557,26 @@ impl BountyEscrowContract {
                 deadline,
             },
         );
+        if let Some(memo) = memo {
+            env.storage()
+                .persistent()
+                .set(&DataKey::LockMemo(bounty_id), &memo);
+            events::emit_lock_memo(
+                &env,
+                events::LockMemo {
+                    bounty_id,
+                    depositor: depositor.clone(),
+                    memo,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
         // INV-2: Verify aggregate balance matches token balance after lock
         multitoken_invariants::assert_after_lock(&env);
 
+        anti_abuse::record_successful_lock(&env, depositor);
+
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(())
@@ -2200,7 +2675,7 @@ impl BountyEscrowContract {
         // 8. Fee computation (pure)
         let (lock_fee_rate, _release_fee_rate, _fee_recipient, fee_enabled) =
             Self::resolve_fee_config(env);
-        let fee_amount = if fee_enabled && lock_fee_rate > 0 {
+        let fee_amount = if fee_enabled && lock_fee_rate > 0 && !Self::has_fee_token(env) {
             Self::calculate_fee(amount, lock_fee_rate)
         } else {
             0
@@ -2225,6 +2700,75 @@ impl BountyEscrowContract {
             .unwrap_or(false))
     }
 
+    /// Require (or stop requiring) the contributor to call `acknowledge` before
+    /// `release_funds` or `claim` can pay out this escrow. Depositor only.
+    pub fn set_require_ack(env: Env, bounty_id: u64, required: bool) -> Result<(), Error> {
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.depositor.require_auth();
+
+        if required {
+            env.storage()
+                .persistent()
+                .set(&DataKey::RequireAck(bounty_id), &true);
+        } else {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RequireAck(bounty_id));
+        }
+        Ok(())
+    }
+
+    /// Returns whether the escrow requires contributor acknowledgment before payout.
+    pub fn get_require_ack(env: Env, bounty_id: u64) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RequireAck(bounty_id))
+            .unwrap_or(false)
+    }
+
+    /// Contributor acknowledges the escrow's terms on-chain.
+    pub fn acknowledge(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        contributor.require_auth();
+
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+        env.storage().persistent().set(
+            &DataKey::Acknowledged(bounty_id, contributor.clone()),
+            &true,
+        );
+
+        env.events().publish(
+            (symbol_short!("ack"), bounty_id),
+            (contributor, env.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Returns whether `contributor` has acknowledged the escrow's terms.
+    pub fn is_acknowledged(env: Env, bounty_id: u64, contributor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Acknowledged(bounty_id, contributor))
+            .unwrap_or(false)
+    }
+
+    fn check_acknowledged(env: &Env, bounty_id: u64, contributor: &Address) -> Result<(), Error> {
+        if Self::get_require_ack(env.clone(), bounty_id)
+            && !Self::is_acknowledged(env.clone(), bounty_id, contributor.clone())
+        {
+            return Err(Error::AcknowledgmentRequired);
+        }
+        Ok(())
+    }
+
     /// Lock funds for a bounty in anonymous mode: only a 32-byte depositor commitment is stored.
     /// The depositor must authorize and transfer; their address is used only for the transfer
     /// in this call and is not stored on-chain. Refunds require the configured anonymous
@@ -2261,7 +2805,8 @@ impl BountyEscrowContract {
         }
 
         // 4. Rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
+        let exemption = anti_abuse::get_exemption(&env, depositor.clone());
+        anti_abuse::check_rate_limit(&env, depositor.clone(), exemption);
 
         // 5. Authorization
         depositor.require_auth();
@@ -2386,6 +2931,21 @@ impl BountyEscrowContract {
             return Err(Error::FundsNotLocked);
         }
 
+        if let Err(e) = Self::check_acknowledged(&env, bounty_id, &contributor) {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(e);
+        }
+
+        // Large releases need enough unexpired multisig approvals
+        let multisig_config = Self::get_multisig_config(env.clone());
+        if escrow.amount >= multisig_config.threshold_amount
+            && Self::count_valid_approvals(&env, bounty_id, &contributor)
+                < multisig_config.required_signatures
+        {
+            env.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(Error::InsufficientApprovals);
+        }
+
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
 
@@ -2399,21 +2959,36 @@ impl BountyEscrowContract {
             0
         };
 
-        // Net payout to contributor after release fee.
-        let net_payout = escrow
-            .amount
-            .checked_sub(release_fee)
-            .unwrap_or(escrow.amount);
+        // Net payout to contributor after release fee. With a fee token
+        // configured the admin pays the fee and the contributor gets it all.
+        let fee_token = Self::get_fee_token(env.clone());
+        let net_payout = if fee_token.is_some() {
+            escrow.amount
+        } else {
+            escrow
+                .amount
+                .checked_sub(release_fee)
+                .unwrap_or(escrow.amount)
+        };
         if net_payout <= 0 {
             return Err(Error::InvalidAmount);
         }
 
         if release_fee > 0 {
-            client.transfer(
-                &env.current_contract_address(),
-                &fee_recipient,
-                &release_fee,
-            );
+            match &fee_token {
+                Some(fee_token) => Self::collect_fee_in_fee_token(
+                    &env,
+                    fee_token,
+                    &admin,
+                    &fee_recipient,
+                    release_fee,
+                ),
+                None => client.transfer(
+                    &env.current_contract_address(),
+                    &fee_recipient,
+                    &release_fee,
+                ),
+            }
             events::emit_fee_collected(
                 &env,
                 events::FeeCollected {
@@ -2511,7 +3086,7 @@ impl BountyEscrowContract {
         }
         let (_lock_fee_rate, release_fee_rate, _fee_recipient, fee_enabled) =
             Self::resolve_fee_config(env);
-        let release_fee = if fee_enabled && release_fee_rate > 0 {
+        let release_fee = if fee_enabled && release_fee_rate > 0 && !Self::has_fee_token(env) {
             Self::calculate_fee(escrow.amount, release_fee_rate)
         } else {
             0
@@ -2612,11 +3187,15 @@ impl BountyEscrowContract {
     }
 
     /// Admin can authorize a release as a pending claim instead of immediate transfer.
+    ///
+    /// With `auto_claim` set, the funds are transferred to `recipient` in the
+    /// same call and the escrow is marked released; no pending claim is stored.
     pub fn authorize_claim(
         env: Env,
         bounty_id: u64,
         recipient: Address,
         reason: DisputeReason,
+        auto_claim: bool,
     ) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
@@ -2631,7 +3210,7 @@ impl BountyEscrowContract {
             return Err(Error::BountyNotFound);
         }
 
-        let escrow: Escrow = env
+        let mut escrow: Escrow = env
             .storage()
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
@@ -2642,15 +3221,47 @@ impl BountyEscrowContract {
         }
 
         let now = env.ledger().timestamp();
+
+        // A delegate belongs to the claim it was set for, never a new one
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimDelegate(bounty_id));
+
+        if auto_claim {
+            Self::check_acknowledged(&env, bounty_id, &recipient)?;
+            let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+            let client = token::Client::new(&env, &token_addr);
+            let amount = escrow.remaining_amount;
+            client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+            escrow.status = EscrowStatus::Released;
+            escrow.remaining_amount = 0;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+
+            env.events().publish(
+                (symbol_short!("claim"), symbol_short!("done")),
+                ClaimExecuted {
+                    bounty_id,
+                    recipient,
+                    amount,
+                    claimed_at: now,
+                },
+            );
+            return Ok(());
+        }
+
         let claim_window: u64 = env
             .storage()
             .instance()
             .get(&DataKey::ClaimWindow)
             .unwrap_or(0);
+        // After a partial claim only the remainder is still held for this bounty
         let claim = ClaimRecord {
             bounty_id,
             recipient: recipient.clone(),
-            amount: escrow.amount,
+            amount: escrow.remaining_amount,
             expires_at: now.saturating_add(claim_window),
             claimed: false,
             reason: reason.clone(),
@@ -2665,14 +3276,42 @@ impl BountyEscrowContract {
             ClaimCreated {
                 bounty_id,
                 recipient,
-                amount: escrow.amount,
+                amount: claim.amount,
                 expires_at: claim.expires_at,
             },
         );
         Ok(())
     }
 
+    /// Claimant directs their pending claim to be paid to `delegate` (e.g. a cold
+    /// wallet). The claimant still has to sign `claim` themselves.
+    pub fn set_claim_delegate(env: Env, bounty_id: u64, delegate: Address) -> Result<(), Error> {
+        let claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        claim.recipient.require_auth();
+
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::ClaimDelegate(bounty_id), &delegate);
+        Ok(())
+    }
+
+    /// Get the delegate payout address for a pending claim, if one is set.
+    pub fn get_claim_delegate(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimDelegate(bounty_id))
+    }
+
     /// Beneficiary calls this to claim their authorized funds within the window.
+    /// Funds go to the claim delegate if one was set.
     pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
         if Self::check_paused(&env, symbol_short!("release")) {
             return Err(Error::FundsPaused);
@@ -2699,14 +3338,14 @@ impl BountyEscrowContract {
         if claim.claimed {
             return Err(Error::FundsNotLocked);
         }
+        Self::check_acknowledged(&env, bounty_id, &claim.recipient)?;
+
+        let payee = Self::get_claim_delegate(env.clone(), bounty_id)
+            .unwrap_or_else(|| claim.recipient.clone());
 
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
+        client.transfer(&env.current_contract_address(), &payee, &claim.amount);
 
         // Update escrow status
         let mut escrow: Escrow = env
@@ -2714,7 +3353,10 @@ impl BountyEscrowContract {
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
-        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount -= claim.amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -2723,6 +3365,9 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::PendingClaim(bounty_id), &claim);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimDelegate(bounty_id));
 
         env.events().publish(
             (symbol_short!("claim"), symbol_short!("done")),
@@ -2736,6 +3381,75 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Claim part of the pending claim within the window. The rest stays
+    /// claimable; the escrow is only marked released once fully claimed.
+    pub fn claim_partial(env: Env, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        let mut claim: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+
+        claim.recipient.require_auth();
+
+        let now = env.ledger().timestamp();
+        if now > claim.expires_at {
+            return Err(Error::DeadlineNotPassed);
+        }
+        if claim.claimed {
+            return Err(Error::FundsNotLocked);
+        }
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if amount > claim.amount {
+            return Err(Error::InsufficientFunds);
+        }
+        Self::check_acknowledged(&env, bounty_id, &claim.recipient)?;
+
+        let payee = Self::get_claim_delegate(env.clone(), bounty_id)
+            .unwrap_or_else(|| claim.recipient.clone());
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &payee, &amount);
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .unwrap();
+        escrow.remaining_amount -= amount;
+        claim.amount -= amount;
+        if claim.amount == 0 {
+            claim.claimed = true;
+            escrow.status = EscrowStatus::Released;
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ClaimDelegate(bounty_id));
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::PendingClaim(bounty_id), &claim);
+
+        env.events().publish(
+            (symbol_short!("claim"), symbol_short!("partial")),
+            ClaimExecuted {
+                bounty_id,
+                recipient: claim.recipient.clone(),
+                amount,
+                claimed_at: now,
+            },
+        );
+        Ok(())
+    }
+
     /// Delegated claim execution using a capability.
     /// Funds are still transferred to the pending claim recipient.
     pub fn claim_with_capability(
@@ -2791,7 +3505,10 @@ impl BountyEscrowContract {
             .persistent()
             .get(&DataKey::Escrow(bounty_id))
             .unwrap();
-        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount -= claim.amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
@@ -2800,6 +3517,9 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::PendingClaim(bounty_id), &claim);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimDelegate(bounty_id));
 
         env.events().publish(
             (symbol_short!("claim"), symbol_short!("done")),
@@ -2845,6 +3565,9 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .remove(&DataKey::PendingClaim(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimDelegate(bounty_id));
 
         env.events().publish(
             (symbol_short!("claim"), symbol_short!("cancel")),
@@ -2867,6 +3590,251 @@ impl BountyEscrowContract {
             .ok_or(Error::BountyNotFound)
     }
 
+    /// Depositor pre-authorizes `contributor` to be paid automatically once the
+    /// deadline passes undisputed: `settle` then releases the escrow to them
+    /// and the depositor can no longer take the deadline refund.
+    pub fn set_auto_release_on_deadline(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+    ) -> Result<(), Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::AutoReleaseContributor(bounty_id), &contributor);
+        Ok(())
+    }
+
+    /// View: contributor pre-authorized for auto-release, if any.
+    pub fn get_auto_release_contributor(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AutoReleaseContributor(bounty_id))
+    }
+
+    /// Release an escrow to its pre-authorized contributor once the deadline
+    /// has passed. Anyone may call; settlement is blocked while a dispute is
+    /// open. Like `claim`, the full remaining amount is paid without a fee.
+    pub fn settle(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if Self::check_paused(&env, symbol_short!("release")) {
+            return Err(Error::FundsPaused);
+        }
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status == EscrowStatus::Disputed {
+            return Err(Error::EscrowDisputed);
+        }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        let contributor: Address = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AutoReleaseContributor(bounty_id))
+            .ok_or(Error::AutoReleaseNotSet)?;
+        let now = env.ledger().timestamp();
+        if now < escrow.deadline {
+            return Err(Error::DeadlineNotPassed);
+        }
+        if let Some(claim) = env
+            .storage()
+            .persistent()
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+        {
+            if !claim.claimed {
+                return Err(Error::ClaimPending);
+            }
+        }
+        Self::check_acknowledged(&env, bounty_id, &contributor)?;
+
+        reentrancy_guard::acquire(&env);
+
+        // EFFECTS: update state before external call (CEI)
+        let amount = escrow.remaining_amount;
+        escrow.status = EscrowStatus::Released;
+        escrow.remaining_amount = 0;
+        invariants::assert_escrow(&env, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AutoReleaseContributor(bounty_id));
+
+        // INTERACTION
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &contributor, &amount);
+
+        emit_funds_released(
+            &env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount,
+                recipient: contributor,
+                timestamp: now,
+            },
+        );
+
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Depositor disputes a locked escrow's work by `contributor`. The escrow is
+    /// frozen as `Disputed` until the admin resolves it, and the contributor's
+    /// dispute count grows; reaching the auto-blacklist threshold blocklists them.
+    ///
+    /// `contributor` must be tied to the escrow, as its pending claim
+    /// recipient, pending release contributor or auto-release contributor;
+    /// otherwise `Unauthorized` is returned.
+    pub fn raise_dispute(env: Env, bounty_id: u64, contributor: Address) -> Result<u32, Error> {
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        escrow.depositor.require_auth();
+
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if !Self::is_escrow_contributor(&env, bounty_id, &contributor) {
+            return Err(Error::Unauthorized);
+        }
+        escrow.status = EscrowStatus::Disputed;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        let count_key = DataKey::DisputeCount(contributor.clone());
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get::<DataKey, u32>(&count_key)
+            .unwrap_or(0)
+            .saturating_add(1);
+        env.storage().persistent().set(&count_key, &count);
+
+        let now = env.ledger().timestamp();
+        events::emit_dispute_raised(
+            &env,
+            events::DisputeRaised {
+                bounty_id,
+                depositor: escrow.depositor,
+                contributor: contributor.clone(),
+                dispute_count: count,
+                timestamp: now,
+            },
+        );
+
+        let threshold = Self::get_auto_blacklist_threshold(env.clone());
+        if threshold > 0
+            && count >= threshold
+            && !anti_abuse::is_blocklisted(&env, contributor.clone())
+        {
+            anti_abuse::set_blocklist(&env, contributor.clone(), true);
+            events::emit_contributor_auto_blocklisted(
+                &env,
+                events::ContributorAutoBlocklisted {
+                    contributor,
+                    dispute_count: count,
+                    timestamp: now,
+                },
+            );
+        }
+        Ok(count)
+    }
+
+    /// Whether `contributor` is the address the escrow is currently headed to.
+    fn is_escrow_contributor(env: &Env, bounty_id: u64, contributor: &Address) -> bool {
+        let storage = env.storage().persistent();
+        if storage
+            .get::<DataKey, Address>(&DataKey::AutoReleaseContributor(bounty_id))
+            .is_some_and(|auto| &auto == contributor)
+        {
+            return true;
+        }
+        if storage
+            .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+            .is_some_and(|claim| !claim.claimed && &claim.recipient == contributor)
+        {
+            return true;
+        }
+        storage
+            .get::<DataKey, ReleaseApproval>(&DataKey::ReleaseApproval(bounty_id))
+            .is_some_and(|approval| &approval.contributor == contributor)
+    }
+
+    /// Admin closes a dispute, returning the escrow to `Locked` so it can be
+    /// released or refunded as usual.
+    pub fn resolve_dispute(env: Env, bounty_id: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Disputed {
+            return Err(Error::FundsNotLocked);
+        }
+        escrow.status = EscrowStatus::Locked;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+        Ok(())
+    }
+
+    pub fn get_dispute_count(env: Env, contributor: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputeCount(contributor))
+            .unwrap_or(0)
+    }
+
+    /// Set the dispute count at which a contributor is automatically
+    /// blocklisted (admin only). Zero disables auto-blacklisting.
+    pub fn set_auto_blacklist_threshold(env: Env, threshold: u32) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::AutoBlacklistThreshold, &threshold);
+        Ok(())
+    }
+
+    pub fn get_auto_blacklist_threshold(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AutoBlacklistThreshold)
+            .unwrap_or(0)
+    }
+
+    /// View: whether `address` is on the participant blocklist.
+    pub fn is_blocklisted(env: Env, address: Address) -> bool {
+        anti_abuse::is_blocklisted(&env, address)
+    }
+
     /// Approve a refund before deadline (admin only).
     /// This allows early refunds with admin approval.
     pub fn approve_refund(
@@ -3046,6 +4014,15 @@ impl BountyEscrowContract {
         if now < escrow.deadline && approval.is_none() {
             return Err(Error::DeadlineNotPassed);
         }
+        // A pre-authorized contributor is paid by `settle` instead
+        if approval.is_none()
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::AutoReleaseContributor(bounty_id))
+        {
+            return Err(Error::AutoReleasePending);
+        }
 
         let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
             let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
@@ -3092,9 +4069,7 @@ impl BountyEscrowContract {
         }
 
         // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        Self::transfer_refund(&env, bounty_id, &refund_to, refund_amount);
 
         emit_funds_refunded(
             &env,
@@ -3122,10 +4097,309 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Cancellation fee withheld from a refund of `gross`.
+    fn refund_fee(env: &Env, gross: i128) -> i128 {
+        Self::calculate_fee(gross, Self::get_cancellation_fee_rate(env.clone()))
+    }
+
+    /// Pay out a refund of `gross` to `refund_to`, sending the cancellation
+    /// fee to the fee recipient. Every refund path goes through here.
+    fn transfer_refund(env: &Env, bounty_id: u64, refund_to: &Address, gross: i128) {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        let contract = env.current_contract_address();
+
+        let fee = Self::refund_fee(env, gross);
+        if fee > 0 {
+            let (_, _, fee_recipient, _) = Self::resolve_fee_config(env);
+            client.transfer(&contract, &fee_recipient, &fee);
+        }
+        let net_refund = gross - fee;
+        if Self::get_refund_claimable_fallback(env.clone()) {
+            let pushed = matches!(
+                client.try_transfer(&contract, refund_to, &net_refund),
+                Ok(Ok(()))
+            );
+            if !pushed {
+                Self::credit_claimable_refund(env, bounty_id, refund_to, net_refund);
+            }
+        } else {
+            client.transfer(&contract, refund_to, &net_refund);
+        }
+
+        if fee > 0 {
+            events::emit_refund_fee_charged(
+                env,
+                events::RefundFeeCharged {
+                    bounty_id,
+                    refund_to: refund_to.clone(),
+                    net_amount: net_refund,
+                    fee,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+    }
+
+    /// Keep refunds whose push transfer fails as a claimable balance instead of
+    /// reverting the refund (admin only). Off by default.
+    pub fn set_refund_claimable_fallback(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundClaimableFallback, &enabled);
+        Ok(())
+    }
+
+    /// View: whether failed refund pushes fall back to a claimable balance.
+    pub fn get_refund_claimable_fallback(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RefundClaimableFallback)
+            .unwrap_or(false)
+    }
+
+    /// View: refund amount waiting to be withdrawn by `recipient`.
+    pub fn get_claimable_refund(env: Env, recipient: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ClaimableRefund(recipient))
+            .unwrap_or(0)
+    }
+
+    /// Withdraw the full claimable refund balance of `recipient`.
+    ///
+    /// The balance is cleared before the token transfer (CEI), so a failed
+    /// transfer reverts and leaves the balance in place.
+    pub fn withdraw_claimable_refund(env: Env, recipient: Address) -> Result<i128, Error> {
+        recipient.require_auth();
+
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let key = DataKey::ClaimableRefund(recipient.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            reentrancy_guard::release(&env);
+            return Err(Error::InvalidAmount);
+        }
+
+        // EFFECTS: clear the balance before the external call
+        env.storage().persistent().remove(&key);
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimableRefundTotal)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimableRefundTotal, &(total - amount));
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        events::emit_claimable_refund_withdrawn(
+            &env,
+            events::ClaimableRefundWithdrawn {
+                recipient,
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        multitoken_invariants::assert_after_disbursement(&env);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(amount)
+    }
+
+    fn credit_claimable_refund(env: &Env, bounty_id: u64, recipient: &Address, amount: i128) {
+        let key = DataKey::ClaimableRefund(recipient.clone());
+        let balance: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(balance + amount));
+        let total: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClaimableRefundTotal)
+            .unwrap_or(0);
+        env.storage()
+            .instance()
+            .set(&DataKey::ClaimableRefundTotal, &(total + amount));
+
+        events::emit_refund_made_claimable(
+            env,
+            events::RefundMadeClaimable {
+                bounty_id,
+                recipient: recipient.clone(),
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Refund up to `max` of `bounty_ids` whose deadline has passed, returning
+    /// the full remaining balance to each depositor. Permissionless so a keeper
+    /// can run it. Escrows that are not locked, not yet expired, have a pending
+    /// claim or carry an admin refund approval are skipped.
+    ///
+    /// Returns the number of escrows refunded.
+    pub fn auto_refund_expired(env: Env, bounty_ids: Vec<u64>, max: u32) -> Result<u32, Error> {
+        if Self::check_paused(&env, symbol_short!("refund")) {
+            return Err(Error::FundsPaused);
+        }
+
+        let now = env.ledger().timestamp();
+        let mut refunded: u32 = 0;
+        for bounty_id in bounty_ids.iter() {
+            if refunded >= max {
+                break;
+            }
+            let escrow: Escrow = match env.storage().persistent().get(&DataKey::Escrow(bounty_id)) {
+                Some(escrow) => escrow,
+                None => continue,
+            };
+            if escrow.status != EscrowStatus::Locked || now < escrow.deadline {
+                continue;
+            }
+            if env
+                .storage()
+                .persistent()
+                .has(&DataKey::RefundApproval(bounty_id))
+            {
+                continue;
+            }
+            if Self::refund(env.clone(), bounty_id).is_ok() {
+                refunded += 1;
+            }
+        }
+        Ok(refunded)
+    }
+
+    /// Set how many seconds before an escrow's deadline its refund warning may be
+    /// emitted (admin only).
+    pub fn set_refund_warning_window(env: Env, seconds: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if seconds == 0 {
+            return Err(Error::InvalidAmount);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::RefundWarningWindow, &seconds);
+        Ok(())
+    }
+
+    pub fn get_refund_warning_window(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::RefundWarningWindow)
+    }
+
+    /// Emit a `RefundImminent` event for a locked escrow whose deadline is within
+    /// the configured warning window. Permissionless; each escrow is warned once.
+    ///
+    /// Returns the seconds remaining until the escrow becomes refundable.
+    ///
+    /// # Errors
+    /// * `BountyNotFound`              – no escrow for `bounty_id`
+    /// * `FundsNotLocked`              – escrow is not locked
+    /// * `RefundWarningNotDue` – no window configured, too early, deadline passed, or
+    ///   the warning was already emitted for this escrow
+    pub fn emit_refund_warning(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let window: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::RefundWarningWindow)
+            .ok_or(Error::RefundWarningNotDue)?;
+        let now = env.ledger().timestamp();
+        if now >= escrow.deadline || escrow.deadline - now > window {
+            return Err(Error::RefundWarningNotDue);
+        }
+
+        let warned_key = DataKey::RefundWarned(bounty_id);
+        if env.storage().persistent().has(&warned_key) {
+            return Err(Error::RefundWarningNotDue);
+        }
+        env.storage().persistent().set(&warned_key, &true);
+
+        let seconds_remaining = escrow.deadline - now;
+        events::emit_refund_imminent(
+            &env,
+            events::RefundImminent {
+                bounty_id,
+                deadline: escrow.deadline,
+                seconds_remaining,
+                timestamp: now,
+            },
+        );
+        Ok(seconds_remaining)
+    }
+
+    /// Preview what a refund of `amount` (or the whole remaining balance for
+    /// `RefundMode::Full`) would pay after the cancellation fee.
+    ///
+    /// Read-only: no storage writes, transfers or events.
+    ///
+    /// # Errors
+    /// * `BountyNotFound` – no escrow for `bounty_id`
+    /// * `FundsNotLocked` – escrow is not refundable
+    /// * `InvalidAmount`  – partial amount missing, non-positive or above the remaining balance
+    pub fn preview_refund(
+        env: Env,
+        bounty_id: u64,
+        amount: Option<i128>,
+        mode: RefundMode,
+    ) -> Result<RefundPreview, Error> {
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
+        {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let gross = match mode {
+            RefundMode::Full => escrow.remaining_amount,
+            RefundMode::Partial => amount.ok_or(Error::InvalidAmount)?,
+        };
+        if gross <= 0 || gross > escrow.remaining_amount {
+            return Err(Error::InvalidAmount);
+        }
+
+        let fee = Self::refund_fee(&env, gross);
+        Ok(RefundPreview {
+            to_depositor: gross - fee,
+            fee,
+            remaining_after: escrow.remaining_amount - gross,
+        })
+    }
+
     /// Simulate refund operation without state changes or token transfers.
     ///
     /// Returns a `SimulationResult` indicating whether the operation would succeed and the
     /// resulting escrow state. Does not require authorization; safe for off-chain preview.
+    /// `amount` is what the recipient would receive after the cancellation fee.
     ///
     /// # Arguments
     /// * `bounty_id` - Bounty identifier
@@ -3144,10 +4418,10 @@ impl BountyEscrowContract {
             }
         }
         match Self::dry_run_refund_impl(&env, bounty_id) {
-            Ok((refund_amount, resulting_status, remaining_amount)) => SimulationResult {
+            Ok((net_refund, resulting_status, remaining_amount)) => SimulationResult {
                 success: true,
                 error_code: 0,
-                amount: refund_amount,
+                amount: net_refund,
                 resulting_status,
                 remaining_amount,
             },
@@ -3195,6 +4469,15 @@ impl BountyEscrowContract {
         if now < escrow.deadline && approval.is_none() {
             return Err(Error::DeadlineNotPassed);
         }
+        // A pre-authorized contributor is paid by `settle` instead
+        if approval.is_none()
+            && env
+                .storage()
+                .persistent()
+                .has(&DataKey::AutoReleaseContributor(bounty_id))
+        {
+            return Err(Error::AutoReleasePending);
+        }
         let (refund_amount, _refund_to, is_full) = if let Some(app) = approval {
             let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
             (app.amount, app.recipient, full)
@@ -3217,7 +4500,8 @@ impl BountyEscrowContract {
         } else {
             EscrowStatus::PartiallyRefunded
         };
-        Ok((refund_amount, resulting_status, remaining_after))
+        let net_refund = refund_amount - Self::refund_fee(env, refund_amount);
+        Ok((net_refund, resulting_status, remaining_after))
     }
 
     /// Sets or clears the anonymous resolver address.
@@ -3314,11 +4598,8 @@ impl BountyEscrowContract {
             return Err(Error::InvalidAmount);
         }
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-
         // Transfer the calculated refund amount to the designated recipient
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        Self::transfer_refund(&env, bounty_id, &refund_to, refund_amount);
 
         // Anonymous escrow uses a parallel storage record and invariant model.
         // Update escrow state: subtract the amount exactly refunded
@@ -3421,12 +4702,10 @@ impl BountyEscrowContract {
             amount,
         )?;
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
         let now = env.ledger().timestamp();
         let refund_to = escrow.depositor.clone();
 
-        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        Self::transfer_refund(&env, bounty_id, &refund_to, amount);
 
         escrow.remaining_amount -= amount;
         if escrow.remaining_amount == 0 {
@@ -3476,6 +4755,18 @@ impl BountyEscrowContract {
             .unwrap())
     }
 
+    /// Seconds until a spontaneous refund becomes available for the bounty.
+    /// Returns 0 once the deadline has passed or the escrow is Released/Refunded.
+    pub fn get_time_until_refund(env: Env, bounty_id: u64) -> Result<u64, Error> {
+        let escrow = Self::get_escrow_info(env.clone(), bounty_id)?;
+        match escrow.status {
+            EscrowStatus::Locked | EscrowStatus::PartiallyRefunded => {
+                Ok(escrow.deadline.saturating_sub(env.ledger().timestamp()))
+            }
+            EscrowStatus::Released | EscrowStatus::Refunded | EscrowStatus::Disputed => Ok(0),
+        }
+    }
+
     /// view function to get contract balance of the token
     pub fn get_balance(env: Env) -> Result<i128, Error> {
         if !env.storage().instance().has(&DataKey::Token) {
@@ -3527,6 +4818,28 @@ impl BountyEscrowContract {
         results
     }
 
+    /// Every escrow currently under dispute, in index order.
+    pub fn get_disputed_escrows(env: Env) -> Vec<(u64, Escrow)> {
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+        let mut results = Vec::new(&env);
+        for bounty_id in index.iter() {
+            if let Some(escrow) = env
+                .storage()
+                .persistent()
+                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
+            {
+                if escrow.status == EscrowStatus::Disputed {
+                    results.push_back((bounty_id, escrow));
+                }
+            }
+        }
+        results
+    }
+
     /// Query escrows with amount range filtering
     pub fn query_escrows_by_amount(
         env: Env,
@@ -3662,7 +4975,7 @@ impl BountyEscrowContract {
                 .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
             {
                 match escrow.status {
-                    EscrowStatus::Locked => {
+                    EscrowStatus::Locked | EscrowStatus::Disputed => {
                         stats.total_locked += escrow.amount;
                         stats.count_locked += 1;
                     }
@@ -3690,6 +5003,32 @@ impl BountyEscrowContract {
         index.len()
     }
 
+    /// Set the lock duration applied when `lock_funds` receives a zero deadline (admin only).
+    /// Pass `0` to clear it, after which zero deadlines are rejected.
+    pub fn set_default_lock_duration(env: Env, duration: u64) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        if duration == 0 {
+            env.storage()
+                .instance()
+                .remove(&DataKey::DefaultLockDuration);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::DefaultLockDuration, &duration);
+        }
+        Ok(())
+    }
+
+    /// Get the default lock duration, if configured.
+    pub fn get_default_lock_duration(env: Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::DefaultLockDuration)
+    }
+
     /// Set the minimum and maximum allowed lock amount (admin only).
     ///
     /// Once set, any call to lock_funds with an amount outside [min_amount, max_amount]
@@ -3862,6 +5201,63 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Register the ed25519 public key the anti-abuse admin signs rate-limit
+    /// exemption tokens with (anti-abuse admin only). Tokens submitted under a
+    /// previous key stop applying.
+    pub fn set_rate_limit_exemption_signer(env: Env, public_key: BytesN<32>) -> Result<(), Error> {
+        let admin = anti_abuse::get_admin(&env).ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_exemption_signer(&env, public_key);
+        Ok(())
+    }
+
+    /// View: the registered exemption signer key, if any.
+    pub fn get_rate_limit_exemption_signer(env: Env) -> Option<BytesN<32>> {
+        anti_abuse::get_exemption_signer(&env)
+    }
+
+    /// The message the anti-abuse admin signs off-chain to exempt `address`
+    /// from rate limiting until `expiry`.
+    pub fn get_rate_limit_exemption_payload(env: Env, address: Address, expiry: u64) -> Bytes {
+        anti_abuse::exemption_payload(&env, address, expiry)
+    }
+
+    /// Present a signed, time-bounded exemption token for `address`. Until
+    /// `expiry`, its locks bypass the rate limit and cooldown; afterwards the
+    /// token is ignored. Expired or wrongly-signed tokens are rejected here.
+    pub fn submit_rate_limit_exemption(
+        env: Env,
+        address: Address,
+        expiry: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        if expiry <= env.ledger().timestamp() {
+            return Err(Error::InvalidDeadline);
+        }
+        anti_abuse::verify_exemption(&env, address.clone(), expiry, &signature);
+        anti_abuse::set_exemption(&env, address, expiry, signature);
+        Ok(())
+    }
+
+    /// Enable or disable automatic whitelisting of a depositor after its first
+    /// successful `lock_funds` (admin only). Off by default. Anonymous locks never
+    /// whitelist, since that would record the depositor address on-chain.
+    pub fn set_auto_whitelist_on_lock(env: Env, enabled: bool) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_auto_whitelist_on_lock(&env, enabled);
+        Ok(())
+    }
+
+    /// View: whether depositors are whitelisted after their first successful lock.
+    pub fn get_auto_whitelist_on_lock(env: Env) -> bool {
+        anti_abuse::get_auto_whitelist_on_lock(&env)
+    }
+
     /// Get current anti-abuse config (rate limit and cooldown).
     pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
         let c = anti_abuse::get_config(&env);
@@ -3872,6 +5268,42 @@ impl BountyEscrowContract {
         }
     }
 
+    /// View: how much of its rate limit window `address` has used and how long
+    /// until its cooldown ends. An expired window reports zero operations.
+    pub fn get_rate_limit_status(env: Env, address: Address) -> RateLimitStatus {
+        let config = anti_abuse::get_config(&env);
+        let now = env.ledger().timestamp();
+        let (operations_in_window, cooldown_remaining) =
+            match anti_abuse::get_state(&env, address.clone()) {
+                Some(state) => {
+                    let window_end = state
+                        .window_start_timestamp
+                        .saturating_add(config.window_size);
+                    let operations = if now >= window_end {
+                        0
+                    } else {
+                        state.operation_count
+                    };
+                    let cooldown_end = state
+                        .last_operation_timestamp
+                        .saturating_add(config.cooldown_period);
+                    let remaining = if state.last_operation_timestamp > 0 {
+                        cooldown_end.saturating_sub(now)
+                    } else {
+                        0
+                    };
+                    (operations, remaining)
+                }
+                None => (0, 0),
+            };
+        RateLimitStatus {
+            operations_in_window,
+            max_operations: config.max_operations,
+            cooldown_remaining,
+            is_whitelisted: anti_abuse::is_whitelisted(&env, address),
+        }
+    }
+
     /// Retrieves the refund history for a specific bounty.
     ///
     /// # Arguments
@@ -3977,6 +5409,9 @@ impl BountyEscrowContract {
     /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
     /// * BountyExists - if any bounty_id already exists
     /// * NotInitialized - if contract is not initialized
+    /// * ParticipantBlocked / ParticipantNotAllowed - if any depositor fails the
+    ///   participant filter. The error does not carry the item's index; use
+    ///   `check_batch_participants` to locate it.
     ///
     /// # Ordering Guarantee
     /// Items are processed in ascending `bounty_id` order, regardless of caller
@@ -4017,11 +5452,15 @@ impl BountyEscrowContract {
         let contract_address = env.current_contract_address();
         let timestamp = env.ledger().timestamp();
 
+        // Participant filtering (blocklist-only / allowlist-only / disabled):
+        // one disallowed depositor rejects the whole batch. The error has no
+        // room for the index; callers locate it with `check_batch_participants`.
+        if let Some((_, err)) = Self::first_disallowed_depositor(&env, &items) {
+            return Err(err);
+        }
+
         // Validate all items before processing (all-or-nothing approach)
         for item in items.iter() {
-            // Participant filtering (blocklist-only / allowlist-only / disabled)
-            Self::check_participant_filter(&env, item.depositor.clone())?;
-
             // Check if bounty already exists
             if env
                 .storage()
@@ -4330,6 +5769,13 @@ impl BountyEscrowContract {
             .ok_or(Error::BountyNotFound)
     }
 
+    /// Depositor memo given when the bounty was locked, if any.
+    pub fn get_lock_memo(env: Env, bounty_id: u64) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockMemo(bounty_id))
+    }
+
     fn build_claim_selection_context(
         env: &Env,
         bounty_id: u64,
@@ -4683,7 +6129,7 @@ impl traits::EscrowInterface for BountyEscrowContract {
         amount: i128,
         deadline: u64,
     ) -> Result<(), crate::Error> {
-        BountyEscrowContract::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)
+        BountyEscrowContract::lock_funds(env.clone(), depositor, bounty_id, amount, deadline, None)
     }
 
     /// Release funds to contributor through the trait interface
@@ -4997,6 +6443,7 @@ mod escrow_status_transition_tests {
                         &bounty_id,
                         &amount,
                         &deadline,
+                        &None,
                     );
                     assert!(
                         result.is_err(),
@@ -5109,7 +6556,7 @@ mod escrow_status_transition_tests {
         let result =
             setup
                 .client
-                .try_lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+                .try_lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
         assert!(
             result.is_err(),
             "Expected locking an already released bounty to fail"
@@ -140,7 +140,7 @@ pub(crate) fn check_anon_refund_consistency(anon: &AnonymousEscrow) -> bool {
 // INV-2  Aggregate-to-Ledger
 // ---------------------------------------------------------------------------
 
-/// Sum the remaining_amount of all active (Locked or PartiallyRefunded) escrows,
+/// Sum the remaining_amount of all active (Locked, PartiallyRefunded or Disputed) escrows,
 /// including both normal Escrow and AnonymousEscrow.
 pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
     let index: Vec<u64> = env
@@ -158,6 +158,7 @@ pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
         {
             if escrow.status == EscrowStatus::Locked
                 || escrow.status == EscrowStatus::PartiallyRefunded
+                || escrow.status == EscrowStatus::Disputed
             {
                 total += escrow.remaining_amount;
             }
@@ -172,6 +173,12 @@ pub(crate) fn sum_active_escrow_balances(env: &Env) -> i128 {
             }
         }
     }
+    // Refunds that could not be pushed stay in the contract until withdrawn.
+    total += env
+        .storage()
+        .instance()
+        .get::<DataKey, i128>(&DataKey::ClaimableRefundTotal)
+        .unwrap_or(0);
     total
 }
 
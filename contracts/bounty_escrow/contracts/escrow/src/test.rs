@@ -1,11 +1,12 @@
 use super::*;
 use soroban_sdk::{
     // Added Ledger as _ to put the trait in scope for set_timestamp
-    testutils::{Address as _, Ledger as _},
+    testutils::{Address as _, Events as _, IssuerFlags, Ledger as _, MockAuth, MockAuthInvoke},
     token,
     vec,
     Address,
     Env,
+    IntoVal,
     Vec,
 };
 
@@ -131,6 +132,16 @@ struct TestSetup<'a> {
 
 impl<'a> TestSetup<'a> {
     fn new() -> Self {
+        Self::build(false)
+    }
+
+    /// Setup whose token issuer has AUTH_REVOCABLE set, so tests can
+    /// deauthorize an account with `set_authorized`.
+    fn new_revocable() -> Self {
+        Self::build(true)
+    }
+
+    fn build(revocable: bool) -> Self {
         let env = Env::default();
         env.mock_all_auths();
 
@@ -138,7 +149,12 @@ impl<'a> TestSetup<'a> {
         let depositor = Address::generate(&env);
         let contributor = Address::generate(&env);
 
-        let (token, token_admin) = create_token_contract(&env, &admin);
+        let contract = env.register_stellar_asset_contract_v2(admin.clone());
+        if revocable {
+            contract.issuer().set_flag(IssuerFlags::RevocableFlag);
+        }
+        let token = token::Client::new(&env, &contract.address());
+        let token_admin = token::StellarAssetClient::new(&env, &contract.address());
         let escrow = create_escrow_contract(&env);
 
         escrow.init(&admin, &token.address);
@@ -171,7 +187,7 @@ fn test_lock_funds_success() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let stored_escrow = setup.escrow.get_escrow_info(&bounty_id);
     assert_eq!(stored_escrow.depositor, setup.depositor);
@@ -193,11 +209,11 @@ fn test_lock_funds_duplicate() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 }
 
 #[test]
@@ -210,7 +226,7 @@ fn test_lock_funds_negative_amount() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 }
 
 #[test]
@@ -222,7 +238,7 @@ fn test_get_escrow_info() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let escrow = setup.escrow.get_escrow_info(&bounty_id);
     assert_eq!(escrow.amount, amount);
@@ -241,7 +257,7 @@ fn test_release_funds_success() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     assert_eq!(setup.token.balance(&setup.escrow.address), amount);
     assert_eq!(setup.token.balance(&setup.contributor), 0);
@@ -265,7 +281,7 @@ fn test_release_funds_already_released() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
@@ -289,7 +305,7 @@ fn test_refund_success() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.env.ledger().set_timestamp(deadline + 1);
 
@@ -318,7 +334,7 @@ fn test_refund_too_early() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.refund(&bounty_id);
 }
@@ -334,7 +350,7 @@ fn test_get_balance() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     assert_eq!(setup.escrow.get_balance(), amount);
 }
@@ -352,7 +368,7 @@ fn test_partial_release_single_minimum_unit() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let payout = 1_i128;
     setup
@@ -375,7 +391,7 @@ fn test_partial_release_leaves_tiny_remainder() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let payout = amount - 1;
     setup
@@ -398,7 +414,7 @@ fn test_partial_release_multiple_sequential_small_amounts() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let payout_per_step = 10_i128;
     let steps = 10_i128;
@@ -436,7 +452,7 @@ fn test_partial_release_full_amount_in_one_shot_marks_released() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
@@ -459,7 +475,7 @@ fn test_partial_release_overpayment_panics() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
@@ -479,7 +495,7 @@ fn test_partial_release_exact_remaining_after_prior_release() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
@@ -508,7 +524,7 @@ fn test_partial_release_zero_amount_rejected() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
@@ -524,7 +540,7 @@ fn test_partial_release_remaining_amount_never_goes_negative() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     for payout in [3_i128, 3_i128, 1_i128] {
         setup
@@ -565,7 +581,7 @@ fn test_partial_release_on_already_released_bounty_panics() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 
@@ -584,7 +600,7 @@ fn test_refund_after_partial_release_returns_only_remainder() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
@@ -616,13 +632,16 @@ fn test_claim_within_window_transfers_funds() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&500_u64);
 
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(pending.recipient, setup.contributor);
     assert_eq!(pending.amount, amount);
@@ -649,12 +668,15 @@ fn test_claim_after_window_expires_panics() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&200_u64);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     let now = setup.env.ledger().timestamp();
     setup.env.ledger().set_timestamp(now + 201);
@@ -662,6 +684,110 @@ fn test_claim_after_window_expires_panics() {
     setup.escrow.claim(&bounty_id);
 }
 
+#[test]
+fn test_claim_partial_in_two_steps() {
+    let setup = TestSetup::new();
+    let bounty_id = 124_u64;
+    let amount = 1_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+
+    let before = setup.token.balance(&setup.contributor);
+    setup.escrow.claim_partial(&bounty_id, &400);
+
+    let pending = setup.escrow.get_pending_claim(&bounty_id);
+    assert_eq!(pending.amount, 600);
+    assert!(!pending.claimed);
+    let escrow_info = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow_info.status, EscrowStatus::Locked);
+    assert_eq!(escrow_info.remaining_amount, 600);
+
+    setup.escrow.claim_partial(&bounty_id, &600);
+
+    assert_eq!(setup.token.balance(&setup.contributor), before + amount);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+    let escrow_info = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow_info.status, EscrowStatus::Released);
+    assert!(setup.escrow.get_pending_claim(&bounty_id).claimed);
+}
+
+#[test]
+fn test_claim_partial_exceeding_remaining_rejected() {
+    let setup = TestSetup::new();
+    let bounty_id = 125_u64;
+    let amount = 1_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+
+    setup.escrow.claim_partial(&bounty_id, &700);
+    let res = setup.escrow.try_claim_partial(&bounty_id, &301);
+    assert_eq!(res, Err(Ok(Error::InsufficientFunds)));
+    assert_eq!(setup.escrow.get_pending_claim(&bounty_id).amount, 300);
+}
+
+#[test]
+fn test_reauthorized_claim_after_partial_pays_only_remainder() {
+    let setup = TestSetup::new();
+    let bounty_id = 126_u64;
+    let other_bounty = 127_u64;
+    let amount = 1_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &other_bounty, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+    setup.escrow.claim_partial(&bounty_id, &400);
+    setup
+        .escrow
+        .cancel_pending_claim(&bounty_id, &DisputeOutcome::CancelledByAdmin);
+
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+    assert_eq!(setup.escrow.get_pending_claim(&bounty_id).amount, 600);
+    setup.escrow.claim(&bounty_id);
+
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+    // The other bounty's funds are untouched
+    assert_eq!(setup.token.balance(&setup.escrow.address), amount);
+    let escrow_info = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow_info.status, EscrowStatus::Released);
+    assert_eq!(escrow_info.remaining_amount, 0);
+}
+
 #[test]
 fn test_cancel_pending_claim_restores_escrow() {
     let setup = TestSetup::new();
@@ -671,12 +797,15 @@ fn test_cancel_pending_claim_restores_escrow() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&300_u64);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(pending.amount, amount);
     setup
@@ -712,11 +841,14 @@ fn test_cancel_expired_claim_then_authorize_new_one() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.set_claim_window(&100_u64);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
     let now = setup.env.ledger().timestamp();
     setup.env.ledger().set_timestamp(now + 101);
     setup
@@ -725,7 +857,7 @@ fn test_cancel_expired_claim_then_authorize_new_one() {
     setup.escrow.set_claim_window(&1_000_u64);
     setup
         .escrow
-        .authorize_claim(&bounty_id, &new_contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &new_contributor, &DisputeReason::Other, &false);
 
     let new_pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(new_pending.recipient, new_contributor);
@@ -749,12 +881,15 @@ fn test_cancel_claim_then_use_release_funds_normally() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&300_u64);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     setup
         .escrow
@@ -777,12 +912,15 @@ fn test_claim_twice_panics() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&500_u64);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     setup.escrow.claim(&bounty_id);
 
@@ -799,15 +937,15 @@ fn test_claim_does_not_affect_other_bounties() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_a, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_a, &amount, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_b, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_b, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&500_u64);
     setup
         .escrow
-        .authorize_claim(&bounty_a, &setup.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_a, &setup.contributor, &DisputeReason::Other, &false);
 
     setup.escrow.claim(&bounty_a);
 
@@ -828,11 +966,14 @@ fn test_authorize_claim_zero_window_expires_immediately() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     let now = setup.env.ledger().timestamp();
     setup.env.ledger().set_timestamp(now + 1);
@@ -851,12 +992,15 @@ fn test_claim_at_exact_window_boundary_succeeds() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&window);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     setup.env.ledger().set_timestamp(pending.expires_at);
@@ -871,7 +1015,7 @@ fn test_authorize_claim_on_nonexistent_bounty() {
     let setup = TestSetup::new();
     setup
         .escrow
-        .authorize_claim(&999_u64, &setup.contributor, &DisputeReason::Other);
+        .authorize_claim(&999_u64, &setup.contributor, &DisputeReason::Other, &false);
 }
 
 #[test]
@@ -884,11 +1028,14 @@ fn test_authorize_claim_on_released_bounty() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 }
 
 #[test]
@@ -902,13 +1049,16 @@ fn test_authorize_claim_on_refunded_bounty() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&bounty_id);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 }
 
 #[test]
@@ -920,12 +1070,15 @@ fn test_authorize_claim_default_window_used_when_not_set() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let auth_time = setup.env.ledger().timestamp();
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(pending.expires_at, auth_time);
@@ -941,14 +1094,17 @@ fn test_set_claim_window_success() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&window);
 
     let auth_time = setup.env.ledger().timestamp();
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(pending.expires_at, auth_time + window);
@@ -970,12 +1126,15 @@ fn test_authorize_claim_creates_pending_claim() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.set_claim_window(&400_u64);
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
 
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(pending.bounty_id, bounty_id);
@@ -984,6 +1143,256 @@ fn test_authorize_claim_creates_pending_claim() {
     assert!(!pending.claimed);
 }
 
+#[test]
+fn test_authorize_claim_auto_claim_transfers_immediately() {
+    let setup = TestSetup::new();
+    let bounty_id = 115_u64;
+    let amount = 2_500_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+
+    let before = setup.token.balance(&setup.contributor);
+    setup
+        .escrow
+        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::Other, &true);
+
+    assert_eq!(setup.token.balance(&setup.contributor), before + amount);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+    let escrow_info = setup.escrow.get_escrow_info(&bounty_id);
+    assert_eq!(escrow_info.status, EscrowStatus::Released);
+    assert_eq!(escrow_info.remaining_amount, 0);
+    assert!(setup.escrow.try_get_pending_claim(&bounty_id).is_err());
+}
+
+#[test]
+fn test_authorize_claim_two_step_leaves_funds_until_claim() {
+    let setup = TestSetup::new();
+    let bounty_id = 116_u64;
+    let amount = 2_500_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+
+    let before = setup.token.balance(&setup.contributor);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), before);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).status,
+        EscrowStatus::Locked
+    );
+
+    setup.escrow.claim(&bounty_id);
+    assert_eq!(setup.token.balance(&setup.contributor), before + amount);
+}
+
+// ============================================================================
+// CONTRIBUTOR ACKNOWLEDGMENT TESTS
+// ============================================================================
+
+#[test]
+fn test_release_blocked_until_contributor_acknowledges() {
+    let setup = TestSetup::new();
+    let bounty_id = 117_u64;
+    let amount = 3_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_require_ack(&bounty_id, &true);
+    assert!(setup.escrow.get_require_ack(&bounty_id));
+
+    let result = setup
+        .escrow
+        .try_release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(result.unwrap_err().unwrap(), Error::AcknowledgmentRequired);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    setup.escrow.acknowledge(&bounty_id, &setup.contributor);
+    assert!(setup.escrow.is_acknowledged(&bounty_id, &setup.contributor));
+
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+}
+
+#[test]
+fn test_other_acknowledgment_does_not_displace_contributor() {
+    let setup = TestSetup::new();
+    let bounty_id = 128_u64;
+    let amount = 3_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let other = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_require_ack(&bounty_id, &true);
+    setup.escrow.acknowledge(&bounty_id, &setup.contributor);
+    setup.escrow.acknowledge(&bounty_id, &other);
+
+    assert!(setup.escrow.is_acknowledged(&bounty_id, &setup.contributor));
+    assert!(setup.escrow.is_acknowledged(&bounty_id, &other));
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+}
+
+#[test]
+fn test_claim_blocked_until_contributor_acknowledges() {
+    let setup = TestSetup::new();
+    let bounty_id = 118_u64;
+    let amount = 2_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_require_ack(&bounty_id, &true);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+
+    let result = setup.escrow.try_claim(&bounty_id);
+    assert_eq!(result.unwrap_err().unwrap(), Error::AcknowledgmentRequired);
+
+    setup.escrow.acknowledge(&bounty_id, &setup.contributor);
+    setup.escrow.claim(&bounty_id);
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+}
+
+#[test]
+fn test_release_without_ack_requirement_needs_no_acknowledgment() {
+    let setup = TestSetup::new();
+    let bounty_id = 119_u64;
+    let amount = 1_500_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    assert!(!setup.escrow.get_require_ack(&bounty_id));
+
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), amount);
+}
+
+// ============================================================================
+// CLAIM DELEGATION TESTS
+// ============================================================================
+
+#[test]
+fn test_claim_pays_delegate_address() {
+    let setup = TestSetup::new();
+    let bounty_id = 120_u64;
+    let amount = 4_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let cold_wallet = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+
+    setup.escrow.set_claim_delegate(&bounty_id, &cold_wallet);
+    assert_eq!(
+        setup.escrow.get_claim_delegate(&bounty_id),
+        Some(cold_wallet.clone())
+    );
+
+    setup.escrow.claim(&bounty_id);
+
+    assert_eq!(setup.token.balance(&cold_wallet), amount);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+}
+
+#[test]
+fn test_delegate_does_not_survive_cancelled_claim() {
+    let setup = TestSetup::new();
+    let bounty_id = 122_u64;
+    let amount = 4_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let old_delegate = Address::generate(&setup.env);
+    let new_recipient = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+    setup.escrow.set_claim_delegate(&bounty_id, &old_delegate);
+    setup
+        .escrow
+        .cancel_pending_claim(&bounty_id, &DisputeOutcome::CancelledByAdmin);
+    assert_eq!(setup.escrow.get_claim_delegate(&bounty_id), None);
+
+    setup
+        .escrow
+        .authorize_claim(&bounty_id, &new_recipient, &DisputeReason::Other, &false);
+    assert_eq!(setup.escrow.get_claim_delegate(&bounty_id), None);
+    setup.escrow.claim(&bounty_id);
+
+    assert_eq!(setup.token.balance(&new_recipient), amount);
+    assert_eq!(setup.token.balance(&old_delegate), 0);
+}
+
+#[test]
+#[should_panic]
+fn test_non_claimant_cannot_set_claim_delegate() {
+    let setup = TestSetup::new();
+    let bounty_id = 121_u64;
+    let amount = 4_000_i128;
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    let attacker = Address::generate(&setup.env);
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
+    setup.escrow.set_claim_window(&500_u64);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::Other,
+        &false,
+    );
+
+    setup.env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &setup.escrow.address,
+            fn_name: "set_claim_delegate",
+            args: (bounty_id, attacker.clone()).into_val(&setup.env),
+            sub_invokes: &[],
+        },
+    }]);
+    setup.escrow.set_claim_delegate(&bounty_id, &attacker);
+}
+
 // ============================================================================
 // BATCH LOCK AND RELEASE FAILURE MODE TESTS
 // ============================================================================
@@ -1147,7 +1556,7 @@ fn test_batch_lock_funds_duplicate_bounty_id() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
 
     let items = vec![
         &setup.env,
@@ -1329,7 +1738,7 @@ fn test_batch_lock_funds_first_valid_second_exists() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &1000, &deadline);
+        .lock_funds(&setup.depositor, &2, &1000, &deadline, &None);
 
     let items = vec![
         &setup.env,
@@ -1359,7 +1768,7 @@ fn test_batch_operations_atomicity() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
 
     let items = vec![
         &setup.env,
@@ -1387,13 +1796,13 @@ fn test_batch_release_funds_success() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &3, &3000, &deadline);
+        .lock_funds(&setup.depositor, &3, &3000, &deadline, &None);
 
     let contributor1 = Address::generate(&setup.env);
     let contributor2 = Address::generate(&setup.env);
@@ -1436,13 +1845,13 @@ fn test_batch_release_funds_deterministic_ordering_by_bounty_id() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &10, &1000, &deadline);
+        .lock_funds(&setup.depositor, &10, &1000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &20, &2000, &deadline);
+        .lock_funds(&setup.depositor, &20, &2000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &30, &3000, &deadline);
+        .lock_funds(&setup.depositor, &30, &3000, &deadline, &None);
 
     let contributor10 = Address::generate(&setup.env);
     let contributor20 = Address::generate(&setup.env);
@@ -1487,7 +1896,7 @@ fn test_batch_release_funds_single_item() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
 
     let contributor = Address::generate(&setup.env);
     let items = vec![
@@ -1560,12 +1969,12 @@ fn test_batch_release_funds_already_released() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
     setup.escrow.release_funds(&1, &setup.contributor);
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &None);
 
     let contributor2 = Address::generate(&setup.env);
 
@@ -1592,7 +2001,7 @@ fn test_batch_release_funds_duplicate_in_batch() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
 
     let contributor = Address::generate(&setup.env);
 
@@ -1619,7 +2028,7 @@ fn test_batch_release_funds_first_valid_second_not_found() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
 
     let contributor = Address::generate(&setup.env);
     let items = vec![
@@ -1645,10 +2054,10 @@ fn test_batch_release_funds_mixed_locked_and_refunded() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &None);
 
     setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&2);
@@ -1677,10 +2086,10 @@ fn test_batch_release_funds_non_adjacent_duplicates() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &None);
 
     let contributor = Address::generate(&setup.env);
     let items = vec![
@@ -1798,13 +2207,13 @@ fn test_batch_release_funds_to_multiple_contributors() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &deadline);
+        .lock_funds(&setup.depositor, &1, &1000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &deadline);
+        .lock_funds(&setup.depositor, &2, &2000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &3, &3000, &deadline);
+        .lock_funds(&setup.depositor, &3, &3000, &deadline, &None);
 
     let contributor1 = Address::generate(&setup.env);
     let contributor2 = Address::generate(&setup.env);
@@ -1834,3 +2243,500 @@ fn test_batch_release_funds_to_multiple_contributors() {
     assert_eq!(setup.token.balance(&contributor3), 3000);
     assert_eq!(setup.escrow.get_balance(), 0);
 }
+
+// ============================================================================
+// MULTISIG CONFIG VALIDATION TESTS
+// ============================================================================
+
+#[test]
+fn test_multisig_config_rejects_zero_required_with_live_threshold() {
+    let setup = TestSetup::new();
+    let signers = vec![
+        &setup.env,
+        Address::generate(&setup.env),
+        Address::generate(&setup.env),
+    ];
+
+    let res = setup
+        .escrow
+        .try_update_multisig_config(&10_000_i128, &signers, &0_u32);
+    assert_eq!(res, Err(Ok(Error::InvalidMultisigConfig)));
+
+    // Multisig can still be switched off explicitly with an unreachable threshold
+    setup
+        .escrow
+        .update_multisig_config(&i128::MAX, &signers, &0_u32);
+    assert_eq!(setup.escrow.get_multisig_config().required_signatures, 0);
+}
+
+#[test]
+fn test_multisig_config_rejects_duplicate_signers() {
+    let setup = TestSetup::new();
+    let signer_a = Address::generate(&setup.env);
+    let signer_b = Address::generate(&setup.env);
+    let signers = vec![
+        &setup.env,
+        signer_a.clone(),
+        signer_b.clone(),
+        signer_a.clone(),
+    ];
+
+    let res = setup
+        .escrow
+        .try_update_multisig_config(&10_000_i128, &signers, &2_u32);
+    assert_eq!(res, Err(Ok(Error::InvalidMultisigConfig)));
+
+    let signers = vec![&setup.env, signer_a, signer_b];
+    setup
+        .escrow
+        .update_multisig_config(&10_000_i128, &signers, &2_u32);
+    assert_eq!(setup.escrow.get_multisig_config().signers.len(), 2);
+}
+
+// ============================================================================
+// MULTISIG APPROVAL EXPIRY TESTS
+// ============================================================================
+
+fn setup_large_release(setup: &TestSetup, bounty_id: u64) -> (Address, Address) {
+    let signer_a = Address::generate(&setup.env);
+    let signer_b = Address::generate(&setup.env);
+    let signers = vec![&setup.env, signer_a.clone(), signer_b.clone()];
+    setup
+        .escrow
+        .update_multisig_config(&1_000_i128, &signers, &2_u32);
+
+    let deadline = setup.env.ledger().timestamp() + 10_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &5_000_i128, &deadline, &None);
+    (signer_a, signer_b)
+}
+
+#[test]
+fn test_large_release_requires_approvals() {
+    let setup = TestSetup::new();
+    let bounty_id = 122_u64;
+    let (signer_a, signer_b) = setup_large_release(&setup, bounty_id);
+
+    setup
+        .escrow
+        .approve_large_release(&bounty_id, &setup.contributor, &signer_a);
+    let res = setup
+        .escrow
+        .try_release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(res, Err(Ok(Error::InsufficientApprovals)));
+
+    setup
+        .escrow
+        .approve_large_release(&bounty_id, &setup.contributor, &signer_b);
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 5_000);
+}
+
+#[test]
+fn test_expired_approvals_require_fresh_signatures() {
+    let setup = TestSetup::new();
+    let bounty_id = 123_u64;
+    let (signer_a, signer_b) = setup_large_release(&setup, bounty_id);
+
+    setup.escrow.set_approval_validity(&100_u64);
+    assert_eq!(setup.escrow.get_approval_validity(), Some(100));
+
+    setup
+        .escrow
+        .approve_large_release(&bounty_id, &setup.contributor, &signer_a);
+    setup
+        .escrow
+        .approve_large_release(&bounty_id, &setup.contributor, &signer_b);
+
+    // Both approvals lapse
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 101);
+    let res = setup
+        .escrow
+        .try_release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(res, Err(Ok(Error::InsufficientApprovals)));
+
+    // Only one signer re-approves: still short
+    setup
+        .escrow
+        .approve_large_release(&bounty_id, &setup.contributor, &signer_a);
+    let res = setup
+        .escrow
+        .try_release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(res, Err(Ok(Error::InsufficientApprovals)));
+
+    setup
+        .escrow
+        .approve_large_release(&bounty_id, &setup.contributor, &signer_b);
+    setup.escrow.release_funds(&bounty_id, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 5_000);
+}
+
+#[test]
+fn test_set_approval_validity_rejects_zero() {
+    let setup = TestSetup::new();
+
+    let res = setup.escrow.try_set_approval_validity(&0_u64);
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+    assert_eq!(setup.escrow.get_approval_validity(), None);
+}
+
+// ============================================================================
+// REFUND PREVIEW TESTS
+// ============================================================================
+
+#[test]
+fn test_preview_full_refund_without_fee() {
+    let setup = TestSetup::new();
+    let bounty_id = 126_u64;
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline, &None);
+
+    let preview = setup
+        .escrow
+        .preview_refund(&bounty_id, &None, &RefundMode::Full);
+    assert_eq!(
+        preview,
+        RefundPreview {
+            to_depositor: 1_000,
+            fee: 0,
+            remaining_after: 0,
+        }
+    );
+    // Previewing has no side effects
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).remaining_amount,
+        1_000
+    );
+}
+
+#[test]
+fn test_preview_partial_refund_with_cancellation_fee() {
+    let setup = TestSetup::new();
+    let bounty_id = 127_u64;
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline, &None);
+    // 5% cancellation fee
+    setup.escrow.set_cancellation_fee_rate(&500);
+
+    let preview = setup
+        .escrow
+        .preview_refund(&bounty_id, &Some(400), &RefundMode::Partial);
+    assert_eq!(preview.fee, 20);
+    assert_eq!(preview.to_depositor, 380);
+    assert_eq!(preview.remaining_after, 600);
+
+    // The actual refund pays out exactly what was previewed
+    let before = setup.token.balance(&setup.depositor);
+    setup
+        .escrow
+        .approve_refund(&bounty_id, &400, &setup.depositor, &RefundMode::Partial);
+    setup.escrow.refund(&bounty_id);
+    assert_eq!(setup.token.balance(&setup.depositor), before + 380);
+    assert_eq!(setup.token.balance(&setup._admin), 20);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&bounty_id).remaining_amount,
+        600
+    );
+}
+
+#[test]
+fn test_cancellation_fee_applies_to_every_refund_path() {
+    let setup = TestSetup::new();
+    let bounty_id = 129_u64;
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline, &None);
+    // 10% cancellation fee
+    setup.escrow.set_cancellation_fee_rate(&1_000);
+
+    // Delegated refunds withhold the fee too
+    let delegate = Address::generate(&setup.env);
+    let capability_id = setup.escrow.issue_capability(
+        &setup._admin,
+        &delegate,
+        &CapabilityAction::Refund,
+        &bounty_id,
+        &500,
+        &(deadline + 1_000),
+        &1,
+    );
+    let before = setup.token.balance(&setup.depositor);
+    setup
+        .escrow
+        .refund_with_capability(&bounty_id, &500, &delegate, &capability_id);
+    assert_eq!(setup.token.balance(&setup.depositor), before + 450);
+    assert_eq!(setup.token.balance(&setup._admin), 50);
+
+    // The dry run reports what the depositor actually receives
+    setup.env.ledger().set_timestamp(deadline + 1);
+    let sim = setup.escrow.dry_run_refund(&bounty_id);
+    assert!(sim.success);
+    assert_eq!(sim.amount, 450);
+
+    setup.escrow.refund(&bounty_id);
+    assert_eq!(setup.token.balance(&setup.depositor), before + 900);
+    assert_eq!(setup.token.balance(&setup._admin), 100);
+}
+
+#[test]
+fn test_preview_refund_rejects_amount_above_remaining() {
+    let setup = TestSetup::new();
+    let bounty_id = 128_u64;
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline, &None);
+
+    let res = setup
+        .escrow
+        .try_preview_refund(&bounty_id, &Some(1_001), &RefundMode::Partial);
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+    let res = setup
+        .escrow
+        .try_preview_refund(&bounty_id, &None, &RefundMode::Partial);
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+}
+
+// ============================================================================
+// LOCK MEMO TESTS
+// ============================================================================
+
+#[test]
+fn test_lock_funds_with_memo_stores_memo() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    let memo = String::from_str(&setup.env, "INV-2024-001");
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &1, &1_000, &deadline, &Some(memo.clone()));
+    let info = setup.escrow.get_escrow_info(&1);
+    assert_eq!(info.amount, 1_000);
+    assert_eq!(setup.escrow.get_lock_memo(&1), Some(memo));
+}
+
+#[test]
+fn test_lock_funds_without_memo() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &2, &1_000, &deadline, &None);
+    let info = setup.escrow.get_escrow_info(&2);
+    assert_eq!(info.status, EscrowStatus::Locked);
+    assert_eq!(setup.escrow.get_lock_memo(&2), None);
+}
+
+#[test]
+fn test_lock_funds_with_memo_rejects_long_memo() {
+    let setup = TestSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    let memo = String::from_bytes(&setup.env, &[b'x'; 129]);
+
+    let res = setup
+        .escrow
+        .try_lock_funds(&setup.depositor, &3, &1_000, &deadline, &Some(memo));
+    assert_eq!(res, Err(Ok(Error::MemoTooLong)));
+    assert!(setup.escrow.try_get_escrow_info(&3).is_err());
+}
+
+// ============================================================================
+// AUTO REFUND TESTS
+// ============================================================================
+
+#[test]
+fn test_auto_refund_expired_refunds_only_eligible() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &131, &1_000, &(now + 100), &None);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &132, &2_000, &(now + 5_000), &None);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &133, &3_000, &(now + 200), &None);
+    setup.env.ledger().set_timestamp(now + 1_000);
+
+    let before = setup.token.balance(&setup.depositor);
+    // 999 does not exist and is skipped
+    let ids = vec![&setup.env, 131_u64, 132, 133, 999];
+    assert_eq!(setup.escrow.auto_refund_expired(&ids, &10), 2);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&131).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&132).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(
+        setup.escrow.get_escrow_info(&133).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.token.balance(&setup.depositor), before + 4_000);
+
+    // Already refunded escrows are not refunded again
+    assert_eq!(setup.escrow.auto_refund_expired(&ids, &10), 0);
+}
+
+#[test]
+fn test_auto_refund_expired_respects_max() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &134, &1_000, &(now + 100), &None);
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &135, &1_000, &(now + 100), &None);
+    setup.env.ledger().set_timestamp(now + 1_000);
+
+    let ids = vec![&setup.env, 134_u64, 135];
+    assert_eq!(setup.escrow.auto_refund_expired(&ids, &1), 1);
+    assert_eq!(
+        setup.escrow.get_escrow_info(&135).status,
+        EscrowStatus::Locked
+    );
+}
+
+// ============================================================================
+// REFUND WARNING TESTS
+// ============================================================================
+
+#[test]
+fn test_emit_refund_warning_inside_window() {
+    let setup = TestSetup::new();
+    let bounty_id = 129_u64;
+    let now = setup.env.ledger().timestamp();
+    let deadline = now + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline, &None);
+    setup.escrow.set_refund_warning_window(&300);
+
+    // Too early: deadline is further away than the window
+    let res = setup.escrow.try_emit_refund_warning(&bounty_id);
+    assert_eq!(res, Err(Ok(Error::RefundWarningNotDue)));
+
+    setup.env.ledger().set_timestamp(now + 800);
+    let before = setup.env.events().all().len();
+    let remaining = setup.escrow.emit_refund_warning(&bounty_id);
+    assert_eq!(remaining, 200);
+    assert_eq!(setup.env.events().all().len(), before + 1);
+}
+
+#[test]
+fn test_emit_refund_warning_rejects_duplicate() {
+    let setup = TestSetup::new();
+    let bounty_id = 130_u64;
+    let now = setup.env.ledger().timestamp();
+    let deadline = now + 1_000;
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &bounty_id, &1_000, &deadline, &None);
+    setup.escrow.set_refund_warning_window(&300);
+
+    setup.env.ledger().set_timestamp(now + 900);
+    setup.escrow.emit_refund_warning(&bounty_id);
+
+    setup.env.ledger().set_timestamp(now + 950);
+    let res = setup.escrow.try_emit_refund_warning(&bounty_id);
+    assert_eq!(res, Err(Ok(Error::RefundWarningNotDue)));
+}
+
+// ============================================================================
+// REFUND CLAIMABLE FALLBACK TESTS
+// ============================================================================
+
+#[test]
+fn test_refund_falls_back_to_claimable_when_push_fails() {
+    let setup = TestSetup::new_revocable();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &141, &1_000, &(now + 100), &None);
+    setup.escrow.set_refund_claimable_fallback(&true);
+
+    // A deauthorized account cannot receive the asset.
+    let balance_before = setup.token.balance(&setup.depositor);
+    setup.token_admin.set_authorized(&setup.depositor, &false);
+    setup.env.ledger().set_timestamp(now + 101);
+    setup.escrow.refund(&141);
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&141).status,
+        EscrowStatus::Refunded
+    );
+    assert_eq!(setup.escrow.get_claimable_refund(&setup.depositor), 1_000);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 1_000);
+    assert_eq!(setup.token.balance(&setup.depositor), balance_before);
+
+    setup.token_admin.set_authorized(&setup.depositor, &true);
+    let withdrawn = setup.escrow.withdraw_claimable_refund(&setup.depositor);
+    assert_eq!(withdrawn, 1_000);
+    assert_eq!(setup.escrow.get_claimable_refund(&setup.depositor), 0);
+    assert_eq!(setup.token.balance(&setup.escrow.address), 0);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        balance_before + 1_000
+    );
+}
+
+#[test]
+fn test_refund_reverts_when_push_fails_and_fallback_disabled() {
+    let setup = TestSetup::new_revocable();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &142, &1_000, &(now + 100), &None);
+
+    setup.token_admin.set_authorized(&setup.depositor, &false);
+    setup.env.ledger().set_timestamp(now + 101);
+    assert!(setup.escrow.try_refund(&142).is_err());
+
+    assert_eq!(
+        setup.escrow.get_escrow_info(&142).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(setup.escrow.get_claimable_refund(&setup.depositor), 0);
+}
+
+#[test]
+fn test_refund_pushes_normally_with_fallback_enabled() {
+    let setup = TestSetup::new();
+    let now = setup.env.ledger().timestamp();
+    setup
+        .escrow
+        .lock_funds(&setup.depositor, &143, &1_000, &(now + 100), &None);
+    setup.escrow.set_refund_claimable_fallback(&true);
+
+    let balance_before = setup.token.balance(&setup.depositor);
+    setup.env.ledger().set_timestamp(now + 101);
+    setup.escrow.refund(&143);
+
+    assert_eq!(setup.escrow.get_claimable_refund(&setup.depositor), 0);
+    assert_eq!(
+        setup.token.balance(&setup.depositor),
+        balance_before + 1_000
+    );
+}
+
+#[test]
+fn test_withdraw_claimable_refund_without_balance_fails() {
+    let setup = TestSetup::new();
+    let res = setup.escrow.try_withdraw_claimable_refund(&setup.depositor);
+    assert_eq!(res, Err(Ok(Error::InvalidAmount)));
+}
@@ -122,8 +122,13 @@ impl<'a> TestCtx<'a> {
 
     /// Lock a single bounty via the normal single-item path (for setup).
     fn lock_one(&self, bounty_id: u64) {
-        self.client
-            .lock_funds(&self.depositor, &bounty_id, &AMOUNT, &self.deadline());
+        self.client.lock_funds(
+            &self.depositor,
+            &bounty_id,
+            &AMOUNT,
+            &self.deadline(),
+            &None,
+        );
     }
 
     /// Lock `n` bounties starting at bounty_id 1 (for release tests setup).
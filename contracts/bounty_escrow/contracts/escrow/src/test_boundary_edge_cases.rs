@@ -42,40 +42,61 @@ fn test_focused_amount_and_deadline_boundaries() {
     // Most other tests just accept success, so we focus on valid boundaries
 
     // At minimum should succeed
-    client.lock_funds(&depositor, &101u64, &min_amount, &future_deadline);
+    client.lock_funds(&depositor, &101u64, &min_amount, &future_deadline, &None);
     let info = client.get_escrow_info(&101u64);
-    assert_eq!(info.amount, min_amount, "stored amount should match minimum");
+    assert_eq!(
+        info.amount, min_amount,
+        "stored amount should match minimum"
+    );
 
     // Just above minimum should succeed
-    client.lock_funds(&depositor, &102u64, &(min_amount + 1), &future_deadline);
+    client.lock_funds(
+        &depositor,
+        &102u64,
+        &(min_amount + 1),
+        &future_deadline,
+        &None,
+    );
 
     // Just below maximum should succeed
-    client.lock_funds(&depositor, &103u64, &(max_amount - 1), &future_deadline);
+    client.lock_funds(
+        &depositor,
+        &103u64,
+        &(max_amount - 1),
+        &future_deadline,
+        &None,
+    );
 
     // At maximum should succeed
-    client.lock_funds(&depositor, &104u64, &max_amount, &future_deadline);
+    client.lock_funds(&depositor, &104u64, &max_amount, &future_deadline, &None);
     let info = client.get_escrow_info(&104u64);
     assert_eq!(info.amount, max_amount, "stored amount should match maximum");
 
     // Test 2: Deadline boundaries (past, now, far future, NO_DEADLINE)
     // Past deadline: should still create escrow but allow immediate refund
     let past_deadline = now.saturating_sub(1);
-    client.lock_funds(&depositor, &200u64, &(min_amount + 10), &past_deadline);
+    client.lock_funds(
+        &depositor,
+        &200u64,
+        &(min_amount + 10),
+        &past_deadline,
+        &None,
+    );
     // Verify it can be refunded immediately
     client.refund(&200u64);
 
     // Exact current timestamp
-    client.lock_funds(&depositor, &201u64, &(min_amount + 10), &now);
+    client.lock_funds(&depositor, &201u64, &(min_amount + 10), &now, &None);
 
     // Far future (large but not overflow)
     let far_future = now + 1_000_000;
-    client.lock_funds(&depositor, &202u64, &(min_amount + 10), &far_future);
+    client.lock_funds(&depositor, &202u64, &(min_amount + 10), &far_future, &None);
     let info = client.get_escrow_info(&202u64);
     assert_eq!(info.deadline, far_future, "stored deadline should match far future");
 
     // NO_DEADLINE sentinel (u64::MAX)
     let no_deadline = u64::MAX;
-    client.lock_funds(&depositor, &203u64, &(min_amount + 10), &no_deadline);
+    client.lock_funds(&depositor, &203u64, &(min_amount + 10), &no_deadline, &None);
     let info = client.get_escrow_info(&203u64);
     assert_eq!(info.deadline, no_deadline, "stored deadline should be NO_DEADLINE");
 
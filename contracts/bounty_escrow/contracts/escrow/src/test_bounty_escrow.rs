@@ -89,6 +89,7 @@ fn test_events_emit_v2_version_tags_for_all_bounty_emitters() {
         &1,
         &10_000,
         &(env.ledger().timestamp() + 10),
+        &None,
     );
     assert_current_call_has_versioned_contract_event(&env, &contract_id);
 
@@ -118,7 +119,7 @@ fn test_lock_fund() {
 
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
     // Get all events emitted
     let events = env.events().all();
@@ -150,7 +151,7 @@ fn test_release_fund() {
 
     token_admin_client.mint(&depositor, &amount);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
     client.release_funds(&bounty_id, &contributor);
 
@@ -176,17 +177,17 @@ fn test_non_transferable_rewards_flag() {
     let deadline = env.ledger().timestamp() + 3600;
 
     // Lock with non_transferable_rewards = true
-    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.lock_funds(&depositor, &1, &1_000, &deadline, &None);
 
     // Lock another bounty with non_transferable_rewards = None (default)
-    client.lock_funds(&depositor, &2, &2_000, &deadline);
+    client.lock_funds(&depositor, &2, &2_000, &deadline, &None);
     assert!(
         !client.get_non_transferable_rewards(&2),
         "bounty 2 should not be marked non-transferable"
     );
 
     // Bounty 3 with explicit false
-    client.lock_funds(&depositor, &3, &500, &deadline);
+    client.lock_funds(&depositor, &3, &500, &deadline, &None);
 }
 
 #[test]
@@ -217,7 +218,7 @@ fn test_lock_funds_zero_amount_edge_case() {
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1_000);
 
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
     let escrow = client.get_escrow_info(&bounty_id);
     assert_eq!(escrow.amount, 0);
@@ -240,7 +241,7 @@ fn test_lock_funds_insufficient_balance_rejected() {
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &100);
 
-    client.lock_funds(&depositor, &bounty_id, &1_000, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &1_000, &deadline, &None);
 }
 
 #[test]
@@ -259,7 +260,7 @@ fn test_refund_allows_exact_deadline_boundary() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &amount);
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
     env.ledger().set_timestamp(deadline);
     client.refund(&bounty_id);
@@ -285,7 +286,7 @@ fn test_maximum_lock_and_release_path() {
     let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &amount);
-    client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
     assert_eq!(token_client.balance(&client.address), amount);
     client.release_funds(&bounty_id, &contributor);
@@ -308,9 +309,9 @@ fn test_integration_multi_bounty_lifecycle() {
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &10_000);
 
-    client.lock_funds(&depositor, &201, &3_000, &(now + 100));
-    client.lock_funds(&depositor, &202, &2_000, &(now + 200));
-    client.lock_funds(&depositor, &203, &1_000, &(now + 300));
+    client.lock_funds(&depositor, &201, &3_000, &(now + 100), &None);
+    client.lock_funds(&depositor, &202, &2_000, &(now + 200), &None);
+    client.lock_funds(&depositor, &203, &1_000, &(now + 300), &None);
     assert_eq!(token_client.balance(&client.address), 6_000);
 
     client.release_funds(&201, &contributor);
@@ -356,8 +357,8 @@ fn test_multi_token_balance_accounting_isolated_across_escrow_instances() {
     token_admin_client_a.mint(&depositor, &5_000);
     token_admin_client_b.mint(&depositor, &7_000);
 
-    client_a.lock_funds(&depositor, &11, &1_200, &(now + 120));
-    client_b.lock_funds(&depositor, &22, &3_400, &(now + 240));
+    client_a.lock_funds(&depositor, &11, &1_200, &(now + 120), &None);
+    client_b.lock_funds(&depositor, &22, &3_400, &(now + 240), &None);
 
     // Per-token locked balances are tracked independently.
     assert_eq!(client_a.get_balance(), 1_200);
@@ -413,7 +414,7 @@ fn test_property_fuzz_lock_release_refund_invariants() {
 
     // Lock deterministic fuzz cases.
     for (id, amount, deadline) in fuzz_cases.iter() {
-        client.lock_funds(&depositor, id, amount, deadline);
+        client.lock_funds(&depositor, id, amount, deadline, &None);
     }
 
     let mut expected_locked_balance = client.get_balance();
@@ -454,7 +455,7 @@ fn test_stress_high_load_bounty_operations() {
     for i in 0..40_u64 {
         let amount = 100 + (i as i128 % 10);
         let deadline = now + 30 + i;
-        client.lock_funds(&depositor, &(5_000 + i), &amount, &deadline);
+        client.lock_funds(&depositor, &(5_000 + i), &amount, &deadline, &None);
     }
     assert!(client.get_balance() > 0);
 
@@ -491,7 +492,7 @@ fn test_gas_proxy_event_footprint_per_operation_is_constant() {
     let before_lock = env.events().all().len();
     for offset in 0..20_u64 {
         let id = 8_001 + offset;
-        client.lock_funds(&depositor, &id, &10, &(now + 100 + offset));
+        client.lock_funds(&depositor, &id, &10, &(now + 100 + offset), &None);
     }
     let after_locks = env.events().all().len();
     let lock_event_growth = after_locks - before_lock;
@@ -1012,7 +1013,7 @@ fn test_lock_funds_below_minimum_rejected() {
 
     // Policy: min=100, max=10_000.  Attempting to lock 50 must be rejected.
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
-    client.lock_funds(&depositor, &1, &50_i128, &deadline);
+    client.lock_funds(&depositor, &1, &50_i128, &deadline, &None);
 }
 
 /// Locking an amount strictly above the configured maximum must be rejected.
@@ -1033,7 +1034,7 @@ fn test_lock_funds_above_maximum_rejected() {
 
     // Policy: min=100, max=10_000.  Attempting to lock 50_000 must be rejected.
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
-    client.lock_funds(&depositor, &2, &50_000_i128, &deadline);
+    client.lock_funds(&depositor, &2, &50_000_i128, &deadline, &None);
 }
 
 /// An amount equal to the configured minimum is on the inclusive boundary and
@@ -1054,7 +1055,7 @@ fn test_lock_funds_at_exact_minimum_succeeds() {
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // amount == min → allowed (inclusive lower bound)
-    client.lock_funds(&depositor, &3, &100_i128, &deadline);
+    client.lock_funds(&depositor, &3, &100_i128, &deadline, &None);
 
     let escrow = client.get_escrow_info(&3);
     assert_eq!(escrow.amount, 100);
@@ -1079,7 +1080,7 @@ fn test_lock_funds_at_exact_maximum_succeeds() {
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // amount == max → allowed (inclusive upper bound)
-    client.lock_funds(&depositor, &4, &10_000_i128, &deadline);
+    client.lock_funds(&depositor, &4, &10_000_i128, &deadline, &None);
 
     let escrow = client.get_escrow_info(&4);
     assert_eq!(escrow.amount, 10_000);
@@ -1102,7 +1103,7 @@ fn test_lock_funds_within_range_succeeds() {
     token_admin_client.mint(&depositor, &5_000);
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
-    client.lock_funds(&depositor, &5, &5_000_i128, &deadline);
+    client.lock_funds(&depositor, &5, &5_000_i128, &deadline, &None);
 
     let escrow = client.get_escrow_info(&5);
     assert_eq!(escrow.amount, 5_000);
@@ -1145,8 +1146,8 @@ fn test_no_policy_set_allows_any_positive_amount() {
     token_admin_client.mint(&depositor, &1_000_000);
 
     // No set_amount_policy call — all positive amounts must be accepted.
-    client.lock_funds(&depositor, &6, &1_i128, &deadline);
-    client.lock_funds(&depositor, &7, &999_999_i128, &deadline);
+    client.lock_funds(&depositor, &6, &1_i128, &deadline, &None);
+    client.lock_funds(&depositor, &7, &999_999_i128, &deadline, &None);
 
     assert_eq!(client.get_escrow_info(&6).amount, 1);
     assert_eq!(client.get_escrow_info(&7).amount, 999_999);
@@ -1190,7 +1191,7 @@ fn test_amount_policy_can_be_updated_by_admin() {
 
     // Loosen the policy: min=10 — amount 500 must now be accepted.
     client.set_amount_policy(&admin, &10_i128, &50_000_i128);
-    client.lock_funds(&depositor, &8, &500_i128, &deadline);
+    client.lock_funds(&depositor, &8, &500_i128, &deadline, &None);
 
     assert_eq!(client.get_escrow_info(&8).amount, 500);
 }
@@ -1214,7 +1215,7 @@ fn test_one_below_minimum_boundary_rejected() {
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // 99 == min(100) - 1 → must be rejected.
-    client.lock_funds(&depositor, &9, &99_i128, &deadline);
+    client.lock_funds(&depositor, &9, &99_i128, &deadline, &None);
 }
 
 /// max + 1 is the tightest possible value above the maximum boundary and must
@@ -1236,7 +1237,7 @@ fn test_one_above_maximum_boundary_rejected() {
 
     client.set_amount_policy(&admin, &100_i128, &10_000_i128);
     // 10_001 == max(10_000) + 1 → must be rejected.
-    client.lock_funds(&depositor, &10, &10_001_i128, &deadline);
+    client.lock_funds(&depositor, &10, &10_001_i128, &deadline, &None);
 }
 
 /// (#501) Create many bounties (bounded for CI) and ensure counts and sampling
@@ -1259,7 +1260,7 @@ fn test_max_bounty_count_queries_accurate() {
     token_admin_client.mint(&depositor, &total);
 
     for i in 1..=N {
-        client.lock_funds(&depositor, &i, &100_i128, &deadline);
+        client.lock_funds(&depositor, &i, &100_i128, &deadline, &None);
     }
 
     assert_eq!(client.get_escrow_count(), N as u32);
@@ -1297,11 +1298,11 @@ fn test_anti_abuse_exact_rate_limit_then_exceeded() {
     token_admin_client.mint(&depositor, &10_000);
 
     // Exactly 2 locks must succeed (different bounty_ids)
-    client.lock_funds(&depositor, &1, &100, &deadline);
-    client.lock_funds(&depositor, &2, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
 
     // Third lock in same window must panic
-    client.lock_funds(&depositor, &3, &100, &deadline);
+    client.lock_funds(&depositor, &3, &100, &deadline, &None);
 }
 
 /// Exactly at limit: max_operations locks succeed; no panic at the boundary.
@@ -1322,9 +1323,9 @@ fn test_anti_abuse_exact_rate_limit_boundary_succeeds() {
 
     token_admin_client.mint(&depositor, &10_000);
 
-    client.lock_funds(&depositor, &1, &100, &deadline);
-    client.lock_funds(&depositor, &2, &100, &deadline);
-    client.lock_funds(&depositor, &3, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
+    client.lock_funds(&depositor, &3, &100, &deadline, &None);
 
     assert_eq!(client.get_escrow_count(), 3);
 }
@@ -1351,10 +1352,10 @@ fn test_anti_abuse_cooldown_violation_panics() {
 
     token_admin_client.mint(&depositor, &10_000);
 
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
 
     env.ledger().set_timestamp(start + 50);
-    client.lock_funds(&depositor, &2, &100, &deadline);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
 }
 
 /// After cooldown period, next lock succeeds.
@@ -1377,10 +1378,10 @@ fn test_anti_abuse_after_cooldown_succeeds() {
 
     token_admin_client.mint(&depositor, &10_000);
 
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
 
     env.ledger().set_timestamp(start + 61);
-    client.lock_funds(&depositor, &2, &100, &deadline);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
 
     assert_eq!(client.get_escrow_count(), 2);
 }
@@ -1406,11 +1407,287 @@ fn test_anti_abuse_whitelisted_address_bypasses_checks() {
 
     // More than max_operations without advancing time; whitelisted so all succeed
     for i in 1..=5 {
-        client.lock_funds(&depositor, &i, &100, &deadline);
+        client.lock_funds(&depositor, &i, &100, &deadline, &None);
     }
     assert_eq!(client.get_escrow_count(), 5);
 }
 
+/// With auto-whitelist enabled, a first successful lock whitelists the depositor
+/// so a second lock inside the cooldown is not throttled.
+#[test]
+fn test_anti_abuse_auto_whitelist_on_lock_bypasses_cooldown() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.update_anti_abuse_config(&3600, &10, &100);
+    client.set_auto_whitelist_on_lock(&true);
+    assert!(client.get_auto_whitelist_on_lock());
+
+    token_admin_client.mint(&depositor, &10_000);
+
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    env.ledger().set_timestamp(start + 50);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
+
+    assert_eq!(client.get_escrow_count(), 2);
+}
+
+/// Auto-whitelist is off by default, so a second lock inside the cooldown panics.
+#[test]
+#[should_panic(expected = "Operation in cooldown period")]
+fn test_anti_abuse_auto_whitelist_disabled_by_default() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    // The cooldown only applies once a prior lock has a non-zero timestamp
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.update_anti_abuse_config(&3600, &10, &100);
+    assert!(!client.get_auto_whitelist_on_lock());
+
+    token_admin_client.mint(&depositor, &10_000);
+
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    env.ledger().set_timestamp(start + 50);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
+}
+
+/// Rate limit status reflects window usage and remaining cooldown.
+#[test]
+fn test_rate_limit_status_partway_through_window() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.update_anti_abuse_config(&3600, &5, &60);
+
+    token_admin_client.mint(&depositor, &10_000);
+
+    let status = client.get_rate_limit_status(&depositor);
+    assert_eq!(status.operations_in_window, 0);
+    assert_eq!(status.cooldown_remaining, 0);
+
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    env.ledger().set_timestamp(start + 100);
+    client.lock_funds(&depositor, &2, &100, &deadline, &None);
+    env.ledger().set_timestamp(start + 120);
+
+    let status = client.get_rate_limit_status(&depositor);
+    assert_eq!(status.operations_in_window, 2);
+    assert_eq!(status.max_operations, 5);
+    assert_eq!(status.cooldown_remaining, 40);
+    assert!(!status.is_whitelisted);
+
+    // Once the window has elapsed, no operations count against it
+    env.ledger().set_timestamp(start + 3600);
+    let status = client.get_rate_limit_status(&depositor);
+    assert_eq!(status.operations_in_window, 0);
+    assert_eq!(status.cooldown_remaining, 0);
+}
+
+/// Whitelisted addresses are reported as such and accumulate no usage.
+#[test]
+fn test_rate_limit_status_whitelisted_address() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+
+    client.set_whitelist_entry(&depositor, &true);
+    token_admin_client.mint(&depositor, &10_000);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+
+    let status = client.get_rate_limit_status(&depositor);
+    assert!(status.is_whitelisted);
+    assert_eq!(status.operations_in_window, 0);
+    assert_eq!(status.cooldown_remaining, 0);
+}
+
+/// Register an ed25519 exemption signer for the anti-abuse admin.
+fn register_exemption_signer(
+    env: &Env,
+    client: &BountyEscrowContractClient,
+) -> ed25519_dalek::SigningKey {
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+    let public_key = soroban_sdk::BytesN::from_array(env, &key.verifying_key().to_bytes());
+    client.set_rate_limit_exemption_signer(&public_key);
+    key
+}
+
+fn sign_exemption(
+    env: &Env,
+    client: &BountyEscrowContractClient,
+    key: &ed25519_dalek::SigningKey,
+    address: &Address,
+    expiry: u64,
+) -> soroban_sdk::BytesN<64> {
+    use ed25519_dalek::Signer;
+    let payload = client.get_rate_limit_exemption_payload(address, &expiry);
+    let mut message = [0u8; 256];
+    let len = payload.len() as usize;
+    payload.copy_into_slice(&mut message[..len]);
+    soroban_sdk::BytesN::from_array(env, &key.sign(&message[..len]).to_bytes())
+}
+
+/// A valid exemption token lets a backend lock past the limit and cooldown.
+#[test]
+fn test_rate_limit_exemption_bypasses_limit() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let backend = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_anti_abuse_admin(&admin);
+    client.update_anti_abuse_config(&3600, &1, &60);
+    let key = register_exemption_signer(&env, &client);
+
+    let expiry = start + 600;
+    let signature = sign_exemption(&env, &client, &key, &backend, expiry);
+    client.submit_rate_limit_exemption(&backend, &expiry, &signature);
+
+    token_admin_client.mint(&backend, &10_000);
+    client.lock_funds(&backend, &1, &100, &deadline, &None);
+    client.lock_funds(&backend, &2, &100, &deadline, &None);
+    client.lock_funds(&backend, &3, &100, &deadline, &None);
+    assert_eq!(client.get_escrow_count(), 3);
+}
+
+/// Once the exemption expires, the normal limit applies again.
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_expired_rate_limit_exemption_is_ignored() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let backend = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_anti_abuse_admin(&admin);
+    client.update_anti_abuse_config(&3600, &1, &0);
+    let key = register_exemption_signer(&env, &client);
+
+    let expiry = start + 600;
+    let signature = sign_exemption(&env, &client, &key, &backend, expiry);
+    client.submit_rate_limit_exemption(&backend, &expiry, &signature);
+
+    env.ledger().set_timestamp(expiry);
+    token_admin_client.mint(&backend, &10_000);
+    client.lock_funds(&backend, &1, &100, &deadline, &None);
+    client.lock_funds(&backend, &2, &100, &deadline, &None);
+}
+
+/// Tokens signed by another key, or already expired, are rejected on submission.
+#[test]
+fn test_rate_limit_exemption_rejects_bad_tokens() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let backend = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, _token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_anti_abuse_admin(&admin);
+    register_exemption_signer(&env, &client);
+
+    let impostor = ed25519_dalek::SigningKey::from_bytes(&[9; 32]);
+    let forged = sign_exemption(&env, &client, &impostor, &backend, start + 600);
+    assert!(client
+        .try_submit_rate_limit_exemption(&backend, &(start + 600), &forged)
+        .is_err());
+
+    let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+    let stale = sign_exemption(&env, &client, &key, &backend, start);
+    assert_eq!(
+        client.try_submit_rate_limit_exemption(&backend, &start, &stale),
+        Err(Ok(ContractError::InvalidDeadline))
+    );
+}
+
+/// Rotating the exemption signer voids tokens submitted under the old key.
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_rate_limit_exemption_voided_by_signer_rotation() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let backend = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_anti_abuse_admin(&admin);
+    client.update_anti_abuse_config(&3600, &1, &0);
+    let key = register_exemption_signer(&env, &client);
+
+    let expiry = start + 600;
+    let signature = sign_exemption(&env, &client, &key, &backend, expiry);
+    client.submit_rate_limit_exemption(&backend, &expiry, &signature);
+
+    let rotated = ed25519_dalek::SigningKey::from_bytes(&[8; 32]);
+    client.set_rate_limit_exemption_signer(&soroban_sdk::BytesN::from_array(
+        &env,
+        &rotated.verifying_key().to_bytes(),
+    ));
+
+    token_admin_client.mint(&backend, &10_000);
+    client.lock_funds(&backend, &1, &100, &deadline, &None);
+    client.lock_funds(&backend, &2, &100, &deadline, &None);
+}
+
 // =============================================================================
 // Admin and config updates (Issue #465)
 // =============================================================================
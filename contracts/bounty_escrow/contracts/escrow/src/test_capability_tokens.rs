@@ -59,7 +59,7 @@ impl CapabilitySetup {
     fn lock(&self, bounty_id: u64, amount: i128) {
         let deadline = self.env.ledger().timestamp() + 10_000;
         self.client
-            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline, &None);
     }
 }
 
@@ -142,7 +142,7 @@ fn test_claim_with_capability() {
     setup.client.set_claim_window(&600);
     setup
         .client
-        .authorize_claim(&2, &setup.recipient, &DisputeReason::Other);
+        .authorize_claim(&2, &setup.recipient, &DisputeReason::Other, &false);
 
     let expiry = setup.env.ledger().timestamp() + 120;
     let capability_id = setup.client.issue_capability(
@@ -243,7 +243,7 @@ fn test_capability_cannot_exceed_owner_authority() {
     setup.client.set_claim_window(&300);
     setup
         .client
-        .authorize_claim(&4, &setup.recipient, &DisputeReason::Other);
+        .authorize_claim(&4, &setup.recipient, &DisputeReason::Other, &false);
     let wrong_claim_owner = setup.client.try_issue_capability(
         &setup.admin,
         &setup.delegate,
@@ -36,7 +36,7 @@ mod test_claim_tickets {
     ) {
         let contract = BountyEscrowContract;
         contract
-            .lock_funds(env.clone(), depositor, bounty_id, amount, deadline)
+            .lock_funds(env.clone(), depositor, bounty_id, amount, deadline, &None)
             .expect("lock_funds failed");
     }
 
@@ -70,7 +70,7 @@ fn test_core_function_signatures_stable() {
     // Core functions
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
-    client.lock_funds(&depositor, &1, &1000, &100);
+    client.lock_funds(&depositor, &1, &1000, &100, &None);
     client.release_funds(&1, &contributor);
 
     // Query functions
@@ -97,7 +97,7 @@ fn test_storage_keys_accessible() {
     // Initialize and create escrow
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
-    client.lock_funds(&depositor, &1, &1000, &100);
+    client.lock_funds(&depositor, &1, &1000, &100, &None);
 
     // Verify we can still access stored data
     let escrow = client.get_escrow_info(&1);
@@ -130,7 +130,7 @@ fn test_old_client_compatibility() {
     // Simulate old client code that doesn't use new features
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
-    client.lock_funds(&depositor, &1, &1000, &100);
+    client.lock_funds(&depositor, &1, &1000, &100, &None);
     client.release_funds(&1, &contributor);
 
     // Old client code should work without errors
@@ -182,7 +182,7 @@ fn test_new_features_dont_break_existing_workflows() {
     // Standard workflow without using new features
     client.init(&admin, &token);
     token_admin_client.mint(&depositor, &1000);
-    client.lock_funds(&depositor, &1, &1000, &100);
+    client.lock_funds(&depositor, &1, &1000, &100, &None);
 
     // New feature: amount policy (should not affect existing escrows)
     client.set_amount_policy(&admin, &100, &10000);
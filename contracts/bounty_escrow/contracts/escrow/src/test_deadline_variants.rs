@@ -61,59 +61,66 @@ impl<'a> Setup<'a> {
 // =============================================================================
 // Zero deadline (deadline = 0)
 //
-// When deadline is 0 the check `now < deadline` is always false for u64,
-// so a refund is eligible immediately without any admin approval or waiting.
+// A zero deadline means "not supplied". The contract substitutes
+// `now + default_lock_duration` when an admin has configured a default, and
+// rejects the lock with `InvalidDeadline` otherwise.
 // =============================================================================
 
 #[test]
-fn test_zero_deadline_stored_correctly() {
+fn test_zero_deadline_rejected_without_default() {
     let s = Setup::new();
-    s.escrow.lock_funds(&s.depositor, &1, &500, &0);
+    let before = s.token.balance(&s.depositor);
 
-    let info = s.escrow.get_escrow_info(&1);
-    assert_eq!(info.deadline, 0);
-    assert_eq!(info.amount, 500);
-    assert_eq!(info.status, EscrowStatus::Locked);
+    let result = s.escrow.try_lock_funds(&s.depositor, &1, &500, &0, &None);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDeadline);
+
+    assert_eq!(s.token.balance(&s.depositor), before);
+    assert_eq!(s.escrow.get_default_lock_duration(), None);
 }
 
 #[test]
-fn test_zero_deadline_refund_succeeds_immediately() {
+fn test_zero_deadline_uses_default_lock_duration() {
     let s = Setup::new();
-    s.escrow.lock_funds(&s.depositor, &2, &1_000, &0);
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow.set_default_lock_duration(&3_600);
+    assert_eq!(s.escrow.get_default_lock_duration(), Some(3_600));
 
-    let before = s.token.balance(&s.depositor);
-    s.escrow.refund(&2);
+    s.escrow.lock_funds(&s.depositor, &2, &1_000, &0, &None);
 
     let info = s.escrow.get_escrow_info(&2);
-    assert_eq!(info.status, EscrowStatus::Refunded);
-    assert_eq!(s.token.balance(&s.depositor), before + 1_000);
-    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    assert_eq!(info.deadline, 4_600);
+    assert_eq!(info.status, EscrowStatus::Locked);
+
+    let result = s.escrow.try_refund(&2);
+    assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
+
+    s.env.ledger().set_timestamp(4_601);
+    s.escrow.refund(&2);
+    assert_eq!(s.escrow.get_escrow_info(&2).status, EscrowStatus::Refunded);
 }
 
 #[test]
-fn test_zero_deadline_refund_succeeds_after_time_advance() {
+fn test_explicit_deadline_ignores_default_lock_duration() {
     let s = Setup::new();
-    s.escrow.lock_funds(&s.depositor, &3, &800, &0);
-
-    s.env.ledger().set_timestamp(9_999_999);
+    s.escrow.set_default_lock_duration(&3_600);
 
-    s.escrow.refund(&3);
+    let deadline = s.env.ledger().timestamp() + 10;
+    s.escrow
+        .lock_funds(&s.depositor, &3, &800, &deadline, &None);
 
     let info = s.escrow.get_escrow_info(&3);
-    assert_eq!(info.status, EscrowStatus::Refunded);
+    assert_eq!(info.deadline, deadline);
 }
 
 #[test]
-fn test_zero_deadline_release_succeeds() {
+fn test_cleared_default_lock_duration_rejects_zero_deadline() {
     let s = Setup::new();
-    s.escrow.lock_funds(&s.depositor, &4, &750, &0);
-
-    s.escrow.release_funds(&4, &s.contributor);
+    s.escrow.set_default_lock_duration(&3_600);
+    s.escrow.set_default_lock_duration(&0);
+    assert_eq!(s.escrow.get_default_lock_duration(), None);
 
-    let info = s.escrow.get_escrow_info(&4);
-    assert_eq!(info.status, EscrowStatus::Released);
-    assert_eq!(s.token.balance(&s.contributor), 750);
-    assert_eq!(s.token.balance(&s.escrow.address), 0);
+    let result = s.escrow.try_lock_funds(&s.depositor, &4, &750, &0, &None);
+    assert_eq!(result.unwrap_err().unwrap(), Error::InvalidDeadline);
 }
 
 // =============================================================================
@@ -128,7 +135,7 @@ fn test_future_deadline_stored_correctly() {
     let s = Setup::new();
     let deadline = s.env.ledger().timestamp() + 3_600;
     s.escrow
-        .lock_funds(&s.depositor, &10, &500, &deadline);
+        .lock_funds(&s.depositor, &10, &500, &deadline, &None);
 
     let info = s.escrow.get_escrow_info(&10);
     assert_eq!(info.deadline, deadline);
@@ -140,7 +147,7 @@ fn test_future_deadline_refund_blocked_before_expiry() {
     let s = Setup::new();
     let deadline = s.env.ledger().timestamp() + 10_000;
     s.escrow
-        .lock_funds(&s.depositor, &11, &1_000, &deadline);
+        .lock_funds(&s.depositor, &11, &1_000, &deadline, &None);
 
     let result = s.escrow.try_refund(&11);
     assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
@@ -156,7 +163,7 @@ fn test_future_deadline_refund_succeeds_after_expiry() {
     let now = s.env.ledger().timestamp();
     let deadline = now + 500;
     s.escrow
-        .lock_funds(&s.depositor, &12, &1_200, &deadline);
+        .lock_funds(&s.depositor, &12, &1_200, &deadline, &None);
 
     s.env.ledger().set_timestamp(deadline + 1);
 
@@ -174,7 +181,7 @@ fn test_future_deadline_early_refund_with_admin_approval() {
     let s = Setup::new();
     let deadline = s.env.ledger().timestamp() + 86_400;
     s.escrow
-        .lock_funds(&s.depositor, &13, &2_000, &deadline);
+        .lock_funds(&s.depositor, &13, &2_000, &deadline, &None);
 
     s.escrow
         .approve_refund(&13, &2_000, &s.depositor, &RefundMode::Full);
@@ -192,7 +199,7 @@ fn test_future_deadline_release_unaffected_by_deadline() {
     let s = Setup::new();
     let deadline = s.env.ledger().timestamp() + 86_400;
     s.escrow
-        .lock_funds(&s.depositor, &14, &3_000, &deadline);
+        .lock_funds(&s.depositor, &14, &3_000, &deadline, &None);
 
     s.escrow.release_funds(&14, &s.contributor);
 
@@ -215,7 +222,7 @@ const NO_DEADLINE: u64 = u64::MAX;
 fn test_no_deadline_stored_correctly() {
     let s = Setup::new();
     s.escrow
-        .lock_funds(&s.depositor, &20, &500, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &20, &500, &NO_DEADLINE, &None);
 
     let info = s.escrow.get_escrow_info(&20);
     assert_eq!(info.deadline, NO_DEADLINE);
@@ -226,7 +233,7 @@ fn test_no_deadline_stored_correctly() {
 fn test_no_deadline_refund_blocked_without_approval() {
     let s = Setup::new();
     s.escrow
-        .lock_funds(&s.depositor, &21, &1_000, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &21, &1_000, &NO_DEADLINE, &None);
 
     let result = s.escrow.try_refund(&21);
     assert_eq!(result.unwrap_err().unwrap(), Error::DeadlineNotPassed);
@@ -240,7 +247,7 @@ fn test_no_deadline_refund_blocked_without_approval() {
 fn test_no_deadline_refund_blocked_even_after_large_time_advance() {
     let s = Setup::new();
     s.escrow
-        .lock_funds(&s.depositor, &22, &1_000, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &22, &1_000, &NO_DEADLINE, &None);
 
     // Advance the clock by 100 years worth of seconds — still less than u64::MAX
     s.env.ledger().set_timestamp(100 * 365 * 24 * 3600);
@@ -253,7 +260,7 @@ fn test_no_deadline_refund_blocked_even_after_large_time_advance() {
 fn test_no_deadline_refund_succeeds_with_admin_approval() {
     let s = Setup::new();
     s.escrow
-        .lock_funds(&s.depositor, &23, &1_500, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &23, &1_500, &NO_DEADLINE, &None);
 
     s.escrow
         .approve_refund(&23, &1_500, &s.depositor, &RefundMode::Full);
@@ -271,7 +278,7 @@ fn test_no_deadline_refund_succeeds_with_admin_approval() {
 fn test_no_deadline_partial_refund_with_admin_approval() {
     let s = Setup::new();
     s.escrow
-        .lock_funds(&s.depositor, &24, &2_000, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &24, &2_000, &NO_DEADLINE, &None);
 
     s.escrow
         .approve_refund(&24, &800, &s.depositor, &RefundMode::Partial);
@@ -288,7 +295,7 @@ fn test_no_deadline_partial_refund_with_admin_approval() {
 fn test_no_deadline_release_succeeds() {
     let s = Setup::new();
     s.escrow
-        .lock_funds(&s.depositor, &25, &2_500, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &25, &2_500, &NO_DEADLINE, &None);
 
     s.escrow.release_funds(&25, &s.contributor);
 
@@ -301,20 +308,26 @@ fn test_no_deadline_release_succeeds() {
 // =============================================================================
 // Cross-configuration comparisons
 //
-// These tests lock identical bounties with the three deadline configurations
+// These tests lock identical bounties with different deadline configurations
 // side-by-side to make the behavioral difference explicit and easy to follow.
 // =============================================================================
 
 #[test]
-fn test_deadline_zero_vs_future_refund_eligibility() {
+fn test_deadline_elapsed_vs_future_refund_eligibility() {
     let s = Setup::new();
+    s.env.ledger().set_timestamp(1_000);
     let now = s.env.ledger().timestamp();
+
+    // Bounty A: deadline already reached – immediately refundable
+    s.escrow.lock_funds(&s.depositor, &30, &400, &now, &None);
+
+    // Step past the depositor's lock cooldown before the second lock
+    let cooldown = s.escrow.get_anti_abuse_config().cooldown_period;
+    s.env.ledger().set_timestamp(now + cooldown);
     let future = now + 5_000;
 
-    // Bounty A: zero deadline – immediately refundable
-    s.escrow.lock_funds(&s.depositor, &30, &400, &0);
     // Bounty B: future deadline – not yet refundable
-    s.escrow.lock_funds(&s.depositor, &31, &400, &future);
+    s.escrow.lock_funds(&s.depositor, &31, &400, &future, &None);
 
     assert!(s.escrow.try_refund(&30).is_ok());
     assert_eq!(
@@ -330,10 +343,10 @@ fn test_deadline_future_vs_no_deadline_after_expiry() {
     let future = now + 1_000;
 
     // Bounty C: finite future deadline
-    s.escrow.lock_funds(&s.depositor, &32, &600, &future);
+    s.escrow.lock_funds(&s.depositor, &32, &600, &future, &None);
     // Bounty D: no deadline (u64::MAX)
     s.escrow
-        .lock_funds(&s.depositor, &33, &600, &NO_DEADLINE);
+        .lock_funds(&s.depositor, &33, &600, &NO_DEADLINE, &None);
 
     // Advance clock past the finite deadline
     s.env.ledger().set_timestamp(future + 1);
@@ -345,3 +358,61 @@ fn test_deadline_future_vs_no_deadline_after_expiry() {
         Error::DeadlineNotPassed
     );
 }
+
+// =============================================================================
+// Time until refund
+//
+// Countdown to the point where `refund` no longer returns DeadlineNotPassed.
+// =============================================================================
+
+#[test]
+fn test_time_until_refund_counts_down_to_deadline() {
+    let s = Setup::new();
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow.lock_funds(&s.depositor, &40, &500, &1_600, &None);
+
+    assert_eq!(s.escrow.get_time_until_refund(&40), 600);
+
+    s.env.ledger().set_timestamp(1_250);
+    assert_eq!(s.escrow.get_time_until_refund(&40), 350);
+
+    s.env.ledger().set_timestamp(1_599);
+    assert_eq!(s.escrow.get_time_until_refund(&40), 1);
+    assert_eq!(
+        s.escrow.try_refund(&40).unwrap_err().unwrap(),
+        Error::DeadlineNotPassed
+    );
+}
+
+#[test]
+fn test_time_until_refund_zero_at_and_past_deadline() {
+    let s = Setup::new();
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow.lock_funds(&s.depositor, &41, &500, &1_600, &None);
+
+    s.env.ledger().set_timestamp(1_600);
+    assert_eq!(s.escrow.get_time_until_refund(&41), 0);
+
+    s.env.ledger().set_timestamp(5_000);
+    assert_eq!(s.escrow.get_time_until_refund(&41), 0);
+    assert!(s.escrow.try_refund(&41).is_ok());
+}
+
+#[test]
+fn test_time_until_refund_zero_for_terminal_escrow() {
+    let s = Setup::new();
+    let deadline = s.env.ledger().timestamp() + 10_000;
+    s.escrow
+        .lock_funds(&s.depositor, &42, &500, &deadline, &None);
+
+    s.escrow.release_funds(&42, &s.contributor);
+
+    assert_eq!(s.escrow.get_time_until_refund(&42), 0);
+    assert_eq!(
+        s.escrow
+            .try_get_time_until_refund(&43)
+            .unwrap_err()
+            .unwrap(),
+        Error::BountyNotFound
+    );
+}
@@ -105,7 +105,8 @@ fn test_dry_run_lock_does_not_mutate_state() {
     assert_eq!(s.token.balance(&s.depositor), balance_before);
     assert_eq!(s.token.balance(&s.escrow.address), 0);
     // Real lock still works afterward
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     assert_eq!(s.token.balance(&s.escrow.address), 1_000);
 }
 
@@ -120,7 +121,8 @@ fn test_dry_run_lock_matches_real_execution_success() {
     assert!(sim.success);
 
     // Real execution should also succeed
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     let info = s.escrow.get_escrow_info(&1_u64);
     assert_eq!(info.status, sim.resulting_status);
     assert_eq!(info.remaining_amount, sim.remaining_amount);
@@ -132,11 +134,10 @@ fn test_dry_run_lock_duplicate_bounty() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
-    let result = s
-        .escrow
-        .dry_run_lock(&s.depositor, &1_u64, &500, &deadline);
+    let result = s.escrow.dry_run_lock(&s.depositor, &1_u64, &500, &deadline);
 
     assert!(!result.success);
     assert_eq!(result.error_code, Error::BountyExists as u32);
@@ -196,7 +197,8 @@ fn test_dry_run_release_success() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     let result = s.escrow.dry_run_release(&1_u64, &s.contributor);
 
@@ -212,7 +214,8 @@ fn test_dry_run_release_does_not_mutate_state() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     let result = s.escrow.dry_run_release(&1_u64, &s.contributor);
     assert!(result.success);
@@ -230,7 +233,8 @@ fn test_dry_run_release_matches_real_execution() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     let sim = s.escrow.dry_run_release(&1_u64, &s.contributor);
     assert!(sim.success);
@@ -256,7 +260,8 @@ fn test_dry_run_release_already_released() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.escrow.release_funds(&1_u64, &s.contributor);
 
     let result = s.escrow.dry_run_release(&1_u64, &s.contributor);
@@ -274,7 +279,8 @@ fn test_dry_run_refund_success_after_deadline() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     s.env.ledger().set_timestamp(deadline + 1);
 
@@ -292,7 +298,8 @@ fn test_dry_run_refund_does_not_mutate_state() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.env.ledger().set_timestamp(deadline + 1);
 
     let depositor_before = s.token.balance(&s.depositor);
@@ -311,7 +318,8 @@ fn test_dry_run_refund_matches_real_execution() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.env.ledger().set_timestamp(deadline + 1);
 
     let sim = s.escrow.dry_run_refund(&1_u64);
@@ -328,7 +336,8 @@ fn test_dry_run_refund_before_deadline_fails() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 10_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     let result = s.escrow.dry_run_refund(&1_u64);
 
@@ -351,7 +360,8 @@ fn test_dry_run_refund_already_released() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.escrow.release_funds(&1_u64, &s.contributor);
 
     let result = s.escrow.dry_run_refund(&1_u64);
@@ -365,10 +375,11 @@ fn test_dry_run_refund_with_pending_claim_fails() {
     let s = SimSetup::new();
     let deadline = s.env.ledger().timestamp() + 10_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.escrow.set_claim_window(&500_u64);
     s.escrow
-        .authorize_claim(&1_u64, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&1_u64, &s.contributor, &DisputeReason::Other, &false);
 
     s.env.ledger().set_timestamp(deadline + 1);
 
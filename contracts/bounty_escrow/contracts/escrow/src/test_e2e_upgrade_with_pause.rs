@@ -95,7 +95,7 @@ impl<'a> TestSetup<'a> {
     fn lock_bounty(&self, bounty_id: u64, amount: i128, deadline_offset: u64) -> u64 {
         let deadline = self.env.ledger().timestamp() + deadline_offset;
         self.escrow_client
-            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline, &None);
         deadline
     }
 
@@ -154,7 +154,7 @@ fn test_e2e_upgrade_with_pause() {
     let deadline = s.env.ledger().timestamp() + 5_000;
     assert_eq!(
         s.escrow_client
-            .try_lock_funds(&s.depositor, &3, &5_000, &deadline),
+            .try_lock_funds(&s.depositor, &3, &5_000, &deadline, &None),
         Err(Ok(Error::FundsPaused))
     );
 
@@ -259,7 +259,7 @@ fn test_full_upgrade_lifecycle() {
     let deadline = s.env.ledger().timestamp() + 10_000;
     assert!(s
         .escrow_client
-        .try_lock_funds(&s.depositor, &99, &1_000, &deadline)
+        .try_lock_funds(&s.depositor, &99, &1_000, &deadline, &None)
         .is_err());
     assert!(s
         .escrow_client
@@ -488,7 +488,7 @@ fn test_emergency_withdraw_preserves_pause_state() {
     let deadline = s.env.ledger().timestamp() + 2_000;
     assert_eq!(
         s.escrow_client
-            .try_lock_funds(&s.depositor, &99, &100, &deadline),
+            .try_lock_funds(&s.depositor, &99, &100, &deadline, &None),
         Err(Ok(Error::FundsPaused))
     );
 
@@ -503,7 +503,7 @@ fn test_emergency_withdraw_preserves_pause_state() {
 
     s.advance_time();
     s.escrow_client
-        .lock_funds(&s.depositor, &99, &100, &deadline);
+        .lock_funds(&s.depositor, &99, &100, &deadline, &None);
     assert_eq!(
         s.escrow_client.get_escrow_info(&99).status,
         EscrowStatus::Locked
@@ -649,22 +649,25 @@ fn test_multiple_pause_resume_cycles_balance() {
 #[test]
 fn test_upgrade_with_high_value_bounties() {
     let s = TestSetup::new();
-    
+
     let high_value: i128 = 100_000_000 * 10_000_000; // 100M tokens with 7 decimals
-    
+
     // Setup rich depositor
     s.token_admin_client.mint(&s.depositor, &(high_value * 3));
-    
+
     // Lock 3 high-value bounties
     let deadline = s.env.ledger().timestamp() + 100_000;
-    s.escrow_client.lock_funds(&s.depositor, &100, &high_value, &deadline);
+    s.escrow_client
+        .lock_funds(&s.depositor, &100, &high_value, &deadline, &None);
     s.advance_time();
-    s.escrow_client.lock_funds(&s.depositor, &200, &high_value, &deadline);
+    s.escrow_client
+        .lock_funds(&s.depositor, &200, &high_value, &deadline, &None);
     s.advance_time();
-    s.escrow_client.lock_funds(&s.depositor, &300, &high_value, &deadline);
-    
+    s.escrow_client
+        .lock_funds(&s.depositor, &300, &high_value, &deadline, &None);
+
     assert_eq!(s.token_client.balance(&s.escrow_id), high_value * 3);
-    
+
     // Pause
     s.advance_time();
     s.pause_all("High value upgrade prep");
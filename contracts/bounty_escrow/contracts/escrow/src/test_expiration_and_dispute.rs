@@ -80,12 +80,15 @@ fn test_pending_claim_blocks_refund() {
     // Lock funds with deadline
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     // Admin opens dispute by authorizing claim (before deadline)
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     // Verify claim is pending
     let claim = setup.escrow.get_pending_claim(&bounty_id);
@@ -121,12 +124,15 @@ fn test_beneficiary_claims_within_window_succeeds() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     // Admin authorizes claim at now, expires at now+500
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     let claim = setup.escrow.get_pending_claim(&bounty_id);
 
@@ -155,12 +161,15 @@ fn test_missed_claim_window_requires_admin_cancel_then_refund() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     // Admin authorizes claim (opens dispute window)
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     let claim = setup.escrow.get_pending_claim(&bounty_id);
     let claim_expires_at = claim.expires_at;
@@ -206,11 +215,14 @@ fn test_resolution_order_requires_explicit_cancel_step() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     // Advance past both windows
     setup.env.ledger().set_timestamp(deadline + 500);
@@ -247,11 +259,14 @@ fn test_correct_resolution_order_cancel_then_refund() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     // Advance past both windows
     setup.env.ledger().set_timestamp(deadline + 500);
@@ -282,11 +297,14 @@ fn test_admin_can_cancel_expired_claim() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     let claim = setup.escrow.get_pending_claim(&bounty_id);
 
@@ -316,11 +334,14 @@ fn test_claim_window_zero_prevents_all_claims() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     let _claim = setup.escrow.get_pending_claim(&bounty_id);
 
@@ -351,23 +372,23 @@ fn test_multiple_bounties_independent_resolution() {
     // Bounty 1: Will be cancelled and refunded
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &1000, &(now + 500));
+        .lock_funds(&setup.depositor, &1, &1000, &(now + 500), &None);
     setup
         .escrow
-        .authorize_claim(&1, &setup.contributor, &DisputeReason::Other);
+        .authorize_claim(&1, &setup.contributor, &DisputeReason::Other, &false);
 
     // Bounty 2: Will be refunded directly (no claim)
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &2000, &(now + 600));
+        .lock_funds(&setup.depositor, &2, &2000, &(now + 600), &None);
 
     // Bounty 3: Will be claimed
     setup
         .escrow
-        .lock_funds(&setup.depositor, &3, &1500, &(now + 1000));
+        .lock_funds(&setup.depositor, &3, &1500, &(now + 1000), &None);
     setup
         .escrow
-        .authorize_claim(&3, &setup.contributor, &DisputeReason::Other);
+        .authorize_claim(&3, &setup.contributor, &DisputeReason::Other, &false);
 
     setup.env.ledger().set_timestamp(now + 550);
 
@@ -419,16 +440,19 @@ fn test_claim_cancellation_restores_refund_eligibility() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     let escrow_before = setup.escrow.get_escrow_info(&bounty_id);
     assert_eq!(escrow_before.remaining_amount, amount);
     assert_eq!(escrow_before.status, EscrowStatus::Locked);
 
     // Authorize claim
-    setup
-        .escrow
-        .authorize_claim(&bounty_id, &setup.contributor, &DisputeReason::QualityIssue);
+    setup.escrow.authorize_claim(
+        &bounty_id,
+        &setup.contributor,
+        &DisputeReason::QualityIssue,
+        &false,
+    );
 
     // Cancel it
     setup
@@ -455,9 +479,9 @@ fn test_expiry_does_not_bypass_active_dispute() {
 
     s.escrow.set_claim_window(&300);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
 
     s.env.ledger().set_timestamp(deadline + 1);
 
@@ -477,11 +501,11 @@ fn test_dispute_before_expiry_cancel_then_refund_after_deadline() {
 
     s.escrow.set_claim_window(&200);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
 
     // Dispute raised before deadline
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
     let claim = s.escrow.get_pending_claim(&bounty_id);
     assert!(!claim.claimed);
 
@@ -514,9 +538,9 @@ fn test_dispute_before_expiry_contributor_claims_wins() {
 
     s.escrow.set_claim_window(&400);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
 
     let claim = s.escrow.get_pending_claim(&bounty_id);
 
@@ -543,14 +567,14 @@ fn test_dispute_opened_after_deadline_contributor_can_still_claim() {
 
     s.escrow.set_claim_window(&500);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
 
     // Deadline passes with no claim
     s.env.ledger().set_timestamp(deadline + 1);
 
     // Admin opens dispute after deadline (late intervention)
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
     let claim = s.escrow.get_pending_claim(&bounty_id);
 
     // Contributor claims within window
@@ -574,9 +598,9 @@ fn test_both_windows_expired_admin_cancels_stale_claim_then_refund() {
 
     s.escrow.set_claim_window(&100);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
 
     // Jump far into the future — both windows long expired
     s.env.ledger().set_timestamp(deadline + 1_000);
@@ -605,11 +629,11 @@ fn test_reauthorize_after_cancel_second_claim_succeeds() {
 
     s.escrow.set_claim_window(&200);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
 
     // First dispute — cancelled
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
     let first_claim = s.escrow.get_pending_claim(&bounty_id);
     s.env.ledger().set_timestamp(first_claim.expires_at + 1);
     s.escrow
@@ -617,7 +641,7 @@ fn test_reauthorize_after_cancel_second_claim_succeeds() {
 
     // Second dispute — contributor claims this time
     s.escrow
-        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &s.contributor, &DisputeReason::Other, &false);
     let second_claim = s.escrow.get_pending_claim(&bounty_id);
     assert!(!second_claim.claimed);
 
@@ -640,7 +664,7 @@ fn test_no_dispute_normal_refund_after_deadline() {
 
     s.escrow.set_claim_window(&200);
     s.escrow
-        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&s.depositor, &bounty_id, &amount, &deadline, &None);
 
     s.env.ledger().set_timestamp(deadline + 1);
     s.escrow.refund(&bounty_id);
@@ -47,7 +47,13 @@ mod test_fee_routing {
         client.update_fee_config(&Some(1000), &Some(0), &Some(treasury.clone()), &Some(true));
         client.set_fee_routing(&1, &treasury, &7000, &Some(partner.clone()), &3000);
 
-        client.lock_funds(&depositor, &1, &1_000, &(env.ledger().timestamp() + 1_000));
+        client.lock_funds(
+            &depositor,
+            &1,
+            &1_000,
+            &(env.ledger().timestamp() + 1_000),
+            &None,
+        );
         client.release_funds(&1, &contributor);
 
         // lock fee = 100, split 70/30, escrow stores and releases 900
@@ -72,7 +78,13 @@ mod test_fee_routing {
         client.update_fee_config(&Some(0), &Some(333), &Some(treasury.clone()), &Some(true));
         client.set_fee_routing(&2, &treasury, &5000, &Some(partner.clone()), &5000);
 
-        client.lock_funds(&depositor, &2, &1_000, &(env.ledger().timestamp() + 1_000));
+        client.lock_funds(
+            &depositor,
+            &2,
+            &1_000,
+            &(env.ledger().timestamp() + 1_000),
+            &None,
+        );
         client.release_funds(&2, &contributor);
 
         // release fee = floor(1000 * 333 / 10000) = 33
@@ -96,7 +108,13 @@ mod test_fee_routing {
         token_admin.mint(&depositor, &1_000);
         client.update_fee_config(&Some(0), &Some(500), &Some(treasury.clone()), &Some(true));
 
-        client.lock_funds(&depositor, &3, &1_000, &(env.ledger().timestamp() + 1_000));
+        client.lock_funds(
+            &depositor,
+            &3,
+            &1_000,
+            &(env.ledger().timestamp() + 1_000),
+            &None,
+        );
         client.release_funds(&3, &contributor);
 
         assert_eq!(token_client.balance(&treasury), 50);
@@ -68,7 +68,7 @@ fn test_release_race_first_recipient_wins_order_ab() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.release_funds(&bounty_id, &recipient_a);
     let second_release = setup.escrow.try_release_funds(&bounty_id, &recipient_b);
@@ -92,7 +92,7 @@ fn test_release_race_first_recipient_wins_order_ba() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.escrow.release_funds(&bounty_id, &recipient_b);
     let second_release = setup.escrow.try_release_funds(&bounty_id, &recipient_a);
@@ -117,14 +117,14 @@ fn test_authorize_claim_race_last_authorization_wins() {
     setup.escrow.set_claim_window(&500);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
-        .authorize_claim(&bounty_id, &claimant_a, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &claimant_a, &DisputeReason::Other, &false);
     setup
         .escrow
-        .authorize_claim(&bounty_id, &claimant_b, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &claimant_b, &DisputeReason::Other, &false);
 
     let pending = setup.escrow.get_pending_claim(&bounty_id);
     assert_eq!(pending.recipient, claimant_b);
@@ -150,7 +150,7 @@ fn test_auto_refund_race_first_caller_wins() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.env.ledger().set_timestamp(deadline + 1);
 
@@ -180,7 +180,7 @@ fn test_partial_release_race_prevents_double_spend() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
@@ -208,10 +208,10 @@ fn test_batch_release_prevents_double_release() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &1, &10_000, &deadline);
+        .lock_funds(&setup.depositor, &1, &10_000, &deadline, &None);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &2, &20_000, &deadline);
+        .lock_funds(&setup.depositor, &2, &20_000, &deadline, &None);
 
     let items = vec![
         &setup.env,
@@ -248,7 +248,7 @@ fn test_refund_vs_release_race_first_wins() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup.env.ledger().set_timestamp(deadline + 1);
 
@@ -277,11 +277,11 @@ fn test_claim_race_unauthorized_fails() {
     setup.escrow.set_claim_window(&500);
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     setup
         .escrow
-        .authorize_claim(&bounty_id, &authorized, &DisputeReason::Other);
+        .authorize_claim(&bounty_id, &authorized, &DisputeReason::Other, &false);
 
     setup.escrow.claim(&bounty_id);
 
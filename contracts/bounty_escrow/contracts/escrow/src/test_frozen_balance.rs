@@ -51,7 +51,7 @@ impl<'a> TestEnv<'a> {
     fn lock(&self, bounty_id: u64, amount: i128) {
         let deadline = self.env.ledger().timestamp() + 10_000;
         self.client
-            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline, &None);
     }
 }
 
@@ -211,7 +211,8 @@ fn test_freeze_escrow_does_not_block_new_lock_on_different_id() {
     t.client.freeze_escrow(&1, &None);
     // locking a new bounty id should work fine
     let deadline = t.env.ledger().timestamp() + 10_000;
-    t.client.lock_funds(&t.depositor, &2, &500, &deadline);
+    t.client
+        .lock_funds(&t.depositor, &2, &500, &deadline, &None);
     let info = t.client.get_escrow_info(&2);
     assert_eq!(info.status, crate::EscrowStatus::Locked);
 }
@@ -284,8 +285,9 @@ fn test_freeze_address_does_not_affect_different_depositor() {
     let other = Address::generate(&t.env);
     t.token_admin.mint(&other, &5_000);
     let deadline = t.env.ledger().timestamp() + 10_000;
-    t.client.lock_funds(&t.depositor, &1, &1000, &deadline);
-    t.client.lock_funds(&other, &2, &500, &deadline);
+    t.client
+        .lock_funds(&t.depositor, &1, &1000, &deadline, &None);
+    t.client.lock_funds(&other, &2, &500, &deadline, &None);
     t.client.freeze_address(&t.depositor, &None);
     // other depositor's escrow unaffected
     t.client.release_funds(&2, &t.contributor);
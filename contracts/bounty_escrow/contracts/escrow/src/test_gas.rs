@@ -145,7 +145,7 @@ mod gas_profile {
             let deadline = self.deadline();
             measure(&self.env, || {
                 self.client
-                    .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+                    .lock_funds(&self.depositor, &bounty_id, &amount, &deadline, &None);
             })
         }
 
@@ -284,7 +284,7 @@ mod gas_profile {
         let s = Setup::new();
         let dl = s.env.ledger().timestamp() + 100;
         s.mint(&s.depositor.clone(), 1_000);
-        s.client.lock_funds(&s.depositor, &1, &1_000, &dl);
+        s.client.lock_funds(&s.depositor, &1, &1_000, &dl, &None);
         s.advance_time(200);
         s.env.budget().reset_unlimited();
         print_header();
@@ -489,7 +489,7 @@ mod gas_profile {
         s.mint(&s.depositor.clone(), 1_000 * n as i128);
         for i in 0..n as u64 {
             s.client
-                .lock_funds(&s.depositor, &(base_id + i), &1_000, &deadline);
+                .lock_funds(&s.depositor, &(base_id + i), &1_000, &deadline, &None);
         }
         s.env.budget().reset_unlimited();
     }
@@ -642,7 +642,7 @@ mod gas_profile {
         s.mint(&s.depositor.clone(), 1_000);
         s.env.budget().reset_unlimited();
         let lock_d = measure(&s.env, || {
-            s.client.lock_funds(&s.depositor, &1, &1_000, &dl);
+            s.client.lock_funds(&s.depositor, &1, &1_000, &dl, &None);
         });
         s.advance_time(200);
         s.env.budget().reset_unlimited();
@@ -854,7 +854,7 @@ mod gas_profile {
             let s = Setup::new();
             let dl = s.env.ledger().timestamp() + 100;
             s.mint(&s.depositor.clone(), 1_000);
-            s.client.lock_funds(&s.depositor, &1, &1_000, &dl);
+            s.client.lock_funds(&s.depositor, &1, &1_000, &dl, &None);
             s.advance_time(200);
             s.env.budget().reset_unlimited();
             let d = s.refund(1);
@@ -81,7 +81,7 @@ fn lock_bounty(
     amount: i128,
 ) -> u64 {
     let deadline = env.ledger().timestamp() + 10_000;
-    client.lock_funds(depositor, &bounty_id, &amount, &deadline);
+    client.lock_funds(depositor, &bounty_id, &amount, &deadline, &None);
     deadline
 }
 
@@ -200,7 +200,7 @@ fn test_lock_funds_blocked_when_lock_paused() {
 
     client.set_paused(&Some(true), &None, &None, &None);
     let deadline = env.ledger().timestamp() + 1_000;
-    let result = client.try_lock_funds(&depositor, &1, &100, &deadline);
+    let result = client.try_lock_funds(&depositor, &1, &100, &deadline, &None);
     assert!(result.is_err());
 }
 
@@ -298,7 +298,7 @@ fn test_lock_allowed_when_only_release_paused() {
 
     client.set_paused(&None, &Some(true), &None, &None);
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
 
     let escrow = client.get_escrow_info(&1);
     assert_eq!(escrow.amount, 100);
@@ -344,7 +344,7 @@ fn test_lock_allowed_when_only_refund_paused() {
 
     client.set_paused(&None, &None, &Some(true), &None);
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
 
     let escrow = client.get_escrow_info(&1);
     assert_eq!(escrow.amount, 100);
@@ -376,7 +376,7 @@ fn test_lock_blocked_when_lock_and_release_paused() {
     client.set_paused(&Some(true), &Some(true), &None, &None);
     let deadline = env.ledger().timestamp() + 1_000;
     assert!(client
-        .try_lock_funds(&depositor, &1, &100, &deadline)
+        .try_lock_funds(&depositor, &1, &100, &deadline, &None)
         .is_err());
 }
 
@@ -419,7 +419,7 @@ fn test_lock_blocked_when_lock_and_refund_paused() {
     client.set_paused(&Some(true), &None, &Some(true), &None);
     let deadline = env.ledger().timestamp() + 1_000;
     assert!(client
-        .try_lock_funds(&depositor, &1, &100, &deadline)
+        .try_lock_funds(&depositor, &1, &100, &deadline, &None)
         .is_err());
 }
 
@@ -459,7 +459,7 @@ fn test_lock_allowed_when_release_and_refund_paused() {
 
     client.set_paused(&None, &Some(true), &Some(true), &None);
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &250, &deadline);
+    client.lock_funds(&depositor, &1, &250, &deadline, &None);
 
     let escrow = client.get_escrow_info(&1);
     assert_eq!(escrow.amount, 250);
@@ -501,7 +501,7 @@ fn test_lock_blocked_when_all_paused() {
     client.set_paused(&Some(true), &Some(true), &Some(true), &None);
     let deadline = env.ledger().timestamp() + 1_000;
     assert!(client
-        .try_lock_funds(&depositor, &1, &100, &deadline)
+        .try_lock_funds(&depositor, &1, &100, &deadline, &None)
         .is_err());
 }
 
@@ -541,11 +541,11 @@ fn test_lock_restored_after_unpause() {
     client.set_paused(&Some(true), &None, &None, &None);
     let deadline = env.ledger().timestamp() + 1_000;
     assert!(client
-        .try_lock_funds(&depositor, &1, &100, &deadline)
+        .try_lock_funds(&depositor, &1, &100, &deadline, &None)
         .is_err());
 
     client.set_paused(&Some(false), &None, &None, &None);
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
     let escrow = client.get_escrow_info(&1);
     assert_eq!(escrow.amount, 100);
 }
@@ -670,7 +670,7 @@ fn test_authorize_claim_blocked_when_release_paused() {
     client.set_paused(&None, &Some(true), &None, &None);
 
     let contributor = Address::generate(&env);
-    let result = client.try_authorize_claim(&1, &contributor, &DisputeReason::Other);
+    let result = client.try_authorize_claim(&1, &contributor, &DisputeReason::Other, &false);
     assert!(result.is_err());
 }
 
@@ -684,7 +684,7 @@ fn test_authorize_claim_allowed_when_lock_and_refund_paused() {
     client.set_paused(&Some(true), &None, &Some(true), &None);
 
     let contributor = Address::generate(&env);
-    client.authorize_claim(&1, &contributor, &DisputeReason::Other);
+    client.authorize_claim(&1, &contributor, &DisputeReason::Other, &false);
 
     let claim = client.get_pending_claim(&1);
     assert_eq!(claim.amount, 500);
@@ -699,7 +699,7 @@ fn test_claim_blocked_when_release_paused() {
     client.set_claim_window(&3600);
 
     let contributor = Address::generate(&env);
-    client.authorize_claim(&1, &contributor, &DisputeReason::Other);
+    client.authorize_claim(&1, &contributor, &DisputeReason::Other, &false);
 
     // Now pause release — claim should be blocked
     client.set_paused(&None, &Some(true), &None, &None);
@@ -716,7 +716,7 @@ fn test_claim_allowed_when_only_lock_paused() {
     client.set_claim_window(&3600);
 
     let contributor = Address::generate(&env);
-    client.authorize_claim(&1, &contributor, &DisputeReason::Other);
+    client.authorize_claim(&1, &contributor, &DisputeReason::Other, &false);
 
     client.set_paused(&Some(true), &None, &None, &None);
     client.claim(&1);
@@ -806,11 +806,11 @@ fn test_rapid_toggle_lock_flag() {
         client.set_paused(&Some(true), &None, &None, &None);
         let deadline = env.ledger().timestamp() + 1_000;
         assert!(client
-            .try_lock_funds(&depositor, &(round * 2), &100, &deadline)
+            .try_lock_funds(&depositor, &(round * 2), &100, &deadline, &None)
             .is_err());
 
         client.set_paused(&Some(false), &None, &None, &None);
-        client.lock_funds(&depositor, &(round * 2 + 1), &100, &deadline);
+        client.lock_funds(&depositor, &(round * 2 + 1), &100, &deadline, &None);
     }
 }
 
@@ -994,7 +994,7 @@ fn test_cancel_pending_claim_unaffected_by_all_paused() {
     client.set_claim_window(&3600);
 
     let contributor = Address::generate(&env);
-    client.authorize_claim(&1, &contributor, &DisputeReason::Other);
+    client.authorize_claim(&1, &contributor, &DisputeReason::Other, &false);
 
     // Pause everything
     client.set_paused(&Some(true), &Some(true), &Some(true), &None);
@@ -69,7 +69,7 @@ fn test_lock_fails_in_maintenance_mode() {
 
     contract.set_maintenance_mode(&true);
 
-    contract.lock_funds(&depositor, &1u64, &1000i128, &999999999u64);
+    contract.lock_funds(&depositor, &1u64, &1000i128, &999999999u64, &None);
 }
 
 #[test]
@@ -83,7 +83,7 @@ fn test_release_and_refund_allowed_in_maintenance_mode() {
     token_admin_client.mint(&depositor, &5000i128);
 
     // Lock funds BEFORE maintenance mode
-    contract.lock_funds(&depositor, &1u64, &1000i128, &999999999u64);
+    contract.lock_funds(&depositor, &1u64, &1000i128, &999999999u64, &None);
 
     // Enable maintenance mode
     contract.set_maintenance_mode(&true);
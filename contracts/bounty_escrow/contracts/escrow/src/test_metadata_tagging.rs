@@ -150,7 +150,8 @@ mod metadata_tagging_tests {
         let amount = 5000i128;
         let dl = s.env.ledger().timestamp() + 3600;
 
-        s.escrow.lock_funds(&s.depositor, &bounty_id, &amount, &dl);
+        s.escrow
+            .lock_funds(&s.depositor, &bounty_id, &amount, &dl, &None);
 
         let info = s.escrow.get_escrow_info(&bounty_id);
         assert_eq!(info.amount, amount);
@@ -285,7 +286,8 @@ mod metadata_tagging_tests {
         for i in 1u64..=15 {
             let amount = (i as i128) * 1000;
             let deadline = dl_base + (i * 100);
-            s.escrow.lock_funds(&s.depositor, &i, &amount, &deadline);
+            s.escrow
+                .lock_funds(&s.depositor, &i, &amount, &deadline, &None);
         }
 
         let amount_results = s.escrow.query_escrows_by_amount(&5000, &10000, &0, &20);
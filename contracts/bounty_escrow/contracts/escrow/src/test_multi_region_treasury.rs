@@ -225,7 +225,7 @@ mod test_multi_region_treasury {
         // Lock funds - should incur 10% lock fee = 100 tokens
         // 60 goes to NA, 40 goes to EU
         let deadline = env.ledger().timestamp() + 1000;
-        client.lock_funds(&depositor, &1u64, &1000i128, &deadline);
+        client.lock_funds(&depositor, &1u64, &1000i128, &deadline, &None);
 
         // Verify contract balance (net amount after fee)
         let contract_balance = token_client.balance(&client.address);
@@ -265,7 +265,7 @@ mod test_multi_region_treasury {
 
         // Lock funds
         let deadline = env.ledger().timestamp() + 1000;
-        client.lock_funds(&depositor, &1u64, &1000i128, &deadline);
+        client.lock_funds(&depositor, &1u64, &1000i128, &deadline, &None);
 
         // Verify fee went to single recipient
         let recipient_balance = token_client.balance(&fee_recipient);
@@ -302,7 +302,7 @@ mod test_multi_region_treasury {
         // Mint and lock
         token_minter.mint(&depositor, &1000);
         let deadline = env.ledger().timestamp() + 1000;
-        client.lock_funds(&depositor, &1u64, &1000i128, &deadline);
+        client.lock_funds(&depositor, &1u64, &1000i128, &deadline, &None);
 
         // All fee should go to single destination
         let treasury_balance = token_client.balance(&treasury_address);
@@ -346,7 +346,7 @@ mod test_multi_region_treasury {
         // Mint and lock (no lock fee)
         token_minter.mint(&depositor, &1000);
         let deadline = env.ledger().timestamp() + 1000;
-        client.lock_funds(&depositor, &1u64, &1000i128, &deadline);
+        client.lock_funds(&depositor, &1u64, &1000i128, &deadline, &None);
 
         // Contract has 1000
         let contract_balance = token_client.balance(&client.address);
@@ -175,7 +175,8 @@ fn test_lock_fee_collected_correctly() {
 
     let gross = 1_000_000i128;
     s.fund_depositor(gross);
-    s.client.lock_funds(&s.depositor, &1, &gross, &s.deadline());
+    s.client
+        .lock_funds(&s.depositor, &1, &gross, &s.deadline(), &None);
 
     // fee = ceil(1_000_000 * 200 / 10_000) = ceil(20_000) = 20_000
     let expected_fee = 20_000i128;
@@ -204,7 +205,7 @@ fn test_lock_no_fee_when_rate_zero() {
     let amount = 500_000i128;
     s.fund_depositor(amount);
     s.client
-        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
 
     assert_eq!(s.balance(&s.fee_recipient), 0);
     assert_eq!(s.client.get_escrow_info(&1).amount, amount);
@@ -220,7 +221,7 @@ fn test_lock_no_fee_when_disabled() {
     let amount = 100_000i128;
     s.fund_depositor(amount);
     s.client
-        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
 
     assert_eq!(s.balance(&s.fee_recipient), 0);
     assert_eq!(s.client.get_escrow_info(&1).amount, amount);
@@ -240,7 +241,8 @@ fn test_dust_deposit_fee_never_zero_with_nonzero_rate() {
 
     // Deposit of 1 stroop: floor would give 0 fee, ceil must give 1
     s.fund_depositor(2); // need at least 2 so net > 0 after 1-stroop fee
-    s.client.lock_funds(&s.depositor, &1, &2, &s.deadline());
+    s.client
+        .lock_funds(&s.depositor, &1, &2, &s.deadline(), &None);
 
     // fee = ceil(2 * 1 / 10_000) = ceil(0.0002) = 1
     assert_eq!(s.balance(&s.fee_recipient), 1);
@@ -260,7 +262,9 @@ fn test_fee_cannot_consume_entire_principal() {
         .set_token_fee_config(&s.token_id, &5_000, &0, &s.fee_recipient, &true);
 
     s.fund_depositor(1);
-    let result = s.client.try_lock_funds(&s.depositor, &1, &1, &s.deadline());
+    let result = s
+        .client
+        .try_lock_funds(&s.depositor, &1, &1, &s.deadline(), &None);
     assert_eq!(
         result.unwrap_err().unwrap(),
         Error::InvalidAmount,
@@ -290,7 +294,7 @@ fn test_per_token_config_overrides_global() {
     let amount = 100_000i128;
     s.fund_depositor(amount);
     s.client
-        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
 
     // Per-token 3% = 3_000 fee; global 1% would have been 1_000
     assert_eq!(
@@ -316,7 +320,7 @@ fn test_global_fee_used_when_no_token_config() {
     let amount = 100_000i128;
     s.fund_depositor(amount);
     s.client
-        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
 
     assert_eq!(s.balance(&s.fee_recipient), 1_000);
 }
@@ -333,7 +337,7 @@ fn test_release_fee_collected_correctly() {
     let amount = 200_000i128;
     s.fund_depositor(amount);
     s.client
-        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
     s.client.release_funds(&1, &s.contributor);
 
     // release fee = ceil(200_000 * 100 / 10_000) = 2_000
@@ -353,7 +357,7 @@ fn test_release_no_fee_when_rate_zero() {
     let amount = 50_000i128;
     s.fund_depositor(amount);
     s.client
-        .lock_funds(&s.depositor, &1, &amount, &s.deadline());
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
     s.client.release_funds(&1, &s.contributor);
 
     assert_eq!(s.balance(&s.fee_recipient), 0);
@@ -378,3 +382,162 @@ fn test_get_token_fee_config_returns_some_when_set() {
     assert_eq!(cfg.lock_fee_rate, 150);
     assert_eq!(cfg.release_fee_rate, 75);
 }
+
+// ── separate fee token ────────────────────────────────────────────────────────
+
+/// Register a fee token, fund `payers` with it and approve the escrow contract.
+fn setup_fee_token(s: &Suite, payers: &[&Address]) -> token::TokenClient<'static> {
+    let fee_token_id = s.env.register_stellar_asset_contract(s.admin.clone());
+    let fee_admin = token::StellarAssetClient::new(&s.env, &fee_token_id);
+    let fee_token = token::TokenClient::new(&s.env, &fee_token_id);
+    let expiration = s.env.ledger().sequence() + 1_000;
+    for payer in payers {
+        fee_admin.mint(payer, &1_000_000);
+        fee_token.approve(payer, &s.client.address, &1_000_000, &expiration);
+    }
+    s.client.set_fee_token(&Some(fee_token_id));
+    fee_token
+}
+
+/// With a fee token set, the lock fee is pulled from the depositor in the fee
+/// token and the full amount stays in escrow.
+#[test]
+fn test_lock_fee_charged_in_fee_token() {
+    let s = Suite::new();
+    // 2% lock fee
+    s.client
+        .set_token_fee_config(&s.token_id, &200, &0, &s.fee_recipient, &true);
+    let fee_token = setup_fee_token(&s, &[&s.depositor]);
+
+    let gross = 1_000_000i128;
+    s.fund_depositor(gross);
+    s.client
+        .lock_funds(&s.depositor, &1, &gross, &s.deadline(), &None);
+
+    assert_eq!(s.client.get_escrow_info(&1).amount, gross);
+    assert_eq!(s.balance(&s.client.address), gross);
+    assert_eq!(s.balance(&s.fee_recipient), 0);
+    assert_eq!(fee_token.balance(&s.fee_recipient), 20_000);
+    assert_eq!(fee_token.balance(&s.depositor), 980_000);
+}
+
+/// With a fee token set, the release fee is pulled from the admin and the
+/// contributor receives the full escrowed amount.
+#[test]
+fn test_release_fee_charged_in_fee_token() {
+    let s = Suite::new();
+    // 1% release fee
+    s.client
+        .set_token_fee_config(&s.token_id, &0, &100, &s.fee_recipient, &true);
+    let fee_token = setup_fee_token(&s, &[&s.admin]);
+
+    let amount = 500_000i128;
+    s.fund_depositor(amount);
+    s.client
+        .lock_funds(&s.depositor, &1, &amount, &s.deadline(), &None);
+    s.client.release_funds(&1, &s.contributor);
+
+    assert_eq!(s.balance(&s.contributor), amount);
+    assert_eq!(s.balance(&s.fee_recipient), 0);
+    assert_eq!(fee_token.balance(&s.fee_recipient), 5_000);
+    assert_eq!(fee_token.balance(&s.admin), 995_000);
+}
+
+/// Clearing the fee token falls back to deducting fees from the escrowed amount.
+#[test]
+fn test_cleared_fee_token_falls_back_to_skim() {
+    let s = Suite::new();
+    s.client
+        .set_token_fee_config(&s.token_id, &200, &0, &s.fee_recipient, &true);
+    let fee_token = setup_fee_token(&s, &[&s.depositor]);
+    s.client.set_fee_token(&None);
+    assert_eq!(s.client.get_fee_token(), None);
+
+    let gross = 1_000_000i128;
+    s.fund_depositor(gross);
+    s.client
+        .lock_funds(&s.depositor, &1, &gross, &s.deadline(), &None);
+
+    assert_eq!(s.client.get_escrow_info(&1).amount, 980_000);
+    assert_eq!(s.balance(&s.fee_recipient), 20_000);
+    assert_eq!(fee_token.balance(&s.fee_recipient), 0);
+}
+
+/// With the approved-token allowlist active, only approved fee tokens are accepted.
+#[test]
+fn test_fee_token_must_be_on_active_allowlist() {
+    let s = Suite::new();
+    let approved = s.env.register_stellar_asset_contract(s.admin.clone());
+    let unapproved = s.env.register_stellar_asset_contract(s.admin.clone());
+
+    s.client.set_token_approval(&s.token_id, &true);
+    s.client.set_token_approval(&approved, &true);
+    assert!(s.client.is_token_approved(&approved));
+    assert!(!s.client.is_token_approved(&unapproved));
+
+    s.client.set_fee_token(&Some(approved.clone()));
+    assert_eq!(s.client.get_fee_token(), Some(approved.clone()));
+
+    let res = s.client.try_set_fee_token(&Some(unapproved));
+    assert_eq!(res, Err(Ok(Error::TokenNotApproved)));
+    assert_eq!(s.client.get_fee_token(), Some(approved));
+}
+
+/// Without an allowlist any fee token is accepted, and removing the last entry
+/// deactivates the list again.
+#[test]
+fn test_fee_token_unrestricted_without_allowlist() {
+    let s = Suite::new();
+    let fee_token_id = s.env.register_stellar_asset_contract(s.admin.clone());
+    assert!(s.client.get_approved_tokens().is_empty());
+
+    s.client.set_token_approval(&s.token_id, &true);
+    assert_eq!(
+        s.client.try_set_fee_token(&Some(fee_token_id.clone())),
+        Err(Ok(Error::TokenNotApproved))
+    );
+
+    s.client.set_token_approval(&s.token_id, &false);
+    s.client.set_fee_token(&Some(fee_token_id.clone()));
+    assert_eq!(s.client.get_fee_token(), Some(fee_token_id));
+}
+
+/// The allowlist also gates per-token fee configs, and can never leave the
+/// escrowed token or the active fee token unapproved.
+#[test]
+fn test_allowlist_applies_to_every_token_path() {
+    let s = Suite::new();
+    let fee_token_id = s.env.register_stellar_asset_contract(s.admin.clone());
+    let other = s.env.register_stellar_asset_contract(s.admin.clone());
+
+    // Activating the list without the escrowed token is rejected
+    assert_eq!(
+        s.client.try_set_token_approval(&other, &true),
+        Err(Ok(Error::TokenNotApproved))
+    );
+
+    s.client.set_token_approval(&s.token_id, &true);
+    s.client.set_token_approval(&fee_token_id, &true);
+    s.client.set_fee_token(&Some(fee_token_id.clone()));
+
+    let res = s
+        .client
+        .try_set_token_fee_config(&other, &100, &100, &s.fee_recipient, &true);
+    assert_eq!(res, Err(Ok(Error::TokenNotApproved)));
+    s.client
+        .set_token_fee_config(&s.token_id, &100, &100, &s.fee_recipient, &true);
+
+    // Neither the escrowed token nor the active fee token can be revoked
+    assert_eq!(
+        s.client.try_set_token_approval(&s.token_id, &false),
+        Err(Ok(Error::TokenNotApproved))
+    );
+    assert_eq!(
+        s.client.try_set_token_approval(&fee_token_id, &false),
+        Err(Ok(Error::TokenNotApproved))
+    );
+
+    s.client.set_fee_token(&None);
+    s.client.set_token_approval(&fee_token_id, &false);
+    assert!(!s.client.is_token_approved(&fee_token_id));
+}
@@ -154,7 +154,8 @@ fn test_inv2_single_lock_invariant_holds() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     // verify_all_invariants calls check_all_invariants internally
     assert!(s.escrow.verify_all_invariants());
@@ -173,9 +174,12 @@ fn test_inv2_multiple_locks_invariant_holds() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &3_u64, &3_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &3_u64, &3_000, &deadline, &None);
 
     assert!(s.escrow.verify_all_invariants());
 
@@ -192,8 +196,10 @@ fn test_inv2_lock_then_release_invariant_holds() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     // Release bounty 1
     s.escrow.release_funds(&1_u64, &s.contributor);
@@ -214,8 +220,10 @@ fn test_inv2_lock_then_refund_invariant_holds() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     // Advance past deadline
     s.env.ledger().set_timestamp(deadline + 1);
@@ -238,8 +246,10 @@ fn test_inv2_all_released_contract_empty() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &500, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &300, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &500, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &300, &deadline, &None);
 
     s.escrow.release_funds(&1_u64, &s.contributor);
     s.escrow.release_funds(&2_u64, &s.contributor);
@@ -260,7 +270,7 @@ fn test_inv2_partial_release_invariant_holds() {
     let deadline = s.env.ledger().timestamp() + 5_000;
 
     s.escrow
-        .lock_funds(&s.depositor, &1_u64, &10_000, &deadline);
+        .lock_funds(&s.depositor, &1_u64, &10_000, &deadline, &None);
 
     // Partial release
     s.escrow.partial_release(&1_u64, &s.contributor, &3_000);
@@ -284,7 +294,8 @@ fn test_inv4_refund_after_deadline_consistent() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &5_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &5_000, &deadline, &None);
 
     s.env.ledger().set_timestamp(deadline + 1);
     s.escrow.refund(&1_u64);
@@ -326,8 +337,10 @@ fn test_inv5_no_orphans_after_normal_flow() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     s.env.as_contract(&s.escrow.address, || {
         let orphans = multitoken_invariants::count_orphaned_index_entries(&s.env);
@@ -340,7 +353,8 @@ fn test_inv5_tampered_index_detects_orphan() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     // Tamper: add a fake bounty ID to the index without a backing escrow
     s.env.as_contract(&s.escrow.address, || {
@@ -376,8 +390,10 @@ fn test_full_invariant_report_healthy() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     s.env.as_contract(&s.escrow.address, || {
         let report = multitoken_invariants::check_all_invariants(&s.env);
@@ -397,9 +413,12 @@ fn test_full_invariant_report_after_mixed_operations() {
     let deadline = s.env.ledger().timestamp() + 1_000;
 
     // Lock 3 bounties
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &3_u64, &3_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &3_u64, &3_000, &deadline, &None);
 
     // Release bounty 1
     s.escrow.release_funds(&1_u64, &s.contributor);
@@ -440,7 +459,8 @@ fn test_check_invariants_reports_healthy_state() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_500, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_500, &deadline, &None);
 
     let report = s.escrow.check_invariants();
     assert!(report.healthy);
@@ -476,7 +496,8 @@ fn test_tampered_balance_detected_by_invariant() {
     let s = InvSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     // Tamper: modify the escrow remaining_amount without moving tokens
     s.env.as_contract(&s.escrow.address, || {
@@ -518,7 +539,7 @@ fn test_invariant_maintained_through_full_lifecycle() {
     // Phase 1: Multiple locks
     for i in 1_u64..=5 {
         s.escrow
-            .lock_funds(&s.depositor, &i, &(i as i128 * 1_000), &deadline);
+            .lock_funds(&s.depositor, &i, &(i as i128 * 1_000), &deadline, &None);
     }
     assert!(s.escrow.verify_all_invariants());
 
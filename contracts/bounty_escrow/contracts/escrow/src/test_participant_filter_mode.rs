@@ -50,7 +50,7 @@ fn test_default_mode_is_disabled() {
     let (client, depositor, _other, _token) = setup(&env);
     assert_eq!(client.get_filter_mode(), ParticipantFilterMode::Disabled);
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
     // Any address can lock when mode is Disabled
 }
 
@@ -63,11 +63,11 @@ fn test_blocklist_only_rejects_blocklisted() {
     client.set_blocklist_entry(&depositor, &true);
 
     let deadline = env.ledger().timestamp() + 86_400;
-    let res = client.try_lock_funds(&depositor, &1, &100, &deadline);
+    let res = client.try_lock_funds(&depositor, &1, &100, &deadline, &None);
     assert!(res.is_err());
 
     // Non-blocklisted can lock
-    client.lock_funds(&other, &2, &100, &deadline);
+    client.lock_funds(&other, &2, &100, &deadline, &None);
 }
 
 #[test]
@@ -78,8 +78,8 @@ fn test_blocklist_only_allows_non_blocklisted() {
     client.set_filter_mode(&ParticipantFilterMode::BlocklistOnly);
     // depositor not blocklisted
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
-    client.lock_funds(&other, &2, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    client.lock_funds(&other, &2, &100, &deadline, &None);
 }
 
 #[test]
@@ -92,9 +92,9 @@ fn test_allowlist_only_rejects_non_allowlisted() {
     // other is not on allowlist
 
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
 
-    let res = client.try_lock_funds(&other, &2, &100, &deadline);
+    let res = client.try_lock_funds(&other, &2, &100, &deadline, &None);
     assert!(res.is_err());
 }
 
@@ -108,8 +108,8 @@ fn test_allowlist_only_allows_allowlisted() {
     client.set_whitelist_entry(&other, &true);
 
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
-    client.lock_funds(&other, &2, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    client.lock_funds(&other, &2, &100, &deadline, &None);
 }
 
 #[test]
@@ -127,9 +127,9 @@ fn test_mode_transition_disabled_to_blocklist_only() {
     client.set_blocklist_entry(&depositor, &true);
     let deadline = env.ledger().timestamp() + 86_400;
     assert!(client
-        .try_lock_funds(&depositor, &1, &100, &deadline)
+        .try_lock_funds(&depositor, &1, &100, &deadline, &None)
         .is_err());
-    client.lock_funds(&other, &2, &100, &deadline);
+    client.lock_funds(&other, &2, &100, &deadline, &None);
 }
 
 #[test]
@@ -140,13 +140,15 @@ fn test_mode_transition_blocklist_only_to_allowlist_only() {
     client.set_filter_mode(&ParticipantFilterMode::BlocklistOnly);
     client.set_blocklist_entry(&other, &true);
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
 
     client.set_filter_mode(&ParticipantFilterMode::AllowlistOnly);
     client.set_whitelist_entry(&depositor, &true);
     // other is blocklisted but mode is now allowlist: other is not allowlisted so rejected
-    assert!(client.try_lock_funds(&other, &2, &100, &deadline).is_err());
-    client.lock_funds(&depositor, &3, &100, &deadline);
+    assert!(client
+        .try_lock_funds(&other, &2, &100, &deadline, &None)
+        .is_err());
+    client.lock_funds(&depositor, &3, &100, &deadline, &None);
 }
 
 #[test]
@@ -157,12 +159,14 @@ fn test_mode_transition_back_to_disabled() {
     client.set_filter_mode(&ParticipantFilterMode::AllowlistOnly);
     client.set_whitelist_entry(&depositor, &true);
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
-    assert!(client.try_lock_funds(&other, &2, &100, &deadline).is_err());
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    assert!(client
+        .try_lock_funds(&other, &2, &100, &deadline, &None)
+        .is_err());
 
     client.set_filter_mode(&ParticipantFilterMode::Disabled);
     // With Disabled, other can now lock (no list check)
-    client.lock_funds(&other, &2, &100, &deadline);
+    client.lock_funds(&other, &2, &100, &deadline, &None);
 }
 
 #[test]
@@ -175,14 +179,18 @@ fn test_lists_persist_across_mode_switch() {
 
     client.set_filter_mode(&ParticipantFilterMode::AllowlistOnly);
     let deadline = env.ledger().timestamp() + 86_400;
-    client.lock_funds(&depositor, &1, &100, &deadline);
-    assert!(client.try_lock_funds(&other, &2, &100, &deadline).is_err());
+    client.lock_funds(&depositor, &1, &100, &deadline, &None);
+    assert!(client
+        .try_lock_funds(&other, &2, &100, &deadline, &None)
+        .is_err());
 
     client.set_filter_mode(&ParticipantFilterMode::Disabled);
     client.set_filter_mode(&ParticipantFilterMode::AllowlistOnly);
     // Still only depositor allowlisted; other still not on allowlist
-    assert!(client.try_lock_funds(&other, &3, &100, &deadline).is_err());
-    client.lock_funds(&depositor, &4, &100, &deadline);
+    assert!(client
+        .try_lock_funds(&other, &3, &100, &deadline, &None)
+        .is_err());
+    client.lock_funds(&depositor, &4, &100, &deadline, &None);
 }
 
 #[test]
@@ -213,6 +221,76 @@ fn test_batch_lock_funds_respects_filter_mode() {
     assert!(res.is_err());
 }
 
+#[test]
+fn test_batch_lock_funds_rejects_blocklisted_depositor() {
+    let env = create_env();
+    let (client, depositor, other, token) = setup(&env);
+
+    client.set_filter_mode(&ParticipantFilterMode::BlocklistOnly);
+    client.set_blocklist_entry(&other, &true);
+
+    let deadline = env.ledger().timestamp() + 86_400;
+    let items = vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 10,
+            depositor: depositor.clone(),
+            amount: 100,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 11,
+            depositor: other.clone(),
+            amount: 100,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 12,
+            depositor: depositor.clone(),
+            amount: 100,
+            deadline,
+        },
+    ];
+    // The revert carries only the filter error; the view reports the index
+    assert_eq!(client.check_batch_participants(&items), Some(1));
+    let res = client.try_batch_lock_funds(&items);
+    assert_eq!(res, Err(Ok(Error::ParticipantBlocked)));
+
+    // Nothing from the batch was locked
+    assert!(client.try_get_escrow_info(&10).is_err());
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_batch_lock_funds_all_allowed_succeeds() {
+    let env = create_env();
+    let (client, depositor, other, token) = setup(&env);
+
+    client.set_filter_mode(&ParticipantFilterMode::AllowlistOnly);
+    client.set_whitelist_entry(&depositor, &true);
+    client.set_whitelist_entry(&other, &true);
+
+    let deadline = env.ledger().timestamp() + 86_400;
+    let items = vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 10,
+            depositor: depositor.clone(),
+            amount: 100,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 11,
+            depositor: other.clone(),
+            amount: 200,
+            deadline,
+        },
+    ];
+    assert_eq!(client.check_batch_participants(&items), None);
+    assert_eq!(client.batch_lock_funds(&items), 2);
+    assert_eq!(token.balance(&client.address), 300);
+}
+
 #[test]
 fn test_set_filter_mode_emits_event_and_persists() {
     let env = create_env();
@@ -85,8 +85,10 @@ fn test_sequential_lock_funds_succeeds() {
     let deadline = s.env.ledger().timestamp() + 5_000;
 
     // Two sequential locks must both succeed (guard released between calls)
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     assert_eq!(s.escrow.get_escrow_info(&1_u64).amount, 1_000);
     assert_eq!(s.escrow.get_escrow_info(&2_u64).amount, 2_000);
@@ -97,8 +99,10 @@ fn test_sequential_release_funds_succeeds() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     // Two sequential releases must both succeed
     s.escrow.release_funds(&1_u64, &s.contributor);
@@ -120,7 +124,8 @@ fn test_sequential_partial_releases_succeed() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     // Multiple sequential partial releases
     s.escrow.partial_release(&1_u64, &s.contributor, &300);
@@ -140,8 +145,10 @@ fn test_sequential_refunds_succeed() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     s.env.ledger().set_timestamp(deadline + 1);
 
@@ -163,14 +170,16 @@ fn test_sequential_claim_calls_succeed() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 10_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     s.escrow.set_claim_window(&500_u64);
     s.escrow
-        .authorize_claim(&1_u64, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&1_u64, &s.contributor, &DisputeReason::Other, &false);
     s.escrow
-        .authorize_claim(&2_u64, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&2_u64, &s.contributor, &DisputeReason::Other, &false);
 
     // Both claims within window must succeed
     s.escrow.claim(&1_u64);
@@ -190,7 +199,7 @@ fn test_release_funds_updates_state_before_transfer() {
     let amount = 5_000_i128;
 
     s.escrow
-        .lock_funds(&s.depositor, &1_u64, &amount, &deadline);
+        .lock_funds(&s.depositor, &1_u64, &amount, &deadline, &None);
     s.escrow.release_funds(&1_u64, &s.contributor);
 
     // After release: escrow shows Released and zero remaining,
@@ -209,7 +218,8 @@ fn test_partial_release_updates_state_before_transfer() {
     let total = 1_000_i128;
     let payout = 400_i128;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &total, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &total, &deadline, &None);
     s.escrow.partial_release(&1_u64, &s.contributor, &payout);
 
     let info = s.escrow.get_escrow_info(&1_u64);
@@ -225,10 +235,10 @@ fn test_claim_updates_state_before_transfer() {
     let amount = 2_000_i128;
 
     s.escrow
-        .lock_funds(&s.depositor, &1_u64, &amount, &deadline);
+        .lock_funds(&s.depositor, &1_u64, &amount, &deadline, &None);
     s.escrow.set_claim_window(&500_u64);
     s.escrow
-        .authorize_claim(&1_u64, &s.contributor, &DisputeReason::Other);
+        .authorize_claim(&1_u64, &s.contributor, &DisputeReason::Other, &false);
     s.escrow.claim(&1_u64);
 
     let info = s.escrow.get_escrow_info(&1_u64);
@@ -244,7 +254,7 @@ fn test_refund_updates_state_before_transfer() {
     let amount = 3_000_i128;
 
     s.escrow
-        .lock_funds(&s.depositor, &1_u64, &amount, &deadline);
+        .lock_funds(&s.depositor, &1_u64, &amount, &deadline, &None);
     s.env.ledger().set_timestamp(deadline + 1);
 
     let before = s.token.balance(&s.depositor);
@@ -265,10 +275,12 @@ fn test_lock_then_release_then_lock_again_succeeds() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.escrow.release_funds(&1_u64, &s.contributor);
     // Lock a new bounty — the guard must be clear
-    s.escrow.lock_funds(&s.depositor, &2_u64, &2_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &2_000, &deadline, &None);
 
     assert_eq!(s.escrow.get_escrow_info(&2_u64).amount, 2_000);
 }
@@ -278,7 +290,8 @@ fn test_partial_release_then_refund_succeeds() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 1_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.escrow.partial_release(&1_u64, &s.contributor, &400);
 
     s.env.ledger().set_timestamp(deadline + 1);
@@ -320,7 +333,8 @@ fn test_batch_lock_funds_guard_cleared_after_success() {
     assert_eq!(count, 2);
 
     // Follow up with a single lock — guard must be clear
-    s.escrow.lock_funds(&s.depositor, &12_u64, &700, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &12_u64, &700, &deadline, &None);
     assert_eq!(s.escrow.get_escrow_info(&12_u64).amount, 700);
 }
 
@@ -329,8 +343,10 @@ fn test_batch_release_funds_guard_cleared_after_success() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &10_u64, &500, &deadline);
-    s.escrow.lock_funds(&s.depositor, &11_u64, &600, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &10_u64, &500, &deadline, &None);
+    s.escrow
+        .lock_funds(&s.depositor, &11_u64, &600, &deadline, &None);
 
     let items = vec![
         &s.env,
@@ -348,7 +364,8 @@ fn test_batch_release_funds_guard_cleared_after_success() {
     assert_eq!(count, 2);
 
     // Follow up with another release — guard must be clear
-    s.escrow.lock_funds(&s.depositor, &12_u64, &700, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &12_u64, &700, &deadline, &None);
     s.escrow.release_funds(&12_u64, &s.contributor);
 
     assert_eq!(s.token.balance(&s.contributor), 500 + 600 + 700);
@@ -363,7 +380,8 @@ fn test_emergency_withdraw_guard_cleared() {
     let s = ReentrancyTestSetup::new();
     let deadline = s.env.ledger().timestamp() + 5_000;
 
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
 
     // Pause lock to enable emergency withdraw
     s.escrow.set_paused(
@@ -385,7 +403,8 @@ fn test_emergency_withdraw_guard_cleared() {
         &None::<soroban_sdk::String>,
     );
     s.token_admin.mint(&s.depositor, &5_000);
-    s.escrow.lock_funds(&s.depositor, &2_u64, &500, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &2_u64, &500, &deadline, &None);
     assert_eq!(s.escrow.get_escrow_info(&2_u64).amount, 500);
 }
 
@@ -402,7 +421,8 @@ fn test_reentrancy_guard_model_documentation() {
     let deadline = s.env.ledger().timestamp() + 5_000;
 
     // Normal flow works
-    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow
+        .lock_funds(&s.depositor, &1_u64, &1_000, &deadline, &None);
     s.escrow.release_funds(&1_u64, &s.contributor);
 
     assert_eq!(s.token.balance(&s.contributor), 1_000);
@@ -52,7 +52,7 @@ impl<'a> RenewTestSetup<'a> {
     /// Lock a bounty and return the bounty_id
     fn lock_bounty(&self, bounty_id: u64, amount: i128, deadline: u64) {
         self.escrow
-            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline, &None);
     }
 }
 
@@ -28,7 +28,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 1000;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Try to refund before deadline - should fail
         let result = client.try_refund(&bounty_id);
@@ -54,7 +54,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Grace period disabled by default (grace_period_seconds = 0, enabled = false)
         let config = client.get_settlement_grace_period_config();
@@ -95,7 +95,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Advance to deadline
         env.ledger().set_timestamp(deadline);
@@ -134,7 +134,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Advance past grace period (100 + 500 + 1 = 601)
         env.ledger().set_timestamp(deadline + 501);
@@ -170,7 +170,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Advance to middle of grace period
         env.ledger().set_timestamp(deadline + 250);
@@ -209,7 +209,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 2_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Create a schedule for release at deadline
         let release_ts = deadline;
@@ -309,7 +309,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Advance to deadline
         env.ledger().set_timestamp(deadline);
@@ -342,7 +342,7 @@ mod test_settlement_grace_periods {
         let amount: i128 = 1_000_0000;
         let deadline = env.ledger().timestamp() + 100;
         token_admin_client.mint(&depositor, &amount);
-        client.lock_funds(&depositor, &bounty_id, &amount, &deadline);
+        client.lock_funds(&depositor, &bounty_id, &amount, &deadline, &None);
 
         // Advance to deadline
         env.ledger().set_timestamp(deadline);
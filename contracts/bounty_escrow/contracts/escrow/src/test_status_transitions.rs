@@ -72,7 +72,7 @@ fn test_locked_to_released() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     assert_eq!(
         setup.escrow.get_escrow_info(&bounty_id).status,
         EscrowStatus::Locked
@@ -95,7 +95,7 @@ fn test_locked_to_refunded() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     assert_eq!(
         setup.escrow.get_escrow_info(&bounty_id).status,
         EscrowStatus::Locked
@@ -119,7 +119,7 @@ fn test_locked_to_partially_refunded() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     assert_eq!(
         setup.escrow.get_escrow_info(&bounty_id).status,
         EscrowStatus::Locked
@@ -146,7 +146,7 @@ fn test_partially_refunded_to_refunded() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 
     // First partial refund
     setup
@@ -178,12 +178,12 @@ fn test_released_to_locked_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 }
 
 // Invalid transition: Released → Released
@@ -197,7 +197,7 @@ fn test_released_to_released_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
@@ -214,7 +214,7 @@ fn test_released_to_refunded_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 
     setup.env.ledger().set_timestamp(deadline + 1);
@@ -232,7 +232,7 @@ fn test_released_to_partially_refunded_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 
     setup.env.ledger().set_timestamp(deadline + 1);
@@ -252,13 +252,13 @@ fn test_refunded_to_locked_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&bounty_id);
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 }
 
 // Invalid transition: Refunded → Released
@@ -272,7 +272,7 @@ fn test_refunded_to_released_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&bounty_id);
 
@@ -290,7 +290,7 @@ fn test_refunded_to_refunded_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&bounty_id);
 
@@ -308,7 +308,7 @@ fn test_refunded_to_partially_refunded_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup.env.ledger().set_timestamp(deadline + 1);
     setup.escrow.refund(&bounty_id);
 
@@ -328,7 +328,7 @@ fn test_partially_refunded_to_locked_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup
         .escrow
         .approve_refund(&bounty_id, &500, &setup.depositor, &RefundMode::Partial);
@@ -336,7 +336,7 @@ fn test_partially_refunded_to_locked_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
 }
 
 // Invalid transition: PartiallyRefunded → Released
@@ -350,7 +350,7 @@ fn test_partially_refunded_to_released_fails() {
 
     setup
         .escrow
-        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+        .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline, &None);
     setup
         .escrow
         .approve_refund(&bounty_id, &500, &setup.depositor, &RefundMode::Partial);
@@ -50,7 +50,7 @@ fn test_upgrade_locked_bounty_remains_locked() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &5_000, &deadline);
+    client.lock_funds(&depositor, &1, &5_000, &deadline, &None);
 
     // Simulate upgrade by verifying state persistence (WASM swap keeps storage)
     let escrow = client.get_escrow_info(&1);
@@ -74,7 +74,7 @@ fn test_upgrade_complete_release_flow() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &5_000, &deadline);
+    client.lock_funds(&depositor, &1, &5_000, &deadline, &None);
 
     let escrow = client.get_escrow_info(&1);
     assert_eq!(escrow.status, EscrowStatus::Locked);
@@ -100,7 +100,7 @@ fn test_upgrade_pending_lock_then_refund() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 100;
-    client.lock_funds(&depositor, &2, &5_000, &deadline);
+    client.lock_funds(&depositor, &2, &5_000, &deadline, &None);
 
     // Advance time past deadline
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
@@ -126,7 +126,7 @@ fn test_upgrade_partial_release_then_complete() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &3, &6_000, &deadline);
+    client.lock_funds(&depositor, &3, &6_000, &deadline, &None);
 
     client.partial_release(&3, &contributor, &2_000);
 
@@ -188,7 +188,7 @@ fn test_safety_check_with_locked_escrows() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &5_000, &deadline);
+    client.lock_funds(&depositor, &1, &5_000, &deadline, &None);
 
     let report = env.as_contract(&contract_id, || upgrade_safety::simulate_upgrade(&env));
     assert!(report.is_safe, "Safety check should pass with locked escrows");
@@ -269,7 +269,7 @@ fn test_safety_check_with_released_escrow() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 1_000;
-    client.lock_funds(&depositor, &1, &5_000, &deadline);
+    client.lock_funds(&depositor, &1, &5_000, &deadline, &None);
     client.release_funds(&1, &contributor);
 
     let report = env.as_contract(&contract_id, || upgrade_safety::simulate_upgrade(&env));
@@ -293,7 +293,7 @@ fn test_safety_check_with_refunded_escrow() {
     token_admin_client.mint(&depositor, &10_000);
 
     let deadline = env.ledger().timestamp() + 100;
-    client.lock_funds(&depositor, &1, &5_000, &deadline);
+    client.lock_funds(&depositor, &1, &5_000, &deadline, &None);
 
     env.ledger().set_timestamp(env.ledger().timestamp() + 200);
 
@@ -324,9 +324,9 @@ fn test_safety_check_with_multiple_escrows() {
     let deadline2 = env.ledger().timestamp() + 1_000;
     let deadline3 = env.ledger().timestamp() + 100;
 
-    client.lock_funds(&depositor, &1, &5_000, &deadline1);
-    client.lock_funds(&depositor, &2, &5_000, &deadline2);
-    client.lock_funds(&depositor, &3, &5_000, &deadline3);
+    client.lock_funds(&depositor, &1, &5_000, &deadline1, &None);
+    client.lock_funds(&depositor, &2, &5_000, &deadline2, &None);
+    client.lock_funds(&depositor, &3, &5_000, &deadline3, &None);
 
     // Release bounty 1
     client.release_funds(&1, &contributor);
@@ -18,7 +18,7 @@
 // ============================================================
 
 use crate::{DataKey, ProgramData, PROGRAM_DATA};
-use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol, Vec};
 
 /// The status of a pending claim record.
 #[contracttype]
@@ -44,13 +44,17 @@ pub struct ClaimRecord {
     pub status: ClaimStatus,
 }
 
-// Event symbols
+// Event symbols. Each payload ends with the contract-wide `event_seq`.
 const CLAIM_CREATED: Symbol = symbol_short!("ClmCrtd");
 const CLAIM_EXECUTED: Symbol = symbol_short!("ClmExec");
 const CLAIM_CANCELLED: Symbol = symbol_short!("ClmCncl");
+const CLAIM_EXPIRED: Symbol = symbol_short!("ClmExpd");
 
 // Storage key for auto-incrementing claim IDs
 const NEXT_CLAIM_ID: Symbol = symbol_short!("NxtClmId");
+// Per-program index of claim IDs and count of those still pending
+const PROGRAM_CLAIMS: Symbol = symbol_short!("PrgClms");
+const PENDING_CLAIMS: Symbol = symbol_short!("PndClms");
 
 fn next_claim_id(env: &Env) -> u64 {
     let id: u64 = env
@@ -77,6 +81,39 @@ fn claim_key(program_id: &String, claim_id: u64) -> DataKey {
     DataKey::PendingClaim(program_id.clone(), claim_id)
 }
 
+fn program_claim_ids(env: &Env, program_id: &String) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&(PROGRAM_CLAIMS, program_id.clone()))
+        .unwrap_or_else(|| Vec::new(env))
+}
+
+fn pending_count(env: &Env, program_id: &String) -> u32 {
+    env.storage()
+        .instance()
+        .get(&(PENDING_CLAIMS, program_id.clone()))
+        .unwrap_or(0)
+}
+
+fn index_claim(env: &Env, program_id: &String, claim_id: u64) {
+    let mut ids = program_claim_ids(env, program_id);
+    ids.push_back(claim_id);
+    env.storage()
+        .persistent()
+        .set(&(PROGRAM_CLAIMS, program_id.clone()), &ids);
+    env.storage().instance().set(
+        &(PENDING_CLAIMS, program_id.clone()),
+        &(pending_count(env, program_id) + 1),
+    );
+}
+
+fn unmark_pending(env: &Env, program_id: &String) {
+    env.storage().instance().set(
+        &(PENDING_CLAIMS, program_id.clone()),
+        &pending_count(env, program_id).saturating_sub(1),
+    );
+}
+
 // ── Public functions ─────────────────────────────────────────
 // These functions should be called from the ProgramEscrowContract impl.
 
@@ -129,6 +166,7 @@ pub fn create_pending_claim(
     env.storage()
         .persistent()
         .set(&claim_key(program_id, claim_id), &record);
+    index_claim(env, program_id, claim_id);
 
     env.events().publish(
         (CLAIM_CREATED,),
@@ -138,6 +176,7 @@ pub fn create_pending_claim(
             recipient.clone(),
             amount,
             claim_deadline,
+            crate::ProgramEscrowContract::next_event_seq(env),
         ),
     );
 
@@ -146,9 +185,10 @@ pub fn create_pending_claim(
 
 // Executes (redeems) a pending claim before its deadline.
 //
-// Transfers the reserved escrowed funds to the recipient.
+// Transfers the reserved escrowed funds to the recipient and returns the
+// amount received, net of any early-claim penalty.
 
-pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Address) {
+pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Address) -> i128 {
     caller.require_auth();
 
     let key = claim_key(program_id, claim_id);
@@ -173,18 +213,29 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
         panic!("ClaimExpired");
     }
 
+    // claims before the program's ready time forfeit the early-claim penalty
+    let penalty = crate::ProgramEscrowContract::apply_early_claim_penalty(
+        env,
+        program_id,
+        &record.recipient,
+        record.amount,
+    );
+
     // transfer funds to recipient
     let program = get_program(env);
     let token_client = soroban_sdk::token::Client::new(env, &program.token_address);
+    let received = record.amount - penalty;
     token_client.transfer(
         &env.current_contract_address(),
         &record.recipient,
-        &record.amount,
+        &received,
     );
 
     // marks the claim as completed and persist the update.
     record.status = ClaimStatus::Completed;
     env.storage().persistent().set(&key, &record);
+    unmark_pending(env, program_id);
+    crate::ProgramEscrowContract::record_financials(env, program_id, 0, record.amount, 0);
 
     env.events().publish(
         (CLAIM_EXECUTED,),
@@ -193,22 +244,21 @@ pub fn execute_claim(env: &Env, program_id: &String, claim_id: u64, caller: &Add
             claim_id,
             record.recipient.clone(),
             record.amount,
+            crate::ProgramEscrowContract::next_event_seq(env),
         ),
     );
+    received
 }
-/// Admin cancels a claim pending or expired and returns reserved funds to escrow.
-pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Address) {
-    // Only contract admin can cancel
-    let stored_admin: Address = env
-        .storage()
-        .instance()
-        .get(&DataKey::Admin)
-        .unwrap_or_else(|| panic!("Not initialized"));
 
-    if *admin != stored_admin {
-        panic!("Unauthorized: only admin can cancel claims");
-    }
-    admin.require_auth();
+/// Authorized payout key expires a pending claim immediately, e.g. when the
+/// winner is disqualified.
+///
+/// The deadline is moved to just before the current ledger time so any later
+/// `execute_claim` fails with `ClaimExpired`. The funds stay reserved until
+/// the admin cancels the claim.
+pub fn expire_claim_now(env: &Env, program_id: &String, claim_id: u64) {
+    let program = get_program(env);
+    program.authorized_payout_key.require_auth();
 
     let key = claim_key(program_id, claim_id);
     let mut record: ClaimRecord = env
@@ -216,12 +266,94 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
         .persistent()
         .get(&key)
         .unwrap_or_else(|| panic!("Claim not found"));
+    match record.status {
+        ClaimStatus::Pending => {}
+        _ => panic!("ClaimAlreadyProcessed"),
+    }
+
+    let now = env.ledger().timestamp();
+    record.claim_deadline = record.claim_deadline.min(now.saturating_sub(1));
+    env.storage().persistent().set(&key, &record);
+
+    env.events().publish(
+        (CLAIM_EXPIRED,),
+        (
+            program_id.clone(),
+            claim_id,
+            record.recipient.clone(),
+            now,
+            crate::ProgramEscrowContract::next_event_seq(env),
+        ),
+    );
+}
+
+/// Admin cancels a claim pending or expired and returns reserved funds to escrow.
+pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Address) {
+    require_admin(env, admin);
+
+    let key = claim_key(program_id, claim_id);
+    let record: ClaimRecord = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic!("Claim not found"));
 
     // can only cancel Pending claims (completed claims are final)
     match record.status {
         ClaimStatus::Pending => {}
         _ => panic!("ClaimAlreadyProcessed"),
     }
+    cancel_pending(env, program_id, record);
+}
+
+/// Admin cancels several claims at once, e.g. when winding down a program.
+///
+/// Claims that are no longer pending are skipped, unless `strict` is set, in
+/// which case the whole batch is rejected. Returns the number cancelled.
+pub fn batch_cancel_claims(
+    env: &Env,
+    program_id: &String,
+    claim_ids: &Vec<u64>,
+    admin: &Address,
+    strict: bool,
+) -> u32 {
+    require_admin(env, admin);
+
+    let mut cancelled = 0u32;
+    for claim_id in claim_ids.iter() {
+        let record: ClaimRecord = env
+            .storage()
+            .persistent()
+            .get(&claim_key(program_id, claim_id))
+            .unwrap_or_else(|| panic!("Claim not found"));
+
+        if record.status != ClaimStatus::Pending {
+            if strict {
+                panic!("ClaimAlreadyProcessed");
+            }
+            continue;
+        }
+        cancel_pending(env, program_id, record);
+        cancelled += 1;
+    }
+    cancelled
+}
+
+fn require_admin(env: &Env, admin: &Address) {
+    // Only contract admin can cancel
+    let stored_admin: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .unwrap_or_else(|| panic!("Not initialized"));
+
+    if *admin != stored_admin {
+        panic!("Unauthorized: only admin can cancel claims");
+    }
+    admin.require_auth();
+}
+
+fn cancel_pending(env: &Env, program_id: &String, mut record: ClaimRecord) {
     // return reserved funds to escrow balance
     let mut program = get_program(env);
     program.remaining_balance += record.amount;
@@ -229,19 +361,49 @@ pub fn cancel_claim(env: &Env, program_id: &String, claim_id: u64, admin: &Addre
 
     // mark claim as cancelled
     record.status = ClaimStatus::Cancelled;
-    env.storage().persistent().set(&key, &record);
+    env.storage()
+        .persistent()
+        .set(&claim_key(program_id, record.claim_id), &record);
+    unmark_pending(env, program_id);
+    crate::ProgramEscrowContract::reopen_schedule_claim(env, program_id, record.claim_id);
 
     env.events().publish(
         (CLAIM_CANCELLED,),
         (
             program_id.clone(),
-            claim_id,
+            record.claim_id,
             record.recipient.clone(),
             record.amount,
+            crate::ProgramEscrowContract::next_event_seq(env),
         ),
     );
 }
 
+/// Whether any claim of `program_id` is still pending, expired or not.
+pub fn has_pending_claims(env: &Env, program_id: &String) -> bool {
+    pending_count(env, program_id) > 0
+}
+
+/// Extend the TTL of every claim record of `program_id`, and of the
+/// program's claim index, to `ledgers`.
+pub fn extend_claims_ttl(env: &Env, program_id: &String, ledgers: u32) {
+    let index_key = (PROGRAM_CLAIMS, program_id.clone());
+    if !env.storage().persistent().has(&index_key) {
+        return;
+    }
+    env.storage()
+        .persistent()
+        .extend_ttl(&index_key, ledgers, ledgers);
+    for claim_id in program_claim_ids(env, program_id).iter() {
+        let key = claim_key(program_id, claim_id);
+        if env.storage().persistent().has(&key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&key, ledgers, ledgers);
+        }
+    }
+}
+
 /// Returns a claim record by its ID.
 ///
 /// Panics if the claim does not exist.
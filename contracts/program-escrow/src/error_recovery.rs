@@ -59,6 +59,12 @@ pub enum CircuitBreakerKey {
     Config,
     /// Operation-level error log (last N errors)
     ErrorLog,
+    /// Seconds within which HalfOpen probe successes must accumulate (0 = no window)
+    ProbeWindow,
+    /// Timestamp of the first success in the current HalfOpen probe run
+    ProbeStartedAt,
+    /// Seconds after which error log entries expire (0 = never)
+    ErrorLogTtl,
 }
 
 /// Configuration for the circuit breaker.
@@ -94,6 +100,18 @@ pub struct ErrorEntry {
     pub failure_count_at_time: u32,
 }
 
+/// Emitted when the circuit changes state on its own (threshold trip or
+/// successful probe), as opposed to an admin reset.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CircuitStateChanged {
+    pub old_state: CircuitState,
+    pub new_state: CircuitState,
+    /// `fail_thr` (failure threshold), `th_breach` (threshold monitor) or `probe_ok`
+    pub reason: soroban_sdk::Symbol,
+    pub timestamp: u64,
+}
+
 /// Snapshot of the circuit breaker's current status (returned by `get_status`).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -139,6 +157,46 @@ pub fn set_config(env: &Env, config: CircuitBreakerConfig) {
         .set(&CircuitBreakerKey::Config, &config);
 }
 
+/// Returns the HalfOpen probe window in seconds (0 = disabled).
+pub fn get_probe_window(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&CircuitBreakerKey::ProbeWindow)
+        .unwrap_or(0)
+}
+
+/// Sets the HalfOpen probe window. Admin only (caller must enforce auth).
+///
+/// When non-zero, `success_threshold` successes must all land within
+/// `window` seconds of the first probe success; otherwise progress resets.
+pub fn set_probe_window(env: &Env, window: u64) {
+    env.storage()
+        .persistent()
+        .set(&CircuitBreakerKey::ProbeWindow, &window);
+}
+
+/// Returns the error log TTL in seconds (0 = entries never expire).
+pub fn get_error_log_ttl(env: &Env) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&CircuitBreakerKey::ErrorLogTtl)
+        .unwrap_or(0)
+}
+
+/// Sets the error log TTL. Admin only (caller must enforce auth).
+///
+/// When non-zero, entries older than `ttl` seconds are hidden from
+/// `get_error_log` and pruned on the next `record_failure`.
+pub fn set_error_log_ttl(env: &Env, ttl: u64) {
+    env.storage()
+        .persistent()
+        .set(&CircuitBreakerKey::ErrorLogTtl, &ttl);
+}
+
+fn is_entry_live(entry: &ErrorEntry, now: u64, ttl: u64) -> bool {
+    ttl == 0 || entry.timestamp.saturating_add(ttl) > now
+}
+
 /// Returns the current circuit state.
 pub fn get_state(env: &Env) -> CircuitState {
     env.storage()
@@ -210,7 +268,14 @@ pub fn check_and_allow_with_thresholds(env: &Env) -> Result<(), u32> {
     // Then check thresholds
     if let Err(breach) = crate::threshold_monitor::check_thresholds(env) {
         // Threshold breached - open circuit
+        let old_state = get_state(env);
         open_circuit(env);
+        emit_state_changed(
+            env,
+            old_state,
+            CircuitState::Open,
+            symbol_short!("th_breach"),
+        );
         crate::threshold_monitor::emit_threshold_breach_event(env, &breach);
         crate::threshold_monitor::apply_cooldown(env);
 
@@ -231,7 +296,9 @@ pub fn check_and_allow_with_thresholds(env: &Env) -> Result<(), u32> {
 /// **Call this after a SUCCESSFUL protected operation.**
 ///
 /// In HalfOpen: increments success counter; closes the circuit when
-/// `success_threshold` is reached.
+/// `success_threshold` is reached. If a probe window is configured and it
+/// has lapsed since the first success of the current run, progress restarts
+/// from this success.
 /// In Closed: resets failure counter to 0.
 pub fn record_success(env: &Env) {
     let state = get_state(env);
@@ -247,7 +314,25 @@ pub fn record_success(env: &Env) {
         }
         CircuitState::HalfOpen => {
             let config = get_config(env);
-            let successes = get_success_count(env) + 1;
+            let window = get_probe_window(env);
+            let now = env.ledger().timestamp();
+            let mut successes = get_success_count(env) + 1;
+
+            if window > 0 {
+                let started_at: u64 = env
+                    .storage()
+                    .persistent()
+                    .get(&CircuitBreakerKey::ProbeStartedAt)
+                    .unwrap_or(now);
+                if successes == 1 || now.saturating_sub(started_at) > window {
+                    // First probe of a run, or the window lapsed — start over
+                    successes = 1;
+                    env.storage()
+                        .persistent()
+                        .set(&CircuitBreakerKey::ProbeStartedAt, &now);
+                }
+            }
+
             env.storage()
                 .persistent()
                 .set(&CircuitBreakerKey::SuccessCount, &successes);
@@ -255,6 +340,12 @@ pub fn record_success(env: &Env) {
             if successes >= config.success_threshold {
                 // Enough successes — close the circuit
                 close_circuit(env);
+                emit_state_changed(
+                    env,
+                    CircuitState::HalfOpen,
+                    CircuitState::Closed,
+                    symbol_short!("probe_ok"),
+                );
             }
         }
         CircuitState::Open => {
@@ -284,12 +375,19 @@ pub fn record_failure(
         .persistent()
         .set(&CircuitBreakerKey::LastFailureTimestamp, &now);
 
-    // Append to error log (capped at max_error_log)
-    let mut log: soroban_sdk::Vec<ErrorEntry> = env
+    // Append to error log (capped at max_error_log), dropping expired entries
+    let stored: soroban_sdk::Vec<ErrorEntry> = env
         .storage()
         .persistent()
         .get(&CircuitBreakerKey::ErrorLog)
         .unwrap_or(soroban_sdk::Vec::new(env));
+    let ttl = get_error_log_ttl(env);
+    let mut log = soroban_sdk::Vec::new(env);
+    for entry in stored.iter() {
+        if is_entry_live(&entry, now, ttl) {
+            log.push_back(entry);
+        }
+    }
 
     let entry = ErrorEntry {
         operation: operation.clone(),
@@ -312,7 +410,14 @@ pub fn record_failure(
 
     // Open circuit if threshold exceeded
     if failures >= config.failure_threshold {
+        let old_state = get_state(env);
         open_circuit(env);
+        emit_state_changed(
+            env,
+            old_state,
+            CircuitState::Open,
+            symbol_short!("fail_thr"),
+        );
     }
 }
 
@@ -409,8 +514,26 @@ pub fn get_circuit_admin(env: &Env) -> Option<Address> {
     env.storage().persistent().get(&CircuitBreakerKey::Admin)
 }
 
-/// Returns the full error log.
+/// Returns the error log, excluding entries older than the TTL.
 pub fn get_error_log(env: &Env) -> soroban_sdk::Vec<ErrorEntry> {
+    let stored: soroban_sdk::Vec<ErrorEntry> = env
+        .storage()
+        .persistent()
+        .get(&CircuitBreakerKey::ErrorLog)
+        .unwrap_or(soroban_sdk::Vec::new(env));
+    let now = env.ledger().timestamp();
+    let ttl = get_error_log_ttl(env);
+    let mut log = soroban_sdk::Vec::new(env);
+    for entry in stored.iter() {
+        if is_entry_live(&entry, now, ttl) {
+            log.push_back(entry);
+        }
+    }
+    log
+}
+
+/// Returns the error log as stored, including expired entries not yet pruned.
+pub fn get_stored_error_log(env: &Env) -> soroban_sdk::Vec<ErrorEntry> {
     env.storage()
         .persistent()
         .get(&CircuitBreakerKey::ErrorLog)
@@ -580,6 +703,28 @@ fn emit_circuit_event(env: &Env, event_type: soroban_sdk::Symbol, value: u32) {
     );
 }
 
+/// Emit `CircuitStateChanged` for an automatic transition; no-op if the state
+/// did not actually change.
+fn emit_state_changed(
+    env: &Env,
+    old_state: CircuitState,
+    new_state: CircuitState,
+    reason: soroban_sdk::Symbol,
+) {
+    if old_state == new_state {
+        return;
+    }
+    env.events().publish(
+        (symbol_short!("circuit"), symbol_short!("cb_state")),
+        CircuitStateChanged {
+            old_state,
+            new_state,
+            reason,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
 // ─────────────────────────────────────────────────────────
 // Invariant Verification
 // ─────────────────────────────────────────────────────────
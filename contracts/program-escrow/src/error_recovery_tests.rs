@@ -3,13 +3,18 @@
 #![cfg(test)]
 
 use soroban_sdk::testutils::Address as TestAddress;
-use soroban_sdk::{contract, contractimpl, symbol_short, testutils::Ledger, Address, Env, String};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short,
+    testutils::{Events, Ledger},
+    Address, Env, String, Symbol, TryFromVal,
+};
 
 use crate::error_recovery::{
     check_and_allow, close_circuit, execute_with_retry, get_circuit_admin, get_config,
-    get_error_log, get_failure_count, get_state, get_status, get_success_count, half_open_circuit,
-    open_circuit, record_failure, record_success, reset_circuit_breaker, set_circuit_admin,
-    set_config, CircuitBreakerConfig, CircuitState, RetryConfig, ERR_CIRCUIT_OPEN,
+    get_error_log, get_failure_count, get_state, get_status, get_stored_error_log,
+    get_success_count, half_open_circuit, open_circuit, record_failure, record_success,
+    reset_circuit_breaker, set_circuit_admin, set_config, set_error_log_ttl, set_probe_window,
+    CircuitBreakerConfig, CircuitState, CircuitStateChanged, RetryConfig, ERR_CIRCUIT_OPEN,
     ERR_TRANSFER_FAILED,
 };
 
@@ -465,6 +470,47 @@ fn test_error_log_contains_latest_errors_when_capped() {
     });
 }
 
+#[test]
+fn test_error_log_ttl_hides_and_prunes_expired_entries() {
+    let (env, _admin, contract_id) = setup_with_admin(100);
+    let prog = String::from_str(&env, "TestProg");
+    let op = symbol_short!("op");
+
+    env.as_contract(&contract_id, || {
+        set_error_log_ttl(&env, 3600);
+        record_failure(&env, prog.clone(), op.clone(), ERR_TRANSFER_FAILED);
+        record_failure(&env, prog.clone(), op.clone(), ERR_TRANSFER_FAILED);
+    });
+
+    env.ledger().set_timestamp(1000 + 3600);
+    env.as_contract(&contract_id, || {
+        // Expired entries are filtered from reads but still stored
+        assert_eq!(get_error_log(&env).len(), 0);
+        assert_eq!(get_stored_error_log(&env).len(), 2);
+
+        // The next failure prunes them before appending
+        record_failure(&env, prog.clone(), op.clone(), ERR_CIRCUIT_OPEN);
+        let stored = get_stored_error_log(&env);
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored.get(0).unwrap().error_code, ERR_CIRCUIT_OPEN);
+        assert_eq!(get_error_log(&env).len(), 1);
+    });
+}
+
+#[test]
+fn test_error_log_without_ttl_keeps_old_entries() {
+    let (env, _admin, contract_id) = setup_with_admin(100);
+    env.as_contract(&contract_id, || {
+        let prog = String::from_str(&env, "TestProg");
+        record_failure(&env, prog, symbol_short!("op"), ERR_TRANSFER_FAILED);
+    });
+
+    env.ledger().set_timestamp(1000 + 365 * 24 * 3600);
+    env.as_contract(&contract_id, || {
+        assert_eq!(get_error_log(&env).len(), 1);
+    });
+}
+
 // ─────────────────────────────────────────────────────────
 // 10. Retry integration: exhaustion opens circuit
 // ─────────────────────────────────────────────────────────
@@ -1591,3 +1637,114 @@ fn test_zero_initial_backoff_with_multiplier() {
     assert_eq!(config.compute_backoff(1), 0);
     assert_eq!(config.compute_backoff(10), 0);
 }
+
+// ─────────────────────────────────────────────────────────
+// 29. HalfOpen probe window
+// ─────────────────────────────────────────────────────────
+
+/// Opens the circuit, resets it to HalfOpen and sets a probe window.
+fn setup_half_open_with_probe_window(success_threshold: u32, window: u64) -> (Env, Address) {
+    let (env, contract_id) = setup_env();
+    let admin = Address::generate(&env);
+    env.as_contract(&contract_id, || {
+        set_circuit_admin(&env, admin.clone(), None);
+        set_config(
+            &env,
+            CircuitBreakerConfig {
+                failure_threshold: 2,
+                success_threshold,
+                max_error_log: 10,
+            },
+        );
+        set_probe_window(&env, window);
+    });
+    simulate_failures(&env, &contract_id, 2);
+    env.as_contract(&contract_id, || {
+        reset_circuit_breaker(&env, &admin);
+        assert_eq!(get_state(&env), CircuitState::HalfOpen);
+    });
+    (env, contract_id)
+}
+
+#[test]
+fn test_probe_successes_within_window_close_circuit() {
+    let (env, contract_id) = setup_half_open_with_probe_window(3, 60);
+    env.as_contract(&contract_id, || {
+        record_success(&env);
+        env.ledger().set_timestamp(1030);
+        record_success(&env);
+        assert_eq!(get_state(&env), CircuitState::HalfOpen);
+        env.ledger().set_timestamp(1060);
+        record_success(&env);
+        assert_eq!(get_state(&env), CircuitState::Closed);
+    });
+}
+
+#[test]
+fn test_probe_gap_longer_than_window_resets_progress() {
+    let (env, contract_id) = setup_half_open_with_probe_window(2, 60);
+    env.as_contract(&contract_id, || {
+        record_success(&env);
+        assert_eq!(get_success_count(&env), 1);
+
+        // Second success arrives after the window lapsed — counts as a fresh first probe
+        env.ledger().set_timestamp(1061);
+        record_success(&env);
+        assert_eq!(get_state(&env), CircuitState::HalfOpen);
+        assert_eq!(get_success_count(&env), 1);
+
+        env.ledger().set_timestamp(1100);
+        record_success(&env);
+        assert_eq!(get_state(&env), CircuitState::Closed);
+    });
+}
+
+// ─────────────────────────────────────────────────────────
+// Automatic state change events
+// ─────────────────────────────────────────────────────────
+
+/// All `CircuitStateChanged` payloads published so far.
+fn state_changes(env: &Env) -> soroban_sdk::Vec<CircuitStateChanged> {
+    let mut changes = soroban_sdk::Vec::new(env);
+    for (_, topics, data) in env.events().all().iter() {
+        let tag: Option<Symbol> = topics
+            .get(1)
+            .and_then(|t| Symbol::try_from_val(env, &t).ok());
+        if tag == Some(symbol_short!("cb_state")) {
+            changes.push_back(CircuitStateChanged::try_from_val(env, &data).unwrap());
+        }
+    }
+    changes
+}
+
+#[test]
+fn test_failure_threshold_trip_emits_state_changed() {
+    let (env, _admin, contract_id) = setup_with_admin(3);
+    simulate_failures(&env, &contract_id, 2);
+    assert_eq!(state_changes(&env).len(), 0);
+
+    // The third failure trips the circuit; the fourth, while already open, is
+    // not another transition
+    simulate_failures(&env, &contract_id, 2);
+    let changes = state_changes(&env);
+    assert_eq!(changes.len(), 1);
+    let change = changes.get(0).unwrap();
+    assert_eq!(change.old_state, CircuitState::Closed);
+    assert_eq!(change.new_state, CircuitState::Open);
+    assert_eq!(change.reason, symbol_short!("fail_thr"));
+}
+
+#[test]
+fn test_probe_close_emits_state_changed() {
+    let (env, admin, contract_id) = setup_with_admin(2);
+    simulate_failures(&env, &contract_id, 2);
+    env.as_contract(&contract_id, || {
+        reset_circuit_breaker(&env, &admin);
+        record_success(&env);
+    });
+
+    let change = state_changes(&env).last().unwrap();
+    assert_eq!(change.old_state, CircuitState::HalfOpen);
+    assert_eq!(change.new_state, CircuitState::Closed);
+    assert_eq!(change.reason, symbol_short!("probe_ok"));
+}
@@ -119,7 +119,7 @@
 //!     2_000_0000000,  // 3rd place: 2,000 USDC
 //! ];
 //!
-//! escrow_client.batch_payout(&winners, &prizes);
+//! escrow_client.batch_payout(&winners, &prizes, &false, &false);
 //! ```
 //!
 //! ## Event System
@@ -141,21 +141,53 @@
 
 #![no_std]
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
 
 // Event types
 const PROGRAM_INITIALIZED: Symbol = symbol_short!("PrgInit");
 const FUNDS_LOCKED: Symbol = symbol_short!("FndsLock");
+const FUNDS_UNLOCKED: Symbol = symbol_short!("FndsUnlk");
 const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
 const PAYOUT: Symbol = symbol_short!("Payout");
+const PAYOUT_QUEUED: Symbol = symbol_short!("PayQueued");
+const PAYOUT_DROPPED: Symbol = symbol_short!("PayDrop");
+const PAYOUT_MEMO: Symbol = symbol_short!("PayMemo");
+const LOCK_MEMO: Symbol = symbol_short!("LockMemo");
+const PAYOUT_NOTICE: Symbol = symbol_short!("PayNotice");
+const PRIVATE_PAYOUT: Symbol = symbol_short!("PrivPay");
+const POOL_FINALIZED: Symbol = symbol_short!("PoolFinal");
+const EARLY_CLAIM_PENALTY: Symbol = symbol_short!("ErlyPen");
+
+/// Category recorded for payouts made without an explicit one
+pub const DEFAULT_PAYOUT_CATEGORY: Symbol = symbol_short!("payout");
 const EVENT_VERSION_V2: u32 = 2;
+/// Events carrying `event_seq`
+const EVENT_VERSION_V3: u32 = 3;
 const PAUSE_STATE_CHANGED: Symbol = symbol_short!("PauseSt");
 const MAINTENANCE_MODE_CHANGED: Symbol = symbol_short!("MaintSt");
 const PROGRAM_RISK_FLAGS_UPDATED: Symbol = symbol_short!("pr_risk");
 const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgRgd");
+const PAYOUT_KEY_ROTATED: Symbol = symbol_short!("PayKeyRot");
+const PROGRAM_TRANSFER_PROPOSED: Symbol = symbol_short!("XferProp");
+const PROGRAM_TRANSFER_ACCEPTED: Symbol = symbol_short!("XferAcpt");
+const PROGRAM_TRANSFER_CANCELLED: Symbol = symbol_short!("XferCncl");
+const CLAIMABLE_CREATED: Symbol = symbol_short!("ClmblNew");
+const CLAIMABLE_WITHDRAWN: Symbol = symbol_short!("ClmblWdr");
+const WINNER_REGISTERED: Symbol = symbol_short!("WinReg");
+const WINNER_PAID: Symbol = symbol_short!("WinPaid");
+const BALANCE_SNAPSHOT: Symbol = symbol_short!("BalSnap");
+const SCHEDULE_RELEASED: Symbol = symbol_short!("SchedRel");
+const PERMANENTLY_FROZEN: Symbol = symbol_short!("PermFrzn");
+const PROPORTIONAL_REFUND: Symbol = symbol_short!("PropRfnd");
+const SCHEDULE_RESCHEDULED: Symbol = symbol_short!("SchedResc");
+const RECIPIENT_REASSIGNED: Symbol = symbol_short!("RcptReasn");
+const PROGRAM_CANCELLED: Symbol = symbol_short!("PrgCncl");
+const CANCEL_REFUND: Symbol = symbol_short!("CnclRfnd");
+const HEARTBEAT: Symbol = symbol_short!("Heartbeat");
+const BLACKLIST_UPDATED: Symbol = symbol_short!("BlkLstUpd");
 
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
@@ -166,12 +198,57 @@ const NEXT_SCHEDULE_ID: Symbol = symbol_short!("NxtSched");
 const PROGRAM_INDEX: Symbol = symbol_short!("ProgIdx");
 const AUTH_KEY_INDEX: Symbol = symbol_short!("AuthIdx");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
+const FROZEN: Symbol = symbol_short!("Frozen");
+const CASE_INSENSITIVE_IDS: Symbol = symbol_short!("CaseIns");
+const AUTO_RELEASE_ON_TOPUP: Symbol = symbol_short!("AutoRel");
+const SCHEDULE_CLAIM: Symbol = symbol_short!("SchedClm");
+const CLAIM_SCHEDULE: Symbol = symbol_short!("ClmSched");
+const RELEASE_BALANCE_CHECK: Symbol = symbol_short!("RelBalChk");
+const CANCELLED: Symbol = symbol_short!("Cancelled");
+const BATCH_PROGRESS: Symbol = symbol_short!("BatchProg");
+const PROGRAM_TTL: Symbol = symbol_short!("ProgTTL");
+const TTL_POLICY: Symbol = symbol_short!("TtlPolicy");
+const EVENT_SEQ: Symbol = symbol_short!("EventSeq");
+const RECIPIENT_ALLOWLIST: Symbol = symbol_short!("RcptAllow");
+const ALLOWLIST_ENFORCED: Symbol = symbol_short!("AllowEnf");
+const HEARTBEAT_CONFIG: Symbol = symbol_short!("HbConfig");
+const LAST_HEARTBEAT: Symbol = symbol_short!("LastHb");
+const PROGRAM_BLACKLIST: Symbol = symbol_short!("ProgBlk");
+const PAYOUT_CATEGORY: Symbol = symbol_short!("PayCat");
+const SCHEDULE_DEPENDENCY: Symbol = symbol_short!("SchedDep");
+const SCHEDULE_FAILURE: Symbol = symbol_short!("SchedFail");
+const EVENT_SALT: Symbol = symbol_short!("EventSalt");
+
+/// Confirmation `permanently_freeze` requires, spelled out so the call
+/// cannot be made by accident.
+pub const FREEZE_CONFIRMATION: &str = "I UNDERSTAND THIS CANNOT BE UNDONE";
 
 // Fee rate is stored in basis points (1 basis point = 0.01%)
 // Example: 100 basis points = 1%, 1000 basis points = 10%
 const BASIS_POINTS: i128 = 10_000;
 const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
 
+// Release schedules a program may hold unless overridden via set_max_schedules
+const DEFAULT_MAX_SCHEDULES: u32 = 100;
+
+// Failed automatic release attempts before a schedule is marked failed,
+// unless overridden via set_max_release_failures
+const DEFAULT_MAX_RELEASE_FAILURES: u32 = 3;
+
+// Largest page returned by get_program_release_history_page
+const MAX_HISTORY_PAGE_SIZE: u32 = 50;
+
+// Longest depositor memo accepted by lock_program_funds, in bytes
+const MAX_LOCK_MEMO_LEN: u32 = 128;
+
+// How long a proposed program transfer stays acceptable, in seconds (7 days)
+const PROGRAM_TRANSFER_WINDOW: u64 = 604_800;
+
+// Instance TTL bump applied on every mutation unless overridden via
+// set_ttl_policy, in ledgers (~1 day threshold, ~30 days extension)
+const DEFAULT_INSTANCE_TTL_THRESHOLD: u32 = 17_280;
+const DEFAULT_INSTANCE_TTL_EXTEND_TO: u32 = 518_400;
+
 pub const RISK_FLAG_HIGH_RISK: u32 = 1 << 0;
 pub const RISK_FLAG_UNDER_REVIEW: u32 = 1 << 1;
 pub const RISK_FLAG_RESTRICTED: u32 = 1 << 2;
@@ -193,6 +270,7 @@ mod monitoring {
     const OPERATION_COUNT: &str = "op_count";
     const USER_COUNT: &str = "usr_count";
     const ERROR_COUNT: &str = "err_count";
+    const PAYOUT_VOLUME: &str = "pay_vol";
 
     // Event: Operation metric
     #[contracttype]
@@ -254,18 +332,65 @@ mod monitoring {
         pub last_called: u64,
     }
 
+    // Event: Counter saturated
+    #[contracttype]
+    #[derive(Clone, Debug)]
+    pub struct CounterSaturated {
+        pub counter: Symbol,
+        pub attempted: i128,
+        pub timestamp: u64,
+    }
+
+    // Emit when an aggregate would overflow and has been capped instead
+    pub fn emit_counter_saturated(env: &Env, counter: Symbol, attempted: i128) {
+        env.events().publish(
+            (symbol_short!("metric"), symbol_short!("cnt_sat")),
+            CounterSaturated {
+                counter,
+                attempted,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    // Increment a u64 counter, capping at u64::MAX
+    fn increment(env: &Env, name: &str) {
+        let key = Symbol::new(env, name);
+        let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        let next = count.checked_add(1).unwrap_or_else(|| {
+            emit_counter_saturated(env, key.clone(), 1);
+            u64::MAX
+        });
+        env.storage().persistent().set(&key, &next);
+    }
+
     // Track operation
     pub fn track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
-        let key = Symbol::new(env, OPERATION_COUNT);
-        let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
-        env.storage().persistent().set(&key, &(count + 1));
+        increment(env, OPERATION_COUNT);
 
         if !success {
-            let err_key = Symbol::new(env, ERROR_COUNT);
-            let err_count: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
-            env.storage().persistent().set(&err_key, &(err_count + 1));
+            increment(env, ERROR_COUNT);
         }
     }
+
+    // Track payout volume, capping at i128::MAX so monitoring never aborts a payout
+    pub fn record_payout_volume(env: &Env, amount: i128) {
+        let key = Symbol::new(env, PAYOUT_VOLUME);
+        let total: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let next = total.checked_add(amount).unwrap_or_else(|| {
+            emit_counter_saturated(env, key.clone(), amount);
+            i128::MAX
+        });
+        env.storage().persistent().set(&key, &next);
+    }
+
+    // Get payout volume
+    pub fn get_payout_volume(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, PAYOUT_VOLUME))
+            .unwrap_or(0)
+    }
 }
 
 // ── Step 1: Add module declarations near the top of lib.rs ──────────────
@@ -283,6 +408,19 @@ pub struct PayoutRecord {
     pub timestamp: u64,
 }
 
+/// Aggregate record of one batch payout, for accounting systems that
+/// reconcile per batch rather than per recipient.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchReceipt {
+    pub batch_id: u64,
+    pub program_id: String,
+    /// Gross amount debited from the program
+    pub total_amount: i128,
+    pub recipient_count: u32,
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramInitializedEvent {
@@ -300,6 +438,30 @@ pub struct FundsLockedEvent {
     pub program_id: String,
     pub amount: i128,
     pub remaining_balance: i128,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+/// A program's balance right after a lock or payout, for time-series indexing.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceSnapshotEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub remaining_balance: i128,
+    pub timestamp: u64,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundsUnlockedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub amount: i128,
+    pub to: Address,
+    pub remaining_balance: i128,
 }
 
 #[contracttype]
@@ -308,8 +470,140 @@ pub struct BatchPayoutEvent {
     pub version: u32,
     pub program_id: String,
     pub recipient_count: u32,
+    /// Gross amount debited from the program
     pub total_amount: i128,
     pub remaining_balance: i128,
+    /// Payout fees withheld across the batch
+    pub fee_amount: i128,
+    /// Amount actually delivered to recipients (`total_amount - fee_amount`)
+    pub net_amount: i128,
+    /// `total_amount` in micro-USD at the program's reference rate, if set.
+    /// Informational only.
+    pub usd_equivalent: Option<i128>,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockMemoEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub amount: i128,
+    pub memo: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleRescheduledEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub old_timestamp: u64,
+    pub new_timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecipientReassignedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub old_recipient: Address,
+    pub new_recipient: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProportionalRefundEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub depositor: Address,
+    pub amount: i128,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PoolFinalizedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub total_funds: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramCancelledEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub remaining_balance: i128,
+    pub cancelled_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CancelRefundEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub to: Address,
+    pub amount: i128,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+/// Liveness signal for monitoring, see `heartbeat`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HeartbeatEvent {
+    pub version: u32,
+    pub timestamp: u64,
+    /// `get_health_detailed().healthy` at the time of the heartbeat
+    pub healthy: bool,
+}
+
+/// Payout event emitted instead of the recipient-revealing ones when event
+/// privacy is on. Carries only the SHA-256 of the program's event salt
+/// followed by the recipient's XDR encoding; the amount stays in the
+/// program's payout history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrivatePayoutEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub recipient_hash: BytesN<32>,
+    pub category: Symbol,
+}
+
+/// Per-recipient notice published under `(PayNotice, category, recipient)`
+/// so wallets can filter incoming payments by category.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutNoticeEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub category: Symbol,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutMemoEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub memo: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutQueuedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub total_amount: i128,
+    pub queue_length: u32,
 }
 
 #[contracttype]
@@ -318,8 +612,91 @@ pub struct PayoutEvent {
     pub version: u32,
     pub program_id: String,
     pub recipient: Address,
+    /// Gross amount, kept for decoders predating the fee fields
     pub amount: i128,
     pub remaining_balance: i128,
+    pub gross_amount: i128,
+    pub fee_amount: i128,
+    pub net_amount: i128,
+    /// `gross_amount` in micro-USD at the program's reference rate, if set.
+    /// Informational only.
+    pub usd_equivalent: Option<i128>,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutKeyRotatedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub old_key: Address,
+    pub new_key: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WinnerPaidEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub label: String,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimablePayoutEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub claimable_balance: i128,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+/// A release schedule paid out, manually or automatically.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleReleasedEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub schedule_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub release_type: ReleaseType,
+    /// Contract-wide sequence number, see `get_event_seq`
+    pub event_seq: u64,
+}
+
+/// Penalty withheld from claims made before `ready_ts`, returned to the
+/// program's remaining balance.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyClaimPenalty {
+    pub bps: u32,
+    pub ready_ts: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyClaimPenaltyEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub recipient: Address,
+    pub penalty: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramTransferEvent {
+    pub version: u32,
+    pub program_id: String,
+    pub old_key: Address,
+    pub new_key: Address,
+    pub timestamp: u64,
 }
 
 #[contracttype]
@@ -362,20 +739,55 @@ pub struct ProgramData {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    Program(String),                 // program_id -> ProgramData
-    Admin,                           // Contract Admin
-    ReleaseSchedule(String, u64),    // program_id, schedule_id -> ProgramReleaseSchedule
-    ReleaseHistory(String),          // program_id -> Vec<ProgramReleaseHistory>
-    NextScheduleId(String),          // program_id -> next schedule_id
-    MultisigConfig(String),          // program_id -> MultisigConfig
-    PayoutApproval(String, Address), // program_id, recipient -> PayoutApproval
-    PendingClaim(String, u64),       // (program_id, schedule_id) -> ClaimRecord
-    ClaimWindow,                     // u64 seconds (global config)
-    PauseFlags,                      // PauseFlags struct
-    RateLimitConfig,                 // RateLimitConfig struct
-    MaintenanceMode,                 // bool flag
-    ProgramDependencies(String),     // program_id -> Vec<String>
-    DependencyStatus(String),        // program_id -> DependencyStatus
+    Program(String),                   // program_id -> ProgramData
+    Admin,                             // Contract Admin
+    ReleaseSchedule(String, u64),      // program_id, schedule_id -> ProgramReleaseSchedule
+    ReleaseHistory(String),            // program_id -> Vec<ProgramReleaseHistory>
+    NextScheduleId(String),            // program_id -> next schedule_id
+    MultisigConfig(String),            // program_id -> MultisigConfig
+    PayoutApproval(String, Address),   // program_id, recipient -> PayoutApproval
+    PendingClaim(String, u64),         // (program_id, schedule_id) -> ClaimRecord
+    ClaimWindow,                       // u64 seconds (global config)
+    PauseFlags,                        // PauseFlags struct
+    RateLimitConfig,                   // RateLimitConfig struct
+    MaintenanceMode,                   // bool flag
+    ProgramDependencies(String),       // program_id -> Vec<String>
+    DependencyStatus(String),          // program_id -> DependencyStatus
+    MaxSchedules(String),              // program_id -> u32 schedule cap
+    PayoutQueue(String),               // program_id -> Vec<QueuedPayout>
+    RequireMemo(String),               // program_id -> bool
+    MinPayout(String),                 // program_id -> i128 smallest computed share paid out
+    StrictRecipients(String),          // program_id -> bool
+    RegisteredWinner(String, Address), // program_id, address -> label
+    Financials(String),                // program_id -> ProgramFinancials
+    PendingTransfer(String),           // program_id -> PendingProgramTransfer
+    AccruedFees(String),               // program_id -> i128 payout fees held for withdrawal
+    Claimable(String, Address),        // program_id, recipient -> i128 awaiting withdrawal
+    RecoveryConfig,                    // RecoveryConfig
+    RecoveryApprovals(String),         // program_id -> Vec<Address>
+    ProgramPaused(String),             // program_id -> bool
+    VerifyDelivery(String),            // program_id -> bool
+    SignerKey(Address),                // signer -> ed25519 public key
+    SignerNonce(Address),              // signer -> u64 nonce of the next signed approval
+    LockRequiresMultisig(String),      // program_id -> bool
+    LockApproval(String),              // program_id -> LockApproval
+    RateLimitQuorum,                   // RateLimitQuorum
+    RateLimitProposal,                 // RateLimitProposal
+    MaxReleaseFailures(String),        // program_id -> u32 failed release attempts allowed
+    BalanceSnapshots(String),          // program_id -> bool
+    BlackoutWindow(String),            // program_id -> (start, end) payout freeze
+    ReferenceRate(String),             // program_id -> micro-USD per token unit
+    ProgramTag(String),                // program_id -> Symbol extra payout event topic
+    MaxFeePerPayout,                   // i128 absolute payout fee cap, 0 = uncapped
+    BatchReceipt(String, u64),         // (program_id, batch_id) -> BatchReceipt
+    NextBatchId(String),               // program_id -> next batch_id
+    Reserve(String),                   // program_id -> i128 balance held back from payouts
+    EventPrivacy(String),              // program_id -> bool, hash recipients in payout events
+    CommittedPool(String),             // program_id -> i128 announced prize pool total
+    PoolFinalized(String),             // program_id -> bool, locks blocked once set
+    EarlyClaimPenalty(String),         // program_id -> EarlyClaimPenalty
+    Contributions(String),             // program_id -> Map<Address, i128> locked per depositor
+    LockMemo(String),                  // program_id -> String note of the latest lock
 }
 
 #[contracttype]
@@ -407,6 +819,24 @@ pub struct MaintenanceModeChanged {
     pub timestamp: u64,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PermanentlyFrozenEvent {
+    pub version: u32,
+    pub admin: Address,
+    pub timestamp: u64,
+}
+
+/// Signers who must approve an emergency withdrawal, and the only address
+/// it may send funds to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RecoveryConfig {
+    pub signers: Vec<Address>,
+    pub required_signatures: u32,
+    pub recovery_address: Address,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EmergencyWithdrawEvent {
@@ -417,6 +847,28 @@ pub struct EmergencyWithdrawEvent {
     pub receipt_id: u64,
 }
 
+/// A program transfer waiting for the proposed key to accept. It lapses at
+/// `expires_at`, or once the payout key or admin that stood behind the
+/// proposal has changed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingProgramTransfer {
+    pub new_key: Address,
+    pub proposed_by: Address,
+    pub admin: Option<Address>,
+    pub expires_at: u64,
+}
+
+/// A batch payout deferred while the circuit breaker was open.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct QueuedPayout {
+    pub recipients: Vec<Address>,
+    pub amounts: Vec<i128>,
+    pub total_amount: i128,
+    pub queued_at: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RateLimitConfig {
@@ -425,6 +877,49 @@ pub struct RateLimitConfig {
     pub cooldown_period: u64,
 }
 
+/// Admins who must jointly approve rate limit config changes once quorum
+/// mode is enabled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitQuorum {
+    pub admins: Vec<Address>,
+    pub required_approvals: u32,
+}
+
+/// A rate limit change that needs quorum approval once quorum mode is on.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitChange {
+    /// Replace the global config.
+    Global(RateLimitConfig),
+    /// Set one operation's override.
+    SetOperation(Symbol, RateLimitConfig),
+    /// Clear one operation's override.
+    ClearOperation(Symbol),
+    /// Replace the quorum itself.
+    Quorum(RateLimitQuorum),
+}
+
+/// A pending rate limit change and the quorum admins who approved it. The
+/// first approval is the proposer's.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitProposal {
+    pub change: RateLimitChange,
+    pub approvals: Vec<Address>,
+}
+
+/// Lifetime money movements of a program, kept apart from `total_funds`,
+/// which is reduced by unlocks.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramFinancials {
+    pub lifetime_locked: i128,
+    pub lifetime_paid_out: i128,
+    pub lifetime_refunded: i128,
+    pub remaining_balance: i128,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Analytics {
@@ -435,6 +930,20 @@ pub struct Analytics {
     pub operation_count: u32,
 }
 
+/// Breakdown of the individual checks behind the contract's health.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthReport {
+    /// Circuit breaker is Closed
+    pub circuit_ok: bool,
+    /// Program balance is within total funds and backed by the token balance
+    pub balance_consistent: bool,
+    /// Operations left before the rate limit's `max_operations` is reached
+    pub rate_limit_headroom: u32,
+    /// All of the above checks pass
+    pub healthy: bool,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramReleaseSchedule {
@@ -447,6 +956,28 @@ pub struct ProgramReleaseSchedule {
     pub released_by: Option<Address>,
 }
 
+/// Failed release attempts of a per-program schedule, kept beside the
+/// schedule under its own key.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleFailure {
+    /// Release attempts whose token transfer failed.
+    pub failure_count: u32,
+    pub last_failure_reason: Option<Symbol>,
+    /// Set once `failure_count` reaches the program's max failures; keepers
+    /// stop retrying a failed schedule.
+    pub failed: bool,
+}
+
+/// One schedule to create via `batch_create_prog_schedules`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleItem {
+    pub recipient: Address,
+    pub amount: i128,
+    pub release_timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramReleaseHistory {
@@ -489,6 +1020,24 @@ pub struct MultisigConfig {
     pub required_signatures: u32,
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutApproval {
+    pub program_id: String,
+    pub recipient: Address,
+    pub amount: i128,
+    pub approvals: Vec<Address>,
+}
+
+/// Multisig approvals collected for a pending lock of `amount`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LockApproval {
+    pub program_id: String,
+    pub amount: i128,
+    pub approvals: Vec<Address>,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProgramAggregateStats {
@@ -503,9 +1052,21 @@ pub struct ProgramAggregateStats {
     pub released_count: u32,
 }
 
-#[contracterror]
-#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
-#[repr(u32)]
+/// Distribution of a program's payouts. All fields are zero when the program
+/// has paid nothing; `average` is rounded down.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutStats {
+    pub count: u32,
+    pub total: i128,
+    pub average: i128,
+    pub min: i128,
+    pub max: i128,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
 pub enum BatchError {
     InvalidBatchSize = 1,
     ProgramAlreadyExists = 2,
@@ -564,13 +1125,201 @@ fn path_exists_to_target(
 }
 
 mod anti_abuse {
-    use soroban_sdk::{symbol_short, Address, Env, Symbol};
+    use crate::{DataKey, RateLimitConfig};
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
     const RATE_LIMIT: Symbol = symbol_short!("RateLim");
 
-    pub fn check_rate_limit(env: &Env, _caller: Address) {
+    /// Operation types passed to `check_rate_limit`.
+    pub const OP_INIT_PROGRAM: Symbol = symbol_short!("init_prg");
+    pub const OP_BATCH_PAYOUT: Symbol = symbol_short!("batch_pay");
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct OperationState {
+        pub last_operation_timestamp: u64,
+        pub window_start_timestamp: u64,
+        pub operation_count: u32,
+    }
+
+    #[contracttype]
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub enum RateLimitKey {
+        /// operation -> RateLimitConfig overriding the global config
+        OperationConfig(Symbol),
+        /// (operation, caller) -> OperationState
+        State(Symbol, Address),
+        /// bool: clear the cooldown after a successful operation
+        ResetCooldownOnSuccess,
+        /// u64: minimum seconds between admin rotations
+        AdminRotationCooldown,
+        /// u64: timestamp of the last `set_admin`
+        LastAdminRotation,
+        /// u64: timestamp the rotation cooldown was last changed
+        AdminCooldownUpdated,
+    }
+
+    pub fn set_operation_config(env: &Env, operation: Symbol, config: Option<RateLimitConfig>) {
+        let key = RateLimitKey::OperationConfig(operation);
+        match config {
+            Some(config) => env.storage().instance().set(&key, &config),
+            None => env.storage().instance().remove(&key),
+        }
+    }
+
+    /// The limit enforced for `operation`: its own config if set, otherwise the
+    /// global config once an admin has stored one. The default reported by
+    /// `get_rate_limit_config` is never enforced; `None` means unlimited.
+    pub fn get_effective_config(env: &Env, operation: Symbol) -> Option<RateLimitConfig> {
+        env.storage()
+            .instance()
+            .get(&RateLimitKey::OperationConfig(operation))
+            .or_else(|| env.storage().instance().get(&DataKey::RateLimitConfig))
+    }
+
+    pub fn check_rate_limit(env: &Env, caller: Address, operation: Symbol) {
         let count: u32 = env.storage().instance().get(&RATE_LIMIT).unwrap_or(0);
         env.storage().instance().set(&RATE_LIMIT, &(count + 1));
+
+        let config = match get_effective_config(env, operation.clone()) {
+            Some(config) => config,
+            None => return,
+        };
+        let now = env.ledger().timestamp();
+        let key = RateLimitKey::State(operation.clone(), caller.clone());
+
+        let mut state: OperationState =
+            env.storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(OperationState {
+                    last_operation_timestamp: 0,
+                    window_start_timestamp: now,
+                    operation_count: 0,
+                });
+
+        // 1. Cooldown check
+        if state.last_operation_timestamp > 0
+            && now
+                < state
+                    .last_operation_timestamp
+                    .saturating_add(config.cooldown_period)
+        {
+            env.events().publish(
+                (symbol_short!("abuse"), symbol_short!("cooldown")),
+                (caller, operation, now),
+            );
+            panic!("Operation in cooldown period");
+        }
+
+        // 2. Window check
+        if now
+            >= state
+                .window_start_timestamp
+                .saturating_add(config.window_size)
+        {
+            // New window
+            state.window_start_timestamp = now;
+            state.operation_count = 1;
+        } else {
+            // Same window
+            if state.operation_count >= config.max_operations {
+                env.events().publish(
+                    (symbol_short!("abuse"), symbol_short!("limit")),
+                    (caller, operation, now),
+                );
+                panic!("Rate limit exceeded");
+            }
+            state.operation_count += 1;
+        }
+
+        state.last_operation_timestamp = now;
+        env.storage().persistent().set(&key, &state);
+    }
+
+    pub fn set_reset_cooldown_on_success(env: &Env, enabled: bool) {
+        env.storage()
+            .instance()
+            .set(&RateLimitKey::ResetCooldownOnSuccess, &enabled);
+    }
+
+    pub fn get_reset_cooldown_on_success(env: &Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&RateLimitKey::ResetCooldownOnSuccess)
+            .unwrap_or(false)
+    }
+
+    /// Clear the caller's cooldown after a successful operation when enabled.
+    /// The operation still counts toward the window cap.
+    pub fn record_success(env: &Env, caller: Address, operation: Symbol) {
+        if !get_reset_cooldown_on_success(env) {
+            return;
+        }
+        let key = RateLimitKey::State(operation, caller);
+        if let Some(mut state) = env.storage().persistent().get::<_, OperationState>(&key) {
+            state.last_operation_timestamp = 0;
+            env.storage().persistent().set(&key, &state);
+        }
+    }
+
+    pub fn get_operation_count(env: &Env) -> u32 {
+        env.storage().instance().get(&RATE_LIMIT).unwrap_or(0)
+    }
+
+    /// Lowering the cooldown is itself held to the current cooldown: it is
+    /// rejected until that long has passed since the last rotation and the
+    /// last cooldown change, so a fresh admin key cannot zero it and rotate
+    /// straight away.
+    pub fn set_admin_rotation_cooldown(env: &Env, cooldown: u64) {
+        let now = env.ledger().timestamp();
+        let current = get_admin_rotation_cooldown(env);
+        if cooldown < current {
+            let storage = env.storage().instance();
+            let last_rotation: u64 = storage.get(&RateLimitKey::LastAdminRotation).unwrap_or(0);
+            let last_update: u64 = storage
+                .get(&RateLimitKey::AdminCooldownUpdated)
+                .unwrap_or(0);
+            if now < last_rotation.max(last_update).saturating_add(current) {
+                panic!("Admin rotation cooldown active");
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&RateLimitKey::AdminRotationCooldown, &cooldown);
+        env.storage()
+            .instance()
+            .set(&RateLimitKey::AdminCooldownUpdated, &now);
+    }
+
+    pub fn get_admin_rotation_cooldown(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&RateLimitKey::AdminRotationCooldown)
+            .unwrap_or(0)
+    }
+
+    /// Reject an admin rotation that lands within the cooldown of the last
+    /// one, then record this rotation.
+    pub fn check_admin_rotation(env: &Env) {
+        let now = env.ledger().timestamp();
+        let cooldown = get_admin_rotation_cooldown(env);
+        if let Some(last) = env
+            .storage()
+            .instance()
+            .get::<_, u64>(&RateLimitKey::LastAdminRotation)
+        {
+            if cooldown > 0 && now < last.saturating_add(cooldown) {
+                env.events().publish(
+                    (symbol_short!("abuse"), symbol_short!("adm_rot")),
+                    (last, now),
+                );
+                panic!("Admin rotation too soon");
+            }
+        }
+        env.storage()
+            .instance()
+            .set(&RateLimitKey::LastAdminRotation, &now);
     }
 }
 
@@ -580,6 +1329,9 @@ pub use claim_period::{ClaimRecord, ClaimStatus};
 mod test_claim_period_expiry_cancellation;
 
 mod error_recovery;
+pub use error_recovery::ErrorEntry;
+mod payout_limits;
+pub use payout_limits::{ProgramSpendingConfig, ProgramSpendingState};
 mod reentrancy_guard;
 #[cfg(test)]
 mod test_token_math;
@@ -659,6 +1411,44 @@ impl ProgramEscrowContract {
         initial_liquidity: Option<i128>,
         reference_hash: Option<soroban_sdk::Bytes>,
     ) -> ProgramData {
+        Self::require_not_frozen(&env);
+        Self::initialize_program(
+            env,
+            program_id,
+            authorized_payout_key,
+            token_address,
+            creator,
+            initial_liquidity,
+            reference_hash,
+        )
+    }
+
+    /// Like `init_program`, but a repeat call with the same program id, payout
+    /// key and token returns the existing `ProgramData` unchanged instead of
+    /// panicking. A repeat call with any of those differing still panics.
+    pub fn init_program_idempotent(
+        env: Env,
+        program_id: String,
+        authorized_payout_key: Address,
+        token_address: Address,
+        creator: Address,
+        initial_liquidity: Option<i128>,
+        reference_hash: Option<soroban_sdk::Bytes>,
+    ) -> ProgramData {
+        Self::require_not_frozen(&env);
+        if let Some(existing) = env
+            .storage()
+            .instance()
+            .get::<_, ProgramData>(&PROGRAM_DATA)
+        {
+            if existing.program_id != program_id
+                || existing.authorized_payout_key != authorized_payout_key
+                || existing.token_address != token_address
+            {
+                panic!("Conflicting program configuration");
+            }
+            return existing;
+        }
         Self::initialize_program(
             env,
             program_id,
@@ -679,10 +1469,21 @@ impl ProgramEscrowContract {
         initial_liquidity: Option<i128>,
         reference_hash: Option<soroban_sdk::Bytes>,
     ) -> ProgramData {
+        Self::require_not_frozen(&env);
+        // Apply rate limiting
+        anti_abuse::check_rate_limit(
+            &env,
+            authorized_payout_key.clone(),
+            anti_abuse::OP_INIT_PROGRAM,
+        );
+
         // Check if program already exists
         if env.storage().instance().has(&PROGRAM_DATA) {
             panic!("Program already initialized");
         }
+        if Self::case_variant_registered(&env, &program_id) {
+            panic!("Program already exists");
+        }
 
         let mut total_funds = 0i128;
         let mut remaining_balance = 0i128;
@@ -716,6 +1517,7 @@ impl ProgramEscrowContract {
         // Store program data in registry
         let program_key = DataKey::Program(program_id.clone());
         env.storage().instance().set(&program_key, &program_data);
+        Self::record_financials(&env, &program_id, init_liquidity, 0, 0);
 
         // Track dependencies (default empty)
         let empty_dependencies: Vec<String> = vec![&env];
@@ -755,13 +1557,9 @@ impl ProgramEscrowContract {
             );
         }
 
-        env.storage()
-            .instance()
-            .set(&SCHEDULES, &Vec::<ProgramReleaseSchedule>::new(&env));
         env.storage()
             .instance()
             .set(&RELEASE_HISTORY, &Vec::<ProgramReleaseHistory>::new(&env));
-        env.storage().instance().set(&NEXT_SCHEDULE_ID, &1_u64);
 
         // Emit ProgramInitialized event
         env.events().publish(
@@ -786,8 +1584,13 @@ impl ProgramEscrowContract {
         organizer: Option<Address>,
         metadata: Option<ProgramMetadata>,
     ) -> ProgramData {
+        Self::require_not_frozen(&env);
         // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, authorized_payout_key.clone());
+        anti_abuse::check_rate_limit(
+            &env,
+            authorized_payout_key.clone(),
+            anti_abuse::OP_INIT_PROGRAM,
+        );
 
         let start = env.ledger().timestamp();
         let caller = authorized_payout_key.clone();
@@ -827,20 +1630,28 @@ impl ProgramEscrowContract {
         env: Env,
         items: Vec<ProgramInitItem>,
     ) -> Result<u32, BatchError> {
+        Self::require_not_frozen(&env);
         let batch_size = items.len() as u32;
         if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
             return Err(BatchError::InvalidBatchSize);
         }
         for i in 0..batch_size {
             for j in (i + 1)..batch_size {
-                if items.get(i).unwrap().program_id == items.get(j).unwrap().program_id {
+                if Self::same_program_id(
+                    &env,
+                    &items.get(i).unwrap().program_id,
+                    &items.get(j).unwrap().program_id,
+                ) {
                     return Err(BatchError::DuplicateProgramId);
                 }
             }
         }
         for i in 0..batch_size {
-            let program_key = DataKey::Program(items.get(i).unwrap().program_id.clone());
-            if env.storage().instance().has(&program_key) {
+            let program_id = items.get(i).unwrap().program_id;
+            let program_key = DataKey::Program(program_id.clone());
+            if env.storage().instance().has(&program_key)
+                || Self::case_variant_registered(&env, &program_id)
+            {
                 return Err(BatchError::ProgramAlreadyExists);
             }
         }
@@ -907,6 +1718,67 @@ impl ProgramEscrowContract {
         Ok(batch_size as u32)
     }
 
+    /// Treat program IDs differing only in ASCII case as the same program when
+    /// registering (admin only). Off by default, so "Hackathon" and
+    /// "hackathon" are distinct programs.
+    pub fn set_case_insensitive_ids(env: Env, enabled: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&CASE_INSENSITIVE_IDS, &enabled);
+    }
+
+    pub fn get_case_insensitive_ids(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&CASE_INSENSITIVE_IDS)
+            .unwrap_or(false)
+    }
+
+    /// ASCII-lowercased bytes of `program_id`, used for case-insensitive
+    /// comparison.
+    fn fold_program_id(env: &Env, program_id: &String) -> Bytes {
+        // Skip the 8-byte XDR header (type tag and length)
+        let encoded = program_id.clone().to_xdr(env);
+        let mut folded = Bytes::new(env);
+        for byte in encoded.slice(8..).iter() {
+            folded.push_back(byte.to_ascii_lowercase());
+        }
+        folded
+    }
+
+    fn same_program_id(env: &Env, a: &String, b: &String) -> bool {
+        if a == b {
+            return true;
+        }
+        Self::get_case_insensitive_ids(env.clone())
+            && a.len() == b.len()
+            && Self::fold_program_id(env, a) == Self::fold_program_id(env, b)
+    }
+
+    /// Whether case-insensitive IDs are on and a registered program's ID
+    /// matches `program_id` ignoring ASCII case.
+    fn case_variant_registered(env: &Env, program_id: &String) -> bool {
+        if !Self::get_case_insensitive_ids(env.clone()) {
+            return false;
+        }
+        let mut existing: Vec<String> = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_REGISTRY)
+            .unwrap_or(vec![env]);
+        if let Some(active) = env
+            .storage()
+            .instance()
+            .get::<_, ProgramData>(&PROGRAM_DATA)
+        {
+            existing.push_back(active.program_id);
+        }
+        existing
+            .iter()
+            .any(|id| Self::same_program_id(env, &id, program_id))
+    }
+
     /// Calculate fee amount based on rate (in basis points)
     fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
         if fee_rate == 0 {
@@ -931,6 +1803,107 @@ impl ProgramEscrowContract {
                 fee_enabled: false,
             })
     }
+
+    /// Payout fee withheld from `amount` under the current fee configuration,
+    /// clamped to the per-payout fee cap when one is set
+    fn payout_fee(env: &Env, amount: i128) -> i128 {
+        let fee_config = Self::get_fee_config_internal(env);
+        if !fee_config.fee_enabled {
+            return 0;
+        }
+        let fee = Self::calculate_fee(amount, fee_config.payout_fee_rate);
+        let cap = Self::get_max_fee_per_payout(env.clone());
+        if cap > 0 {
+            fee.min(cap)
+        } else {
+            fee
+        }
+    }
+
+    fn accrue_fees(env: &Env, program_id: &String, fees: i128) {
+        if fees == 0 {
+            return;
+        }
+        let key = DataKey::AccruedFees(program_id.clone());
+        let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        env.storage().persistent().set(&key, &(accrued + fees));
+    }
+
+    /// Update fee configuration (admin only)
+    pub fn set_fee_config(
+        env: Env,
+        lock_fee_rate: i128,
+        payout_fee_rate: i128,
+        fee_recipient: Address,
+        fee_enabled: bool,
+    ) {
+        Self::require_admin(&env);
+        if !(0..=MAX_FEE_RATE).contains(&lock_fee_rate)
+            || !(0..=MAX_FEE_RATE).contains(&payout_fee_rate)
+        {
+            panic!("Invalid fee rate");
+        }
+
+        env.storage().instance().set(
+            &FEE_CONFIG,
+            &FeeConfig {
+                lock_fee_rate,
+                payout_fee_rate,
+                fee_recipient,
+                fee_enabled,
+            },
+        );
+    }
+
+    pub fn get_fee_config(env: Env) -> FeeConfig {
+        Self::get_fee_config_internal(&env)
+    }
+
+    /// Cap the absolute fee withheld from any single payout (admin only).
+    /// Zero means uncapped.
+    pub fn set_max_fee_per_payout(env: Env, max_fee_per_payout: i128) {
+        Self::require_admin(&env);
+        if max_fee_per_payout < 0 {
+            panic!("Invalid fee cap");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxFeePerPayout, &max_fee_per_payout);
+    }
+
+    pub fn get_max_fee_per_payout(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxFeePerPayout)
+            .unwrap_or(0)
+    }
+
+    /// Payout fees withheld for a program and not yet withdrawn.
+    pub fn get_accrued_fees(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AccruedFees(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Send a program's accrued payout fees to the fee recipient (admin only).
+    pub fn withdraw_fees(env: Env, program_id: String) -> i128 {
+        Self::require_admin(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+
+        let key = DataKey::AccruedFees(program_id);
+        let accrued: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if accrued == 0 {
+            return 0;
+        }
+
+        let fee_recipient = Self::get_fee_config_internal(&env).fee_recipient;
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &fee_recipient, &accrued);
+        env.storage().persistent().remove(&key);
+
+        accrued
+    }
     /// Check if a program exists (legacy single-program check)
     ///
     /// # Returns
@@ -945,6 +1918,130 @@ impl ProgramEscrowContract {
         env.storage().instance().has(&DataKey::Program(program_id))
     }
 
+    /// Add to a program's lifetime lock/payout/refund totals.
+    pub(crate) fn record_financials(
+        env: &Env,
+        program_id: &String,
+        locked: i128,
+        paid_out: i128,
+        refunded: i128,
+    ) {
+        let key = DataKey::Financials(program_id.clone());
+        let mut financials: ProgramFinancials =
+            env.storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or(ProgramFinancials {
+                    lifetime_locked: 0,
+                    lifetime_paid_out: 0,
+                    lifetime_refunded: 0,
+                    remaining_balance: 0,
+                });
+        financials.lifetime_locked += locked;
+        financials.lifetime_paid_out += paid_out;
+        financials.lifetime_refunded += refunded;
+        env.storage().persistent().set(&key, &financials);
+
+        // Every lock, payout and refund lands here, so active programs keep
+        // the instance alive
+        Self::bump_instance(env);
+    }
+
+    /// Extend the instance TTL according to the TTL policy.
+    fn bump_instance(env: &Env) {
+        let (threshold, extend_to) = Self::get_ttl_policy(env.clone());
+        env.storage().instance().extend_ttl(threshold, extend_to);
+    }
+
+    /// Tune the instance TTL bump applied on every mutation (admin only):
+    /// once the TTL falls below `threshold` ledgers it is extended to
+    /// `extend_to`.
+    pub fn set_ttl_policy(env: Env, admin: Address, threshold: u32, extend_to: u32) {
+        Self::require_not_frozen(&env);
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .unwrap_or_else(|| panic!("Not initialized"));
+        if admin != stored_admin {
+            panic!("Unauthorized");
+        }
+        admin.require_auth();
+        if extend_to == 0 || threshold > extend_to {
+            panic!("Invalid TTL policy");
+        }
+        env.storage()
+            .instance()
+            .set(&TTL_POLICY, &(threshold, extend_to));
+        Self::bump_instance(&env);
+    }
+
+    /// The instance TTL policy as `(threshold, extend_to)`, in ledgers.
+    pub fn get_ttl_policy(env: Env) -> (u32, u32) {
+        env.storage().instance().get(&TTL_POLICY).unwrap_or((
+            DEFAULT_INSTANCE_TTL_THRESHOLD,
+            DEFAULT_INSTANCE_TTL_EXTEND_TO,
+        ))
+    }
+
+    /// Take the next event sequence number.
+    pub(crate) fn next_event_seq(env: &Env) -> u64 {
+        let seq = Self::get_event_seq(env.clone()) + 1;
+        env.storage().instance().set(&EVENT_SEQ, &seq);
+        seq
+    }
+
+    /// Sequence number of the most recent sequenced event (0 if none): locks,
+    /// payouts, schedule releases, claims, refunds and balance snapshots.
+    /// Incremented by one per event, so off-chain indexers can detect gaps
+    /// and replay from the last sequence they saw.
+    pub fn get_event_seq(env: Env) -> u64 {
+        env.storage().instance().get(&EVENT_SEQ).unwrap_or(0)
+    }
+
+    /// Lifetime locked, paid out and refunded totals alongside the current balance.
+    pub fn get_program_financials(env: Env, program_id: String) -> ProgramFinancials {
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        let mut financials: ProgramFinancials = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Financials(program_id))
+            .unwrap_or(ProgramFinancials {
+                lifetime_locked: 0,
+                lifetime_paid_out: 0,
+                lifetime_refunded: 0,
+                remaining_balance: 0,
+            });
+        financials.remaining_balance = program_data.remaining_balance;
+        financials
+    }
+
+    /// Check existence of several program IDs at once, in input order.
+    /// At most `MAX_BATCH_SIZE` IDs may be queried per call.
+    pub fn programs_exist(env: Env, ids: Vec<String>) -> Vec<bool> {
+        if ids.len() > MAX_BATCH_SIZE {
+            panic!("Too many program IDs");
+        }
+        let mut result = Vec::new(&env);
+        for id in ids.iter() {
+            result.push_back(env.storage().instance().has(&DataKey::Program(id)));
+        }
+        result
+    }
+
+    /// Split `total_items` into chunks of at most `MAX_BATCH_SIZE`, largest
+    /// first, so a client can submit a long list as several batches.
+    pub fn plan_batch_chunks(env: Env, total_items: u32) -> Vec<u32> {
+        let mut chunks = Vec::new(&env);
+        let mut remaining = total_items;
+        while remaining > 0 {
+            let chunk = remaining.min(MAX_BATCH_SIZE);
+            chunks.push_back(chunk);
+            remaining -= chunk;
+        }
+        chunks
+    }
+
     // ========================================================================
     // Fund Management
     // ========================================================================
@@ -953,10 +2050,14 @@ impl ProgramEscrowContract {
     ///
     /// # Arguments
     /// * `amount` - Amount of funds to lock (in native token units)
+    /// * `memo` - Optional depositor note (e.g. an invoice number), at most
+    ///   `MAX_LOCK_MEMO_LEN` bytes. Returned by `get_lock_memo` until the
+    ///   next lock, which replaces or clears it, and emitted in a `LockMemo`
+    ///   event
     ///
     /// # Returns
     /// Updated ProgramData with locked funds
-    pub fn lock_program_funds(env: Env, amount: i128) -> ProgramData {
+    pub fn lock_program_funds(env: Env, amount: i128, memo: Option<String>) -> ProgramData {
         // Validation precedence (deterministic ordering):
         // 1. Contract initialized
         // 2. Paused (operational state)
@@ -976,6 +2077,11 @@ impl ProgramEscrowContract {
         if amount <= 0 {
             panic!("Amount must be greater than zero");
         }
+        if let Some(ref memo) = memo {
+            if memo.len() > MAX_LOCK_MEMO_LEN {
+                panic!("Memo too long");
+            }
+        }
 
         let mut program_data: ProgramData = env
             .storage()
@@ -983,59 +2089,410 @@ impl ProgramEscrowContract {
             .get(&PROGRAM_DATA)
             .unwrap();
 
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            panic!("Program paused");
+        }
+        if Self::is_pool_finalized(env.clone(), program_data.program_id.clone()) {
+            panic!("Pool finalized");
+        }
+
+        // 4. Multisig-gated lock
+        Self::consume_lock_approval(&env, &program_data.program_id, amount);
+
         // Update balances
         program_data.total_funds += amount;
         program_data.remaining_balance += amount;
 
         // Store updated data
         env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        let memo_key = DataKey::LockMemo(program_data.program_id.clone());
+        match memo {
+            Some(ref memo) => env.storage().persistent().set(&memo_key, memo),
+            None => env.storage().persistent().remove(&memo_key),
+        }
+        Self::record_financials(&env, &program_data.program_id, amount, 0, 0);
 
         // Emit FundsLocked event
         env.events().publish(
             (FUNDS_LOCKED,),
             FundsLockedEvent {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 program_id: program_data.program_id.clone(),
                 amount,
                 remaining_balance: program_data.remaining_balance,
+                event_seq: Self::next_event_seq(&env),
             },
         );
+        if let Some(memo) = memo {
+            env.events().publish(
+                (LOCK_MEMO, program_data.program_id.clone()),
+                LockMemoEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id: program_data.program_id.clone(),
+                    amount,
+                    memo,
+                },
+            );
+        }
+        Self::emit_balance_snapshot(&env, &program_data);
+
+        // Release schedules that were due but unaffordable before the top-up
+        if Self::get_auto_release_on_topup(env.clone())
+            && !Self::check_paused(&env, symbol_short!("release"))
+            && Self::release_due_schedules(&env) > 0
+        {
+            return Self::get_program_info(env);
+        }
 
         program_data
     }
 
-    // ========================================================================
-    // Initialization & Admin
-    // ========================================================================
+    /// Have `lock_program_funds` release the active program's due schedules
+    /// that the topped-up balance now covers (admin only). Off by default.
+    pub fn set_auto_release_on_topup(env: Env, enabled: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&AUTO_RELEASE_ON_TOPUP, &enabled);
+    }
 
-    /// Initialize the contract with an admin.
-    /// This must be called before any admin protected functions (like pause) can be used.
-    pub fn initialize_contract(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            panic!("Already initialized");
-        }
-        env.storage().instance().set(&DataKey::Admin, &admin);
+    pub fn get_auto_release_on_topup(env: Env) -> bool {
         env.storage()
             .instance()
-            .set(&DataKey::MaintenanceMode, &false);
-        env.storage().instance().set(
-            &DataKey::PauseFlags,
-            &PauseFlags {
-                lock_paused: false,
-                release_paused: false,
-                refund_paused: false,
-                pause_reason: None,
-                paused_at: 0,
-            },
-        );
+            .get(&AUTO_RELEASE_ON_TOPUP)
+            .unwrap_or(false)
     }
 
-    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
-    pub fn set_admin(env: Env, admin: Address) {
-        if env.storage().instance().has(&DataKey::Admin) {
-            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-            current.require_auth();
+    /// Lock `amount` pulled from `depositor` (depositor only) and record it
+    /// as their contribution, which `refund_all_proportional` refunds
+    /// against.
+    pub fn lock_program_funds_from(
+        env: Env,
+        program_id: String,
+        depositor: Address,
+        amount: i128,
+    ) -> ProgramData {
+        depositor.require_auth();
+        let program_data =
+            Self::lock_registry_program_funds(env.clone(), program_id.clone(), amount);
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&depositor, &env.current_contract_address(), &amount);
+
+        let mut contributions = Self::get_contributions(env.clone(), program_id.clone());
+        let contributed = contributions.get(depositor.clone()).unwrap_or(0);
+        contributions.set(depositor, contributed + amount);
+        env.storage()
+            .persistent()
+            .set(&DataKey::Contributions(program_id), &contributions);
+
+        program_data
+    }
+
+    pub fn get_contributions(env: Env, program_id: String) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Contributions(program_id))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Cancel a pooled program by refunding its remaining balance to the
+    /// depositors in proportion to their contributions (admin only).
+    ///
+    /// No depositor gets back more than they contributed: funds that did not
+    /// come from tracked contributions (plain locks, top-ups, returned
+    /// claims) stay in the program for the admin refund path.
+    ///
+    /// Shares are rounded down; the rounding remainder goes to the last
+    /// depositor, within their contribution. Contributions are cleared
+    /// afterwards. Returns each depositor's refund.
+    pub fn refund_all_proportional(env: Env, program_id: String) -> Map<Address, i128> {
+        Self::require_admin(&env);
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+        let contributions = Self::get_contributions(env.clone(), program_id.clone());
+        let mut total_contributed = 0i128;
+        for (_, amount) in contributions.iter() {
+            total_contributed += amount;
+        }
+        if total_contributed == 0 {
+            reentrancy_guard::clear_entered(&env);
+            panic!("No contributions");
+        }
+
+        let refundable = program_data.remaining_balance.min(total_contributed);
+        let mut refunds: Map<Address, i128> = Map::new(&env);
+        let mut allocated = 0i128;
+        let last = contributions.len() - 1;
+        for (i, (depositor, contributed)) in contributions.iter().enumerate() {
+            let share = if i as u32 == last {
+                refundable - allocated
+            } else {
+                refundable * contributed / total_contributed
+            };
+            let share = share.min(contributed);
+            allocated += share;
+            refunds.set(depositor, share);
+        }
+
+        // Effects
+        program_data.remaining_balance -= allocated;
+        Self::store_program_data(&env, &program_id, &program_data);
+        Self::record_financials(&env, &program_id, 0, 0, allocated);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Contributions(program_id.clone()));
+
+        // Interactions
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        for (depositor, amount) in refunds.iter() {
+            if amount > 0 {
+                token_client.transfer(&env.current_contract_address(), &depositor, &amount);
+            }
+            env.events().publish(
+                (PROPORTIONAL_REFUND, program_id.clone()),
+                ProportionalRefundEvent {
+                    version: EVENT_VERSION_V3,
+                    program_id: program_id.clone(),
+                    depositor,
+                    amount,
+                    event_seq: Self::next_event_seq(&env),
+                },
+            );
+        }
+        Self::emit_balance_snapshot(&env, &program_data);
+
+        reentrancy_guard::clear_entered(&env);
+        refunds
+    }
+
+    /// Cancel a program mid-vesting (admin only). A cancelled program is
+    /// paused for good: no further locks, payouts or schedule releases.
+    /// Pending claims can still be executed or cancelled, after which
+    /// `refund_on_cancel` returns what is left.
+    pub fn cancel_program(env: Env, program_id: String) {
+        Self::require_admin(&env);
+        if Self::is_program_cancelled(env.clone(), program_id.clone()) {
+            panic!("Program already cancelled");
+        }
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        env.storage()
+            .persistent()
+            .set(&(CANCELLED, program_id.clone()), &true);
+        env.events().publish(
+            (PROGRAM_CANCELLED, program_id.clone()),
+            ProgramCancelledEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                remaining_balance: program_data.remaining_balance,
+                cancelled_at: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    pub fn is_program_cancelled(env: Env, program_id: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(CANCELLED, program_id))
+            .unwrap_or(false)
+    }
+
+    /// Refund a cancelled program's unreleased funds to `to` (admin only),
+    /// zeroing its balance. Amounts reserved by pending claims are already
+    /// out of `remaining_balance`, but the refund is rejected while any such
+    /// claim is still pending so that it is settled first. Returns the amount
+    /// refunded.
+    pub fn refund_on_cancel(env: Env, program_id: String, to: Address) -> i128 {
+        Self::require_admin(&env);
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        if !Self::is_program_cancelled(env.clone(), program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not cancelled");
+        }
+        if claim_period::has_pending_claims(&env, &program_id) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Pending claims still active");
+        }
+
+        // Effects
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+        let amount = program_data.remaining_balance;
+        program_data.remaining_balance = 0;
+        Self::store_program_data(&env, &program_id, &program_data);
+        Self::record_financials(&env, &program_id, 0, 0, amount);
+
+        // Interactions
+        if amount > 0 {
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            token_client.transfer(&env.current_contract_address(), &to, &amount);
+        }
+        env.events().publish(
+            (CANCEL_REFUND, program_id.clone()),
+            CancelRefundEvent {
+                version: EVENT_VERSION_V3,
+                program_id,
+                to,
+                amount,
+                event_seq: Self::next_event_seq(&env),
+            },
+        );
+        Self::emit_balance_snapshot(&env, &program_data);
+
+        reentrancy_guard::clear_entered(&env);
+        amount
+    }
+
+    /// Lock `amount` and split it into `count` equal release schedules for
+    /// `recipient`, the first due now and each later one `interval` seconds
+    /// after the previous. Any remainder goes on the last schedule.
+    pub fn lock_and_schedule(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        recipient: Address,
+        count: u32,
+        interval: u64,
+    ) -> Vec<ProgramReleaseSchedule> {
+        Self::require_not_frozen(&env);
+        if count == 0 {
+            panic!("Count must be greater than zero");
+        }
+        let program_data = Self::get_program_info(env.clone());
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+        // Authorize once for all tranches rather than once per schedule
+        program_data.authorized_payout_key.require_auth();
+        let share = amount / count as i128;
+        if share <= 0 {
+            panic!("Amount too small to split");
+        }
+        let existing = Self::load_schedules(&env, &program_id);
+        Self::check_schedule_capacity(&env, &program_id, &existing, count);
+
+        Self::lock_program_funds(env.clone(), amount, None);
+
+        let now = env.ledger().timestamp();
+        let mut schedules = Vec::new(&env);
+        for i in 0..count {
+            let tranche = if i == count - 1 {
+                amount - share * (count as i128 - 1)
+            } else {
+                share
+            };
+            let release_timestamp = now.saturating_add(interval.saturating_mul(i as u64));
+            schedules.push_back(Self::push_schedule(
+                &env,
+                &program_id,
+                recipient.clone(),
+                tranche,
+                release_timestamp,
+            ));
+        }
+        schedules
+    }
+
+    /// Return locked funds before any payout has happened.
+    ///
+    /// Lets an organizer pull back a premature lock. Only allowed while the
+    /// program has no payout history and no release schedules.
+    ///
+    /// # Arguments
+    /// * `program_id` - Program to unlock from
+    /// * `amount` - Amount to return (must not exceed remaining balance)
+    /// * `to` - Address receiving the unlocked funds
+    ///
+    /// # Returns
+    /// Updated ProgramData with reduced balances
+    pub fn unlock_program_funds(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        to: Address,
+    ) -> ProgramData {
+        Self::require_not_frozen(&env);
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        if Self::is_program_paused(env.clone(), program_id.clone()) {
+            panic!("Program paused");
+        }
+
+        if !program_data.payout_history.is_empty() {
+            panic!("Cannot unlock after payouts");
+        }
+        let schedules: Vec<ProgramReleaseSchedule> = Self::load_schedules(&env, &program_id);
+        if !schedules.is_empty() {
+            panic!("Cannot unlock with release schedules");
+        }
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        if amount > program_data.remaining_balance {
+            panic!("Insufficient balance");
+        }
+
+        program_data.total_funds -= amount;
+        program_data.remaining_balance -= amount;
+        Self::store_program_data(&env, &program_id, &program_data);
+        Self::record_financials(&env, &program_id, 0, 0, amount);
+
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &to, &amount);
+
+        env.events().publish(
+            (FUNDS_UNLOCKED,),
+            FundsUnlockedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                amount,
+                to,
+                remaining_balance: program_data.remaining_balance,
+            },
+        );
+
+        program_data
+    }
+
+    // ========================================================================
+    // Initialization & Admin
+    // ========================================================================
+
+    /// Initialize the contract with an admin.
+    /// This must be called before any admin protected functions (like pause) can be used.
+    pub fn initialize_contract(env: Env, admin: Address) {
+        Self::require_not_frozen(&env);
+        if env.storage().instance().has(&DataKey::Admin) {
+            panic!("Already initialized");
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::MaintenanceMode, &false);
+        env.storage().instance().set(
+            &DataKey::PauseFlags,
+            &PauseFlags {
+                lock_paused: false,
+                release_paused: false,
+                refund_paused: false,
+                pause_reason: None,
+                paused_at: 0,
+            },
+        );
+    }
+
+    /// Set or rotate admin. If no admin is set, sets initial admin. If admin exists, current admin must authorize and the new address becomes admin.
+    pub fn set_admin(env: Env, admin: Address) {
+        Self::require_not_frozen(&env);
+        if env.storage().instance().has(&DataKey::Admin) {
+            let current: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+            current.require_auth();
         }
+        anti_abuse::check_admin_rotation(&env);
         env.storage().instance().set(&DataKey::Admin, &admin);
     }
 
@@ -1045,6 +2502,7 @@ impl ProgramEscrowContract {
     }
 
     fn require_admin(env: &Env) -> Address {
+        Self::require_not_frozen(env);
         let admin: Address = env
             .storage()
             .instance()
@@ -1055,15 +2513,8 @@ impl ProgramEscrowContract {
     }
 
     fn get_program_data_by_id(env: &Env, program_id: &String) -> ProgramData {
-        let program_key = DataKey::Program(program_id.clone());
-        if env.storage().instance().has(&program_key) {
-            return env
-                .storage()
-                .instance()
-                .get(&program_key)
-                .unwrap_or_else(|| panic!("Program not found"));
-        }
-
+        // The active program's PROGRAM_DATA is the live copy; payouts and locks
+        // only update that slot, so prefer it over the registry entry.
         if env.storage().instance().has(&PROGRAM_DATA) {
             let program_data: ProgramData = env
                 .storage()
@@ -1075,6 +2526,15 @@ impl ProgramEscrowContract {
             }
         }
 
+        let program_key = DataKey::Program(program_id.clone());
+        if env.storage().instance().has(&program_key) {
+            return env
+                .storage()
+                .instance()
+                .get(&program_key)
+                .unwrap_or_else(|| panic!("Program not found"));
+        }
+
         panic!("Program not found");
     }
 
@@ -1140,71 +2600,517 @@ impl ProgramEscrowContract {
         program_data
     }
 
-    pub fn get_program_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
-        env.storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env))
+    /// Get the key authorized to sign payouts for a program.
+    pub fn get_authorized_payout_key(env: Env, program_id: String) -> Address {
+        Self::get_program_data_by_id(&env, &program_id).authorized_payout_key
     }
 
-    /// Update pause flags (admin only)
-    pub fn set_paused(
+    /// Replace the authorized payout key (current key only).
+    pub fn rotate_authorized_payout_key(
         env: Env,
-        lock: Option<bool>,
-        release: Option<bool>,
-        refund: Option<bool>,
-        reason: Option<String>,
-    ) {
-        if !env.storage().instance().has(&DataKey::Admin) {
-            panic!("Not initialized");
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        program_id: String,
+        new_key: Address,
+    ) -> ProgramData {
+        Self::require_not_frozen(&env);
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+        let old_key = program_data.authorized_payout_key.clone();
+        old_key.require_auth();
 
-        let mut flags = Self::get_pause_flags(&env);
-        let timestamp = env.ledger().timestamp();
+        program_data.authorized_payout_key = new_key.clone();
+        Self::store_program_data(&env, &program_id, &program_data);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::PendingTransfer(program_id.clone()));
 
-        if reason.is_some() {
-            flags.pause_reason = reason.clone();
-        }
+        env.events().publish(
+            (PAYOUT_KEY_ROTATED, program_id.clone()),
+            PayoutKeyRotatedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                old_key,
+                new_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
-        if let Some(paused) = lock {
-            flags.lock_paused = paused;
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                PauseStateChanged {
-                    operation: symbol_short!("lock"),
-                    paused,
-                    admin: admin.clone(),
-                    reason: reason.clone(),
-                    timestamp,
-                    receipt_id,
-                },
-            );
-        }
+        program_data
+    }
 
-        if let Some(paused) = release {
-            flags.release_paused = paused;
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
-                (PAUSE_STATE_CHANGED,),
-                PauseStateChanged {
-                    operation: symbol_short!("release"),
-                    paused,
-                    admin: admin.clone(),
-                    reason: reason.clone(),
-                    timestamp,
-                    receipt_id,
-                },
-            );
-        }
+    /// Propose handing a program to a new organizer (current key only).
+    ///
+    /// Control does not move until `new_key` calls `accept_program_transfer`.
+    /// A new proposal replaces any pending one. The proposal lapses after
+    /// seven days, or as soon as the payout key or the admin changes.
+    pub fn propose_program_transfer(env: Env, program_id: String, new_key: Address) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        let old_key = program_data.authorized_payout_key;
+        old_key.require_auth();
+
+        env.storage().persistent().set(
+            &DataKey::PendingTransfer(program_id.clone()),
+            &PendingProgramTransfer {
+                new_key: new_key.clone(),
+                proposed_by: old_key.clone(),
+                admin: Self::get_admin(env.clone()),
+                expires_at: env.ledger().timestamp() + PROGRAM_TRANSFER_WINDOW,
+            },
+        );
 
-        if let Some(paused) = refund {
-            flags.refund_paused = paused;
-            let receipt_id = Self::increment_receipt_id(&env);
-            env.events().publish(
+        env.events().publish(
+            (PROGRAM_TRANSFER_PROPOSED, program_id.clone()),
+            ProgramTransferEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                old_key,
+                new_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// Accept a pending program transfer (proposed key only).
+    pub fn accept_program_transfer(env: Env, program_id: String) -> ProgramData {
+        Self::require_not_frozen(&env);
+        let key = DataKey::PendingTransfer(program_id.clone());
+        let new_key = Self::get_pending_program_transfer(env.clone(), program_id.clone())
+            .unwrap_or_else(|| panic!("No pending transfer"));
+        new_key.require_auth();
+
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+        let old_key = program_data.authorized_payout_key.clone();
+        program_data.authorized_payout_key = new_key.clone();
+        Self::store_program_data(&env, &program_id, &program_data);
+        env.storage().persistent().remove(&key);
+
+        env.events().publish(
+            (PROGRAM_TRANSFER_ACCEPTED, program_id.clone()),
+            ProgramTransferEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                old_key,
+                new_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        program_data
+    }
+
+    /// Withdraw a pending program transfer (current key only).
+    pub fn cancel_payout_key_transfer(env: Env, program_id: String) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        let old_key = program_data.authorized_payout_key;
+        old_key.require_auth();
+
+        let key = DataKey::PendingTransfer(program_id.clone());
+        let pending: PendingProgramTransfer = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| panic!("No pending transfer"));
+        env.storage().persistent().remove(&key);
+
+        env.events().publish(
+            (PROGRAM_TRANSFER_CANCELLED, program_id.clone()),
+            ProgramTransferEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                old_key,
+                new_key: pending.new_key,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    /// The key a program transfer is pending to, if the proposal has not
+    /// lapsed.
+    pub fn get_pending_program_transfer(env: Env, program_id: String) -> Option<Address> {
+        let pending: PendingProgramTransfer = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingTransfer(program_id.clone()))?;
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        if env.ledger().timestamp() > pending.expires_at
+            || pending.proposed_by != program_data.authorized_payout_key
+            || pending.admin != Self::get_admin(env.clone())
+        {
+            return None;
+        }
+        Some(pending.new_key)
+    }
+
+    /// Configure the signers whose approvals large payouts collect (admin only).
+    pub fn set_multisig_config(
+        env: Env,
+        program_id: String,
+        threshold_amount: i128,
+        signers: Vec<Address>,
+        required_signatures: u32,
+    ) {
+        Self::require_admin(&env);
+        if required_signatures == 0 {
+            panic!("Required signatures must be at least 1");
+        }
+        if required_signatures > signers.len() {
+            panic!("Required signatures exceed signer count");
+        }
+        for i in 0..signers.len() {
+            let signer = signers.get(i).unwrap();
+            for j in (i + 1)..signers.len() {
+                if signers.get(j).unwrap() == signer {
+                    panic!("Duplicate multisig signer");
+                }
+            }
+        }
+
+        env.storage().persistent().set(
+            &DataKey::MultisigConfig(program_id),
+            &MultisigConfig {
+                threshold_amount,
+                signers,
+                required_signatures,
+            },
+        );
+    }
+
+    pub fn get_multisig_config(env: Env, program_id: String) -> MultisigConfig {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MultisigConfig(program_id))
+            .unwrap_or(MultisigConfig {
+                threshold_amount: i128::MAX,
+                signers: vec![&env],
+                required_signatures: 0,
+            })
+    }
+
+    /// Record a signer's approval of a large payout to `recipient`.
+    ///
+    /// Approving a different amount than the one on record starts a fresh
+    /// approval, so signatures never carry over to a changed payout.
+    pub fn approve_large_payout(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        approver: Address,
+    ) -> PayoutApproval {
+        Self::require_not_frozen(&env);
+        let config = Self::get_multisig_config(env.clone(), program_id.clone());
+        if !config.signers.contains(&approver) {
+            panic!("Not a multisig signer");
+        }
+        approver.require_auth();
+
+        let mut approval =
+            Self::get_payout_approval(env.clone(), program_id.clone(), recipient.clone());
+        Self::add_payout_approval(&env, &mut approval, amount, approver);
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutApproval(program_id, recipient), &approval);
+        approval
+    }
+
+    /// Register the ed25519 public key a signer uses for off-chain approvals.
+    pub fn set_signer_key(env: Env, signer: Address, public_key: BytesN<32>) {
+        Self::require_not_frozen(&env);
+        signer.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::SignerKey(signer), &public_key);
+    }
+
+    pub fn get_signer_key(env: Env, signer: Address) -> Option<BytesN<32>> {
+        env.storage().persistent().get(&DataKey::SignerKey(signer))
+    }
+
+    /// Nonce the signer's next off-chain approval must be signed over.
+    pub fn get_signer_nonce(env: Env, signer: Address) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SignerNonce(signer))
+            .unwrap_or(0)
+    }
+
+    /// The message a signer signs off-chain to approve a large payout.
+    ///
+    /// `nonce` is the signer's `get_signer_nonce` and `expiry` the last ledger
+    /// timestamp the signature is accepted at.
+    pub fn get_payout_approval_payload(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        nonce: u64,
+        expiry: u64,
+    ) -> Bytes {
+        (
+            env.current_contract_address(),
+            program_id,
+            recipient,
+            amount,
+            nonce,
+            expiry,
+        )
+            .to_xdr(&env)
+    }
+
+    /// Record several large-payout approvals at once from off-chain ed25519
+    /// signatures over `get_payout_approval_payload`.
+    ///
+    /// Every entry must come from a multisig signer with a registered key and
+    /// be signed over that signer's current nonce, which is then bumped so the
+    /// signature cannot be replayed. An invalid or expired signature aborts
+    /// the whole call.
+    pub fn approve_large_payout_signed(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        expiry: u64,
+        approvals: Vec<(Address, BytesN<64>)>,
+    ) -> PayoutApproval {
+        Self::require_not_frozen(&env);
+        if env.ledger().timestamp() > expiry {
+            panic!("Approval signature expired");
+        }
+        let config = Self::get_multisig_config(env.clone(), program_id.clone());
+
+        let mut approval =
+            Self::get_payout_approval(env.clone(), program_id.clone(), recipient.clone());
+        for (signer, signature) in approvals.iter() {
+            if !config.signers.contains(&signer) {
+                panic!("Not a multisig signer");
+            }
+            let key = Self::get_signer_key(env.clone(), signer.clone())
+                .unwrap_or_else(|| panic!("Signer key not registered"));
+            let nonce = Self::get_signer_nonce(env.clone(), signer.clone());
+            let payload = Self::get_payout_approval_payload(
+                env.clone(),
+                program_id.clone(),
+                recipient.clone(),
+                amount,
+                nonce,
+                expiry,
+            );
+            env.crypto().ed25519_verify(&key, &payload, &signature);
+            env.storage()
+                .persistent()
+                .set(&DataKey::SignerNonce(signer.clone()), &(nonce + 1));
+            Self::add_payout_approval(&env, &mut approval, amount, signer);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PayoutApproval(program_id, recipient), &approval);
+        approval
+    }
+
+    /// Add `approver` to `approval`, starting fresh if the amount changed.
+    fn add_payout_approval(
+        env: &Env,
+        approval: &mut PayoutApproval,
+        amount: i128,
+        approver: Address,
+    ) {
+        if approval.amount != amount {
+            approval.amount = amount;
+            approval.approvals = vec![env];
+        }
+        if !approval.approvals.contains(&approver) {
+            approval.approvals.push_back(approver);
+        }
+    }
+
+    /// Payouts at or above the multisig threshold need `required_signatures`
+    /// approvals for exactly `amount`; the approval is consumed by the payout.
+    /// Every payout transfer goes through here.
+    fn consume_payout_approval(env: &Env, program_id: &String, recipient: &Address, amount: i128) {
+        let config = Self::get_multisig_config(env.clone(), program_id.clone());
+        if amount < config.threshold_amount {
+            return;
+        }
+        let approval =
+            Self::get_payout_approval(env.clone(), program_id.clone(), recipient.clone());
+        let mut valid = 0u32;
+        for approver in approval.approvals.iter() {
+            if config.signers.contains(&approver) {
+                valid += 1;
+            }
+        }
+        if approval.amount != amount || valid < config.required_signatures {
+            reentrancy_guard::clear_entered(env);
+            panic!("Insufficient payout approvals");
+        }
+        env.storage().persistent().remove(&DataKey::PayoutApproval(
+            program_id.clone(),
+            recipient.clone(),
+        ));
+    }
+
+    /// Approvals collected so far for a large payout, or an empty approval if none.
+    pub fn get_payout_approval(env: Env, program_id: String, recipient: Address) -> PayoutApproval {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutApproval(
+                program_id.clone(),
+                recipient.clone(),
+            ))
+            .unwrap_or(PayoutApproval {
+                program_id,
+                recipient,
+                amount: 0,
+                approvals: vec![&env],
+            })
+    }
+
+    /// Require `required_signatures` multisig approvals before
+    /// `lock_program_funds` commits (admin only).
+    pub fn set_lock_requires_multisig(env: Env, program_id: String, enabled: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::LockRequiresMultisig(program_id), &enabled);
+    }
+
+    pub fn get_lock_requires_multisig(env: Env, program_id: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockRequiresMultisig(program_id))
+            .unwrap_or(false)
+    }
+
+    /// When the program's locks are multisig-gated, consume the approvals
+    /// collected for `amount`. Every lock entry point goes through here.
+    fn consume_lock_approval(env: &Env, program_id: &String, amount: i128) {
+        if !Self::get_lock_requires_multisig(env.clone(), program_id.clone()) {
+            return;
+        }
+        let config = Self::get_multisig_config(env.clone(), program_id.clone());
+        let approval = Self::get_lock_approval(env.clone(), program_id.clone());
+        let mut valid = 0u32;
+        for approver in approval.approvals.iter() {
+            if config.signers.contains(&approver) {
+                valid += 1;
+            }
+        }
+        if approval.amount != amount || valid < config.required_signatures {
+            panic!("Insufficient lock approvals");
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::LockApproval(program_id.clone()));
+    }
+
+    /// Record a signer's approval of locking `amount` into the program.
+    ///
+    /// Approving a different amount starts a fresh approval.
+    pub fn approve_lock(
+        env: Env,
+        program_id: String,
+        amount: i128,
+        approver: Address,
+    ) -> LockApproval {
+        Self::require_not_frozen(&env);
+        let config = Self::get_multisig_config(env.clone(), program_id.clone());
+        if !config.signers.contains(&approver) {
+            panic!("Not a multisig signer");
+        }
+        approver.require_auth();
+
+        let mut approval = Self::get_lock_approval(env.clone(), program_id.clone());
+        if approval.amount != amount {
+            approval.amount = amount;
+            approval.approvals = vec![&env];
+        }
+        if !approval.approvals.contains(&approver) {
+            approval.approvals.push_back(approver);
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::LockApproval(program_id), &approval);
+        approval
+    }
+
+    /// Approvals collected so far for a pending lock, or an empty approval if none.
+    pub fn get_lock_approval(env: Env, program_id: String) -> LockApproval {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockApproval(program_id.clone()))
+            .unwrap_or(LockApproval {
+                program_id,
+                amount: 0,
+                approvals: vec![&env],
+            })
+    }
+
+    pub fn get_program_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
+        Self::active_schedules(&env)
+    }
+
+    /// Update pause flags (admin only)
+    pub fn set_paused(
+        env: Env,
+        lock: Option<bool>,
+        release: Option<bool>,
+        refund: Option<bool>,
+        reason: Option<String>,
+    ) {
+        Self::require_not_frozen(&env);
+        if !env.storage().instance().has(&DataKey::Admin) {
+            panic!("Not initialized");
+        }
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut flags = Self::get_pause_flags(&env);
+        let timestamp = env.ledger().timestamp();
+
+        if reason.is_some() {
+            flags.pause_reason = reason.clone();
+        }
+
+        if let Some(paused) = lock {
+            flags.lock_paused = paused;
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                PauseStateChanged {
+                    operation: symbol_short!("lock"),
+                    paused,
+                    admin: admin.clone(),
+                    reason: reason.clone(),
+                    timestamp,
+                    receipt_id,
+                },
+            );
+        }
+
+        if let Some(paused) = release {
+            flags.release_paused = paused;
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
+                (PAUSE_STATE_CHANGED,),
+                PauseStateChanged {
+                    operation: symbol_short!("release"),
+                    paused,
+                    admin: admin.clone(),
+                    reason: reason.clone(),
+                    timestamp,
+                    receipt_id,
+                },
+            );
+        }
+
+        if let Some(paused) = refund {
+            flags.refund_paused = paused;
+            let receipt_id = Self::increment_receipt_id(&env);
+            env.events().publish(
                 (PAUSE_STATE_CHANGED,),
                 PauseStateChanged {
                     operation: symbol_short!("refund"),
@@ -1241,6 +3147,7 @@ impl ProgramEscrowContract {
 
     /// Update maintenance mode (admin only)
     pub fn set_maintenance_mode(env: Env, enabled: bool) {
+        Self::require_not_frozen(&env);
         if !env.storage().instance().has(&DataKey::Admin) {
             panic!("Not initialized");
         }
@@ -1260,8 +3167,112 @@ impl ProgramEscrowContract {
         );
     }
 
-    /// Emergency withdraw all program funds (admin only, must have lock_paused = true)
-    pub fn emergency_withdraw(env: Env, target: Address) {
+    /// Permanently freeze every mutating operation (admin only). There is no
+    /// way back: views stay readable, but locks, payouts, claims and all
+    /// configuration, including unfreezing, are rejected from then on.
+    /// `confirmation` must equal [`FREEZE_CONFIRMATION`].
+    pub fn permanently_freeze(env: Env, admin: Address, confirmation: String) {
+        let stored = Self::require_admin(&env);
+        if stored != admin {
+            panic!("Unauthorized");
+        }
+        if confirmation != String::from_str(&env, FREEZE_CONFIRMATION) {
+            panic!("Invalid freeze confirmation");
+        }
+
+        env.storage().instance().set(&FROZEN, &true);
+        env.events().publish(
+            (PERMANENTLY_FROZEN,),
+            PermanentlyFrozenEvent {
+                version: EVENT_VERSION_V2,
+                admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+
+    pub fn is_permanently_frozen(env: Env) -> bool {
+        env.storage().instance().get(&FROZEN).unwrap_or(false)
+    }
+
+    /// Prologue of every mutating entry point: rejects calls once the
+    /// contract is frozen and keeps the instance alive per the TTL policy.
+    fn require_not_frozen(env: &Env) {
+        if Self::is_permanently_frozen(env.clone()) {
+            panic!("Contract permanently frozen");
+        }
+        Self::bump_instance(env);
+    }
+
+    /// Configure the recovery multisig for emergency withdrawals (circuit admin only).
+    ///
+    /// Once set, `emergency_withdraw` may only send funds to `recovery_address`
+    /// and needs `required_signatures` approvals from `signers`.
+    pub fn set_recovery_config(
+        env: Env,
+        signers: Vec<Address>,
+        required_signatures: u32,
+        recovery_address: Address,
+    ) {
+        Self::require_not_frozen(&env);
+        let circuit_admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
+        circuit_admin.require_auth();
+
+        if required_signatures == 0 || required_signatures > signers.len() {
+            panic!("Invalid required signatures");
+        }
+        env.storage().instance().set(
+            &DataKey::RecoveryConfig,
+            &RecoveryConfig {
+                signers,
+                required_signatures,
+                recovery_address,
+            },
+        );
+    }
+
+    pub fn get_recovery_config(env: Env) -> Option<RecoveryConfig> {
+        env.storage().instance().get(&DataKey::RecoveryConfig)
+    }
+
+    /// Approve an emergency withdrawal of a program's funds (recovery signer only).
+    pub fn approve_emergency_withdraw(env: Env, program_id: String, signer: Address) {
+        Self::require_not_frozen(&env);
+        let config = Self::get_recovery_config(env.clone())
+            .unwrap_or_else(|| panic!("Recovery not configured"));
+        if !config.signers.contains(&signer) {
+            panic!("Not a recovery signer");
+        }
+        signer.require_auth();
+
+        let key = DataKey::RecoveryApprovals(program_id);
+        let mut approvals: Vec<Address> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+        if !approvals.contains(&signer) {
+            approvals.push_back(signer);
+            env.storage().persistent().set(&key, &approvals);
+        }
+    }
+
+    pub fn get_recovery_approvals(env: Env, program_id: String) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RecoveryApprovals(program_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Emergency withdraw a program's remaining balance (admin only, must have
+    /// lock_paused = true).
+    ///
+    /// Requires a recovery config: `target` must be the recovery address and
+    /// enough recovery signers must have approved; approvals are consumed.
+    /// Only the program's own balance moves, and the program is left with a
+    /// zero remaining balance.
+    pub fn emergency_withdraw(env: Env, program_id: String, target: Address) {
+        Self::require_not_frozen(&env);
         if !env.storage().instance().has(&DataKey::Admin) {
             panic!("Not initialized");
         }
@@ -1273,25 +3284,48 @@ impl ProgramEscrowContract {
             panic!("Not paused");
         }
 
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+
+        let config = Self::get_recovery_config(env.clone())
+            .unwrap_or_else(|| panic!("Recovery not configured"));
+        if target != config.recovery_address {
+            panic!("Not the recovery address");
+        }
+        let approvals = Self::get_recovery_approvals(env.clone(), program_id.clone());
+        let mut valid = 0u32;
+        for signer in approvals.iter() {
+            if config.signers.contains(&signer) {
+                valid += 1;
+            }
+        }
+        if valid < config.required_signatures {
+            panic!("Insufficient recovery approvals");
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RecoveryApprovals(program_id.clone()));
+
         let token_client = token::TokenClient::new(&env, &program_data.token_address);
 
+        // Never more than the contract still holds, e.g. after a clawback
         let contract_address = env.current_contract_address();
-        let balance = token_client.balance(&contract_address);
+        let amount = program_data
+            .remaining_balance
+            .min(token_client.balance(&contract_address));
 
-        if balance > 0 {
-            token_client.transfer(&contract_address, &target, &balance);
+        if amount > 0 {
+            program_data.remaining_balance = 0;
+            Self::store_program_data(&env, &program_id, &program_data);
+            Self::record_financials(&env, &program_id, 0, 0, amount);
+
+            token_client.transfer(&contract_address, &target, &amount);
             let receipt_id = Self::increment_receipt_id(&env);
             env.events().publish(
                 (symbol_short!("em_wtd"),),
                 EmergencyWithdrawEvent {
                     admin,
                     target: target.clone(),
-                    amount: balance,
+                    amount,
                     timestamp: env.ledger().timestamp(),
                     receipt_id,
                 },
@@ -1315,6 +3349,7 @@ impl ProgramEscrowContract {
 
     /// Check if an operation is paused
     fn check_paused(env: &Env, operation: Symbol) -> bool {
+        Self::require_not_frozen(env);
         if Self::is_maintenance_mode(env.clone()) && operation == symbol_short!("lock") {
             return true;
         }
@@ -1329,9 +3364,53 @@ impl ProgramEscrowContract {
         false
     }
 
+    /// Freeze a single program's mutating operations without touching the
+    /// global pause flags (`caller` must be the admin or the program's
+    /// authorized payout key).
+    pub fn pause_program(env: Env, program_id: String, caller: Address) {
+        Self::require_not_frozen(&env);
+        Self::set_program_paused(&env, program_id, caller, true);
+    }
+
+    /// Lift a per-program pause (admin or authorized payout key).
+    pub fn unpause_program(env: Env, program_id: String, caller: Address) {
+        Self::require_not_frozen(&env);
+        Self::set_program_paused(&env, program_id, caller, false);
+    }
+
+    /// Whether the program is paused. Cancelled programs stay paused.
+    pub fn is_program_paused(env: Env, program_id: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramPaused(program_id.clone()))
+            .unwrap_or(false)
+            || Self::is_program_cancelled(env, program_id)
+    }
+
+    fn set_program_paused(env: &Env, program_id: String, caller: Address, paused: bool) {
+        let program_data = Self::get_program_data_by_id(env, &program_id);
+        let admin: Option<Address> = env.storage().instance().get(&DataKey::Admin);
+        if caller != program_data.authorized_payout_key && Some(caller.clone()) != admin {
+            panic!("Unauthorized");
+        }
+        caller.require_auth();
+
+        let key = DataKey::ProgramPaused(program_id.clone());
+        if paused {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+        env.events().publish(
+            (symbol_short!("prg_pause"), program_id),
+            (paused, caller, env.ledger().timestamp()),
+        );
+    }
+
     // --- Circuit Breaker & Rate Limit ---
 
     pub fn set_circuit_admin(env: Env, new_admin: Address, caller: Option<Address>) {
+        Self::require_not_frozen(&env);
         error_recovery::set_circuit_admin(&env, new_admin, caller);
     }
 
@@ -1340,6 +3419,7 @@ impl ProgramEscrowContract {
     }
 
     pub fn reset_circuit_breaker(env: Env, caller: Address) {
+        Self::require_not_frozen(&env);
         caller.require_auth();
         let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
         if caller != admin {
@@ -1355,6 +3435,7 @@ impl ProgramEscrowContract {
         _lookback: u32,
         _cooldown: u32,
     ) {
+        Self::require_not_frozen(&env);
         caller.require_auth();
         let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
         if caller != admin {
@@ -1363,16 +3444,61 @@ impl ProgramEscrowContract {
         // Logic to update config in storage would go here
     }
 
+    /// Require HalfOpen probe successes to land within `window_seconds` of
+    /// each other before the circuit closes (0 disables the window).
+    pub fn set_circuit_probe_window(env: Env, caller: Address, window_seconds: u64) {
+        Self::require_not_frozen(&env);
+        caller.require_auth();
+        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
+        if caller != admin {
+            panic!("Unauthorized: only circuit admin can configure");
+        }
+        error_recovery::set_probe_window(&env, window_seconds);
+    }
+
+    pub fn get_circuit_probe_window(env: Env) -> u64 {
+        error_recovery::get_probe_window(&env)
+    }
+
+    /// Expire circuit error log entries older than `ttl_seconds` (0 keeps
+    /// them until pushed out by `max_error_log`).
+    pub fn set_circuit_error_log_ttl(env: Env, caller: Address, ttl_seconds: u64) {
+        Self::require_not_frozen(&env);
+        caller.require_auth();
+        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
+        if caller != admin {
+            panic!("Unauthorized: only circuit admin can configure");
+        }
+        error_recovery::set_error_log_ttl(&env, ttl_seconds);
+    }
+
+    pub fn get_circuit_error_log_ttl(env: Env) -> u64 {
+        error_recovery::get_error_log_ttl(&env)
+    }
+
+    /// Recent circuit breaker errors, excluding entries older than the TTL.
+    pub fn get_circuit_error_log(env: Env) -> Vec<ErrorEntry> {
+        error_recovery::get_error_log(&env)
+    }
+
+    /// Store the global rate limit (admin only). Once set it applies to every
+    /// operation type without its own limit, including `init_program` and
+    /// `batch_payout`; until then those calls are not rate limited.
     pub fn update_rate_limit_config(
         env: Env,
         window_size: u64,
         max_operations: u32,
         cooldown_period: u64,
     ) {
+        Self::require_not_frozen(&env);
         // Only admin can update rate limit config
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
 
+        if env.storage().instance().has(&DataKey::RateLimitQuorum) {
+            panic!("Rate limit quorum required");
+        }
+
         let config = RateLimitConfig {
             window_size,
             max_operations,
@@ -1394,17 +3520,374 @@ impl ProgramEscrowContract {
             })
     }
 
-    pub fn get_analytics(_env: Env) -> Analytics {
+    /// Require `required_approvals` of `admins` to approve rate limit
+    /// changes instead of the single admin (admin only). Once set, the quorum
+    /// can only be changed through `propose_rate_limit_quorum`.
+    pub fn set_rate_limit_quorum(env: Env, admins: Vec<Address>, required_approvals: u32) {
+        Self::require_admin(&env);
+        if env.storage().instance().has(&DataKey::RateLimitQuorum) {
+            panic!("Rate limit quorum required");
+        }
+
+        let quorum = Self::new_rate_limit_quorum(admins, required_approvals);
+        env.storage()
+            .instance()
+            .set(&DataKey::RateLimitQuorum, &quorum);
+    }
+
+    fn new_rate_limit_quorum(admins: Vec<Address>, required_approvals: u32) -> RateLimitQuorum {
+        if required_approvals == 0 || required_approvals > admins.len() {
+            panic!("Invalid required approvals");
+        }
+        RateLimitQuorum {
+            admins,
+            required_approvals,
+        }
+    }
+
+    pub fn get_rate_limit_quorum(env: Env) -> Option<RateLimitQuorum> {
+        env.storage().instance().get(&DataKey::RateLimitQuorum)
+    }
+
+    /// Propose a global rate limit config change (quorum admin only). The
+    /// proposer's approval is counted. Only one proposal can be pending.
+    pub fn propose_rate_limit_config(
+        env: Env,
+        proposer: Address,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) -> RateLimitProposal {
+        let change = RateLimitChange::Global(RateLimitConfig {
+            window_size,
+            max_operations,
+            cooldown_period,
+        });
+        Self::propose_rate_limit_change(&env, proposer, change)
+    }
+
+    /// Propose setting one operation's rate limit override (quorum admin
+    /// only).
+    pub fn propose_operation_rate_limit(
+        env: Env,
+        proposer: Address,
+        operation: Symbol,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) -> RateLimitProposal {
+        let change = RateLimitChange::SetOperation(
+            operation,
+            RateLimitConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
+            },
+        );
+        Self::propose_rate_limit_change(&env, proposer, change)
+    }
+
+    /// Propose removing one operation's rate limit override (quorum admin
+    /// only).
+    pub fn propose_clear_op_rate_limit(
+        env: Env,
+        proposer: Address,
+        operation: Symbol,
+    ) -> RateLimitProposal {
+        let change = RateLimitChange::ClearOperation(operation);
+        Self::propose_rate_limit_change(&env, proposer, change)
+    }
+
+    /// Propose replacing the quorum (quorum admin only). Approval is counted
+    /// against the current quorum.
+    pub fn propose_rate_limit_quorum(
+        env: Env,
+        proposer: Address,
+        admins: Vec<Address>,
+        required_approvals: u32,
+    ) -> RateLimitProposal {
+        let quorum = Self::new_rate_limit_quorum(admins, required_approvals);
+        Self::propose_rate_limit_change(&env, proposer, RateLimitChange::Quorum(quorum))
+    }
+
+    /// Withdraw the pending rate limit proposal (its proposer only).
+    pub fn cancel_rate_limit_proposal(env: Env, proposer: Address) {
+        Self::require_not_frozen(&env);
+        let proposal = Self::get_rate_limit_proposal(env.clone())
+            .unwrap_or_else(|| panic!("No pending rate limit proposal"));
+        if proposal.approvals.get(0) != Some(proposer.clone()) {
+            panic!("Not the proposer");
+        }
+        proposer.require_auth();
+
+        env.storage().instance().remove(&DataKey::RateLimitProposal);
+    }
+
+    fn propose_rate_limit_change(
+        env: &Env,
+        proposer: Address,
+        change: RateLimitChange,
+    ) -> RateLimitProposal {
+        Self::require_not_frozen(env);
+        let quorum = Self::get_rate_limit_quorum(env.clone())
+            .unwrap_or_else(|| panic!("Rate limit quorum not configured"));
+        if !quorum.admins.contains(&proposer) {
+            panic!("Not a quorum admin");
+        }
+        proposer.require_auth();
+        if env.storage().instance().has(&DataKey::RateLimitProposal) {
+            panic!("Rate limit proposal pending");
+        }
+
+        let proposal = RateLimitProposal {
+            change,
+            approvals: vec![env, proposer],
+        };
+        Self::apply_rate_limit_proposal(env, &quorum, proposal)
+    }
+
+    /// Approve the pending rate limit change (quorum admin only). The change
+    /// is applied once `required_approvals` admins have approved it.
+    pub fn approve_rate_limit_config(env: Env, approver: Address) -> RateLimitProposal {
+        Self::require_not_frozen(&env);
+        let quorum = Self::get_rate_limit_quorum(env.clone())
+            .unwrap_or_else(|| panic!("Rate limit quorum not configured"));
+        if !quorum.admins.contains(&approver) {
+            panic!("Not a quorum admin");
+        }
+        approver.require_auth();
+
+        let mut proposal = Self::get_rate_limit_proposal(env.clone())
+            .unwrap_or_else(|| panic!("No pending rate limit proposal"));
+        if !proposal.approvals.contains(&approver) {
+            proposal.approvals.push_back(approver);
+        }
+        Self::apply_rate_limit_proposal(&env, &quorum, proposal)
+    }
+
+    pub fn get_rate_limit_proposal(env: Env) -> Option<RateLimitProposal> {
+        env.storage().instance().get(&DataKey::RateLimitProposal)
+    }
+
+    /// Store `proposal`, or commit its change and clear it once it has quorum.
+    fn apply_rate_limit_proposal(
+        env: &Env,
+        quorum: &RateLimitQuorum,
+        proposal: RateLimitProposal,
+    ) -> RateLimitProposal {
+        if proposal.approvals.len() >= quorum.required_approvals {
+            match proposal.change.clone() {
+                RateLimitChange::Global(config) => env
+                    .storage()
+                    .instance()
+                    .set(&DataKey::RateLimitConfig, &config),
+                RateLimitChange::SetOperation(operation, config) => {
+                    anti_abuse::set_operation_config(env, operation, Some(config))
+                }
+                RateLimitChange::ClearOperation(operation) => {
+                    anti_abuse::set_operation_config(env, operation, None)
+                }
+                RateLimitChange::Quorum(new_quorum) => env
+                    .storage()
+                    .instance()
+                    .set(&DataKey::RateLimitQuorum, &new_quorum),
+            }
+            env.storage().instance().remove(&DataKey::RateLimitProposal);
+        } else {
+            env.storage()
+                .instance()
+                .set(&DataKey::RateLimitProposal, &proposal);
+        }
+        proposal
+    }
+
+    /// Set a rate limit for one operation type (e.g. `init_prg`, `batch_pay`)
+    /// that overrides the global config for it (admin only). In quorum mode
+    /// use `propose_operation_rate_limit` instead.
+    pub fn update_operation_rate_limit(
+        env: Env,
+        operation: Symbol,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) {
+        Self::require_not_frozen(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if env.storage().instance().has(&DataKey::RateLimitQuorum) {
+            panic!("Rate limit quorum required");
+        }
+
+        anti_abuse::set_operation_config(
+            &env,
+            operation,
+            Some(RateLimitConfig {
+                window_size,
+                max_operations,
+                cooldown_period,
+            }),
+        );
+    }
+
+    /// Remove an operation's rate limit so it falls back to the global config (admin only).
+    pub fn clear_operation_rate_limit(env: Env, operation: Symbol) {
+        Self::require_not_frozen(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+        if env.storage().instance().has(&DataKey::RateLimitQuorum) {
+            panic!("Rate limit quorum required");
+        }
+
+        anti_abuse::set_operation_config(&env, operation, None);
+    }
+
+    /// When enabled, a successful payout clears the caller's cooldown so
+    /// follow-up payouts can run immediately; they still count toward the
+    /// window cap (admin only).
+    pub fn set_reset_cooldown_on_success(env: Env, enabled: bool) {
+        Self::require_not_frozen(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        anti_abuse::set_reset_cooldown_on_success(&env, enabled);
+    }
+
+    pub fn get_reset_cooldown_on_success(env: Env) -> bool {
+        anti_abuse::get_reset_cooldown_on_success(&env)
+    }
+
+    /// Minimum seconds between `set_admin` calls (admin only). Zero disables
+    /// the cooldown. Raising it applies immediately; lowering it only once
+    /// the current cooldown has run out.
+    pub fn set_admin_rotation_cooldown(env: Env, cooldown: u64) {
+        Self::require_admin(&env);
+        anti_abuse::set_admin_rotation_cooldown(&env, cooldown);
+    }
+
+    pub fn get_admin_rotation_cooldown(env: Env) -> u64 {
+        anti_abuse::get_admin_rotation_cooldown(&env)
+    }
+
+    /// The rate limit enforced for an operation type, or `None` if unlimited.
+    pub fn get_operation_rate_limit(env: Env, operation: Symbol) -> Option<RateLimitConfig> {
+        anti_abuse::get_effective_config(&env, operation)
+    }
+
+    pub fn get_analytics(env: Env) -> Analytics {
         Analytics {
             total_locked: 0,
-            total_released: 0,
+            total_released: monitoring::get_payout_volume(&env),
             total_payouts: 0,
             active_programs: 0,
             operation_count: 0,
         }
     }
 
+    /// Report each health check separately so operators can see which one failed.
+    pub fn get_health_detailed(env: Env) -> HealthReport {
+        let circuit_ok = error_recovery::get_state(&env) == error_recovery::CircuitState::Closed;
+
+        let balance_consistent = match env
+            .storage()
+            .instance()
+            .get::<_, ProgramData>(&PROGRAM_DATA)
+        {
+            Some(program_data) => {
+                let token_client = token::Client::new(&env, &program_data.token_address);
+                let contract_balance = token_client.balance(&env.current_contract_address());
+                program_data.remaining_balance >= 0
+                    && program_data.remaining_balance <= program_data.total_funds
+                    && contract_balance >= program_data.remaining_balance
+            }
+            None => true,
+        };
+
+        let config = Self::get_rate_limit_config(env.clone());
+        let rate_limit_headroom = config
+            .max_operations
+            .saturating_sub(anti_abuse::get_operation_count(&env));
+
+        HealthReport {
+            circuit_ok,
+            balance_consistent,
+            rate_limit_headroom,
+            healthy: circuit_ok && balance_consistent && rate_limit_headroom > 0,
+        }
+    }
+
+    /// Emit a `Heartbeat` event with the ledger timestamp and overall health
+    /// so monitors can confirm the contract is responsive. Changes no state
+    /// beyond the last-heartbeat record, if enabled. Returns the timestamp.
+    pub fn heartbeat(env: Env) -> u64 {
+        let (admin_only, record_last) = Self::get_heartbeat_config(env.clone());
+        if admin_only {
+            Self::require_admin(&env);
+        }
+        let timestamp = env.ledger().timestamp();
+        if record_last {
+            env.storage().instance().set(&LAST_HEARTBEAT, &timestamp);
+        }
+        env.events().publish(
+            (HEARTBEAT,),
+            HeartbeatEvent {
+                version: EVENT_VERSION_V2,
+                timestamp,
+                healthy: Self::get_health_detailed(env.clone()).healthy,
+            },
+        );
+        timestamp
+    }
+
+    /// Configure `heartbeat` (admin only): whether it requires the admin, and
+    /// whether it records the last heartbeat timestamp. Defaults to
+    /// permissionless with recording on.
+    pub fn set_heartbeat_config(env: Env, admin_only: bool, record_last: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&HEARTBEAT_CONFIG, &(admin_only, record_last));
+    }
+
+    /// The heartbeat config as `(admin_only, record_last)`.
+    pub fn get_heartbeat_config(env: Env) -> (bool, bool) {
+        env.storage()
+            .instance()
+            .get(&HEARTBEAT_CONFIG)
+            .unwrap_or((false, true))
+    }
+
+    pub fn get_last_heartbeat(env: Env) -> Option<u64> {
+        env.storage().instance().get(&LAST_HEARTBEAT)
+    }
+
+    /// Whether `address` may currently receive the program's funds: not on
+    /// its blacklist and, if the allow-list is enforced, allow-listed.
+    pub fn is_participant_allowed(env: Env, program_id: String, address: Address) -> bool {
+        Self::is_recipient_allowed(&env, &program_id, &address)
+            && !Self::is_blacklisted(env, program_id, address)
+    }
+
+    /// IDs of unreleased schedules whose recipient is no longer allowed,
+    /// for compliance review before a mass release.
+    pub fn get_flagged_schedules(env: Env, program_id: String) -> Vec<u64> {
+        Self::get_program_data_by_id(&env, &program_id);
+        let mut flagged = Vec::new(&env);
+        for schedule in Self::load_schedules(&env, &program_id).iter() {
+            if !schedule.released
+                && !Self::is_participant_allowed(
+                    env.clone(),
+                    program_id.clone(),
+                    schedule.recipient.clone(),
+                )
+            {
+                flagged.push_back(schedule.schedule_id);
+            }
+        }
+        flagged
+    }
+
     pub fn set_whitelist(env: Env, _address: Address, _whitelisted: bool) {
+        Self::require_not_frozen(&env);
         // Only admin can set whitelist
         let admin: Address = env
             .storage()
@@ -1422,17 +3905,65 @@ impl ProgramEscrowContract {
     /// # Arguments
     /// * `recipients` - Vector of recipient addresses
     /// * `amounts` - Vector of amounts (must match recipients length)
+    /// * `queue_on_open` - If the circuit breaker is open, store the batch in
+    ///   the payout queue instead of panicking (see `process_payout_queue`)
+    /// * `reject_duplicates` - Reject the batch if any recipient appears more
+    ///   than once; when false, repeated recipients are paid once per entry
     ///
     /// # Returns
-    /// Updated ProgramData after payouts
-    pub fn batch_payout(env: Env, recipients: Vec<Address>, amounts: Vec<i128>) -> ProgramData {
+    /// Updated ProgramData after payouts (unchanged if the batch was queued)
+    pub fn batch_payout(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        queue_on_open: bool,
+        reject_duplicates: bool,
+    ) -> ProgramData {
+        Self::batch_payout_internal(
+            env,
+            recipients,
+            amounts,
+            queue_on_open,
+            reject_duplicates,
+            DEFAULT_PAYOUT_CATEGORY,
+        )
+    }
+
+    /// Execute a batch payout whose records and notices all carry `category`
+    /// (`payout` when `None`). Duplicates are allowed and the batch is never
+    /// queued.
+    pub fn batch_payout_with_category(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        category: Option<Symbol>,
+    ) -> ProgramData {
+        Self::batch_payout_internal(
+            env,
+            recipients,
+            amounts,
+            false,
+            false,
+            category.unwrap_or(DEFAULT_PAYOUT_CATEGORY),
+        )
+    }
+
+    fn batch_payout_internal(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        queue_on_open: bool,
+        reject_duplicates: bool,
+        category: Symbol,
+    ) -> ProgramData {
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
         // 2. Contract initialized
         // 3. Paused (operational state)
         // 4. Authorization
         // 5. Input validation (batch size, amounts)
-        // 6. Business logic (sufficient balance)
+        // 6. Circuit breaker (queue or reject while open)
+        // 7. Business logic (sufficient balance)
 
         // 1. Reentrancy guard
         reentrancy_guard::check_not_entered(&env);
@@ -1453,9 +3984,22 @@ impl ProgramEscrowContract {
             reentrancy_guard::clear_entered(&env);
             panic!("Funds Paused");
         }
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+        if Self::in_blackout_window(&env, &program_data.program_id) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Payouts frozen during blackout window");
+        }
 
         // 4. Authorization
         program_data.authorized_payout_key.require_auth();
+        anti_abuse::check_rate_limit(
+            &env,
+            program_data.authorized_payout_key.clone(),
+            anti_abuse::OP_BATCH_PAYOUT,
+        );
 
         // 5. Input validation
         if recipients.len() != amounts.len() {
@@ -1468,6 +4012,29 @@ impl ProgramEscrowContract {
             panic!("Cannot process empty batch");
         }
 
+        let contract_address = env.current_contract_address();
+        if recipients.contains(&contract_address) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Cannot pay the contract itself");
+        }
+        for recipient in recipients.iter() {
+            Self::check_registered_recipient(&env, &program_data.program_id, &recipient);
+            Self::check_allowed_recipient(&env, &program_data.program_id, &recipient);
+            Self::check_not_blacklisted(&env, &program_data.program_id, &recipient);
+        }
+
+        if reject_duplicates {
+            for i in 0..recipients.len() {
+                let recipient = recipients.get(i).unwrap();
+                for j in (i + 1)..recipients.len() {
+                    if recipients.get(j).unwrap() == recipient {
+                        reentrancy_guard::clear_entered(&env);
+                        panic!("Duplicate recipient in batch");
+                    }
+                }
+            }
+        }
+
         // Calculate total payout amount
         let mut total_payout: i128 = 0;
         for amount in amounts.iter() {
@@ -1481,60 +4048,432 @@ impl ProgramEscrowContract {
             });
         }
 
-        // 6. Business logic: sufficient balance
-        if total_payout > program_data.remaining_balance {
+        // 6. Circuit breaker
+        if error_recovery::check_and_allow(&env).is_err() {
+            if !queue_on_open {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Circuit breaker is open");
+            }
+            Self::enqueue_payout(
+                &env,
+                &program_data.program_id,
+                recipients,
+                amounts,
+                total_payout,
+            );
             reentrancy_guard::clear_entered(&env);
-            panic!("Insufficient balance");
+            return program_data;
         }
 
-        // Execute transfers
-        let mut updated_history = program_data.payout_history.clone();
-        let timestamp = env.ledger().timestamp();
-        let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-
-        for i in 0..recipients.len() {
-            let recipient = recipients.get(i).unwrap();
-            let amount = amounts.get(i).unwrap();
+        let payout_key = program_data.authorized_payout_key.clone();
+        let updated_data = Self::execute_batch_payout(
+            &env,
+            program_data,
+            recipients,
+            amounts,
+            total_payout,
+            &category,
+        );
+        anti_abuse::record_success(&env, payout_key, anti_abuse::OP_BATCH_PAYOUT);
 
-            // Transfer funds from contract to recipient
-            token_client.transfer(&contract_address, &recipient, &amount);
+        // Clear reentrancy guard before returning
+        reentrancy_guard::clear_entered(&env);
 
-            // Record payout
-            let payout_record = PayoutRecord {
-                recipient,
-                amount,
-                timestamp,
-            };
-            updated_history.push_back(payout_record);
-        }
+        updated_data
+    }
 
-        // Update program data
-        let mut updated_data = program_data.clone();
-        updated_data.remaining_balance -= total_payout;
-        updated_data.payout_history = updated_history;
+    /// Pay a large batch across several transactions (authorized payout key
+    /// only). Each call pays at most `max_per_call` recipients, starting after
+    /// the last index committed under `idempotency_key`, and commits the new
+    /// index with the transfers, so a call cut short by resource limits never
+    /// leaves a partial checkpoint. Repeat with the same key and batch until
+    /// the returned progress equals the batch length; further calls are no-ops.
+    ///
+    /// Returns the number of recipients paid so far.
+    pub fn batch_payout_checkpointed(
+        env: Env,
+        program_id: String,
+        idempotency_key: BytesN<32>,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        max_per_call: u32,
+    ) -> u32 {
+        Self::require_not_frozen(&env);
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
 
-        // Store updated data
+        let program_data = Self::get_program_info(env.clone());
+        if program_data.program_id != program_id {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not found");
+        }
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funds Paused");
+        }
+        if Self::is_program_paused(env.clone(), program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if recipients.len() != amounts.len() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Recipients and amounts vectors must have the same length");
+        }
+        if recipients.is_empty() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Cannot process empty batch");
+        }
+        if max_per_call == 0 {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Invalid checkpoint size");
+        }
+
+        // The key is bound to the batch it was first used with
+        let batch_hash: BytesN<32> = env
+            .crypto()
+            .sha256(&(recipients.clone(), amounts.clone()).to_xdr(&env))
+            .into();
+        let key = (BATCH_PROGRESS, program_id.clone(), idempotency_key);
+        let (progress, stored_hash): (u32, BytesN<32>) = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or((0, batch_hash.clone()));
+        if stored_hash != batch_hash {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Idempotency key used for a different batch");
+        }
+        if progress >= recipients.len() {
+            reentrancy_guard::clear_entered(&env);
+            return progress;
+        }
+
+        if error_recovery::check_and_allow(&env).is_err() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Circuit breaker is open");
+        }
+
+        let end = progress.saturating_add(max_per_call).min(recipients.len());
+        let chunk_recipients = recipients.slice(progress..end);
+        let chunk_amounts = amounts.slice(progress..end);
+        let contract_address = env.current_contract_address();
+        let mut total_payout: i128 = 0;
+        for i in 0..chunk_recipients.len() {
+            let recipient = chunk_recipients.get(i).unwrap();
+            let amount = chunk_amounts.get(i).unwrap();
+            if recipient == contract_address {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Cannot pay the contract itself");
+            }
+            Self::check_registered_recipient(&env, &program_id, &recipient);
+            Self::check_allowed_recipient(&env, &program_id, &recipient);
+            Self::check_not_blacklisted(&env, &program_id, &recipient);
+            if amount <= 0 {
+                reentrancy_guard::clear_entered(&env);
+                panic!("All amounts must be greater than zero");
+            }
+            total_payout = total_payout.checked_add(amount).unwrap_or_else(|| {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Payout amount overflow")
+            });
+        }
+
+        Self::execute_batch_payout(
+            &env,
+            program_data,
+            chunk_recipients,
+            chunk_amounts,
+            total_payout,
+            &DEFAULT_PAYOUT_CATEGORY,
+        );
+        env.storage().persistent().set(&key, &(end, batch_hash));
+
+        reentrancy_guard::clear_entered(&env);
+        end
+    }
+
+    /// Number of recipients of the checkpointed batch under `idempotency_key`
+    /// paid so far (the last committed index), or zero if it never started.
+    pub fn get_batch_progress(env: Env, program_id: String, idempotency_key: BytesN<32>) -> u32 {
+        env.storage()
+            .persistent()
+            .get::<_, (u32, BytesN<32>)>(&(BATCH_PROGRESS, program_id, idempotency_key))
+            .map(|(progress, _)| progress)
+            .unwrap_or(0)
+    }
+
+    /// Pay out batches queued while the circuit breaker was open, oldest first
+    /// (authorized payout key only).
+    ///
+    /// Recipients are screened again at drain time: a batch naming anyone
+    /// who is no longer payable (blacklisted, off the allow-list or not a
+    /// registered winner) is dropped from the queue unpaid, as a whole.
+    ///
+    /// Takes at most `max` batches off the queue and returns how many were
+    /// paid. Panics if the circuit is still open.
+    pub fn process_payout_queue(env: Env, program_id: String, max: u32) -> u32 {
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let program_data: ProgramData =
+            env.storage()
+                .instance()
+                .get(&PROGRAM_DATA)
+                .unwrap_or_else(|| {
+                    reentrancy_guard::clear_entered(&env);
+                    panic!("Program not initialized")
+                });
+        if program_data.program_id != program_id {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not found");
+        }
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funds Paused");
+        }
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if error_recovery::check_and_allow(&env).is_err() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Circuit breaker is open");
+        }
+
+        let key = DataKey::PayoutQueue(program_id.clone());
+        let mut queue: Vec<QueuedPayout> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let mut data = program_data;
+        let mut taken = 0u32;
+        let mut processed = 0u32;
+        while taken < max && !queue.is_empty() {
+            let entry = queue.pop_front_unchecked();
+            taken += 1;
+            let payable = entry
+                .recipients
+                .iter()
+                .all(|recipient| Self::is_payable_recipient(&env, &program_id, &recipient));
+            if !payable {
+                env.events().publish(
+                    (PAYOUT_DROPPED, program_id.clone()),
+                    (entry.total_amount, entry.queued_at),
+                );
+                continue;
+            }
+            data = Self::execute_batch_payout(
+                &env,
+                data,
+                entry.recipients,
+                entry.amounts,
+                entry.total_amount,
+                &DEFAULT_PAYOUT_CATEGORY,
+            );
+            processed += 1;
+        }
+
+        if queue.is_empty() {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage().persistent().set(&key, &queue);
+        }
+
+        reentrancy_guard::clear_entered(&env);
+        processed
+    }
+
+    /// Batches waiting in the payout queue for a program.
+    pub fn get_payout_queue(env: Env, program_id: String) -> Vec<QueuedPayout> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PayoutQueue(program_id))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    fn enqueue_payout(
+        env: &Env,
+        program_id: &String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        total_amount: i128,
+    ) {
+        let key = DataKey::PayoutQueue(program_id.clone());
+        let mut queue: Vec<QueuedPayout> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        queue.push_back(QueuedPayout {
+            recipients,
+            amounts,
+            total_amount,
+            queued_at: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(&key, &queue);
+
+        env.events().publish(
+            (PAYOUT_QUEUED,),
+            PayoutQueuedEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_id.clone(),
+                total_amount,
+                queue_length: queue.len(),
+            },
+        );
+    }
+
+    /// Checks the balance, transfers each amount and records the payouts.
+    /// Callers handle the reentrancy guard and input validation.
+    fn execute_batch_payout(
+        env: &Env,
+        program_data: ProgramData,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        total_payout: i128,
+        category: &Symbol,
+    ) -> ProgramData {
+        // Business logic: sufficient balance
+        if total_payout > program_data.remaining_balance {
+            reentrancy_guard::clear_entered(env);
+            panic!("Insufficient balance");
+        }
+        if total_payout > Self::payout_available(env, &program_data) {
+            reentrancy_guard::clear_entered(env);
+            panic!("Payout would breach reserve");
+        }
+
+        // Execute transfers
+        let mut updated_data = program_data.clone();
+        let timestamp = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, &program_data.token_address);
+        let mut fees = 0i128;
+        let private = Self::get_event_privacy(env.clone(), program_data.program_id.clone());
+
+        for i in 0..recipients.len() {
+            let recipient = recipients.get(i).unwrap();
+            let amount = amounts.get(i).unwrap();
+
+            // Limits see earlier entries of this batch via the running history
+            payout_limits::consume(
+                env,
+                &updated_data,
+                &program_data.authorized_payout_key,
+                &recipient,
+                amount,
+            );
+            Self::consume_payout_approval(env, &program_data.program_id, &recipient, amount);
+
+            // Transfer funds from contract to recipient, holding back any payout fee
+            let fee = Self::payout_fee(env, amount);
+            token_client.transfer(&contract_address, &recipient, &(amount - fee));
+            fees += fee;
+            if private {
+                Self::emit_private_payout(env, &program_data.program_id, &recipient, category);
+            } else {
+                Self::emit_winner_paid(env, &program_data.program_id, &recipient, amount);
+                Self::emit_payout_notice(
+                    env,
+                    &program_data.program_id,
+                    &recipient,
+                    amount,
+                    category,
+                );
+            }
+
+            // Record payout
+            let payout_record = PayoutRecord {
+                recipient,
+                amount,
+                timestamp,
+            };
+            updated_data.payout_history.push_back(payout_record);
+            Self::record_payout_category(
+                env,
+                &updated_data.program_id,
+                updated_data.payout_history.len() - 1,
+                category,
+            );
+        }
+        Self::accrue_fees(env, &updated_data.program_id, fees);
+
+        // Update program data
+        updated_data.remaining_balance -= total_payout;
+
+        // Store updated data
         env.storage().instance().set(&PROGRAM_DATA, &updated_data);
+        monitoring::record_payout_volume(env, total_payout);
+        Self::record_financials(env, &updated_data.program_id, 0, total_payout, 0);
+        Self::record_batch_receipt(
+            env,
+            &updated_data.program_id,
+            total_payout,
+            recipients.len(),
+            timestamp,
+        );
 
         // Emit BatchPayout event
-        env.events().publish(
-            (BATCH_PAYOUT,),
+        Self::publish_payout_event(
+            env,
+            BATCH_PAYOUT,
+            &updated_data.program_id,
             BatchPayoutEvent {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 program_id: updated_data.program_id.clone(),
                 recipient_count: recipients.len() as u32,
                 total_amount: total_payout,
                 remaining_balance: updated_data.remaining_balance,
+                fee_amount: fees,
+                net_amount: total_payout - fees,
+                usd_equivalent: Self::usd_equivalent(env, &updated_data.program_id, total_payout),
+                event_seq: Self::next_event_seq(env),
             },
         );
-
-        // Clear reentrancy guard before returning
-        reentrancy_guard::clear_entered(&env);
+        Self::emit_balance_snapshot(env, &updated_data);
 
         updated_data
     }
 
+    fn record_batch_receipt(
+        env: &Env,
+        program_id: &String,
+        total_amount: i128,
+        recipient_count: u32,
+        timestamp: u64,
+    ) {
+        let id_key = DataKey::NextBatchId(program_id.clone());
+        let batch_id: u64 = env.storage().persistent().get(&id_key).unwrap_or(1);
+        env.storage().persistent().set(
+            &DataKey::BatchReceipt(program_id.clone(), batch_id),
+            &BatchReceipt {
+                batch_id,
+                program_id: program_id.clone(),
+                total_amount,
+                recipient_count,
+                timestamp,
+            },
+        );
+        env.storage().persistent().set(&id_key, &(batch_id + 1));
+    }
+
+    /// Aggregate receipt for a program's `batch_id`-th batch payout, counting
+    /// from 1.
+    pub fn get_batch_receipt(env: Env, program_id: String, batch_id: u64) -> Option<BatchReceipt> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BatchReceipt(program_id, batch_id))
+    }
+
     /// Execute a single payout to one recipient
     ///
     /// # Arguments
@@ -1544,6 +4483,18 @@ impl ProgramEscrowContract {
     /// # Returns
     /// Updated ProgramData after payout
     pub fn single_payout(env: Env, recipient: Address, amount: i128) -> ProgramData {
+        Self::single_payout_with_category(env, recipient, amount, None)
+    }
+
+    /// Execute a single payout recorded and announced under `category`
+    /// (`payout` when `None`).
+    pub fn single_payout_with_category(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        category: Option<Symbol>,
+    ) -> ProgramData {
+        let category = category.unwrap_or(DEFAULT_PAYOUT_CATEGORY);
         // Validation precedence (deterministic ordering):
         // 1. Reentrancy guard
         // 2. Contract initialized
@@ -1571,6 +4522,14 @@ impl ProgramEscrowContract {
             reentrancy_guard::clear_entered(&env);
             panic!("Funds Paused");
         }
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+        if Self::in_blackout_window(&env, &program_data.program_id) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Payouts frozen during blackout window");
+        }
 
         // 4. Authorization
         program_data.authorized_payout_key.require_auth();
@@ -1580,17 +4539,61 @@ impl ProgramEscrowContract {
             reentrancy_guard::clear_entered(&env);
             panic!("Amount must be greater than zero");
         }
+        if recipient == env.current_contract_address() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Cannot pay the contract itself");
+        }
+        Self::check_registered_recipient(&env, &program_data.program_id, &recipient);
+        Self::check_allowed_recipient(&env, &program_data.program_id, &recipient);
+        Self::check_not_blacklisted(&env, &program_data.program_id, &recipient);
 
         // 6. Business logic: sufficient balance
         if amount > program_data.remaining_balance {
             reentrancy_guard::clear_entered(&env);
             panic!("Insufficient balance");
         }
+        if amount > Self::payout_available(&env, &program_data) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Payout would breach reserve");
+        }
+        payout_limits::consume(
+            &env,
+            &program_data,
+            &program_data.authorized_payout_key,
+            &recipient,
+            amount,
+        );
+        Self::consume_payout_approval(&env, &program_data.program_id, &recipient, amount);
 
-        // Transfer funds from contract to recipient
+        // Transfer funds from contract to recipient, holding back any payout fee
         let contract_address = env.current_contract_address();
         let token_client = token::Client::new(&env, &program_data.token_address);
-        token_client.transfer(&contract_address, &recipient, &amount);
+        let fee = Self::payout_fee(&env, amount);
+        let verify = Self::get_verify_delivery(env.clone(), program_data.program_id.clone());
+        let balance_before = if verify {
+            token_client.balance(&recipient)
+        } else {
+            0
+        };
+        token_client.transfer(&contract_address, &recipient, &(amount - fee));
+        if verify && token_client.balance(&recipient) - balance_before != amount - fee {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Delivery verification failed");
+        }
+        Self::accrue_fees(&env, &program_data.program_id, fee);
+        let private = Self::get_event_privacy(env.clone(), program_data.program_id.clone());
+        if private {
+            Self::emit_private_payout(&env, &program_data.program_id, &recipient, &category);
+        } else {
+            Self::emit_winner_paid(&env, &program_data.program_id, &recipient, amount);
+            Self::emit_payout_notice(
+                &env,
+                &program_data.program_id,
+                &recipient,
+                amount,
+                &category,
+            );
+        }
 
         // Record payout
         let timestamp = env.ledger().timestamp();
@@ -1602,6 +4605,12 @@ impl ProgramEscrowContract {
 
         let mut updated_history = program_data.payout_history.clone();
         updated_history.push_back(payout_record);
+        Self::record_payout_category(
+            &env,
+            &program_data.program_id,
+            updated_history.len() - 1,
+            &category,
+        );
 
         // Update program data
         let mut updated_data = program_data.clone();
@@ -1610,18 +4619,30 @@ impl ProgramEscrowContract {
 
         // Store updated data
         env.storage().instance().set(&PROGRAM_DATA, &updated_data);
+        monitoring::record_payout_volume(&env, amount);
+        Self::record_financials(&env, &updated_data.program_id, 0, amount, 0);
 
         // Emit Payout event
-        env.events().publish(
-            (PAYOUT,),
-            PayoutEvent {
-                version: EVENT_VERSION_V2,
-                program_id: updated_data.program_id.clone(),
-                recipient,
-                amount,
-                remaining_balance: updated_data.remaining_balance,
-            },
-        );
+        if !private {
+            Self::publish_payout_event(
+                &env,
+                PAYOUT,
+                &updated_data.program_id,
+                PayoutEvent {
+                    version: EVENT_VERSION_V3,
+                    program_id: updated_data.program_id.clone(),
+                    recipient,
+                    amount,
+                    remaining_balance: updated_data.remaining_balance,
+                    gross_amount: amount,
+                    fee_amount: fee,
+                    net_amount: amount - fee,
+                    usd_equivalent: Self::usd_equivalent(&env, &updated_data.program_id, amount),
+                    event_seq: Self::next_event_seq(&env),
+                },
+            );
+        }
+        Self::emit_balance_snapshot(&env, &updated_data);
 
         // Clear reentrancy guard before returning
         reentrancy_guard::clear_entered(&env);
@@ -1629,204 +4650,1812 @@ impl ProgramEscrowContract {
         updated_data
     }
 
-    /// Get program information
-    ///
-    /// # Returns
-    /// ProgramData containing all program information
-    pub fn get_program_info(env: Env) -> ProgramData {
+    /// Freeze `single_payout` and `batch_payout` for a program while
+    /// `start <= now < end` (admin only). Replaces any existing window.
+    pub fn set_blackout_window(env: Env, program_id: String, start: u64, end: u64) {
+        Self::require_admin(&env);
+        if start >= end {
+            panic!("Invalid blackout window");
+        }
         env.storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"))
+            .persistent()
+            .set(&DataKey::BlackoutWindow(program_id), &(start, end));
     }
 
-    /// Get remaining balance
-    ///
-    /// # Returns
-    /// Current remaining balance
-    pub fn get_remaining_balance(env: Env) -> i128 {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
-
-        program_data.remaining_balance
+    /// Remove a program's blackout window (admin only).
+    pub fn clear_blackout_window(env: Env, program_id: String) {
+        Self::require_admin(&env);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::BlackoutWindow(program_id));
     }
 
-    /// Create a release schedule entry that can be triggered at/after `release_timestamp`.
-    pub fn create_program_release_schedule(
-        env: Env,
-        recipient: Address,
-        amount: i128,
-        release_timestamp: u64,
-    ) -> ProgramReleaseSchedule {
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| panic!("Program not initialized"));
+    pub fn get_blackout_window(env: Env, program_id: String) -> Option<(u64, u64)> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BlackoutWindow(program_id))
+    }
 
-        program_data.authorized_payout_key.require_auth();
+    fn in_blackout_window(env: &Env, program_id: &String) -> bool {
+        match Self::get_blackout_window(env.clone(), program_id.clone()) {
+            Some((start, end)) => {
+                let now = env.ledger().timestamp();
+                now >= start && now < end
+            }
+            None => false,
+        }
+    }
 
-        if amount <= 0 {
-            panic!("Amount must be greater than zero");
+    /// Hold back `reserve` of the program's balance from payouts (admin only).
+    /// Payouts may only draw on `remaining_balance - reserve`; zero removes
+    /// the reserve.
+    pub fn set_reserve(env: Env, program_id: String, reserve: i128) {
+        Self::require_admin(&env);
+        if reserve < 0 {
+            panic!("Invalid reserve");
         }
+        env.storage()
+            .persistent()
+            .set(&DataKey::Reserve(program_id), &reserve);
+    }
 
-        let mut schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
-        let schedule_id: u64 = env
-            .storage()
-            .instance()
-            .get(&NEXT_SCHEDULE_ID)
-            .unwrap_or(1_u64);
+    pub fn get_reserve(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Reserve(program_id))
+            .unwrap_or(0)
+    }
 
-        let schedule = ProgramReleaseSchedule {
-            schedule_id,
-            recipient,
-            amount,
-            release_timestamp,
-            released: false,
+    /// Commit the program to an announced prize pool of `total` (admin only).
+    /// `finalize_pool` then only succeeds once exactly that much is locked.
+    pub fn set_committed_pool(env: Env, program_id: String, total: i128) {
+        Self::require_admin(&env);
+        if total <= 0 {
+            panic!("Invalid committed pool");
+        }
+        if Self::is_pool_finalized(env.clone(), program_id.clone()) {
+            panic!("Pool finalized");
+        }
+        Self::get_program_data_by_id(&env, &program_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::CommittedPool(program_id), &total);
+    }
+
+    pub fn get_committed_pool(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::CommittedPool(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Finalize the pool once `total_funds` equals the committed total
+    /// (admin only). Under- and over-funded pools are rejected; after
+    /// finalization no further funds can be locked.
+    pub fn finalize_pool(env: Env, program_id: String) -> ProgramData {
+        Self::require_admin(&env);
+        if Self::is_pool_finalized(env.clone(), program_id.clone()) {
+            panic!("Pool finalized");
+        }
+        let committed = Self::get_committed_pool(env.clone(), program_id.clone());
+        if committed == 0 {
+            panic!("No committed pool");
+        }
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        if program_data.total_funds < committed {
+            panic!("Pool underfunded");
+        }
+        if program_data.total_funds > committed {
+            panic!("Pool overfunded");
+        }
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::PoolFinalized(program_id.clone()), &true);
+        env.events().publish(
+            (POOL_FINALIZED, program_id.clone()),
+            PoolFinalizedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                total_funds: program_data.total_funds,
+            },
+        );
+        program_data
+    }
+
+    pub fn is_pool_finalized(env: Env, program_id: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::PoolFinalized(program_id))
+            .unwrap_or(false)
+    }
+
+    /// Balance payouts may draw on: the remaining balance less the reserve.
+    fn payout_available(env: &Env, program_data: &ProgramData) -> i128 {
+        let reserve = Self::get_reserve(env.clone(), program_data.program_id.clone());
+        program_data.remaining_balance.saturating_sub(reserve)
+    }
+
+    /// Tag a program so its payout events carry `tag` as a second topic,
+    /// letting audit subscribers filter on it (admin only).
+    pub fn tag_program(env: Env, program_id: String, tag: Symbol) {
+        Self::require_admin(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ProgramTag(program_id), &tag);
+    }
+
+    pub fn get_program_tag(env: Env, program_id: String) -> Option<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ProgramTag(program_id))
+    }
+
+    /// Publish a payout event under `topic`, followed by the program's tag
+    /// when it has one.
+    fn publish_payout_event<T>(env: &Env, topic: Symbol, program_id: &String, data: T)
+    where
+        T: IntoVal<Env, Val>,
+    {
+        match Self::get_program_tag(env.clone(), program_id.clone()) {
+            Some(tag) => env.events().publish((topic, tag), data),
+            None => env.events().publish((topic,), data),
+        }
+    }
+
+    /// Set the reporting rate, in micro-USD per token unit, used to annotate
+    /// payout events with a USD equivalent (admin only). Transfers are always
+    /// in tokens; a rate of zero clears it.
+    pub fn set_reference_rate(env: Env, program_id: String, rate_micro_usd_per_unit: i128) {
+        Self::require_admin(&env);
+        if rate_micro_usd_per_unit < 0 {
+            panic!("Invalid reference rate");
+        }
+        let key = DataKey::ReferenceRate(program_id);
+        if rate_micro_usd_per_unit == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&key, &rate_micro_usd_per_unit);
+        }
+    }
+
+    pub fn get_reference_rate(env: Env, program_id: String) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReferenceRate(program_id))
+    }
+
+    /// `amount` in micro-USD at the program's reference rate. `None` when no
+    /// rate is set or the product would overflow.
+    fn usd_equivalent(env: &Env, program_id: &String, amount: i128) -> Option<i128> {
+        Self::get_reference_rate(env.clone(), program_id.clone())
+            .and_then(|rate| amount.checked_mul(rate))
+    }
+
+    /// Emit a `BalanceSnapshot` event after every lock and payout of a program
+    /// (admin only). Off by default.
+    pub fn set_balance_snapshots(env: Env, program_id: String, enabled: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::BalanceSnapshots(program_id), &enabled);
+    }
+
+    pub fn get_balance_snapshots(env: Env, program_id: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::BalanceSnapshots(program_id))
+            .unwrap_or(false)
+    }
+
+    fn emit_balance_snapshot(env: &Env, program_data: &ProgramData) {
+        if !Self::get_balance_snapshots(env.clone(), program_data.program_id.clone()) {
+            return;
+        }
+        env.events().publish(
+            (BALANCE_SNAPSHOT, program_data.program_id.clone()),
+            BalanceSnapshotEvent {
+                version: EVENT_VERSION_V3,
+                program_id: program_data.program_id.clone(),
+                remaining_balance: program_data.remaining_balance,
+                timestamp: env.ledger().timestamp(),
+                event_seq: Self::next_event_seq(env),
+            },
+        );
+    }
+
+    fn emit_schedule_released(
+        env: &Env,
+        program_id: &String,
+        schedule: &ProgramReleaseSchedule,
+        release_type: ReleaseType,
+    ) {
+        env.events().publish(
+            (SCHEDULE_RELEASED, program_id.clone()),
+            ScheduleReleasedEvent {
+                version: EVENT_VERSION_V3,
+                program_id: program_id.clone(),
+                schedule_id: schedule.schedule_id,
+                recipient: schedule.recipient.clone(),
+                amount: schedule.amount,
+                release_type,
+                event_seq: Self::next_event_seq(env),
+            },
+        );
+    }
+
+    /// Make `single_payout` check the recipient's token balance rose by the
+    /// net amount sent (admin only). Off by default: it costs two extra
+    /// balance reads per payout.
+    pub fn set_verify_delivery(env: Env, program_id: String, enabled: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .persistent()
+            .set(&DataKey::VerifyDelivery(program_id), &enabled);
+    }
+
+    pub fn get_verify_delivery(env: Env, program_id: String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DataKey::VerifyDelivery(program_id))
+            .unwrap_or(false)
+    }
+
+    /// Pay `recipient` by crediting a claimable balance instead of pushing tokens
+    /// (authorized payout key only).
+    ///
+    /// The amount leaves the program balance immediately and is held by the
+    /// contract until the recipient calls `withdraw_claimable`. Repeated
+    /// payouts to the same recipient accumulate.
+    pub fn payout_claimable(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funds Paused");
+        }
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+
+        program_data.authorized_payout_key.require_auth();
+
+        if amount <= 0 {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Amount must be greater than zero");
+        }
+        if amount > program_data.remaining_balance {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Insufficient balance");
+        }
+        if amount > Self::payout_available(&env, &program_data) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Payout would breach reserve");
+        }
+
+        let key = DataKey::Claimable(program_id.clone(), recipient.clone());
+        let claimable: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        let claimable_balance = claimable + amount;
+        env.storage().persistent().set(&key, &claimable_balance);
+
+        program_data.remaining_balance -= amount;
+        program_data.payout_history.push_back(PayoutRecord {
+            recipient: recipient.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        });
+        Self::store_program_data(&env, &program_id, &program_data);
+        Self::record_financials(&env, &program_id, 0, amount, 0);
+
+        env.events().publish(
+            (CLAIMABLE_CREATED, program_id.clone()),
+            ClaimablePayoutEvent {
+                version: EVENT_VERSION_V3,
+                program_id,
+                recipient,
+                amount,
+                claimable_balance,
+                event_seq: Self::next_event_seq(&env),
+            },
+        );
+
+        reentrancy_guard::clear_entered(&env);
+        program_data
+    }
+
+    /// Collect the recipient's whole claimable balance (recipient only).
+    ///
+    /// Follows checks-effects-interactions: the stored balance is cleared
+    /// before the token transfer, so a token that calls back into this
+    /// function finds nothing left to withdraw.
+    pub fn withdraw_claimable(env: Env, program_id: String, recipient: Address) -> i128 {
+        Self::require_not_frozen(&env);
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        recipient.require_auth();
+
+        // Checks
+        let key = DataKey::Claimable(program_id.clone(), recipient.clone());
+        let amount: i128 = env.storage().persistent().get(&key).unwrap_or(0);
+        if amount == 0 {
+            reentrancy_guard::clear_entered(&env);
+            panic!("No claimable balance");
+        }
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+
+        // Effects
+        env.storage().persistent().remove(&key);
+        let penalty = Self::apply_early_claim_penalty(&env, &program_id, &recipient, amount);
+        let amount = amount - penalty;
+
+        // Interactions
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+
+        env.events().publish(
+            (CLAIMABLE_WITHDRAWN, program_id.clone()),
+            ClaimablePayoutEvent {
+                version: EVENT_VERSION_V3,
+                program_id,
+                recipient,
+                amount,
+                claimable_balance: 0,
+                event_seq: Self::next_event_seq(&env),
+            },
+        );
+
+        reentrancy_guard::clear_entered(&env);
+        amount
+    }
+
+    pub fn get_claimable_balance(env: Env, program_id: String, recipient: Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Claimable(program_id, recipient))
+            .unwrap_or(0)
+    }
+
+    /// Withhold `bps` of any claim made before `ready_ts` and return it to
+    /// the program's remaining balance (admin only). Claims at or after
+    /// `ready_ts` are penalty-free; zero bps removes the penalty.
+    pub fn set_early_claim_penalty(env: Env, program_id: String, bps: u32, ready_ts: u64) {
+        Self::require_admin(&env);
+        if bps > BASIS_POINTS as u32 {
+            panic!("Invalid penalty");
+        }
+        let key = DataKey::EarlyClaimPenalty(program_id);
+        if bps == 0 {
+            env.storage().persistent().remove(&key);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&key, &EarlyClaimPenalty { bps, ready_ts });
+        }
+    }
+
+    pub fn get_early_claim_penalty(env: Env, program_id: String) -> Option<EarlyClaimPenalty> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EarlyClaimPenalty(program_id))
+    }
+
+    /// Return the early-claim penalty on `amount` to the program's remaining
+    /// balance and report how much was withheld. Zero once `ready_ts` passes.
+    pub(crate) fn apply_early_claim_penalty(
+        env: &Env,
+        program_id: &String,
+        recipient: &Address,
+        amount: i128,
+    ) -> i128 {
+        let config = match Self::get_early_claim_penalty(env.clone(), program_id.clone()) {
+            Some(config) if env.ledger().timestamp() < config.ready_ts => config,
+            _ => return 0,
+        };
+        let penalty = amount * config.bps as i128 / BASIS_POINTS;
+        if penalty == 0 {
+            return 0;
+        }
+
+        let mut program_data = Self::get_program_data_by_id(env, program_id);
+        program_data.remaining_balance += penalty;
+        Self::store_program_data(env, program_id, &program_data);
+        Self::record_financials(env, program_id, 0, -penalty, 0);
+
+        env.events().publish(
+            (EARLY_CLAIM_PENALTY, program_id.clone()),
+            EarlyClaimPenaltyEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_id.clone(),
+                recipient: recipient.clone(),
+                penalty,
+            },
+        );
+        penalty
+    }
+
+    // ========================================================================
+    // Payout Limits
+    // ========================================================================
+
+    /// Configure a per-window spending cap (authorized payout key only).
+    pub fn set_program_spending_limit(
+        env: Env,
+        program_id: String,
+        window_size: u64,
+        max_amount: i128,
+        enabled: bool,
+    ) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        payout_limits::set_spending_limit(
+            &env,
+            &program_id,
+            &ProgramSpendingConfig {
+                window_size,
+                max_amount,
+                enabled,
+            },
+        );
+    }
+
+    pub fn get_program_spending_limit(
+        env: Env,
+        program_id: String,
+    ) -> Option<ProgramSpendingConfig> {
+        payout_limits::get_spending_limit(&env, &program_id)
+    }
+
+    pub fn get_program_spending_state(
+        env: Env,
+        program_id: String,
+    ) -> Option<ProgramSpendingState> {
+        payout_limits::get_spending_state(&env, &program_id)
+    }
+
+    /// Cap the cumulative amount any single recipient can receive (authorized payout key only).
+    /// Pass `None` to remove the cap.
+    pub fn set_recipient_cap(env: Env, program_id: String, cap: Option<i128>) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        payout_limits::set_recipient_cap(&env, &program_id, cap);
+    }
+
+    /// Set the remaining amount `operator` may pay out (admin only).
+    /// Pass `None` to remove the allowance.
+    pub fn set_operator_allowance(
+        env: Env,
+        program_id: String,
+        operator: Address,
+        allowance: Option<i128>,
+    ) {
+        Self::require_admin(&env);
+        payout_limits::set_operator_allowance(&env, &program_id, &operator, allowance);
+    }
+
+    /// How much `operator` may pay `recipient` right now.
+    ///
+    /// Returns the minimum of the remaining balance, the remaining spending
+    /// window, the recipient's remaining cap and the operator's allowance.
+    /// Unconfigured limits are ignored.
+    pub fn get_operator_spendable(
+        env: Env,
+        program_id: String,
+        operator: Address,
+        recipient: Address,
+    ) -> i128 {
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        payout_limits::spendable(&env, &program_data, &operator, &recipient)
+    }
+
+    /// Get program information
+    ///
+    /// # Returns
+    /// ProgramData containing all program information
+    pub fn get_program_info(env: Env) -> ProgramData {
+        env.storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"))
+    }
+
+    /// Depositor memo of the program's latest `lock_program_funds` call, if
+    /// it had one.
+    pub fn get_lock_memo(env: Env, program_id: String) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::LockMemo(program_id))
+    }
+
+    /// Get remaining balance
+    ///
+    /// # Returns
+    /// Current remaining balance
+    pub fn get_remaining_balance(env: Env) -> i128 {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+
+        program_data.remaining_balance
+    }
+
+    /// Set the maximum number of release schedules a program may hold
+    /// (authorized payout key only).
+    pub fn set_max_schedules(env: Env, program_id: String, max_schedules: u32) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxSchedules(program_id), &max_schedules);
+    }
+
+    /// Get the release schedule cap for a program (defaults to 100).
+    pub fn get_max_schedules(env: Env, program_id: String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxSchedules(program_id))
+            .unwrap_or(DEFAULT_MAX_SCHEDULES)
+    }
+
+    /// Panic unless `adding` more schedules fit under the program's cap,
+    /// which counts only schedules not yet released.
+    fn check_schedule_capacity(
+        env: &Env,
+        program_id: &String,
+        schedules: &Vec<ProgramReleaseSchedule>,
+        adding: u32,
+    ) {
+        let pending = schedules.iter().filter(|s| !s.released).count() as u32;
+        let max_schedules = Self::get_max_schedules(env.clone(), program_id.clone());
+        if pending + adding > max_schedules {
+            panic!("Maximum schedules reached");
+        }
+    }
+
+    /// Set how many failed releases a program's schedule tolerates
+    /// before it is marked failed (authorized payout key only).
+    pub fn set_max_release_failures(env: Env, program_id: String, max_failures: u32) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        if max_failures == 0 {
+            panic!("Max failures must be greater than zero");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MaxReleaseFailures(program_id), &max_failures);
+    }
+
+    /// Get the failed-release cap for a program (defaults to 3).
+    pub fn get_max_release_failures(env: Env, program_id: String) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MaxReleaseFailures(program_id))
+            .unwrap_or(DEFAULT_MAX_RELEASE_FAILURES)
+    }
+
+    /// Create a release schedule entry that can be triggered at/after `release_timestamp`.
+    pub fn create_program_release_schedule(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        Self::require_not_frozen(&env);
+        Self::create_dependent_prog_schedule(env, recipient, amount, release_timestamp, None)
+    }
+
+    /// Create a release schedule that, when `depends_on` is set, cannot be
+    /// released until that prerequisite schedule has been released.
+    pub fn create_dependent_prog_schedule(
+        env: Env,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+        depends_on: Option<u64>,
+    ) -> ProgramReleaseSchedule {
+        Self::require_not_frozen(&env);
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+
+        program_data.authorized_payout_key.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+
+        let program_id = program_data.program_id;
+        let schedules = Self::load_schedules(&env, &program_id);
+        Self::check_schedule_capacity(&env, &program_id, &schedules, 1);
+        if let Some(prerequisite) = depends_on {
+            if !schedules.iter().any(|s| s.schedule_id == prerequisite) {
+                panic!("Dependency not found");
+            }
+        }
+
+        let schedule = Self::push_schedule(&env, &program_id, recipient, amount, release_timestamp);
+        if let Some(prerequisite) = depends_on {
+            env.storage().persistent().set(
+                &(SCHEDULE_DEPENDENCY, program_id, schedule.schedule_id),
+                &prerequisite,
+            );
+        }
+
+        schedule
+    }
+
+    /// The schedule that must be released before `schedule_id` can be.
+    pub fn get_schedule_dependency(env: Env, program_id: String, schedule_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&(SCHEDULE_DEPENDENCY, program_id, schedule_id))
+    }
+
+    /// Next schedule ID of a program; every schedule API allocates from here.
+    fn next_schedule_id(env: &Env, program_id: &String) -> u64 {
+        Self::migrate_legacy_schedules(env);
+        env.storage()
+            .persistent()
+            .get(&DataKey::NextScheduleId(program_id.clone()))
+            .unwrap_or(1)
+    }
+
+    fn load_schedule(
+        env: &Env,
+        program_id: &String,
+        schedule_id: u64,
+    ) -> Option<ProgramReleaseSchedule> {
+        Self::migrate_legacy_schedules(env);
+        env.storage()
+            .persistent()
+            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
+    }
+
+    /// A program's schedules in ID order.
+    fn load_schedules(env: &Env, program_id: &String) -> Vec<ProgramReleaseSchedule> {
+        let mut schedules = Vec::new(env);
+        for schedule_id in 1..Self::next_schedule_id(env, program_id) {
+            if let Some(schedule) = Self::load_schedule(env, program_id, schedule_id) {
+                schedules.push_back(schedule);
+            }
+        }
+        schedules
+    }
+
+    /// Schedules of the active program, or none before initialization.
+    fn active_schedules(env: &Env) -> Vec<ProgramReleaseSchedule> {
+        let program_data: Option<ProgramData> = env.storage().instance().get(&PROGRAM_DATA);
+        match program_data {
+            Some(program_data) => Self::load_schedules(env, &program_data.program_id),
+            None => Vec::new(env),
+        }
+    }
+
+    fn store_schedule(env: &Env, program_id: &String, schedule: &ProgramReleaseSchedule) {
+        env.storage().persistent().set(
+            &DataKey::ReleaseSchedule(program_id.clone(), schedule.schedule_id),
+            schedule,
+        );
+    }
+
+    fn store_schedules(env: &Env, program_id: &String, schedules: &Vec<ProgramReleaseSchedule>) {
+        for schedule in schedules.iter() {
+            Self::store_schedule(env, program_id, &schedule);
+        }
+    }
+
+    /// Store a new pending schedule under the program's next schedule ID.
+    fn push_schedule(
+        env: &Env,
+        program_id: &String,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        let schedule_id = Self::next_schedule_id(env, program_id);
+        let schedule = ProgramReleaseSchedule {
+            schedule_id,
+            recipient,
+            amount,
+            release_timestamp,
+            released: false,
             released_at: None,
             released_by: None,
         };
-        schedules.push_back(schedule.clone());
+        Self::store_schedule(env, program_id, &schedule);
+        env.storage().persistent().set(
+            &DataKey::NextScheduleId(program_id.clone()),
+            &(schedule_id + 1),
+        );
+        schedule
+    }
+
+    /// Move schedules from the contract-wide list used before schedules were
+    /// stored per program into the active program's entries. A no-op once
+    /// the list is gone.
+    fn migrate_legacy_schedules(env: &Env) {
+        let legacy: Vec<ProgramReleaseSchedule> = match env.storage().instance().get(&SCHEDULES) {
+            Some(legacy) => legacy,
+            None => return,
+        };
+        let next_id: u64 = env.storage().instance().get(&NEXT_SCHEDULE_ID).unwrap_or(1);
+        env.storage().instance().remove(&SCHEDULES);
+        env.storage().instance().remove(&NEXT_SCHEDULE_ID);
+
+        let program_data: Option<ProgramData> = env.storage().instance().get(&PROGRAM_DATA);
+        let program_id = match program_data {
+            Some(program_data) => program_data.program_id,
+            None => return,
+        };
+        for schedule in legacy.iter() {
+            Self::store_schedule(env, &program_id, &schedule);
+        }
+        let id_key = DataKey::NextScheduleId(program_id);
+        let current: u64 = env.storage().persistent().get(&id_key).unwrap_or(1);
+        env.storage()
+            .persistent()
+            .set(&id_key, &current.max(next_id));
+    }
+
+    /// Trigger all due schedules where `now >= release_timestamp`.
+    pub fn trigger_program_releases(env: Env) -> u32 {
+        // Reentrancy guard: Check and set
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        let mut program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Program not initialized")
+            });
+        program_data.authorized_payout_key.require_auth();
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funds Paused");
+        }
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+
+        let mut schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
+        let mut release_history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .instance()
+            .get(&RELEASE_HISTORY)
+            .unwrap_or_else(|| Vec::new(&env));
+
+        let now = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(&env, &program_data.token_address);
+        let check_balance = Self::get_release_balance_check(env.clone());
+        let mut held = token_client.balance(&contract_address);
+        let mut released_count: u32 = 0;
+
+        for i in 0..schedules.len() {
+            let mut schedule = schedules.get(i).unwrap();
+            if schedule.released
+                || now < schedule.release_timestamp
+                || Self::is_schedule_failed(&env, &program_data.program_id, schedule.schedule_id)
+            {
+                continue;
+            }
+            if !Self::dependency_released(&env, &program_data.program_id, &schedules, &schedule) {
+                continue;
+            }
+            if !Self::is_recipient_allowed(&env, &program_data.program_id, &schedule.recipient) {
+                continue;
+            }
+
+            if schedule.amount > program_data.remaining_balance {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Insufficient balance");
+            }
+            if check_balance && schedule.amount > held {
+                reentrancy_guard::clear_entered(&env);
+                panic!("Insufficient funds to release schedule");
+            }
+
+            if !Self::transfer_schedule(&env, &token_client, &program_data.program_id, &schedule) {
+                continue;
+            }
+            held -= schedule.amount;
+            schedule.released = true;
+            schedule.released_at = Some(now);
+            schedule.released_by = Some(contract_address.clone());
+            schedules.set(i, schedule.clone());
+
+            program_data.remaining_balance -= schedule.amount;
+            Self::record_financials(&env, &program_data.program_id, 0, schedule.amount, 0);
+            Self::emit_schedule_released(
+                &env,
+                &program_data.program_id,
+                &schedule,
+                ReleaseType::Automatic,
+            );
+            program_data.payout_history.push_back(PayoutRecord {
+                recipient: schedule.recipient.clone(),
+                amount: schedule.amount,
+                timestamp: now,
+            });
+            release_history.push_back(ProgramReleaseHistory {
+                schedule_id: schedule.schedule_id,
+                recipient: schedule.recipient,
+                amount: schedule.amount,
+                released_at: now,
+                release_type: ReleaseType::Automatic,
+            });
+            released_count += 1;
+        }
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        Self::store_schedules(&env, &program_data.program_id, &schedules);
+        env.storage()
+            .instance()
+            .set(&RELEASE_HISTORY, &release_history);
+
+        // Clear reentrancy guard before returning
+        reentrancy_guard::clear_entered(&env);
+
+        released_count
+    }
+
+    /// Lock funds into `program_id`, which may be the active program or any
+    /// program in the registry, e.g. to back the per-program schedules
+    /// settled by `release_all_due`.
+    pub fn lock_registry_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
+        let is_active = env
+            .storage()
+            .instance()
+            .get::<_, ProgramData>(&PROGRAM_DATA)
+            .map(|data| data.program_id == program_id)
+            .unwrap_or(false);
+        if is_active {
+            return Self::lock_program_funds(env, amount, None);
+        }
+
+        if Self::check_paused(&env, symbol_short!("lock")) {
+            panic!("Funds Paused");
+        }
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+        if Self::is_program_paused(env.clone(), program_id.clone()) {
+            panic!("Program paused");
+        }
+        if Self::is_pool_finalized(env.clone(), program_id.clone()) {
+            panic!("Pool finalized");
+        }
+        Self::consume_lock_approval(&env, &program_id, amount);
+
+        program_data.total_funds += amount;
+        program_data.remaining_balance += amount;
+        Self::store_program_data(&env, &program_id, &program_data);
+        Self::record_financials(&env, &program_id, amount, 0, 0);
+
+        env.events().publish(
+            (FUNDS_LOCKED,),
+            FundsLockedEvent {
+                version: EVENT_VERSION_V3,
+                program_id,
+                amount,
+                remaining_balance: program_data.remaining_balance,
+                event_seq: Self::next_event_seq(&env),
+            },
+        );
+        Self::emit_balance_snapshot(&env, &program_data);
+        program_data
+    }
+
+    /// Create a release schedule owned by `program_id` (its authorized payout
+    /// key only). Unlike `create_program_release_schedule`, this works for any
+    /// program in the registry. Both share the program's schedule store.
+    pub fn create_program_schedule(
+        env: Env,
+        program_id: String,
+        recipient: Address,
+        amount: i128,
+        release_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+
+        if amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        let schedules = Self::load_schedules(&env, &program_id);
+        Self::check_schedule_capacity(&env, &program_id, &schedules, 1);
+
+        Self::push_schedule(&env, &program_id, recipient, amount, release_timestamp)
+    }
+
+    /// Create several per-program release schedules in one call (authorized
+    /// payout key only), with sequential IDs. Panics if any amount is not
+    /// positive or if pending schedules would then exceed the program's
+    /// remaining balance. Returns the created schedule IDs.
+    pub fn batch_create_prog_schedules(
+        env: Env,
+        program_id: String,
+        items: Vec<ScheduleItem>,
+    ) -> Vec<u64> {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+
+        if items.is_empty() || items.len() > MAX_BATCH_SIZE {
+            panic!("Invalid batch size");
+        }
+
+        let schedules = Self::load_schedules(&env, &program_id);
+        Self::check_schedule_capacity(&env, &program_id, &schedules, items.len());
+        let mut committed: i128 = 0;
+        for schedule in schedules.iter() {
+            if !schedule.released
+                && !Self::is_schedule_failed(&env, &program_id, schedule.schedule_id)
+            {
+                committed += schedule.amount;
+            }
+        }
+        for item in items.iter() {
+            if item.amount <= 0 {
+                panic!("Amount must be greater than zero");
+            }
+            committed = committed
+                .checked_add(item.amount)
+                .unwrap_or_else(|| panic!("Schedules exceed program balance"));
+        }
+        if committed > program_data.remaining_balance {
+            panic!("Schedules exceed program balance");
+        }
+
+        let mut ids = Vec::new(&env);
+        for item in items.iter() {
+            let schedule = Self::push_schedule(
+                &env,
+                &program_id,
+                item.recipient,
+                item.amount,
+                item.release_timestamp,
+            );
+            ids.push_back(schedule.schedule_id);
+        }
+        ids
+    }
 
-        env.storage().instance().set(&SCHEDULES, &schedules);
+    pub fn get_program_schedule(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+    ) -> Option<ProgramReleaseSchedule> {
+        Self::load_schedule(&env, &program_id, schedule_id)
+    }
+
+    /// Extend the TTL of the program's ledger entries to at least `ledgers`
+    /// ledgers: its per-program release schedules, its claims and the
+    /// contract instance holding `PROGRAM_DATA`. Anyone may pay for the bump,
+    /// and it stays allowed while frozen so escrow accounting never expires.
+    pub fn extend_program_ttl(env: Env, program_id: String, ledgers: u32) {
+        if ledgers == 0 {
+            panic!("Invalid TTL");
+        }
+        Self::get_program_data_by_id(&env, &program_id);
+
+        let next_id = Self::next_schedule_id(&env, &program_id);
+        let id_key = DataKey::NextScheduleId(program_id.clone());
+        if env.storage().persistent().has(&id_key) {
+            env.storage()
+                .persistent()
+                .extend_ttl(&id_key, ledgers, ledgers);
+            for schedule_id in 1..next_id {
+                let key = DataKey::ReleaseSchedule(program_id.clone(), schedule_id);
+                if env.storage().persistent().has(&key) {
+                    env.storage()
+                        .persistent()
+                        .extend_ttl(&key, ledgers, ledgers);
+                }
+            }
+        }
+        claim_period::extend_claims_ttl(&env, &program_id, ledgers);
+        env.storage().instance().extend_ttl(ledgers, ledgers);
+
+        let ttl_key = (PROGRAM_TTL, program_id);
+        let live_until = env.ledger().sequence().saturating_add(ledgers);
+        env.storage().persistent().set(&ttl_key, &live_until);
         env.storage()
-            .instance()
-            .set(&NEXT_SCHEDULE_ID, &(schedule_id + 1));
+            .persistent()
+            .extend_ttl(&ttl_key, ledgers, ledgers);
+    }
+
+    /// Ledgers left on the guarantee of the last `extend_program_ttl`, i.e.
+    /// that extension's `ledgers` minus the ledgers elapsed since. Zero if
+    /// never extended or lapsed.
+    ///
+    /// This is not the live minimum TTL of the program's entries: entries
+    /// written since the last extension carry their own, possibly shorter, TTL.
+    pub fn get_last_ttl_extension(env: Env, program_id: String) -> u32 {
+        let live_until: u32 = env
+            .storage()
+            .persistent()
+            .get(&(PROGRAM_TTL, program_id))
+            .unwrap_or(0);
+        live_until.saturating_sub(env.ledger().sequence())
+    }
+
+    /// Move an unreleased per-program schedule to `new_timestamp`, keeping
+    /// its ID (authorized payout key only). Panics if it is already released.
+    pub fn reschedule_release(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+        new_timestamp: u64,
+    ) -> ProgramReleaseSchedule {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+
+        let mut schedule = Self::load_schedule(&env, &program_id, schedule_id)
+            .unwrap_or_else(|| panic!("Schedule not found"));
+        if schedule.released {
+            panic!("Schedule already released");
+        }
 
+        let old_timestamp = schedule.release_timestamp;
+        schedule.release_timestamp = new_timestamp;
+        Self::store_schedule(&env, &program_id, &schedule);
+
+        env.events().publish(
+            (SCHEDULE_RESCHEDULED, program_id.clone()),
+            ScheduleRescheduledEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                schedule_id,
+                old_timestamp,
+                new_timestamp,
+            },
+        );
         schedule
     }
 
-    /// Trigger all due schedules where `now >= release_timestamp`.
-    pub fn trigger_program_releases(env: Env) -> u32 {
-        // Reentrancy guard: Check and set
-        reentrancy_guard::check_not_entered(&env);
-        reentrancy_guard::set_entered(&env);
+    /// Point an unreleased per-program schedule at `new_recipient`, keeping
+    /// its ID (authorized payout key only). Panics if it is already released.
+    pub fn reassign_schedule_recipient(
+        env: Env,
+        program_id: String,
+        schedule_id: u64,
+        new_recipient: Address,
+    ) -> ProgramReleaseSchedule {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
 
-        let mut program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&PROGRAM_DATA)
-            .unwrap_or_else(|| {
-                reentrancy_guard::clear_entered(&env);
-                panic!("Program not initialized")
+        let mut schedule = Self::load_schedule(&env, &program_id, schedule_id)
+            .unwrap_or_else(|| panic!("Schedule not found"));
+        if schedule.released {
+            panic!("Schedule already released");
+        }
+
+        let old_recipient = schedule.recipient.clone();
+        schedule.recipient = new_recipient.clone();
+        Self::store_schedule(&env, &program_id, &schedule);
+
+        env.events().publish(
+            (RECIPIENT_REASSIGNED, program_id.clone()),
+            RecipientReassignedEvent {
+                version: EVENT_VERSION_V2,
+                program_id,
+                schedule_id,
+                old_recipient,
+                new_recipient,
+            },
+        );
+        schedule
+    }
+
+    /// Unreleased per-program schedules with at least one failed release
+    /// attempt, including those marked failed.
+    pub fn get_failed_schedules(env: Env, program_id: String) -> Vec<ProgramReleaseSchedule> {
+        let mut failed = Vec::new(&env);
+        for schedule in Self::load_schedules(&env, &program_id).iter() {
+            let failure =
+                Self::get_schedule_failure(env.clone(), program_id.clone(), schedule.schedule_id);
+            if !schedule.released && failure.failure_count > 0 {
+                failed.push_back(schedule);
+            }
+        }
+        failed
+    }
+
+    /// Failed release attempts recorded for a per-program schedule.
+    pub fn get_schedule_failure(env: Env, program_id: String, schedule_id: u64) -> ScheduleFailure {
+        env.storage()
+            .persistent()
+            .get(&(SCHEDULE_FAILURE, program_id, schedule_id))
+            .unwrap_or(ScheduleFailure {
+                failure_count: 0,
+                last_failure_reason: None,
+                failed: false,
+            })
+    }
+
+    fn is_schedule_failed(env: &Env, program_id: &String, schedule_id: u64) -> bool {
+        Self::get_schedule_failure(env.clone(), program_id.clone(), schedule_id).failed
+    }
+
+    /// Count a failed release attempt, marking the schedule failed once the
+    /// program's max failures is reached.
+    fn record_schedule_failure(env: &Env, program_id: &String, schedule_id: u64, reason: Symbol) {
+        let mut failure = Self::get_schedule_failure(env.clone(), program_id.clone(), schedule_id);
+        failure.failure_count += 1;
+        failure.last_failure_reason = Some(reason);
+        failure.failed = failure.failure_count
+            >= Self::get_max_release_failures(env.clone(), program_id.clone());
+        env.storage().persistent().set(
+            &(SCHEDULE_FAILURE, program_id.clone(), schedule_id),
+            &failure,
+        );
+    }
+
+    /// Pay out `schedule`, recording a failed attempt instead of aborting when
+    /// the token transfer fails. Every schedule release path goes through here.
+    fn transfer_schedule(
+        env: &Env,
+        token_client: &token::Client,
+        program_id: &String,
+        schedule: &ProgramReleaseSchedule,
+    ) -> bool {
+        let transferred = matches!(
+            token_client.try_transfer(
+                &env.current_contract_address(),
+                &schedule.recipient,
+                &schedule.amount
+            ),
+            Ok(Ok(()))
+        );
+        if !transferred {
+            Self::record_schedule_failure(
+                env,
+                program_id,
+                schedule.schedule_id,
+                symbol_short!("xfer_fail"),
+            );
+        }
+        transferred
+    }
+
+    /// Earliest `release_timestamp` among the program's pending schedules, or
+    /// `None` when nothing is left to release. Failed schedules are ignored.
+    pub fn get_next_release_time(env: Env, program_id: String) -> Option<u64> {
+        let mut next: Option<u64> = None;
+        for schedule in Self::load_schedules(&env, &program_id).iter() {
+            if schedule.released
+                || Self::is_schedule_failed(&env, &program_id, schedule.schedule_id)
+            {
+                continue;
+            }
+            next = Some(match next {
+                Some(t) => t.min(schedule.release_timestamp),
+                None => schedule.release_timestamp,
             });
-        program_data.authorized_payout_key.require_auth();
+        }
+        next
+    }
+
+    /// Release due per-program schedules across every registered program,
+    /// oldest schedule first within each program, up to `max` in total
+    /// (admin only). Paused programs and schedules the program can't cover
+    /// are skipped. Returns how many were released.
+    ///
+    /// A failed transfer leaves the schedule pending and records the failure;
+    /// after the program's max failures the schedule is marked failed.
+    pub fn release_all_due(env: Env, max: u32) -> u32 {
+        Self::require_admin(&env);
+
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
 
         if Self::check_paused(&env, symbol_short!("release")) {
             reentrancy_guard::clear_entered(&env);
             panic!("Funds Paused");
         }
+        if error_recovery::check_and_allow(&env).is_err() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Circuit breaker is open");
+        }
 
-        let mut schedules: Vec<ProgramReleaseSchedule> = env
+        let registry: Vec<String> = env
             .storage()
             .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
-        let mut release_history: Vec<ProgramReleaseHistory> = env
-            .storage()
-            .instance()
-            .get(&RELEASE_HISTORY)
+            .get(&PROGRAM_REGISTRY)
             .unwrap_or_else(|| Vec::new(&env));
-
         let now = env.ledger().timestamp();
         let contract_address = env.current_contract_address();
-        let token_client = token::Client::new(&env, &program_data.token_address);
-        let mut released_count: u32 = 0;
+        let check_balance = Self::get_release_balance_check(env.clone());
+        let mut released: u32 = 0;
 
-        for i in 0..schedules.len() {
-            let mut schedule = schedules.get(i).unwrap();
-            if schedule.released || now < schedule.release_timestamp {
+        for program_id in registry.iter() {
+            if released >= max {
+                break;
+            }
+            if Self::is_program_paused(env.clone(), program_id.clone()) {
                 continue;
             }
 
-            if schedule.amount > program_data.remaining_balance {
-                reentrancy_guard::clear_entered(&env);
-                panic!("Insufficient balance");
+            let mut program_data = Self::get_program_data_by_id(&env, &program_id);
+            let token_client = token::Client::new(&env, &program_data.token_address);
+            let mut held = token_client.balance(&contract_address);
+            let mut schedules = Self::load_schedules(&env, &program_id);
+            let mut paid_out: i128 = 0;
+
+            for i in 0..schedules.len() {
+                if released >= max {
+                    break;
+                }
+                let mut schedule = schedules.get(i).unwrap();
+                let schedule_id = schedule.schedule_id;
+                if schedule.released
+                    || Self::is_schedule_failed(&env, &program_id, schedule_id)
+                    || !Self::dependency_released(&env, &program_id, &schedules, &schedule)
+                    || now < schedule.release_timestamp
+                    || schedule.amount > program_data.remaining_balance
+                    || (check_balance && schedule.amount > held)
+                    || !Self::is_recipient_allowed(&env, &program_id, &schedule.recipient)
+                {
+                    continue;
+                }
+
+                if !Self::transfer_schedule(&env, &token_client, &program_id, &schedule) {
+                    continue;
+                }
+                held -= schedule.amount;
+                schedule.released = true;
+                schedule.released_at = Some(now);
+                schedule.released_by = Some(contract_address.clone());
+                Self::store_schedule(&env, &program_id, &schedule);
+                schedules.set(i, schedule.clone());
+
+                program_data.remaining_balance -= schedule.amount;
+                program_data.payout_history.push_back(PayoutRecord {
+                    recipient: schedule.recipient,
+                    amount: schedule.amount,
+                    timestamp: now,
+                });
+                paid_out += schedule.amount;
+                released += 1;
             }
 
-            token_client.transfer(&contract_address, &schedule.recipient, &schedule.amount);
-            schedule.released = true;
-            schedule.released_at = Some(now);
-            schedule.released_by = Some(contract_address.clone());
-            schedules.set(i, schedule.clone());
+            if paid_out > 0 {
+                Self::store_program_data(&env, &program_id, &program_data);
+                Self::record_financials(&env, &program_id, 0, paid_out, 0);
+            }
+        }
 
-            program_data.remaining_balance -= schedule.amount;
-            program_data.payout_history.push_back(PayoutRecord {
-                recipient: schedule.recipient.clone(),
-                amount: schedule.amount,
-                timestamp: now,
-            });
-            release_history.push_back(ProgramReleaseHistory {
-                schedule_id: schedule.schedule_id,
-                recipient: schedule.recipient,
-                amount: schedule.amount,
-                released_at: now,
-                release_type: ReleaseType::Automatic,
-            });
-            released_count += 1;
+        reentrancy_guard::clear_entered(&env);
+        released
+    }
+
+    /// Whether the prerequisite of `schedule` (if any) has been released.
+    fn dependency_released(
+        env: &Env,
+        program_id: &String,
+        schedules: &Vec<ProgramReleaseSchedule>,
+        schedule: &ProgramReleaseSchedule,
+    ) -> bool {
+        match Self::get_schedule_dependency(env.clone(), program_id.clone(), schedule.schedule_id) {
+            Some(prerequisite) => schedules
+                .iter()
+                .any(|s| s.schedule_id == prerequisite && s.released),
+            None => true,
+        }
+    }
+
+    pub fn get_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
+        Self::active_schedules(&env)
+    }
+
+    pub fn get_program_release_history(env: Env) -> Vec<ProgramReleaseHistory> {
+        env.storage()
+            .instance()
+            .get(&RELEASE_HISTORY)
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Return up to `limit` release history entries starting at index `start`,
+    /// oldest first. `limit` is clamped to `MAX_HISTORY_PAGE_SIZE`; a `start`
+    /// past the end returns an empty page.
+    pub fn get_program_release_history_page(
+        env: Env,
+        program_id: String,
+        start: u32,
+        limit: u32,
+    ) -> Vec<ProgramReleaseHistory> {
+        let program_data = Self::get_program_info(env.clone());
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+
+        let history = Self::get_program_release_history(env.clone());
+        let end = start
+            .saturating_add(limit.min(MAX_HISTORY_PAGE_SIZE))
+            .min(history.len());
+        if start >= end {
+            return Vec::new(&env);
+        }
+        history.slice(start..end)
+    }
+
+    /// Number of entries in the release history.
+    pub fn get_release_history_count(env: Env) -> u32 {
+        Self::get_program_release_history(env).len()
+    }
+
+    // ========================================================================
+    // Multi-tenant / Multi-program Migration Wrappers (ignore id for now)
+    // ========================================================================
+
+    pub fn get_program_info_v2(env: Env, _program_id: String) -> ProgramData {
+        Self::get_program_info(env)
+    }
+
+    pub fn lock_program_funds_v2(env: Env, _program_id: String, amount: i128) -> ProgramData {
+        Self::lock_program_funds(env, amount, None)
+    }
+
+    pub fn single_payout_v2(
+        env: Env,
+        _program_id: String,
+        recipient: Address,
+        amount: i128,
+    ) -> ProgramData {
+        Self::single_payout(env, recipient, amount)
+    }
+
+    pub fn batch_payout_v2(
+        env: Env,
+        _program_id: String,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+    ) -> ProgramData {
+        Self::batch_payout(env, recipients, amounts, false, false)
+    }
+
+    /// Require every payout made via `batch_payout_with_memos` to carry a
+    /// non-empty memo (admin only). Memos are optional by default.
+    pub fn set_require_memo(env: Env, program_id: String, required: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&DataKey::RequireMemo(program_id), &required);
+    }
+
+    pub fn get_require_memo(env: Env, program_id: String) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::RequireMemo(program_id))
+            .unwrap_or(false)
+    }
+
+    /// Execute a batch payout with an optional memo per recipient.
+    ///
+    /// Each memo is emitted in a `PayMemo` event alongside its payout. When the
+    /// program requires memos, a missing or empty memo rejects the whole batch.
+    pub fn batch_payout_with_memos(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        memos: Vec<Option<String>>,
+    ) -> ProgramData {
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+
+        if memos.len() != recipients.len() {
+            panic!("Recipients and memos vectors must have the same length");
+        }
+        if Self::get_require_memo(env.clone(), program_data.program_id.clone()) {
+            for memo in memos.iter() {
+                match memo {
+                    Some(memo) if !memo.is_empty() => {}
+                    _ => panic!("Memo required"),
+                }
+            }
+        }
+
+        let updated_data = Self::batch_payout(
+            env.clone(),
+            recipients.clone(),
+            amounts.clone(),
+            false,
+            false,
+        );
+
+        if Self::get_event_privacy(env.clone(), updated_data.program_id.clone()) {
+            return updated_data;
+        }
+        for i in 0..recipients.len() {
+            if let Some(memo) = memos.get(i).unwrap() {
+                env.events().publish(
+                    (PAYOUT_MEMO, updated_data.program_id.clone()),
+                    PayoutMemoEvent {
+                        version: EVENT_VERSION_V2,
+                        program_id: updated_data.program_id.clone(),
+                        recipient: recipients.get(i).unwrap(),
+                        amount: amounts.get(i).unwrap(),
+                        memo,
+                    },
+                );
+            }
+        }
+
+        updated_data
+    }
+
+    /// Pre-register a winner address with a short label (authorized payout key only).
+    /// With strict recipients on, payouts may only target registered addresses.
+    pub fn register_winner(env: Env, program_id: String, address: Address, label: String) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+
+        env.storage().persistent().set(
+            &DataKey::RegisteredWinner(program_id.clone(), address.clone()),
+            &label,
+        );
+        env.events()
+            .publish((WINNER_REGISTERED, program_id), (address, label));
+    }
+
+    pub fn get_winner_label(env: Env, program_id: String, address: Address) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::RegisteredWinner(program_id, address))
+    }
+
+    /// Require payouts to target registered winners (authorized payout key only).
+    pub fn set_strict_recipients(env: Env, program_id: String, strict: bool) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        env.storage()
+            .instance()
+            .set(&DataKey::StrictRecipients(program_id), &strict);
+    }
+
+    pub fn get_strict_recipients(env: Env, program_id: String) -> bool {
+        env.storage()
+            .instance()
+            .get(&DataKey::StrictRecipients(program_id))
+            .unwrap_or(false)
+    }
+
+    fn check_registered_recipient(env: &Env, program_id: &String, recipient: &Address) {
+        if Self::get_strict_recipients(env.clone(), program_id.clone())
+            && Self::get_winner_label(env.clone(), program_id.clone(), recipient.clone()).is_none()
+        {
+            reentrancy_guard::clear_entered(env);
+            panic!("Recipient not registered");
+        }
+    }
+
+    /// Add `address` to or remove it from a program's recipient allow-list
+    /// (authorized payout key only). Only enforced once
+    /// `set_allowlist_enforced` is on.
+    pub fn set_program_recipient_allowed(
+        env: Env,
+        program_id: String,
+        address: Address,
+        allowed: bool,
+    ) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        let key = (RECIPIENT_ALLOWLIST, program_id, address);
+        if allowed {
+            env.storage().persistent().set(&key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
         }
+    }
+
+    pub fn is_program_recipient_allowed(env: Env, program_id: String, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(RECIPIENT_ALLOWLIST, program_id, address))
+    }
+
+    /// Restrict payouts and schedule releases to allow-listed recipients
+    /// (authorized payout key only). Direct payouts and single-schedule
+    /// releases to other addresses panic; bulk schedule releases skip them,
+    /// leaving the schedules pending.
+    pub fn set_allowlist_enforced(env: Env, program_id: String, enforced: bool) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        env.storage()
+            .instance()
+            .set(&(ALLOWLIST_ENFORCED, program_id), &enforced);
+    }
 
-        env.storage().instance().set(&PROGRAM_DATA, &program_data);
-        env.storage().instance().set(&SCHEDULES, &schedules);
+    pub fn get_allowlist_enforced(env: Env, program_id: String) -> bool {
         env.storage()
             .instance()
-            .set(&RELEASE_HISTORY, &release_history);
+            .get(&(ALLOWLIST_ENFORCED, program_id))
+            .unwrap_or(false)
+    }
 
-        // Clear reentrancy guard before returning
-        reentrancy_guard::clear_entered(&env);
+    fn is_recipient_allowed(env: &Env, program_id: &String, recipient: &Address) -> bool {
+        !Self::get_allowlist_enforced(env.clone(), program_id.clone())
+            || Self::is_program_recipient_allowed(
+                env.clone(),
+                program_id.clone(),
+                recipient.clone(),
+            )
+    }
 
-        released_count
+    fn check_allowed_recipient(env: &Env, program_id: &String, recipient: &Address) {
+        if !Self::is_recipient_allowed(env, program_id, recipient) {
+            reentrancy_guard::clear_entered(env);
+            panic!("RecipientNotAllowed");
+        }
     }
 
-    pub fn get_release_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
+    /// Screen `address` out of a program's payouts, e.g. for sanctions
+    /// (admin only). `reason` is kept for audit and emitted with the update.
+    pub fn add_program_blacklist(env: Env, program_id: String, address: Address, reason: String) {
+        Self::require_admin(&env);
+        Self::get_program_data_by_id(&env, &program_id);
+        let key = (PROGRAM_BLACKLIST, program_id.clone(), address.clone());
+        env.storage().persistent().set(&key, &reason);
+        env.events()
+            .publish((BLACKLIST_UPDATED, program_id), (address, Some(reason)));
+    }
+
+    /// Lift a program blacklist entry (admin only).
+    pub fn remove_program_blacklist(env: Env, program_id: String, address: Address) {
+        Self::require_admin(&env);
+        let key = (PROGRAM_BLACKLIST, program_id.clone(), address.clone());
+        env.storage().persistent().remove(&key);
+        env.events()
+            .publish((BLACKLIST_UPDATED, program_id), (address, None::<String>));
+    }
+
+    pub fn is_blacklisted(env: Env, program_id: String, address: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&(PROGRAM_BLACKLIST, program_id, address))
+    }
+
+    pub fn get_blacklist_reason(env: Env, program_id: String, address: Address) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&(PROGRAM_BLACKLIST, program_id, address))
+    }
+
+    /// Non-panicking form of the registered, allow-list and blacklist checks.
+    fn is_payable_recipient(env: &Env, program_id: &String, recipient: &Address) -> bool {
+        let registered = !Self::get_strict_recipients(env.clone(), program_id.clone())
+            || Self::get_winner_label(env.clone(), program_id.clone(), recipient.clone()).is_some();
+        registered
+            && Self::is_recipient_allowed(env, program_id, recipient)
+            && !Self::is_blacklisted(env.clone(), program_id.clone(), recipient.clone())
+    }
+
+    fn check_not_blacklisted(env: &Env, program_id: &String, recipient: &Address) {
+        if Self::is_blacklisted(env.clone(), program_id.clone(), recipient.clone()) {
+            reentrancy_guard::clear_entered(env);
+            panic!("RecipientBlacklisted");
+        }
+    }
+
+    /// Hash recipients and omit amounts from payout events for a program
+    /// (authorized payout key only). Off by default.
+    ///
+    /// Hashes are salted with a random per-program value drawn on first
+    /// enable, so recipients can't be matched against a list of known
+    /// addresses from the event stream alone. This only keeps details out of
+    /// events: the salt and the program's payout history stay in contract
+    /// storage, which anyone reading ledger state can see.
+    pub fn set_event_privacy(env: Env, program_id: String, enabled: bool) {
+        Self::require_not_frozen(&env);
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        program_data.authorized_payout_key.require_auth();
+        let salt_key = (EVENT_SALT, program_id.clone());
+        if enabled && !env.storage().persistent().has(&salt_key) {
+            let salt: BytesN<32> = env.prng().gen();
+            env.storage().persistent().set(&salt_key, &salt);
+        }
         env.storage()
             .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env))
+            .set(&DataKey::EventPrivacy(program_id), &enabled);
     }
 
-    pub fn get_program_release_history(env: Env) -> Vec<ProgramReleaseHistory> {
+    pub fn get_event_privacy(env: Env, program_id: String) -> bool {
         env.storage()
             .instance()
-            .get(&RELEASE_HISTORY)
-            .unwrap_or_else(|| Vec::new(&env))
+            .get(&DataKey::EventPrivacy(program_id))
+            .unwrap_or(false)
     }
 
-    // ========================================================================
-    // Multi-tenant / Multi-program Migration Wrappers (ignore id for now)
-    // ========================================================================
+    fn emit_private_payout(env: &Env, program_id: &String, recipient: &Address, category: &Symbol) {
+        let salt: BytesN<32> = env
+            .storage()
+            .persistent()
+            .get(&(EVENT_SALT, program_id.clone()))
+            .unwrap();
+        let mut preimage: Bytes = salt.into();
+        preimage.append(&recipient.clone().to_xdr(env));
+        let recipient_hash: BytesN<32> = env.crypto().sha256(&preimage).into();
+        env.events().publish(
+            (PRIVATE_PAYOUT, program_id.clone()),
+            PrivatePayoutEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_id.clone(),
+                recipient_hash,
+                category: category.clone(),
+            },
+        );
+    }
 
-    pub fn get_program_info_v2(env: Env, _program_id: String) -> ProgramData {
-        Self::get_program_info(env)
+    /// Category of the payout at `index` in the program's payout history;
+    /// `payout` unless the payer chose one.
+    pub fn get_payout_category(env: Env, program_id: String, index: u32) -> Symbol {
+        env.storage()
+            .persistent()
+            .get(&(PAYOUT_CATEGORY, program_id, index))
+            .unwrap_or(DEFAULT_PAYOUT_CATEGORY)
     }
 
-    pub fn lock_program_funds_v2(env: Env, _program_id: String, amount: i128) -> ProgramData {
-        Self::lock_program_funds(env, amount)
+    // Kept beside the history rather than in `PayoutRecord`, whose stored
+    // layout is frozen. Only non-default categories are written.
+    fn record_payout_category(env: &Env, program_id: &String, index: u32, category: &Symbol) {
+        if *category != DEFAULT_PAYOUT_CATEGORY {
+            env.storage()
+                .persistent()
+                .set(&(PAYOUT_CATEGORY, program_id.clone(), index), category);
+        }
     }
 
-    pub fn single_payout_v2(
-        env: Env,
-        _program_id: String,
-        recipient: Address,
+    fn emit_payout_notice(
+        env: &Env,
+        program_id: &String,
+        recipient: &Address,
         amount: i128,
-    ) -> ProgramData {
-        Self::single_payout(env, recipient, amount)
+        category: &Symbol,
+    ) {
+        env.events().publish(
+            (PAYOUT_NOTICE, category.clone(), recipient.clone()),
+            PayoutNoticeEvent {
+                version: EVENT_VERSION_V2,
+                program_id: program_id.clone(),
+                recipient: recipient.clone(),
+                amount,
+                category: category.clone(),
+            },
+        );
     }
 
-    pub fn batch_payout_v2(
+    /// Emit the winner's label alongside a payout when strict recipients is on.
+    fn emit_winner_paid(env: &Env, program_id: &String, recipient: &Address, amount: i128) {
+        if !Self::get_strict_recipients(env.clone(), program_id.clone()) {
+            return;
+        }
+        if let Some(label) =
+            Self::get_winner_label(env.clone(), program_id.clone(), recipient.clone())
+        {
+            env.events().publish(
+                (WINNER_PAID, program_id.clone()),
+                WinnerPaidEvent {
+                    version: EVENT_VERSION_V2,
+                    program_id: program_id.clone(),
+                    recipient: recipient.clone(),
+                    amount,
+                    label,
+                },
+            );
+        }
+    }
+
+    /// Set the smallest computed share that weighted and split payouts will
+    /// transfer, in the token's base units (admin only). Zero disables the floor.
+    pub fn set_min_payout(env: Env, program_id: String, min_payout: i128) {
+        Self::require_admin(&env);
+        if min_payout < 0 {
+            panic!("Minimum payout cannot be negative");
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::MinPayout(program_id), &min_payout);
+    }
+
+    pub fn get_min_payout(env: Env, program_id: String) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinPayout(program_id))
+            .unwrap_or(0)
+    }
+
+    /// Split `total_amount` across recipients in proportion to `weights`.
+    ///
+    /// Shares that round to zero or fall below the program's minimum payout are
+    /// skipped; their amount joins the rounding dust, which goes to the first
+    /// recipient that is paid.
+    pub fn batch_payout_weighted(
         env: Env,
-        _program_id: String,
         recipients: Vec<Address>,
-        amounts: Vec<i128>,
+        weights: Vec<u32>,
+        total_amount: i128,
     ) -> ProgramData {
-        Self::batch_payout(env, recipients, amounts)
+        let program_data: ProgramData = env
+            .storage()
+            .instance()
+            .get(&PROGRAM_DATA)
+            .unwrap_or_else(|| panic!("Program not initialized"));
+
+        if recipients.len() != weights.len() {
+            panic!("Recipients and weights vectors must have the same length");
+        }
+        if total_amount <= 0 {
+            panic!("Amount must be greater than zero");
+        }
+        let mut total_weight: i128 = 0;
+        for weight in weights.iter() {
+            total_weight += weight as i128;
+        }
+        if total_weight == 0 {
+            panic!("Total weight must be greater than zero");
+        }
+
+        let min_payout = Self::get_min_payout(env.clone(), program_data.program_id);
+        let mut paid_recipients = Vec::new(&env);
+        let mut amounts = Vec::new(&env);
+        let mut distributed: i128 = 0;
+        for i in 0..recipients.len() {
+            let share = total_amount
+                .checked_mul(weights.get(i).unwrap() as i128)
+                .and_then(|x| x.checked_div(total_weight))
+                .unwrap_or_else(|| panic!("Payout amount overflow"));
+            if share == 0 || share < min_payout {
+                continue;
+            }
+            paid_recipients.push_back(recipients.get(i).unwrap());
+            amounts.push_back(share);
+            distributed += share;
+        }
+        if paid_recipients.is_empty() {
+            panic!("All shares below minimum payout");
+        }
+
+        // Dust, including skipped shares, goes to the first paid recipient
+        let dust = total_amount - distributed;
+        amounts.set(0, amounts.get(0).unwrap() + dust);
+
+        Self::batch_payout(env, paid_recipients, amounts, false, false)
     }
 
     /// Query payout history by recipient with pagination
@@ -1933,18 +6562,17 @@ impl ProgramEscrowContract {
         results
     }
 
-    /// Query release schedules by recipient
+    /// Query a program's release schedules by recipient, in schedule ID order.
+    /// Returns an empty page when the recipient has no schedules.
     pub fn query_schedules_by_recipient(
         env: Env,
+        program_id: String,
         recipient: Address,
         offset: u32,
         limit: u32,
     ) -> Vec<ProgramReleaseSchedule> {
-        let schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+        Self::get_program_data_by_id(&env, &program_id);
+        let schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
         let mut results = Vec::new(&env);
         let mut count = 0u32;
         let mut skipped = 0u32;
@@ -1973,11 +6601,7 @@ impl ProgramEscrowContract {
         offset: u32,
         limit: u32,
     ) -> Vec<ProgramReleaseSchedule> {
-        let schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+        let schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
         let mut results = Vec::new(&env);
         let mut count = 0u32;
         let mut skipped = 0u32;
@@ -2039,11 +6663,7 @@ impl ProgramEscrowContract {
             .instance()
             .get(&PROGRAM_DATA)
             .unwrap_or_else(|| panic!("Program not initialized"));
-        let schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+        let schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
 
         let mut scheduled_count = 0u32;
         let mut released_count = 0u32;
@@ -2070,6 +6690,33 @@ impl ProgramEscrowContract {
         }
     }
 
+    /// Count, total, average, minimum and maximum of the program's recorded
+    /// payouts.
+    pub fn get_payout_stats(env: Env, program_id: String) -> PayoutStats {
+        let program_data = Self::get_program_data_by_id(&env, &program_id);
+        let mut stats = PayoutStats {
+            count: 0,
+            total: 0,
+            average: 0,
+            min: 0,
+            max: 0,
+        };
+        for record in program_data.payout_history.iter() {
+            if stats.count == 0 || record.amount < stats.min {
+                stats.min = record.amount;
+            }
+            if stats.count == 0 || record.amount > stats.max {
+                stats.max = record.amount;
+            }
+            stats.count += 1;
+            stats.total += record.amount;
+        }
+        if stats.count > 0 {
+            stats.average = stats.total / stats.count as i128;
+        }
+        stats
+    }
+
     /// Get payouts by recipient
     pub fn get_payouts_by_recipient(
         env: Env,
@@ -2106,11 +6753,7 @@ impl ProgramEscrowContract {
 
     /// Get pending schedules (not yet released)
     pub fn get_pending_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
-        let schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+        let schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
         let mut results = Vec::new(&env);
 
         for i in 0..schedules.len() {
@@ -2124,11 +6767,7 @@ impl ProgramEscrowContract {
 
     /// Get due schedules (ready to be released)
     pub fn get_due_schedules(env: Env) -> Vec<ProgramReleaseSchedule> {
-        let schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+        let schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
         let now = env.ledger().timestamp();
         let mut results = Vec::new(&env);
 
@@ -2143,11 +6782,7 @@ impl ProgramEscrowContract {
 
     /// Get total amount in pending schedules
     pub fn get_total_scheduled_amount(env: Env) -> i128 {
-        let schedules: Vec<ProgramReleaseSchedule> = env
-            .storage()
-            .instance()
-            .get(&SCHEDULES)
-            .unwrap_or_else(|| Vec::new(&env));
+        let schedules: Vec<ProgramReleaseSchedule> = Self::active_schedules(&env);
         let mut total = 0i128;
 
         for i in 0..schedules.len() {
@@ -2198,8 +6833,12 @@ impl ProgramEscrowContract {
     }
 
     pub fn release_program_schedule_manual(env: Env, schedule_id: u64) {
+        Self::require_not_frozen(&env);
         let mut schedules = Self::get_release_schedules(env.clone());
         let program_data = Self::get_program_info(env.clone());
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            panic!("Program paused");
+        }
 
         program_data.authorized_payout_key.require_auth();
 
@@ -2214,10 +6853,29 @@ impl ProgramEscrowContract {
                 if s.released {
                     panic!("Already released");
                 }
+                if Self::is_schedule_failed(&env, &program_data.program_id, schedule_id) {
+                    panic!("Schedule failed");
+                }
+                if !Self::dependency_released(&env, &program_data.program_id, &schedules, &s) {
+                    panic!("Dependency not yet released");
+                }
+                Self::check_allowed_recipient(&env, &program_data.program_id, &s.recipient);
+                if s.amount > program_data.remaining_balance {
+                    panic!("Insufficient balance");
+                }
 
-                // Transfer funds
+                // Transfer funds, checking first that they are still held
+                // (they may have been clawed back since locking). A failed
+                // transfer is recorded, not released.
                 let token_client = token::Client::new(&env, &program_data.token_address);
-                token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
+                if Self::get_release_balance_check(env.clone())
+                    && token_client.balance(&env.current_contract_address()) < s.amount
+                {
+                    panic!("Insufficient funds to release schedule");
+                }
+                if !Self::transfer_schedule(&env, &token_client, &program_data.program_id, &s) {
+                    return;
+                }
 
                 s.released = true;
                 s.released_at = Some(now);
@@ -2233,7 +6891,7 @@ impl ProgramEscrowContract {
             panic!("Schedule not found");
         }
 
-        env.storage().instance().set(&SCHEDULES, &schedules);
+        Self::store_schedules(&env, &program_data.program_id, &schedules);
 
         // Write to release history
         if let Some(s) = released_schedule {
@@ -2242,6 +6900,8 @@ impl ProgramEscrowContract {
             env.storage()
                 .instance()
                 .set(&PROGRAM_DATA, &updated_program_data);
+            Self::record_financials(&env, &program_data.program_id, 0, s.amount, 0);
+            Self::emit_schedule_released(&env, &program_data.program_id, &s, ReleaseType::Manual);
 
             let mut history: Vec<ProgramReleaseHistory> = env
                 .storage()
@@ -2260,8 +6920,12 @@ impl ProgramEscrowContract {
     }
 
     pub fn release_prog_schedule_automatic(env: Env, schedule_id: u64) {
+        Self::require_not_frozen(&env);
         let mut schedules = Self::get_release_schedules(env.clone());
         let program_data = Self::get_program_info(env.clone());
+        if Self::is_program_paused(env.clone(), program_data.program_id.clone()) {
+            panic!("Program paused");
+        }
         let now = env.ledger().timestamp();
         let mut released_schedule: Option<ProgramReleaseSchedule> = None;
 
@@ -2272,13 +6936,32 @@ impl ProgramEscrowContract {
                 if s.released {
                     panic!("Already released");
                 }
+                if Self::is_schedule_failed(&env, &program_data.program_id, schedule_id) {
+                    panic!("Schedule failed");
+                }
+                if !Self::dependency_released(&env, &program_data.program_id, &schedules, &s) {
+                    panic!("Dependency not yet released");
+                }
                 if now < s.release_timestamp {
                     panic!("Not yet due");
                 }
+                Self::check_allowed_recipient(&env, &program_data.program_id, &s.recipient);
+                if s.amount > program_data.remaining_balance {
+                    panic!("Insufficient balance");
+                }
 
-                // Transfer funds
+                // Transfer funds, checking first that they are still held
+                // (they may have been clawed back since locking). A failed
+                // transfer is recorded, not released.
                 let token_client = token::Client::new(&env, &program_data.token_address);
-                token_client.transfer(&env.current_contract_address(), &s.recipient, &s.amount);
+                if Self::get_release_balance_check(env.clone())
+                    && token_client.balance(&env.current_contract_address()) < s.amount
+                {
+                    panic!("Insufficient funds to release schedule");
+                }
+                if !Self::transfer_schedule(&env, &token_client, &program_data.program_id, &s) {
+                    return;
+                }
 
                 s.released = true;
                 s.released_at = Some(now);
@@ -2294,7 +6977,7 @@ impl ProgramEscrowContract {
             panic!("Schedule not found");
         }
 
-        env.storage().instance().set(&SCHEDULES, &schedules);
+        Self::store_schedules(&env, &program_data.program_id, &schedules);
 
         // Write to release history
         if let Some(s) = released_schedule {
@@ -2303,6 +6986,13 @@ impl ProgramEscrowContract {
             env.storage()
                 .instance()
                 .set(&PROGRAM_DATA, &updated_program_data);
+            Self::record_financials(&env, &program_data.program_id, 0, s.amount, 0);
+            Self::emit_schedule_released(
+                &env,
+                &program_data.program_id,
+                &s,
+                ReleaseType::Automatic,
+            );
 
             let mut history: Vec<ProgramReleaseHistory> = env
                 .storage()
@@ -2320,6 +7010,139 @@ impl ProgramEscrowContract {
         }
     }
 
+    /// Check the contract's live token balance before each schedule release
+    /// (admin only). On by default.
+    pub fn set_release_balance_check(env: Env, enabled: bool) {
+        Self::require_admin(&env);
+        env.storage()
+            .instance()
+            .set(&RELEASE_BALANCE_CHECK, &enabled);
+    }
+
+    pub fn get_release_balance_check(env: Env) -> bool {
+        env.storage()
+            .instance()
+            .get(&RELEASE_BALANCE_CHECK)
+            .unwrap_or(true)
+    }
+
+    /// Release every due schedule of the active program in one call, for
+    /// keepers that flush on a timer. Each release is recorded in history as
+    /// `Automatic`; schedules with unreleased dependencies are skipped.
+    ///
+    /// Stops at the first due schedule the remaining balance (or, with the
+    /// release balance check on, the contract's token balance) cannot cover,
+    /// leaving it and any later ones pending. Returns the number released.
+    pub fn release_all_due_schedules(env: Env, program_id: String) -> u32 {
+        Self::require_not_frozen(&env);
+        reentrancy_guard::check_not_entered(&env);
+        reentrancy_guard::set_entered(&env);
+
+        if Self::check_paused(&env, symbol_short!("release")) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Funds Paused");
+        }
+        if error_recovery::check_and_allow(&env).is_err() {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Circuit breaker is open");
+        }
+
+        let program_data = Self::get_program_info(env.clone());
+        if program_data.program_id != program_id {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program not found");
+        }
+        if Self::is_program_paused(env.clone(), program_id.clone()) {
+            reentrancy_guard::clear_entered(&env);
+            panic!("Program paused");
+        }
+
+        let released_count = Self::release_due_schedules(&env);
+
+        reentrancy_guard::clear_entered(&env);
+        released_count
+    }
+
+    /// Release the active program's due schedules until one cannot be
+    /// covered by the remaining balance or, with the release balance check
+    /// on, by the tokens the contract still holds. Callers handle guards and
+    /// pauses.
+    fn release_due_schedules(env: &Env) -> u32 {
+        let mut program_data = Self::get_program_info(env.clone());
+        let due = Self::get_due_program_schedules(env.clone());
+        let mut schedules = Self::get_release_schedules(env.clone());
+        let mut history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .instance()
+            .get(&RELEASE_HISTORY)
+            .unwrap_or_else(|| Vec::new(env));
+
+        let now = env.ledger().timestamp();
+        let contract_address = env.current_contract_address();
+        let token_client = token::Client::new(env, &program_data.token_address);
+        let check_balance = Self::get_release_balance_check(env.clone());
+        let mut held = token_client.balance(&contract_address);
+        let mut released_count: u32 = 0;
+        let mut released_total: i128 = 0;
+
+        for due_schedule in due.iter() {
+            let index = match schedules
+                .iter()
+                .position(|s| s.schedule_id == due_schedule.schedule_id)
+            {
+                Some(index) => index as u32,
+                None => continue,
+            };
+            let mut schedule = schedules.get(index).unwrap();
+            if Self::is_schedule_failed(env, &program_data.program_id, schedule.schedule_id) {
+                continue;
+            }
+            if !Self::dependency_released(env, &program_data.program_id, &schedules, &schedule) {
+                continue;
+            }
+            if !Self::is_recipient_allowed(env, &program_data.program_id, &schedule.recipient) {
+                continue;
+            }
+            if schedule.amount > program_data.remaining_balance
+                || (check_balance && schedule.amount > held)
+            {
+                break;
+            }
+
+            if !Self::transfer_schedule(env, &token_client, &program_data.program_id, &schedule) {
+                continue;
+            }
+            held -= schedule.amount;
+            schedule.released = true;
+            schedule.released_at = Some(now);
+            schedule.released_by = Some(contract_address.clone());
+            schedules.set(index, schedule.clone());
+
+            program_data.remaining_balance -= schedule.amount;
+            released_total += schedule.amount;
+            Self::emit_schedule_released(
+                env,
+                &program_data.program_id,
+                &schedule,
+                ReleaseType::Automatic,
+            );
+            history.push_back(ProgramReleaseHistory {
+                schedule_id: schedule.schedule_id,
+                recipient: schedule.recipient,
+                amount: schedule.amount,
+                released_at: now,
+                release_type: ReleaseType::Automatic,
+            });
+            released_count += 1;
+        }
+
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        Self::store_schedules(env, &program_data.program_id, &schedules);
+        env.storage().instance().set(&RELEASE_HISTORY, &history);
+        Self::record_financials(env, &program_data.program_id, 0, released_total, 0);
+        released_count
+    }
+
     pub fn create_pending_claim(
         env: Env,
         program_id: String,
@@ -2327,28 +7150,172 @@ impl ProgramEscrowContract {
         amount: i128,
         claim_deadline: u64,
     ) -> u64 {
+        Self::require_not_frozen(&env);
         claim_period::create_pending_claim(&env, &program_id, &recipient, amount, claim_deadline)
     }
 
     pub fn execute_claim(env: Env, program_id: String, claim_id: u64, recipient: Address) {
-        claim_period::execute_claim(&env, &program_id, claim_id, &recipient)
+        Self::require_not_frozen(&env);
+        claim_period::execute_claim(&env, &program_id, claim_id, &recipient);
     }
 
     pub fn cancel_claim(env: Env, program_id: String, claim_id: u64, admin: Address) {
+        Self::require_not_frozen(&env);
         claim_period::cancel_claim(&env, &program_id, claim_id, &admin)
     }
 
+    pub fn expire_claim_now(env: Env, program_id: String, claim_id: u64) {
+        Self::require_not_frozen(&env);
+        claim_period::expire_claim_now(&env, &program_id, claim_id)
+    }
+
+    pub fn batch_cancel_claims(
+        env: Env,
+        program_id: String,
+        claim_ids: Vec<u64>,
+        admin: Address,
+        strict: bool,
+    ) -> u32 {
+        Self::require_not_frozen(&env);
+        claim_period::batch_cancel_claims(&env, &program_id, &claim_ids, &admin, strict)
+    }
+
     pub fn get_claim(env: Env, program_id: String, claim_id: u64) -> claim_period::ClaimRecord {
         claim_period::get_claim(&env, &program_id, claim_id)
     }
 
     pub fn set_claim_window(env: Env, admin: Address, window_seconds: u64) {
+        Self::require_not_frozen(&env);
         claim_period::set_claim_window(&env, &admin, window_seconds)
     }
 
     pub fn get_claim_window(env: Env) -> u64 {
         claim_period::get_claim_window(&env)
     }
+
+    /// Turn a due schedule of the active program into a pending claim that
+    /// its recipient pulls with `claim_schedule` within the claim window
+    /// (authorized payout key only). The schedule is marked released and its
+    /// amount reserved so no other release path pays it; if it goes
+    /// unclaimed, cancelling the expired claim with `cancel_claim` returns
+    /// the amount and reopens the schedule. Returns the claim ID.
+    pub fn authorize_schedule_claim(env: Env, program_id: String, schedule_id: u64) -> u64 {
+        Self::require_not_frozen(&env);
+        Self::require_schedule_claims_open(&env);
+        let program_data = Self::get_program_info(env.clone());
+        if program_data.program_id != program_id {
+            panic!("Program not found");
+        }
+        if Self::is_program_paused(env.clone(), program_id.clone()) {
+            panic!("Program paused");
+        }
+
+        let schedules = Self::load_schedules(&env, &program_id);
+        let index = schedules
+            .iter()
+            .position(|s| s.schedule_id == schedule_id)
+            .unwrap_or_else(|| panic!("Schedule not found")) as u32;
+        let mut schedule = schedules.get(index).unwrap();
+        if schedule.released {
+            panic!("Already released");
+        }
+        if !Self::dependency_released(&env, &program_id, &schedules, &schedule) {
+            panic!("Dependency not yet released");
+        }
+        let now = env.ledger().timestamp();
+        if now < schedule.release_timestamp {
+            panic!("Not yet due");
+        }
+        Self::check_allowed_recipient(&env, &program_id, &schedule.recipient);
+
+        // Reserves the amount and requires the authorized payout key
+        let claim_id = claim_period::create_pending_claim(
+            &env,
+            &program_id,
+            &schedule.recipient,
+            schedule.amount,
+            now + claim_period::get_claim_window(&env),
+        );
+
+        schedule.released = true;
+        schedule.released_at = Some(now);
+        schedule.released_by = Some(program_data.authorized_payout_key);
+        Self::store_schedule(&env, &program_id, &schedule);
+        env.storage().persistent().set(
+            &(SCHEDULE_CLAIM, program_id.clone(), schedule_id),
+            &claim_id,
+        );
+        env.storage()
+            .persistent()
+            .set(&(CLAIM_SCHEDULE, program_id, claim_id), &schedule_id);
+
+        claim_id
+    }
+
+    fn require_schedule_claims_open(env: &Env) {
+        if Self::check_paused(env, symbol_short!("release")) {
+            panic!("Funds Paused");
+        }
+        if error_recovery::check_and_allow(env).is_err() {
+            panic!("Circuit breaker is open");
+        }
+    }
+
+    /// Called when a pending claim is cancelled: if it was created by
+    /// `authorize_schedule_claim`, the schedule goes back to unreleased.
+    pub(crate) fn reopen_schedule_claim(env: &Env, program_id: &String, claim_id: u64) {
+        let reverse_key = (CLAIM_SCHEDULE, program_id.clone(), claim_id);
+        let schedule_id: u64 = match env.storage().persistent().get(&reverse_key) {
+            Some(schedule_id) => schedule_id,
+            None => return,
+        };
+        env.storage().persistent().remove(&reverse_key);
+        env.storage()
+            .persistent()
+            .remove(&(SCHEDULE_CLAIM, program_id.clone(), schedule_id));
+
+        if let Some(mut schedule) = Self::load_schedule(env, program_id, schedule_id) {
+            schedule.released = false;
+            schedule.released_at = None;
+            schedule.released_by = None;
+            Self::store_schedule(env, program_id, &schedule);
+        }
+    }
+
+    /// Claim ID created for a schedule by `authorize_schedule_claim`, if any.
+    pub fn get_schedule_claim(env: Env, program_id: String, schedule_id: u64) -> Option<u64> {
+        env.storage()
+            .persistent()
+            .get(&(SCHEDULE_CLAIM, program_id, schedule_id))
+    }
+
+    /// Pull the funds of a schedule authorized for claiming (schedule
+    /// recipient only). Fails once the claim window has passed. Returns the
+    /// amount received, net of any early-claim penalty.
+    pub fn claim_schedule(env: Env, program_id: String, schedule_id: u64) -> i128 {
+        Self::require_not_frozen(&env);
+        Self::require_schedule_claims_open(&env);
+        let claim_id = Self::get_schedule_claim(env.clone(), program_id.clone(), schedule_id)
+            .unwrap_or_else(|| panic!("Schedule not claimable"));
+        let record = claim_period::get_claim(&env, &program_id, claim_id);
+        let received = claim_period::execute_claim(&env, &program_id, claim_id, &record.recipient);
+
+        let mut history: Vec<ProgramReleaseHistory> = env
+            .storage()
+            .instance()
+            .get(&RELEASE_HISTORY)
+            .unwrap_or_else(|| Vec::new(&env));
+        history.push_back(ProgramReleaseHistory {
+            schedule_id,
+            recipient: record.recipient,
+            amount: received,
+            released_at: env.ledger().timestamp(),
+            release_type: ReleaseType::Manual,
+        });
+        env.storage().instance().set(&RELEASE_HISTORY, &history);
+
+        received
+    }
 }
 
 #[cfg(test)]
@@ -22,7 +22,13 @@ use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, Address, E
 /// Interface for the ProgramEscrow contract (simplified for testing)
 pub trait ProgramEscrowTrait {
     fn single_payout(env: Env, recipient: Address, amount: i128);
-    fn batch_payout(env: Env, recipients: Vec<Address>, amounts: Vec<i128>);
+    fn batch_payout(
+        env: Env,
+        recipients: Vec<Address>,
+        amounts: Vec<i128>,
+        queue_on_open: bool,
+        reject_duplicates: bool,
+    );
     fn trigger_program_releases(env: Env) -> u32;
 }
 
@@ -236,7 +242,7 @@ impl MaliciousReentrantContract {
         let amounts = Vec::from_array(env, [amount]);
 
         let client = crate::ProgramEscrowContractClient::new(env, &target);
-        client.batch_payout(&recipients, &amounts);
+        client.batch_payout(&recipients, &amounts, &false, &false);
     }
 
     /// Attempt reentrancy on trigger_program_releases
@@ -281,7 +287,7 @@ impl MaliciousReentrantContract {
         let amounts = Vec::from_array(env, [amount]);
 
         let client = crate::ProgramEscrowContractClient::new(env, &target);
-        client.batch_payout(&recipients, &amounts);
+        client.batch_payout(&recipients, &amounts, &false, &false);
     }
 
     /// Attempt cross-function reentrancy: batch_payout -> single_payout
@@ -311,7 +317,7 @@ impl MaliciousReentrantContract {
         Self::set_attack_mode(&env, AttackMode::BatchPayoutReentrant);
 
         let client = crate::ProgramEscrowContractClient::new(&env, &target);
-        client.batch_payout(&recipients, &amounts);
+        client.batch_payout(&recipients, &amounts, &false, &false);
     }
 
     /// Public function to start a nested attack
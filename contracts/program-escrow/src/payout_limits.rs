@@ -0,0 +1,240 @@
+// contracts/program-escrow/src/payout_limits.rs
+//
+// Payout Limits Module
+//
+// Optional per-program limits on direct payouts:
+// - a rolling spending window (e.g. a daily cap),
+// - a per-recipient cumulative cap,
+// - a per-operator allowance that is drawn down by each payout.
+//
+// Every limit is off until configured. `spendable` combines them with the
+// program's remaining balance so callers can show a single safe maximum.
+
+use crate::ProgramData;
+use soroban_sdk::{contracttype, Address, Env, String};
+
+// ─────────────────────────────────────────────────────────
+// Types
+// ─────────────────────────────────────────────────────────
+
+/// Per-window spending cap for a program.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramSpendingConfig {
+    /// Window length in seconds (e.g. 86400 for a daily limit)
+    pub window_size: u64,
+    /// Maximum total amount released within one window
+    pub max_amount: i128,
+    /// Stored but not enforced when false
+    pub enabled: bool,
+}
+
+/// Amount released in the current spending window.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProgramSpendingState {
+    pub window_start: u64,
+    pub amount_released: i128,
+}
+
+/// Storage keys for payout limit data.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PayoutLimitKey {
+    /// program_id -> ProgramSpendingConfig
+    SpendingConfig(String),
+    /// program_id -> ProgramSpendingState
+    SpendingState(String),
+    /// program_id -> i128 cumulative cap per recipient
+    RecipientCap(String),
+    /// (program_id, operator) -> i128 remaining allowance
+    OperatorAllowance(String, Address),
+}
+
+// ─────────────────────────────────────────────────────────
+// Configuration
+// ─────────────────────────────────────────────────────────
+
+pub fn set_spending_limit(env: &Env, program_id: &String, config: &ProgramSpendingConfig) {
+    if config.window_size == 0 {
+        panic!("Window size must be greater than zero");
+    }
+    if config.max_amount < 0 {
+        panic!("Max amount cannot be negative");
+    }
+    env.storage()
+        .instance()
+        .set(&PayoutLimitKey::SpendingConfig(program_id.clone()), config);
+}
+
+pub fn get_spending_limit(env: &Env, program_id: &String) -> Option<ProgramSpendingConfig> {
+    env.storage()
+        .instance()
+        .get(&PayoutLimitKey::SpendingConfig(program_id.clone()))
+}
+
+pub fn get_spending_state(env: &Env, program_id: &String) -> Option<ProgramSpendingState> {
+    env.storage()
+        .instance()
+        .get(&PayoutLimitKey::SpendingState(program_id.clone()))
+}
+
+pub fn set_recipient_cap(env: &Env, program_id: &String, cap: Option<i128>) {
+    let key = PayoutLimitKey::RecipientCap(program_id.clone());
+    match cap {
+        Some(cap) => {
+            if cap < 0 {
+                panic!("Recipient cap cannot be negative");
+            }
+            env.storage().instance().set(&key, &cap);
+        }
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+pub fn get_recipient_cap(env: &Env, program_id: &String) -> Option<i128> {
+    env.storage()
+        .instance()
+        .get(&PayoutLimitKey::RecipientCap(program_id.clone()))
+}
+
+pub fn set_operator_allowance(
+    env: &Env,
+    program_id: &String,
+    operator: &Address,
+    allowance: Option<i128>,
+) {
+    let key = PayoutLimitKey::OperatorAllowance(program_id.clone(), operator.clone());
+    match allowance {
+        Some(allowance) => {
+            if allowance < 0 {
+                panic!("Allowance cannot be negative");
+            }
+            env.storage().instance().set(&key, &allowance);
+        }
+        None => env.storage().instance().remove(&key),
+    }
+}
+
+pub fn get_operator_allowance(env: &Env, program_id: &String, operator: &Address) -> Option<i128> {
+    env.storage()
+        .instance()
+        .get(&PayoutLimitKey::OperatorAllowance(
+            program_id.clone(),
+            operator.clone(),
+        ))
+}
+
+// ─────────────────────────────────────────────────────────
+// Queries
+// ─────────────────────────────────────────────────────────
+
+/// Amount still releasable in the current window, or `None` if no limit is enforced.
+pub fn remaining_window_amount(env: &Env, program_id: &String) -> Option<i128> {
+    let config = get_spending_limit(env, program_id)?;
+    if !config.enabled {
+        return None;
+    }
+    let now = env.ledger().timestamp();
+    let used = match get_spending_state(env, program_id) {
+        Some(state) if now.saturating_sub(state.window_start) < config.window_size => {
+            state.amount_released
+        }
+        _ => 0,
+    };
+    Some((config.max_amount - used).max(0))
+}
+
+/// Amount `recipient` may still receive under the recipient cap, or `None` if uncapped.
+pub fn remaining_recipient_amount(
+    env: &Env,
+    program_data: &ProgramData,
+    recipient: &Address,
+) -> Option<i128> {
+    let cap = get_recipient_cap(env, &program_data.program_id)?;
+    let mut received = 0i128;
+    for record in program_data.payout_history.iter() {
+        if record.recipient == *recipient {
+            received = received.saturating_add(record.amount);
+        }
+    }
+    Some((cap - received).max(0))
+}
+
+/// The largest amount `operator` can pay `recipient` right now: the minimum of
+/// the remaining balance, the window limit, the recipient cap and the
+/// operator allowance.
+pub fn spendable(
+    env: &Env,
+    program_data: &ProgramData,
+    operator: &Address,
+    recipient: &Address,
+) -> i128 {
+    let program_id = &program_data.program_id;
+    let mut amount = program_data.remaining_balance.max(0);
+
+    if let Some(window) = remaining_window_amount(env, program_id) {
+        amount = amount.min(window);
+    }
+    if let Some(cap) = remaining_recipient_amount(env, program_data, recipient) {
+        amount = amount.min(cap);
+    }
+    if let Some(allowance) = get_operator_allowance(env, program_id, operator) {
+        amount = amount.min(allowance);
+    }
+    amount
+}
+
+// ─────────────────────────────────────────────────────────
+// Enforcement
+// ─────────────────────────────────────────────────────────
+
+/// Checks a payout of `amount` to `recipient` against every configured limit
+/// and records it. Call before transferring; panics if any limit would be
+/// exceeded.
+pub fn consume(
+    env: &Env,
+    program_data: &ProgramData,
+    operator: &Address,
+    recipient: &Address,
+    amount: i128,
+) {
+    let program_id = &program_data.program_id;
+
+    if let Some(cap) = remaining_recipient_amount(env, program_data, recipient) {
+        if amount > cap {
+            panic!("Recipient cap exceeded");
+        }
+    }
+
+    if let Some(allowance) = get_operator_allowance(env, program_id, operator) {
+        if amount > allowance {
+            panic!("Operator allowance exceeded");
+        }
+        set_operator_allowance(env, program_id, operator, Some(allowance - amount));
+    }
+
+    if let Some(config) = get_spending_limit(env, program_id) {
+        if config.enabled {
+            let now = env.ledger().timestamp();
+            let mut state = match get_spending_state(env, program_id) {
+                Some(state) if now.saturating_sub(state.window_start) < config.window_size => state,
+                _ => ProgramSpendingState {
+                    window_start: now,
+                    amount_released: 0,
+                },
+            };
+            let new_total = state
+                .amount_released
+                .checked_add(amount)
+                .unwrap_or_else(|| panic!("Spending total overflow"));
+            if new_total > config.max_amount {
+                panic!("Program spending limit exceeded for current window");
+            }
+            state.amount_released = new_total;
+            env.storage()
+                .instance()
+                .set(&PayoutLimitKey::SpendingState(program_id.clone()), &state);
+        }
+    }
+}
@@ -10,6 +10,7 @@
 //
 // - Shares are expressed in basis points (1 bp = 0.01%), summing to 10_000 (100%)
 // - Dust (remainder after integer division) is awarded to the first beneficiary
+// - Shares below the program's minimum payout are skipped and added to the dust
 // - Splits are stored per-program and validated at creation time
 // - Both partial releases and full releases honour the ratio
 //
@@ -25,8 +26,8 @@
 // Expose the public functions inside the `ProgramEscrowContract` impl block.
 // ============================================================
 
+use crate::{DataKey, PayoutRecord, ProgramData, ProgramEscrowContract, PROGRAM_DATA};
 use soroban_sdk::{contracttype, symbol_short, token, Address, Env, String, Symbol, Vec};
-use crate::{DataKey, ProgramData, PayoutRecord, PROGRAM_DATA};
 
 // ---------------------------------------------------------------------------
 // Constants
@@ -233,30 +234,7 @@ pub fn execute_split_payout(
     let token_client = token::Client::new(env, &program.token_address);
     let now = env.ledger().timestamp();
 
-    // Compute individual amounts using bp arithmetic; accumulate dust.
-    // dust = total_amount - sum(floor(total_amount * share_bps / 10_000))
-    let mut amounts: soroban_sdk::Vec<i128> = soroban_sdk::Vec::new(env);
-    let mut distributed: i128 = 0;
-
-    for i in 0..n {
-        let entry = config.beneficiaries.get(i).unwrap();
-        let share_amount = total_amount
-            .checked_mul(entry.share_bps)
-            .and_then(|x| x.checked_div(TOTAL_BASIS_POINTS))
-            .unwrap_or_else(|| panic!("SplitPayout: arithmetic overflow"));
-        amounts.push_back(share_amount);
-        distributed = distributed
-            .checked_add(share_amount)
-            .unwrap_or_else(|| panic!("SplitPayout: sum overflow"));
-    }
-
-    // Dust goes to index 0.
-    let dust = total_amount - distributed;
-    if dust < 0 {
-        panic!("SplitPayout: internal accounting error");
-    }
-    let first_amount = amounts.get(0).unwrap() + dust;
-    amounts.set(0, first_amount);
+    let amounts = split_amounts(env, program_id, &config, total_amount);
 
     // Transfer and record payouts.
     for i in 0..n {
@@ -299,9 +277,48 @@ pub fn execute_split_payout(
     }
 }
 
+/// Per-beneficiary amounts of a split of `total_amount`, in config order.
+///
+/// Shares use basis-point arithmetic; shares below the program's minimum
+/// payout are zeroed, and the resulting dust goes to index 0.
+fn split_amounts(
+    env: &Env,
+    program_id: &String,
+    config: &SplitConfig,
+    total_amount: i128,
+) -> soroban_sdk::Vec<i128> {
+    // dust = total_amount - sum(floor(total_amount * share_bps / 10_000))
+    let min_payout = ProgramEscrowContract::get_min_payout(env.clone(), program_id.clone());
+    let mut amounts: soroban_sdk::Vec<i128> = soroban_sdk::Vec::new(env);
+    let mut distributed: i128 = 0;
+
+    for entry in config.beneficiaries.iter() {
+        let mut share_amount = total_amount
+            .checked_mul(entry.share_bps)
+            .and_then(|x| x.checked_div(TOTAL_BASIS_POINTS))
+            .unwrap_or_else(|| panic!("SplitPayout: arithmetic overflow"));
+        if share_amount < min_payout {
+            share_amount = 0;
+        }
+        amounts.push_back(share_amount);
+        distributed = distributed
+            .checked_add(share_amount)
+            .unwrap_or_else(|| panic!("SplitPayout: sum overflow"));
+    }
+
+    let dust = total_amount - distributed;
+    if dust < 0 {
+        panic!("SplitPayout: internal accounting error");
+    }
+    let first_amount = amounts.get(0).unwrap() + dust;
+    amounts.set(0, first_amount);
+    amounts
+}
+
 /// Calculate the hypothetical split amounts for `total_amount` without executing transfers.
 ///
-/// Useful for off-chain previews and tests.  Dust is awarded to index 0.
+/// Useful for off-chain previews and tests.  Applies the same minimum payout
+/// and dust rules as `execute_split_payout`.
 ///
 /// Returns a `Vec` of `(recipient, amount)` pairs in config order.
 pub fn preview_split(
@@ -315,29 +332,9 @@ pub fn preview_split(
         .get(&split_key(program_id))
         .unwrap_or_else(|| panic!("No split config found for program"));
 
-    let n = config.beneficiaries.len();
+    let amounts = split_amounts(env, program_id, &config, total_amount);
     let mut preview: Vec<BeneficiarySplit> = Vec::new(env);
-    let mut distributed: i128 = 0;
-    let mut computed: soroban_sdk::Vec<i128> = soroban_sdk::Vec::new(env);
-
-    for i in 0..n {
-        let entry = config.beneficiaries.get(i).unwrap();
-        let share_amount = total_amount
-            .checked_mul(entry.share_bps)
-            .and_then(|x| x.checked_div(TOTAL_BASIS_POINTS))
-            .unwrap_or(0);
-        computed.push_back(share_amount);
-        distributed += share_amount;
-    }
-
-    let dust = total_amount - distributed;
-
-    for i in 0..n {
-        let entry = config.beneficiaries.get(i).unwrap();
-        let mut amount = computed.get(i).unwrap();
-        if i == 0 {
-            amount += dust;
-        }
+    for (entry, amount) in config.beneficiaries.iter().zip(amounts.iter()) {
         preview.push_back(BeneficiarySplit {
             recipient: entry.recipient,
             share_bps: amount, // repurposed field: holds computed amount in preview context
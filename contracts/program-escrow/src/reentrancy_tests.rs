@@ -124,7 +124,7 @@ fn test_single_payout_normal_execution() {
     token_client.transfer(&authorized_key, &contract_id, &amount);
 
     // Lock funds
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Execute single payout (should succeed)
     let result = client.single_payout(&recipient, &(amount / 2));
@@ -158,7 +158,7 @@ fn test_single_payout_blocks_reentrancy() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &amount);
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Manually set the reentrancy guard to simulate an ongoing call
     crate::reentrancy_guard::set_entered(&env);
@@ -198,13 +198,13 @@ fn test_batch_payout_normal_execution() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &total_amount);
-    client.lock_program_funds(&total_amount);
+    client.lock_program_funds(&total_amount, &None);
 
     // Execute batch payout
     let recipients = vec![&env, recipient1, recipient2];
     let amounts = vec![&env, 400_0000000i128, 600_0000000i128];
 
-    let result = client.batch_payout(&recipients, &amounts);
+    let result = client.batch_payout(&recipients, &amounts, &false, &false);
 
     assert_eq!(result.remaining_balance, 0);
 }
@@ -237,7 +237,7 @@ fn test_batch_payout_blocks_reentrancy() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &total_amount);
-    client.lock_program_funds(&total_amount);
+    client.lock_program_funds(&total_amount, &None);
 
     // Manually set the reentrancy guard
     crate::reentrancy_guard::set_entered(&env);
@@ -245,7 +245,7 @@ fn test_batch_payout_blocks_reentrancy() {
     // This should panic
     let recipients = vec![&env, recipient1, recipient2];
     let amounts = vec![&env, 400_0000000i128, 600_0000000i128];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 // ============================================================================
@@ -279,7 +279,7 @@ fn test_cross_function_reentrancy_single_to_batch() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &amount);
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Simulate being inside single_payout
     crate::reentrancy_guard::set_entered(&env);
@@ -287,7 +287,7 @@ fn test_cross_function_reentrancy_single_to_batch() {
     // Try to call batch_payout (should be blocked)
     let recipients = vec![&env, recipient];
     let amounts = vec![&env, amount / 2];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 #[test]
@@ -317,7 +317,7 @@ fn test_cross_function_reentrancy_batch_to_single() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &amount);
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Simulate being inside batch_payout
     crate::reentrancy_guard::set_entered(&env);
@@ -357,7 +357,7 @@ fn test_trigger_releases_normal_execution() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &amount);
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Create schedule
     client.create_program_release_schedule(&amount, &release_timestamp, &recipient);
@@ -399,7 +399,7 @@ fn test_trigger_releases_blocks_reentrancy() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &amount);
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Create schedule
     client.create_program_release_schedule(&amount, &release_timestamp, &recipient);
@@ -447,7 +447,7 @@ fn test_multiple_sequential_payouts_succeed() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &total_amount);
-    client.lock_program_funds(&total_amount);
+    client.lock_program_funds(&total_amount, &None);
 
     // Execute multiple sequential payouts (all should succeed)
     client.single_payout(&recipient1, &payout_amount);
@@ -493,7 +493,7 @@ fn test_guard_cleared_after_successful_payout() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &amount);
-    client.lock_program_funds(&amount);
+    client.lock_program_funds(&amount, &None);
 
     // Guard should not be set initially
     assert!(!is_entered(&env));
@@ -534,7 +534,7 @@ fn test_guard_state_across_multiple_operations() {
         &None,
     );
     token_client.transfer(&authorized_key, &contract_id, &total_amount);
-    client.lock_program_funds(&total_amount);
+    client.lock_program_funds(&total_amount, &None);
 
     // Verify guard state through multiple operations
     assert!(!is_entered(&env));
@@ -544,7 +544,7 @@ fn test_guard_state_across_multiple_operations() {
 
     let recipients = vec![&env, recipient2];
     let amounts = vec![&env, 200_0000000i128];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
     assert!(!is_entered(&env));
 
     client.single_payout(&recipient1, &100_0000000i128);
@@ -3,9 +3,9 @@ pub const EXPECTED: &[(&str, &str)] = &[
     ("PayoutRecord", concat!("0000001100000001000000030000000f00000006616d6f756e7400000000000a0000000000000000", "000000000000007b0000000f00000009726563697069656e74000000000000120000000103030303", "030303030303030303030303030303030303030303030303030303030000000f0000000974696d65", "7374616d7000000000000005000000000000000a")),
     ("FeeConfig", concat!("0000001100000001000000040000000f0000000b6665655f656e61626c6564000000000000000001", "0000000f0000000d6665655f726563697069656e7400000000000012000000010404040404040404", "0404040404040404040404040404040404040404040404040000000f0000000d6c6f636b5f666565", "5f726174650000000000000a000000000000000000000000000000640000000f0000000f7061796f", "75745f6665655f72617465000000000a000000000000000000000000000000c8")),
     ("ProgramInitializedEvent", concat!("0000001100000001000000050000000f00000015617574686f72697a65645f7061796f75745f6b65", "79000000000000120000000101010101010101010101010101010101010101010101010101010101", "010101010000000f0000000a70726f6772616d5f696400000000000e0000000d4861636b6174686f", "6e323032360000000000000f0000000d746f6b656e5f616464726573730000000000001200000001", "02020202020202020202020202020202020202020202020202020202020202020000000f0000000b", "746f74616c5f66756e6473000000000a000000000000000000000000000027100000000f00000007", "76657273696f6e000000000300000002")),
-    ("FundsLockedEvent", concat!("0000001100000001000000040000000f00000006616d6f756e7400000000000a0000000000000000", "00000000000003e80000000f0000000a70726f6772616d5f696400000000000e0000000d4861636b", "6174686f6e323032360000000000000f0000001172656d61696e696e675f62616c616e6365000000", "0000000a000000000000000000000000000023280000000f0000000776657273696f6e0000000003", "00000002")),
-    ("BatchPayoutEvent", concat!("0000001100000001000000050000000f0000000a70726f6772616d5f696400000000000e0000000d", "4861636b6174686f6e323032360000000000000f0000000f726563697069656e745f636f756e7400", "00000003000000020000000f0000001172656d61696e696e675f62616c616e63650000000000000a", "000000000000000000000000000021340000000f0000000c746f74616c5f616d6f756e740000000a", "000000000000000000000000000001f40000000f0000000776657273696f6e000000000300000002")),
-    ("PayoutEvent", concat!("0000001100000001000000050000000f00000006616d6f756e7400000000000a0000000000000000", "00000000000000c80000000f0000000a70726f6772616d5f696400000000000e0000000d4861636b", "6174686f6e323032360000000000000f00000009726563697069656e740000000000001200000001", "03030303030303030303030303030303030303030303030303030303030303030000000f00000011", "72656d61696e696e675f62616c616e63650000000000000a00000000000000000000000000002260", "0000000f0000000776657273696f6e000000000300000002")),
+    ("FundsLockedEvent", concat!("0000001100000001000000050000000f00000006616d6f756e7400000000000a0000000000000000", "00000000000003e80000000f000000096576656e745f736571000000000000050000000000000001", "0000000f0000000a70726f6772616d5f696400000000000e0000000d4861636b6174686f6e323032", "360000000000000f0000001172656d61696e696e675f62616c616e63650000000000000a00000000", "0000000000000000000023280000000f0000000776657273696f6e000000000300000003")),
+    ("BatchPayoutEvent", concat!("0000001100000001000000090000000f000000096576656e745f7365710000000000000500000000", "000000020000000f0000000a6665655f616d6f756e7400000000000a000000000000000000000000", "000000000000000f0000000a6e65745f616d6f756e7400000000000a000000000000000000000000", "000001f40000000f0000000a70726f6772616d5f696400000000000e0000000d4861636b6174686f", "6e323032360000000000000f0000000f726563697069656e745f636f756e74000000000300000002", "0000000f0000001172656d61696e696e675f62616c616e63650000000000000a0000000000000000", "00000000000021340000000f0000000c746f74616c5f616d6f756e740000000a0000000000000000", "00000000000001f40000000f0000000e7573645f6571756976616c656e740000000000010000000f", "0000000776657273696f6e000000000300000003")),
+    ("PayoutEvent", concat!("00000011000000010000000a0000000f00000006616d6f756e7400000000000a0000000000000000", "00000000000000c80000000f000000096576656e745f736571000000000000050000000000000003", "0000000f0000000a6665655f616d6f756e7400000000000a00000000000000000000000000000000", "0000000f0000000c67726f73735f616d6f756e740000000a000000000000000000000000000000c8", "0000000f0000000a6e65745f616d6f756e7400000000000a000000000000000000000000000000c8", "0000000f0000000a70726f6772616d5f696400000000000e0000000d4861636b6174686f6e323032", "360000000000000f00000009726563697069656e7400000000000012000000010303030303030303", "0303030303030303030303030303030303030303030303030000000f0000001172656d61696e696e", "675f62616c616e63650000000000000a000000000000000000000000000022600000000f0000000e", "7573645f6571756976616c656e740000000000010000000f0000000776657273696f6e0000000003", "00000003")),
     ("ProgramData", concat!("0000001100000001000000070000000f00000015617574686f72697a65645f7061796f75745f6b65", "79000000000000120000000101010101010101010101010101010101010101010101010101010101", "010101010000000f00000011696e697469616c5f6c69717569646974790000000000000a00000000", "0000000000000000000001f40000000f0000000e7061796f75745f686973746f7279000000000010", "00000001000000010000001100000001000000030000000f00000006616d6f756e7400000000000a", "0000000000000000000000000000007b0000000f00000009726563697069656e7400000000000012", "0000000103030303030303030303030303030303030303030303030303030303030303030000000f", "0000000974696d657374616d7000000000000005000000000000000a0000000f0000000a70726f67", "72616d5f696400000000000e0000000d4861636b6174686f6e323032360000000000000f00000011", "72656d61696e696e675f62616c616e63650000000000000a00000000000000000000000000002328", "0000000f0000000d746f6b656e5f6164647265737300000000000012000000010202020202020202", "0202020202020202020202020202020202020202020202020000000f0000000b746f74616c5f6675", "6e6473000000000a00000000000000000000000000002710")),
     ("PauseFlags", concat!("0000001100000001000000050000000f0000000b6c6f636b5f706175736564000000000000000001", "0000000f0000000c70617573655f726561736f6e0000000e0000000b6d61696e74656e616e636500", "0000000f000000097061757365645f61740000000000000500000000000000010000000f0000000d", "726566756e645f70617573656400000000000000000000010000000f0000000e72656c656173655f", "70617573656400000000000000000000")),
     ("PauseStateChanged", concat!("0000001100000001000000030000000f0000000561646d696e000000000000120000000105050505", "050505050505050505050505050505050505050505050505050505050000000f000000096f706572", "6174696f6e0000000000000f000000046c6f636b0000000f00000006706175736564000000000000", "00000001")),
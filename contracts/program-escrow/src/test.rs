@@ -30,7 +30,7 @@ fn setup_program(
 
     if initial_amount > 0 {
         token_admin_client.mint(&client.address, &initial_amount);
-        client.lock_program_funds(&initial_amount);
+        client.lock_program_funds(&initial_amount, &None);
     }
 
     (client, admin, token_client, token_admin_client)
@@ -41,14 +41,19 @@ fn next_seed(seed: &mut u64) -> u64 {
     *seed
 }
 
-fn assert_event_data_has_v2_tag(env: &Env, data: &Val) {
+/// Sequenced events are tagged V3, everything else V2.
+fn assert_event_data_has_version_tag(env: &Env, data: &Val) {
     let data_map: Map<Symbol, Val> =
         Map::try_from_val(env, data).unwrap_or_else(|_| panic!("event payload should be a map"));
     let version_val = data_map
         .get(Symbol::new(env, "version"))
         .unwrap_or_else(|| panic!("event payload must contain version field"));
     let version = u32::try_from_val(env, &version_val).expect("version should decode as u32");
-    assert_eq!(version, 2);
+    if data_map.contains_key(Symbol::new(env, "event_seq")) {
+        assert_eq!(version, 3);
+    } else {
+        assert_eq!(version, 2);
+    }
 }
 
 #[test]
@@ -77,8 +82,8 @@ fn test_lock_program_funds_multi_step_balance() {
     let env = Env::default();
     let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
 
-    client.lock_program_funds(&10_000);
-    client.lock_program_funds(&5_000);
+    client.lock_program_funds(&10_000, &None);
+    client.lock_program_funds(&5_000, &None);
     assert_eq!(client.get_remaining_balance(), 15_000);
     assert_eq!(client.get_program_info().total_funds, 15_000);
 }
@@ -132,7 +137,7 @@ fn test_batch_payout_token_transfer_integration() {
     let recipients = vec![&env, r1.clone(), r2.clone(), r3.clone()];
     let amounts = vec![&env, 10_000, 20_000, 30_000];
 
-    let data = client.batch_payout(&recipients, &amounts);
+    let data = client.batch_payout(&recipients, &amounts, &false, &false);
     assert_eq!(data.remaining_balance, 90_000);
     assert_eq!(data.payout_history.len(), 3);
 
@@ -147,7 +152,7 @@ fn test_complete_lifecycle_integration() {
     let (client, _admin, token_client, token_admin) = setup_program(&env, 0);
 
     token_admin.mint(&client.address, &300_000);
-    client.lock_program_funds(&300_000);
+    client.lock_program_funds(&300_000, &None);
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
@@ -156,7 +161,7 @@ fn test_complete_lifecycle_integration() {
     client.single_payout(&r1, &50_000);
     let recipients = vec![&env, r2.clone(), r3.clone()];
     let amounts = vec![&env, 70_000, 30_000];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 
     let info = client.get_program_info();
     assert_eq!(info.total_funds, 300_000);
@@ -174,6 +179,8 @@ fn test_property_fuzz_balance_invariants() {
     let mut expected_remaining = 1_000_000_i128;
 
     for _ in 0..40 {
+        // Each payout is its own transaction on-chain, with a fresh budget.
+        env.budget().reset_default();
         let amount = (next_seed(&mut seed) % 4_000 + 1) as i128;
         if amount > expected_remaining {
             continue;
@@ -192,7 +199,7 @@ fn test_property_fuzz_balance_invariants() {
             }
             let recipients = vec![&env, recipient1, recipient2];
             let amounts = vec![&env, first, second];
-            client.batch_payout(&recipients, &amounts);
+            client.batch_payout(&recipients, &amounts, &false, &false);
         }
 
         expected_remaining -= amount;
@@ -246,14 +253,14 @@ fn test_gas_proxy_batch_vs_single_event_efficiency() {
     }
 
     let batch_before = env_batch.events().all().len();
-    batch_client.batch_payout(&recipients, &amounts);
+    batch_client.batch_payout(&recipients, &amounts, &false, &false);
     let batch_events = env_batch.events().all().len() - batch_before;
 
     assert!(batch_events <= single_events);
 }
 
 #[test]
-fn test_events_emit_v2_version_tags_for_all_program_emitters() {
+fn test_events_emit_version_tags_for_all_program_emitters() {
     let env = Env::default();
     let (client, _admin, _token_client, _token_admin) = setup_program(&env, 100_000);
     let r1 = Address::generate(&env);
@@ -262,7 +269,7 @@ fn test_events_emit_v2_version_tags_for_all_program_emitters() {
     client.single_payout(&r1, &10_000);
     let recipients = vec![&env, r2];
     let amounts = vec![&env, 5_000];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 
     let events = env.events().all();
     let mut program_events_checked = 0_u32;
@@ -270,7 +277,7 @@ fn test_events_emit_v2_version_tags_for_all_program_emitters() {
         if contract != client.address {
             continue;
         }
-        assert_event_data_has_v2_tag(&env, &data);
+        assert_event_data_has_version_tag(&env, &data);
         program_events_checked += 1;
     }
 
@@ -360,6 +367,215 @@ fn test_release_schedule_overlapping_schedules() {
     assert_eq!(history.len(), 3);
 }
 
+#[test]
+fn test_release_all_due_schedules_flushes_in_one_call() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    let r4 = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&r1, &10_000, &(now + 10));
+    client.create_program_release_schedule(&r2, &15_000, &(now + 20));
+    client.create_program_release_schedule(&r3, &20_000, &(now + 30));
+    client.create_program_release_schedule(&r4, &5_000, &(now + 1_000));
+
+    env.ledger().set_timestamp(now + 30);
+    assert_eq!(client.release_all_due_schedules(&program_id), 3);
+    assert_eq!(token_client.balance(&r1), 10_000);
+    assert_eq!(token_client.balance(&r2), 15_000);
+    assert_eq!(token_client.balance(&r3), 20_000);
+    assert_eq!(token_client.balance(&r4), 0);
+    assert_eq!(client.get_remaining_balance(), 55_000);
+
+    let history = client.get_program_release_history();
+    assert_eq!(history.len(), 3);
+    for entry in history.iter() {
+        assert_eq!(entry.release_type, ReleaseType::Automatic);
+    }
+    assert_eq!(client.release_all_due_schedules(&program_id), 0);
+}
+
+#[test]
+fn test_release_all_due_schedules_stops_when_balance_runs_out() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 25_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&r1, &10_000, &(now + 10));
+    client.create_program_release_schedule(&r2, &10_000, &(now + 10));
+    client.create_program_release_schedule(&r3, &10_000, &(now + 10));
+
+    env.ledger().set_timestamp(now + 10);
+    assert_eq!(client.release_all_due_schedules(&program_id), 2);
+    assert_eq!(token_client.balance(&r3), 0);
+    assert_eq!(client.get_remaining_balance(), 5_000);
+    assert_eq!(client.get_due_program_schedules().len(), 1);
+    assert_eq!(client.get_program_release_history().len(), 2);
+}
+
+#[test]
+fn test_release_all_due_schedules_stops_at_clawed_back_balance() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 30_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&r1, &10_000, &(now + 10));
+    client.create_program_release_schedule(&r2, &10_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+
+    // Only 15k of the 30k accounted for is still held by the contract
+    let sink = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        token_client.transfer(&client.address, &sink, &15_000);
+    });
+
+    assert_eq!(client.release_all_due_schedules(&program_id), 1);
+    assert_eq!(token_client.balance(&r1), 10_000);
+    assert_eq!(token_client.balance(&r2), 0);
+    assert_eq!(client.get_remaining_balance(), 20_000);
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_release_all_due_schedules_respects_release_pause() {
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 20_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&recipient, &10_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+
+    client.set_paused(&None, &Some(true), &None, &None);
+    client.release_all_due_schedules(&program_id);
+}
+
+#[test]
+fn test_topup_releases_underfunded_due_schedule_when_enabled() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 5_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&recipient, &10_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+
+    // Underfunded: the due schedule cannot be released yet
+    assert_eq!(client.release_all_due_schedules(&program_id), 0);
+    assert_eq!(token_client.balance(&recipient), 0);
+
+    client.set_auto_release_on_topup(&true);
+    assert!(client.get_auto_release_on_topup());
+    token_admin.mint(&client.address, &10_000);
+    let data = client.lock_program_funds(&10_000, &None);
+
+    assert_eq!(token_client.balance(&recipient), 10_000);
+    assert_eq!(data.remaining_balance, 5_000);
+    assert!(client.get_release_schedules().get(0).unwrap().released);
+    let history = client.get_program_release_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().release_type, ReleaseType::Automatic);
+}
+
+#[test]
+fn test_topup_leaves_due_schedule_pending_by_default() {
+    let env = Env::default();
+    let (client, _admin, token_client, token_admin) = setup_program(&env, 5_000);
+    let recipient = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&recipient, &10_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+
+    token_admin.mint(&client.address, &10_000);
+    let data = client.lock_program_funds(&10_000, &None);
+    assert_eq!(data.remaining_balance, 15_000);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert!(!client.get_release_schedules().get(0).unwrap().released);
+}
+
+#[test]
+fn test_automatic_schedule_release_with_sufficient_balance() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 20_000);
+    let recipient = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    let schedule = client.create_program_release_schedule(&recipient, &15_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+
+    assert!(client.get_release_balance_check());
+    client.release_prog_schedule_automatic(&schedule.schedule_id);
+    assert_eq!(token_client.balance(&recipient), 15_000);
+    assert_eq!(client.get_remaining_balance(), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds to release schedule")]
+fn test_automatic_schedule_release_rejects_clawed_back_balance() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 20_000);
+    let recipient = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    let schedule = client.create_program_release_schedule(&recipient, &15_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+
+    // Simulate funds leaving the contract outside of escrow accounting
+    let sink = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        token_client.transfer(&client.address, &sink, &10_000);
+    });
+    assert_eq!(client.get_remaining_balance(), 20_000);
+
+    client.release_prog_schedule_automatic(&schedule.schedule_id);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient funds to release schedule")]
+fn test_manual_schedule_release_rejects_clawed_back_balance() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 20_000);
+    let recipient = Address::generate(&env);
+    let schedule = client.create_program_release_schedule(&recipient, &15_000, &0);
+
+    let sink = Address::generate(&env);
+    env.as_contract(&client.address, || {
+        token_client.transfer(&client.address, &sink, &10_000);
+    });
+
+    client.release_program_schedule_manual(&schedule.schedule_id);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_schedule_release_rejects_amount_above_program_balance() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 20_000);
+    let recipient = Address::generate(&env);
+    let schedule = client.create_program_release_schedule(&recipient, &30_000, &0);
+
+    // Tokens the contract holds for someone else do not cover this program
+    token_admin.mint(&client.address, &50_000);
+
+    client.release_prog_schedule_automatic(&schedule.schedule_id);
+}
+
 // ---------------------------------------------------------------------------
 // Full program lifecycle integration test with batch payouts across two
 // independent program-escrow instances.
@@ -410,23 +626,23 @@ fn test_full_lifecycle_multi_program_batch_payouts() {
     // ── Phase 1: Lock funds in multiple steps ───────────────────────────
     // Program A receives 500_000 in two tranches
     token_admin_client.mint(&client_a.address, &300_000);
-    client_a.lock_program_funds(&300_000);
+    client_a.lock_program_funds(&300_000, &None);
     assert_eq!(client_a.get_remaining_balance(), 300_000);
 
     token_admin_client.mint(&client_a.address, &200_000);
-    client_a.lock_program_funds(&200_000);
+    client_a.lock_program_funds(&200_000, &None);
     assert_eq!(client_a.get_remaining_balance(), 500_000);
     assert_eq!(client_a.get_program_info().total_funds, 500_000);
 
     // Program B receives 400_000 in three tranches
     token_admin_client.mint(&client_b.address, &150_000);
-    client_b.lock_program_funds(&150_000);
+    client_b.lock_program_funds(&150_000, &None);
 
     token_admin_client.mint(&client_b.address, &150_000);
-    client_b.lock_program_funds(&150_000);
+    client_b.lock_program_funds(&150_000, &None);
 
     token_admin_client.mint(&client_b.address, &100_000);
-    client_b.lock_program_funds(&100_000);
+    client_b.lock_program_funds(&100_000, &None);
     assert_eq!(client_b.get_remaining_balance(), 400_000);
     assert_eq!(client_b.get_program_info().total_funds, 400_000);
 
@@ -444,6 +660,8 @@ fn test_full_lifecycle_multi_program_batch_payouts() {
             winner_a3.clone(),
         ],
         &vec![&env, 100_000, 75_000, 50_000],
+        &false,
+        &false,
     );
     assert_eq!(data_a1.remaining_balance, 275_000);
     assert_eq!(data_a1.payout_history.len(), 3);
@@ -458,6 +676,8 @@ fn test_full_lifecycle_multi_program_batch_payouts() {
     let data_b1 = client_b.batch_payout(
         &vec![&env, winner_b1.clone(), winner_b2.clone()],
         &vec![&env, 120_000, 80_000],
+        &false,
+        &false,
     );
     assert_eq!(data_b1.remaining_balance, 200_000);
     assert_eq!(data_b1.payout_history.len(), 2);
@@ -472,6 +692,8 @@ fn test_full_lifecycle_multi_program_batch_payouts() {
     let data_a2 = client_a.batch_payout(
         &vec![&env, winner_a4.clone(), winner_a5.clone()],
         &vec![&env, 125_000, 50_000],
+        &false,
+        &false,
     );
     assert_eq!(data_a2.remaining_balance, 100_000);
     assert_eq!(data_a2.payout_history.len(), 5);
@@ -491,6 +713,8 @@ fn test_full_lifecycle_multi_program_batch_payouts() {
             winner_b5.clone(),
         ],
         &vec![&env, 60_000, 40_000, 30_000],
+        &false,
+        &false,
     );
     assert_eq!(data_b2.remaining_balance, 70_000);
     assert_eq!(data_b2.payout_history.len(), 5);
@@ -593,8 +817,8 @@ fn test_multi_token_balance_accounting_isolated_across_program_instances() {
 
     token_admin_client_a.mint(&client_a.address, &500_000);
     token_admin_client_b.mint(&client_b.address, &300_000);
-    client_a.lock_program_funds(&500_000);
-    client_b.lock_program_funds(&300_000);
+    client_a.lock_program_funds(&500_000, &None);
+    client_b.lock_program_funds(&300_000, &None);
 
     // Initial per-token accounting after lock.
     assert_eq!(client_a.get_remaining_balance(), 500_000);
@@ -618,6 +842,8 @@ fn test_multi_token_balance_accounting_isolated_across_program_instances() {
     client_b.batch_payout(
         &vec![&env, r_b1.clone(), r_b2.clone()],
         &vec![&env, 50_000, 25_000],
+        &false,
+        &false,
     );
 
     // Payout in token B should not affect token A accounting.
@@ -728,6 +954,123 @@ fn test_non_admin_cannot_update_config() {
     client.update_rate_limit_config(&3600, &10, &30);
 }
 
+/// In quorum mode a rate limit change applies only once enough admins approve.
+#[test]
+fn test_rate_limit_config_change_applies_with_quorum() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let a1 = Address::generate(&env);
+    let a2 = Address::generate(&env);
+    let a3 = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_rate_limit_quorum(&vec![&env, a1.clone(), a2.clone(), a3.clone()], &2);
+    let before = client.get_rate_limit_config();
+
+    client.propose_rate_limit_config(&a1, &7200, &5, &120);
+    // One approval of two: nothing applied yet
+    assert_eq!(client.get_rate_limit_config(), before);
+    assert!(client.get_rate_limit_proposal().is_some());
+
+    client.approve_rate_limit_config(&a3);
+    let config = client.get_rate_limit_config();
+    assert_eq!(config.window_size, 7200);
+    assert_eq!(config.max_operations, 5);
+    assert_eq!(config.cooldown_period, 120);
+    assert!(client.get_rate_limit_proposal().is_none());
+}
+
+/// In quorum mode the single admin can no longer change the config directly.
+#[test]
+#[should_panic(expected = "Rate limit quorum required")]
+fn test_rate_limit_config_change_without_quorum_reverts() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let a1 = Address::generate(&env);
+    let a2 = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_rate_limit_quorum(&vec![&env, a1, a2], &2);
+
+    client.update_rate_limit_config(&7200, &5, &120);
+}
+
+/// Operation overrides and the quorum itself are also gated on approvals.
+#[test]
+fn test_rate_limit_quorum_gates_overrides_and_quorum_changes() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let a1 = Address::generate(&env);
+    let a2 = Address::generate(&env);
+    let a3 = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_rate_limit_quorum(&vec![&env, a1.clone(), a2.clone()], &2);
+
+    let operation = symbol_short!("batch_pay");
+    assert!(client
+        .try_update_operation_rate_limit(&operation, &60, &1, &0)
+        .is_err());
+    assert!(client
+        .try_set_rate_limit_quorum(&vec![&env, admin.clone()], &1)
+        .is_err());
+
+    client.propose_operation_rate_limit(&a1, &operation, &60, &1, &0);
+    assert!(client.get_operation_rate_limit(&operation).is_none());
+    client.approve_rate_limit_config(&a2);
+    let override_config = client.get_operation_rate_limit(&operation).unwrap();
+    assert_eq!(override_config.max_operations, 1);
+
+    client.propose_rate_limit_quorum(&a2, &vec![&env, a1.clone(), a2.clone(), a3.clone()], &3);
+    let quorum = client.get_rate_limit_quorum().unwrap();
+    assert_eq!(quorum.required_approvals, 2);
+    client.approve_rate_limit_config(&a1);
+    let quorum = client.get_rate_limit_quorum().unwrap();
+    assert_eq!(quorum.admins.len(), 3);
+    assert_eq!(quorum.required_approvals, 3);
+}
+
+/// A new proposal cannot replace one that is still collecting approvals.
+#[test]
+fn test_rate_limit_proposal_is_not_silently_replaced() {
+    let env = Env::default();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let a1 = Address::generate(&env);
+    let a2 = Address::generate(&env);
+    let a3 = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    client.set_admin(&admin);
+    client.set_rate_limit_quorum(&vec![&env, a1.clone(), a2.clone(), a3.clone()], &3);
+
+    client.propose_rate_limit_config(&a1, &7200, &5, &120);
+    client.approve_rate_limit_config(&a2);
+    assert!(client
+        .try_propose_rate_limit_config(&a3, &60, &100, &0)
+        .is_err());
+    assert!(client.try_cancel_rate_limit_proposal(&a2).is_err());
+    assert_eq!(client.get_rate_limit_proposal().unwrap().approvals.len(), 2);
+
+    client.cancel_rate_limit_proposal(&a1);
+    assert!(client.get_rate_limit_proposal().is_none());
+    client.propose_rate_limit_config(&a3, &60, &100, &0);
+}
+
 // =============================================================================
 // TESTS FOR batch_initialize_programs
 // =============================================================================
@@ -803,6 +1146,92 @@ fn test_batch_initialize_programs_duplicate_id_err() {
     assert!(matches!(res, Err(Ok(BatchError::DuplicateProgramId))));
 }
 
+fn init_item(env: &Env, program_id: &str, key: &Address, token: &Address) -> ProgramInitItem {
+    ProgramInitItem {
+        program_id: String::from_str(env, program_id),
+        authorized_payout_key: key.clone(),
+        token_address: token.clone(),
+        reference_hash: None,
+    }
+}
+
+#[test]
+fn test_case_variant_program_ids_distinct_by_default() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    assert!(!client.get_case_insensitive_ids());
+
+    let items = vec![
+        &env,
+        init_item(&env, "Hackathon", &admin, &token),
+        init_item(&env, "hackathon", &admin, &token),
+    ];
+    assert_eq!(client.batch_initialize_programs(&items), 2);
+    let data = client.init_program(
+        &String::from_str(&env, "HACKATHON"),
+        &admin,
+        &token,
+        &admin,
+        &None,
+        &None,
+    );
+    assert_eq!(data.program_id, String::from_str(&env, "HACKATHON"));
+}
+
+#[test]
+fn test_case_insensitive_ids_reject_case_variants_in_batches() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.initialize_contract(&admin);
+    client.set_case_insensitive_ids(&true);
+
+    let res = client.try_batch_initialize_programs(&vec![
+        &env,
+        init_item(&env, "Hackathon", &admin, &token),
+        init_item(&env, "hackathon", &admin, &token),
+    ]);
+    assert!(matches!(res, Err(Ok(BatchError::DuplicateProgramId))));
+
+    client.batch_initialize_programs(&vec![&env, init_item(&env, "Hackathon", &admin, &token)]);
+    let res = client
+        .try_batch_initialize_programs(&vec![&env, init_item(&env, "hackATHON", &admin, &token)]);
+    assert!(matches!(res, Err(Ok(BatchError::ProgramAlreadyExists))));
+
+    // IDs that differ beyond case are still accepted
+    client.batch_initialize_programs(&vec![&env, init_item(&env, "hackathon-2", &admin, &token)]);
+}
+
+#[test]
+#[should_panic(expected = "Program already exists")]
+fn test_case_insensitive_ids_reject_case_variant_init() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let token = Address::generate(&env);
+    client.initialize_contract(&admin);
+    client.set_case_insensitive_ids(&true);
+
+    client.batch_initialize_programs(&vec![&env, init_item(&env, "Hackathon", &admin, &token)]);
+    client.init_program(
+        &String::from_str(&env, "hackathon"),
+        &admin,
+        &token,
+        &admin,
+        &None,
+        &None,
+    );
+}
+
 // =============================================================================
 // EXTENDED TESTS FOR batch_initialize_programs
 // =============================================================================
@@ -1362,8 +1791,8 @@ fn test_multi_tenant_no_cross_program_balance_or_analytics() {
 
     token_sac.mint(&client_a.address, &500_000);
     token_sac.mint(&client_b.address, &300_000);
-    client_a.lock_program_funds(&500_000);
-    client_b.lock_program_funds(&300_000);
+    client_a.lock_program_funds(&500_000, &None);
+    client_b.lock_program_funds(&300_000, &None);
 
     let stats_a = client_a.get_program_aggregate_stats();
     let stats_b = client_b.get_program_aggregate_stats();
@@ -1456,7 +1885,7 @@ fn test_analytics_after_batch_payout() {
     let recipients = vec![&env, r1.clone(), r2.clone(), r3.clone()];
     let amounts = vec![&env, 10_000_0000000, 20_000_0000000, 30_000_0000000];
 
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 
     let stats = client.get_program_aggregate_stats();
 
@@ -1474,9 +1903,9 @@ fn test_analytics_multiple_operations() {
     token_admin.mint(&client.address, &30_000_0000000);
 
     // Lock funds in multiple calls
-    client.lock_program_funds(&10_000_0000000);
-    client.lock_program_funds(&15_000_0000000);
-    client.lock_program_funds(&5_000_0000000);
+    client.lock_program_funds(&10_000_0000000, &None);
+    client.lock_program_funds(&15_000_0000000, &None);
+    client.lock_program_funds(&5_000_0000000, &None);
 
     // Perform payouts
     let r1 = Address::generate(&env);
@@ -1485,7 +1914,7 @@ fn test_analytics_multiple_operations() {
 
     let recipients = vec![&env, r2.clone()];
     let amounts = vec![&env, 3_000_0000000];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 
     let stats = client.get_program_aggregate_stats();
 
@@ -1603,8 +2032,8 @@ fn test_comprehensive_analytics_workflow() {
     let (client, _admin, _token, token_admin) = setup_program(&env, 0);
     token_admin.mint(&client.address, &100_000_0000000);
 
-    client.lock_program_funds(&50_000_0000000);
-    client.lock_program_funds(&50_000_0000000);
+    client.lock_program_funds(&50_000_0000000, &None);
+    client.lock_program_funds(&50_000_0000000, &None);
 
     let r1 = Address::generate(&env);
     client.single_payout(&r1, &10_000_0000000);
@@ -1613,7 +2042,7 @@ fn test_comprehensive_analytics_workflow() {
     let r3 = Address::generate(&env);
     let recipients = vec![&env, r2.clone(), r3.clone()];
     let amounts = vec![&env, 15_000_0000000, 20_000_0000000];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 
     let future_timestamp = env.ledger().timestamp() + 100;
     let r4 = Address::generate(&env);
@@ -1716,7 +2145,7 @@ fn test_analytics_metrics_match_operation_counts() {
 
     let recipients = vec![&env, Address::generate(&env)];
     let amounts = vec![&env, 5_000_0000000i128];
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 
     let stats = client.get_program_aggregate_stats();
     assert_eq!(stats.payout_count, 3);
@@ -1747,7 +2176,7 @@ fn test_batch_payout_happy_path_multiple_recipients() {
     let recipients = vec![&env, r1.clone(), r2.clone(), r3.clone()];
     let amounts = vec![&env, 1_000_000, 2_000_000, 3_000_000];
 
-    let data = client.batch_payout(&recipients, &amounts);
+    let data = client.batch_payout(&recipients, &amounts, &false, &false);
 
     // Verify balance updated correctly (all-or-nothing)
     assert_eq!(data.remaining_balance, 0);
@@ -1788,7 +2217,7 @@ fn test_batch_payout_with_duplicate_recipient_addresses() {
     let recipients = vec![&env, r1.clone(), r2.clone(), r1.clone()];
     let amounts = vec![&env, 1_000_000, 2_000_000, 1_500_000];
 
-    let data = client.batch_payout(&recipients, &amounts);
+    let data = client.batch_payout(&recipients, &amounts, &false, &false);
 
     // Balance should be fully consumed
     assert_eq!(data.remaining_balance, 0);
@@ -1836,7 +2265,7 @@ fn test_batch_payout_maximum_batch_size() {
     }
 
     // Execute large batch payout
-    let data = client.batch_payout(&recipients, &amounts);
+    let data = client.batch_payout(&recipients, &amounts, &false, &false);
 
     // Balance should be fully consumed
     assert_eq!(data.remaining_balance, 0);
@@ -1864,7 +2293,7 @@ fn test_batch_payout_empty_batch_panic() {
     let amounts = vec![&env];
 
     // Should panic
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 #[test]
@@ -1878,7 +2307,7 @@ fn test_batch_payout_mismatched_arrays_panic() {
     let amounts = vec![&env, 1_000_000]; // Only 1 amount for 2 recipients
 
     // Should panic
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 #[test]
@@ -1892,7 +2321,7 @@ fn test_batch_payout_invalid_amount_zero_panic() {
     let amounts = vec![&env, 0i128]; // Zero amount - invalid
 
     // Should panic
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 #[test]
@@ -1906,7 +2335,7 @@ fn test_batch_payout_invalid_amount_negative_panic() {
     let amounts = vec![&env, -1_000_000]; // Negative amount - invalid
 
     // Should panic
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 #[test]
@@ -1920,7 +2349,7 @@ fn test_batch_payout_insufficient_balance_panic() {
     let amounts = vec![&env, 10_000_000]; // More than available
 
     // Should panic
-    client.batch_payout(&recipients, &amounts);
+    client.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 #[test]
@@ -1936,7 +2365,7 @@ fn test_batch_payout_partial_spend() {
     let recipients = vec![&env, r1, r2];
     let amounts = vec![&env, 3_000_000, 3_000_000];
 
-    let data = client.batch_payout(&recipients, &amounts);
+    let data = client.batch_payout(&recipients, &amounts, &false, &false);
 
     // Remaining balance should be correct
     assert_eq!(data.remaining_balance, 4_000_000);
@@ -1964,7 +2393,7 @@ fn test_batch_payout_atomicity_all_or_nothing() {
     let recipients = vec![&env, r1, r2];
     let amounts = vec![&env, 1_000_000, 2_000_000];
 
-    let data = client.batch_payout(&recipients, &amounts);
+    let data = client.batch_payout(&recipients, &amounts, &false, &false);
 
     // All records must be written
     assert_eq!(data.payout_history.len(), history_len_before + 2);
@@ -1988,7 +2417,7 @@ fn test_batch_payout_sequential_batches() {
     let r1 = Address::generate(&env);
     let recipients1 = vec![&env, r1];
     let amounts1 = vec![&env, 3_000_000];
-    let data1 = client.batch_payout(&recipients1, &amounts1);
+    let data1 = client.batch_payout(&recipients1, &amounts1, &false, &false);
 
     // Verify after first batch
     assert_eq!(data1.payout_history.len(), 1);
@@ -1999,7 +2428,7 @@ fn test_batch_payout_sequential_batches() {
     let r3 = Address::generate(&env);
     let recipients2 = vec![&env, r2, r3];
     let amounts2 = vec![&env, 2_000_000, 4_000_000];
-    let data2 = client.batch_payout(&recipients2, &amounts2);
+    let data2 = client.batch_payout(&recipients2, &amounts2, &false, &false);
 
     // Verify after second batch
     assert_eq!(data2.payout_history.len(), 3);
@@ -2207,6 +2636,7 @@ fn test_query_schedules_by_status_pending_vs_released() {
 fn test_query_schedules_by_recipient_returns_correct_subset() {
     let env = Env::default();
     let (client, _admin, _token, _token_admin) = setup_program(&env, 300_000);
+    let program_id = String::from_str(&env, "hack-2026");
 
     let now = env.ledger().timestamp();
     let winner = Address::generate(&env);
@@ -2216,13 +2646,13 @@ fn test_query_schedules_by_recipient_returns_correct_subset() {
     client.create_program_release_schedule(&other, &50_000, &(now + 200));
     client.create_program_release_schedule(&winner, &50_000, &(now + 300));
 
-    let winner_schedules = client.query_schedules_by_recipient(&winner, &0, &10);
+    let winner_schedules = client.query_schedules_by_recipient(&program_id, &winner, &0, &10);
     assert_eq!(winner_schedules.len(), 2);
     for s in winner_schedules.iter() {
         assert_eq!(s.recipient, winner);
     }
 
-    let other_schedules = client.query_schedules_by_recipient(&other, &0, &10);
+    let other_schedules = client.query_schedules_by_recipient(&program_id, &other, &0, &10);
     assert_eq!(other_schedules.len(), 1);
 }
 
@@ -2293,3 +2723,4421 @@ fn test_release_schedules_persist_after_simulated_upgrade() {
     assert_eq!(stats_final.scheduled_count, 0);
     assert_eq!(stats_final.remaining_balance, 100_000);
 }
+
+#[test]
+fn test_schedule_apis_share_one_store() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 200_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    let first = client.create_program_release_schedule(&recipient, &10_000, &100);
+    let second = client.create_program_schedule(&program_id, &recipient, &20_000, &100);
+    assert_eq!(second.schedule_id, first.schedule_id + 1);
+    assert_eq!(client.get_all_prog_release_schedules().len(), 2);
+    assert_eq!(
+        client.get_program_schedule(&program_id, &first.schedule_id),
+        Some(first)
+    );
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.release_all_due_schedules(&program_id), 2);
+    assert_eq!(client.get_pending_schedules().len(), 0);
+}
+
+#[test]
+fn test_legacy_schedule_list_is_migrated_on_access() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 200_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    let legacy = ProgramReleaseSchedule {
+        schedule_id: 1,
+        recipient: recipient.clone(),
+        amount: 10_000,
+        release_timestamp: 100,
+        released: false,
+        released_at: None,
+        released_by: None,
+    };
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .set(&SCHEDULES, &vec![&env, legacy.clone()]);
+        env.storage().instance().set(&NEXT_SCHEDULE_ID, &2_u64);
+    });
+
+    assert_eq!(client.get_program_schedule(&program_id, &1), Some(legacy));
+    let next = client.create_program_release_schedule(&recipient, &5_000, &200);
+    assert_eq!(next.schedule_id, 2);
+    assert_eq!(client.get_all_prog_release_schedules().len(), 2);
+    env.as_contract(&client.address, || {
+        assert!(!env.storage().instance().has(&SCHEDULES));
+    });
+}
+
+// =============================================================================
+// TESTS FOR OPERATOR SPENDABLE AMOUNT
+// =============================================================================
+
+#[test]
+fn test_operator_spendable_defaults_to_remaining_balance() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    assert_eq!(
+        client.get_operator_spendable(&program_id, &admin, &recipient),
+        100_000
+    );
+}
+
+#[test]
+fn test_operator_spendable_window_limit_binds_below_allowance() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    client.set_program_spending_limit(&program_id, &86_400, &30_000, &true);
+    client.set_operator_allowance(&program_id, &admin, &Some(50_000));
+    client.single_payout(&recipient, &10_000);
+
+    // Window: 30k - 10k = 20k; allowance: 50k - 10k = 40k
+    assert_eq!(
+        client.get_operator_spendable(&program_id, &admin, &recipient),
+        20_000
+    );
+}
+
+#[test]
+fn test_operator_spendable_recipient_cap_binds_below_window_limit() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let capped = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.set_program_spending_limit(&program_id, &86_400, &60_000, &true);
+    client.set_recipient_cap(&program_id, &Some(25_000));
+    client.single_payout(&capped, &15_000);
+
+    assert_eq!(
+        client.get_operator_spendable(&program_id, &admin, &capped),
+        10_000
+    );
+    // Once the window runs lower than the cap, it binds instead: 60k - 40k
+    client.single_payout(&Address::generate(&env), &25_000);
+    assert_eq!(
+        client.get_operator_spendable(&program_id, &admin, &other),
+        20_000
+    );
+}
+
+#[test]
+#[should_panic(expected = "Recipient cap exceeded")]
+fn test_payout_above_recipient_cap_rejected() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+
+    client.set_recipient_cap(&program_id, &Some(25_000));
+    client.single_payout(&recipient, &20_000);
+    client.single_payout(&recipient, &10_000);
+}
+
+// =============================================================================
+// TESTS FOR UNLOCKING FUNDS BEFORE PAYOUTS
+// =============================================================================
+
+#[test]
+fn test_unlock_program_funds_from_fresh_lock() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let organizer = Address::generate(&env);
+
+    let data = client.unlock_program_funds(&program_id, &40_000, &organizer);
+
+    assert_eq!(data.total_funds, 60_000);
+    assert_eq!(data.remaining_balance, 60_000);
+    assert_eq!(client.get_remaining_balance(), 60_000);
+    assert_eq!(token_client.balance(&organizer), 40_000);
+    assert_eq!(token_client.balance(&client.address), 60_000);
+}
+
+#[test]
+#[should_panic(expected = "Cannot unlock after payouts")]
+fn test_unlock_program_funds_rejected_after_payout() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let organizer = Address::generate(&env);
+
+    client.single_payout(&winner, &10_000);
+    client.unlock_program_funds(&program_id, &40_000, &organizer);
+}
+
+// =============================================================================
+// TESTS FOR DETAILED HEALTH REPORT
+// =============================================================================
+
+#[test]
+fn test_health_detailed_all_checks_green() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+
+    let report = client.get_health_detailed();
+    assert!(report.circuit_ok);
+    assert!(report.balance_consistent);
+    assert!(report.rate_limit_headroom > 0);
+    assert!(report.healthy);
+}
+
+#[test]
+fn test_health_detailed_reports_open_circuit() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+
+    env.as_contract(&client.address, || {
+        error_recovery::open_circuit(&env);
+    });
+
+    let report = client.get_health_detailed();
+    assert!(!report.circuit_ok);
+    assert!(report.balance_consistent);
+    assert!(report.rate_limit_headroom > 0);
+    assert!(!report.healthy);
+}
+
+#[test]
+fn test_health_detailed_reports_underfunded_contract() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let sink = Address::generate(&env);
+
+    env.as_contract(&client.address, || {
+        token_client.transfer(&client.address, &sink, &1);
+    });
+
+    let report = client.get_health_detailed();
+    assert!(report.circuit_ok);
+    assert!(!report.balance_consistent);
+    assert!(!report.healthy);
+}
+
+// =============================================================================
+// TESTS FOR MAXIMUM SCHEDULE COUNT
+// =============================================================================
+
+#[test]
+fn test_max_schedules_defaults_to_100() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    assert_eq!(client.get_max_schedules(&program_id), 100);
+}
+
+#[test]
+fn test_create_schedules_up_to_cap() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.set_max_schedules(&program_id, &3);
+    for _ in 0..3 {
+        client.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    }
+
+    assert_eq!(client.get_all_prog_release_schedules().len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "Maximum schedules reached")]
+fn test_create_schedule_beyond_cap_rejected() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.set_max_schedules(&program_id, &3);
+    for _ in 0..4 {
+        client.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    }
+}
+
+#[test]
+fn test_released_schedules_free_cap_slots() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client.set_max_schedules(&program_id, &2);
+    let first = client.create_program_release_schedule(&recipient, &1_000, &now);
+    client.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    assert!(client
+        .try_create_program_release_schedule(&recipient, &1_000, &(now + 100))
+        .is_err());
+
+    // Releasing one leaves a single pending schedule, so one more fits
+    client.release_prog_schedule_automatic(&first.schedule_id);
+    client.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    assert!(client
+        .try_create_program_release_schedule(&recipient, &1_000, &(now + 100))
+        .is_err());
+    assert!(client
+        .try_create_program_schedule(&program_id, &recipient, &1_000, &(now + 100))
+        .is_err());
+    assert_eq!(client.get_all_prog_release_schedules().len(), 3);
+}
+
+#[test]
+fn test_max_schedules_isolated_per_program() {
+    let env = Env::default();
+    let (client_a, _admin_a, _token_a, _token_admin_a) = setup_program(&env, 100_000);
+    let (client_b, _admin_b, _token_b, _token_admin_b) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+
+    client_a.set_max_schedules(&program_id, &1);
+    client_a.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    assert!(client_a
+        .try_create_program_release_schedule(&recipient, &1_000, &(now + 100))
+        .is_err());
+
+    // Program B keeps the default cap and is unaffected by A being full
+    assert_eq!(client_b.get_max_schedules(&program_id), 100);
+    client_b.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    client_b.create_program_release_schedule(&recipient, &1_000, &(now + 100));
+    assert_eq!(client_b.get_all_prog_release_schedules().len(), 2);
+}
+
+// =============================================================================
+// TESTS FOR PAYOUT QUEUE WHILE CIRCUIT IS OPEN
+// =============================================================================
+
+#[test]
+#[should_panic(expected = "Circuit breaker is open")]
+fn test_batch_payout_rejected_while_circuit_open() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let winner = Address::generate(&env);
+
+    env.as_contract(&client.address, || {
+        error_recovery::open_circuit(&env);
+    });
+
+    client.batch_payout(&vec![&env, winner], &vec![&env, 10_000], &false, &false);
+}
+
+#[test]
+fn test_payout_queue_drained_after_circuit_closes() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner1 = Address::generate(&env);
+    let winner2 = Address::generate(&env);
+    let winner3 = Address::generate(&env);
+
+    client.set_circuit_admin(&admin, &None);
+    env.as_contract(&client.address, || {
+        error_recovery::open_circuit(&env);
+    });
+
+    // Both batches are queued; nothing moves while the circuit is open
+    let data = client.batch_payout(
+        &vec![&env, winner1.clone(), winner2.clone()],
+        &vec![&env, 20_000, 10_000],
+        &true,
+        &false,
+    );
+    assert_eq!(data.remaining_balance, 100_000);
+    client.batch_payout(
+        &vec![&env, winner3.clone()],
+        &vec![&env, 5_000],
+        &true,
+        &false,
+    );
+    assert_eq!(client.get_payout_queue(&program_id).len(), 2);
+    assert_eq!(token_client.balance(&winner1), 0);
+
+    assert!(client.try_process_payout_queue(&program_id, &10).is_err());
+
+    client.reset_circuit_breaker(&admin);
+
+    // Drain one batch at a time, oldest first
+    assert_eq!(client.process_payout_queue(&program_id, &1), 1);
+    assert_eq!(token_client.balance(&winner1), 20_000);
+    assert_eq!(token_client.balance(&winner2), 10_000);
+    assert_eq!(token_client.balance(&winner3), 0);
+    assert_eq!(client.get_payout_queue(&program_id).len(), 1);
+
+    assert_eq!(client.process_payout_queue(&program_id, &10), 1);
+    assert_eq!(token_client.balance(&winner3), 5_000);
+    assert_eq!(client.get_payout_queue(&program_id).len(), 0);
+    assert_eq!(client.get_remaining_balance(), 65_000);
+    assert_eq!(client.get_program_info().payout_history.len(), 3);
+}
+
+#[test]
+fn test_payout_queue_drops_batch_with_blacklisted_recipient() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner1 = Address::generate(&env);
+    let winner2 = Address::generate(&env);
+    let winner3 = Address::generate(&env);
+
+    client.set_circuit_admin(&admin, &None);
+    env.as_contract(&client.address, || {
+        error_recovery::open_circuit(&env);
+    });
+    client.batch_payout(
+        &vec![&env, winner1.clone(), winner2.clone()],
+        &vec![&env, 20_000, 10_000],
+        &true,
+        &false,
+    );
+    client.batch_payout(
+        &vec![&env, winner3.clone()],
+        &vec![&env, 5_000],
+        &true,
+        &false,
+    );
+
+    // Screened out after the batch was queued
+    client.add_program_blacklist(&program_id, &winner2, &String::from_str(&env, "sanctioned"));
+    client.reset_circuit_breaker(&admin);
+
+    // The first batch is dropped whole; the one behind it still pays
+    assert_eq!(client.process_payout_queue(&program_id, &10), 1);
+    assert_eq!(token_client.balance(&winner1), 0);
+    assert_eq!(token_client.balance(&winner2), 0);
+    assert_eq!(token_client.balance(&winner3), 5_000);
+    assert_eq!(client.get_payout_queue(&program_id).len(), 0);
+    assert_eq!(client.get_remaining_balance(), 95_000);
+}
+
+// =============================================================================
+// TESTS FOR AUTHORIZED PAYOUT KEY VIEW AND ROTATION
+// =============================================================================
+
+#[test]
+fn test_get_authorized_payout_key() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    assert_eq!(client.get_authorized_payout_key(&program_id), admin);
+}
+
+#[test]
+fn test_rotate_authorized_payout_key() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let new_key = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    let data = client.rotate_authorized_payout_key(&program_id, &new_key);
+    assert_eq!(data.authorized_payout_key, new_key);
+    assert_eq!(client.get_authorized_payout_key(&program_id), new_key);
+    assert_eq!(client.get_program_info().authorized_payout_key, new_key);
+
+    // Payouts are now authorized by the new key
+    env.mock_auths(&[MockAuth {
+        address: &new_key,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "single_payout",
+            args: (winner.clone(), 10_000i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.single_payout(&winner, &10_000);
+    assert_eq!(token_client.balance(&winner), 10_000);
+    assert_ne!(admin, new_key);
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_authorized_payout_key_requires_current_key() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let attacker = Address::generate(&env);
+
+    env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "rotate_authorized_payout_key",
+            args: (program_id.clone(), attacker.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.rotate_authorized_payout_key(&program_id, &attacker);
+}
+
+#[test]
+fn test_program_transfer_two_step_handoff() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let new_org = Address::generate(&env);
+    let winner = Address::generate(&env);
+
+    client.propose_program_transfer(&program_id, &new_org);
+    assert_eq!(
+        client.get_pending_program_transfer(&program_id),
+        Some(new_org.clone())
+    );
+    // Nothing moves until the new key accepts
+    assert_eq!(client.get_authorized_payout_key(&program_id), admin);
+
+    env.mock_auths(&[MockAuth {
+        address: &new_org,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "accept_program_transfer",
+            args: (program_id.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    let data = client.accept_program_transfer(&program_id);
+    assert_eq!(data.authorized_payout_key, new_org);
+    assert_eq!(client.get_authorized_payout_key(&program_id), new_org);
+    assert_eq!(client.get_pending_program_transfer(&program_id), None);
+
+    env.mock_auths(&[MockAuth {
+        address: &new_org,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "single_payout",
+            args: (winner.clone(), 10_000i128).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.single_payout(&winner, &10_000);
+    assert_eq!(token_client.balance(&winner), 10_000);
+}
+
+#[test]
+#[should_panic]
+fn test_program_transfer_rejects_non_proposed_key() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let new_org = Address::generate(&env);
+    let attacker = Address::generate(&env);
+
+    client.propose_program_transfer(&program_id, &new_org);
+
+    env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "accept_program_transfer",
+            args: (program_id.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.accept_program_transfer(&program_id);
+}
+
+#[test]
+#[should_panic(expected = "No pending transfer")]
+fn test_accept_program_transfer_without_proposal() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.accept_program_transfer(&program_id);
+}
+
+#[test]
+#[should_panic(expected = "No pending transfer")]
+fn test_key_rotation_clears_pending_transfer() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let new_org = Address::generate(&env);
+    let rotated = Address::generate(&env);
+
+    client.propose_program_transfer(&program_id, &new_org);
+    client.rotate_authorized_payout_key(&program_id, &rotated);
+    assert_eq!(client.get_pending_program_transfer(&program_id), None);
+
+    client.accept_program_transfer(&program_id);
+}
+
+#[test]
+fn test_pending_transfer_lapses_on_expiry_or_admin_change() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let new_org = Address::generate(&env);
+
+    client.propose_program_transfer(&program_id, &new_org);
+    env.ledger().with_mut(|li| li.timestamp += 604_801);
+    assert_eq!(client.get_pending_program_transfer(&program_id), None);
+    assert!(client.try_accept_program_transfer(&program_id).is_err());
+
+    client.propose_program_transfer(&program_id, &new_org);
+    client.set_admin(&Address::generate(&env));
+    assert_eq!(client.get_pending_program_transfer(&program_id), None);
+    assert!(client.try_accept_program_transfer(&program_id).is_err());
+}
+
+#[test]
+fn test_cancel_payout_key_transfer() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let new_org = Address::generate(&env);
+
+    client.propose_program_transfer(&program_id, &new_org);
+    client.cancel_payout_key_transfer(&program_id);
+    assert_eq!(client.get_pending_program_transfer(&program_id), None);
+    assert!(client.try_accept_program_transfer(&program_id).is_err());
+    assert_eq!(client.get_authorized_payout_key(&program_id), admin);
+}
+
+// =============================================================================
+// TESTS FOR MULTISIG PAYOUT APPROVAL STATUS
+// =============================================================================
+
+#[test]
+fn test_get_payout_approval_tracks_collected_approvals() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+
+    let signer_a = Address::generate(&env);
+    let signer_b = Address::generate(&env);
+    let signer_c = Address::generate(&env);
+    let recipient = Address::generate(&env);
+    client.set_multisig_config(
+        &program_id,
+        &10_000,
+        &vec![&env, signer_a.clone(), signer_b.clone(), signer_c.clone()],
+        &3,
+    );
+
+    // Nothing collected yet
+    let empty = client.get_payout_approval(&program_id, &recipient);
+    assert_eq!(empty.approvals.len(), 0);
+    assert_eq!(empty.amount, 0);
+
+    client.approve_large_payout(&program_id, &recipient, &50_000, &signer_a);
+    client.approve_large_payout(&program_id, &recipient, &50_000, &signer_c);
+    // Re-approving does not double count
+    client.approve_large_payout(&program_id, &recipient, &50_000, &signer_a);
+
+    let approval = client.get_payout_approval(&program_id, &recipient);
+    assert_eq!(approval.program_id, program_id);
+    assert_eq!(approval.recipient, recipient);
+    assert_eq!(approval.amount, 50_000);
+    assert_eq!(approval.approvals, vec![&env, signer_a, signer_c]);
+    assert!(approval.approvals.len() < client.get_multisig_config(&program_id).required_signatures);
+}
+
+#[test]
+#[should_panic(expected = "Not a multisig signer")]
+fn test_approve_large_payout_rejects_non_signer() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_multisig_config(
+        &program_id,
+        &10_000,
+        &vec![&env, Address::generate(&env)],
+        &1,
+    );
+
+    client.approve_large_payout(
+        &program_id,
+        &Address::generate(&env),
+        &50_000,
+        &Address::generate(&env),
+    );
+}
+
+#[test]
+#[should_panic(expected = "Required signatures must be at least 1")]
+fn test_set_multisig_config_rejects_zero_required_signatures() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_multisig_config(
+        &program_id,
+        &10_000,
+        &vec![&env, Address::generate(&env)],
+        &0,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Required signatures exceed signer count")]
+fn test_set_multisig_config_rejects_more_signatures_than_signers() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_multisig_config(
+        &program_id,
+        &10_000,
+        &vec![&env, Address::generate(&env)],
+        &2,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Duplicate multisig signer")]
+fn test_set_multisig_config_rejects_duplicate_signers() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    let signer = Address::generate(&env);
+    // Two approvals from the same key must not satisfy a 2-of-N threshold
+    client.set_multisig_config(
+        &program_id,
+        &10_000,
+        &vec![&env, signer.clone(), Address::generate(&env), signer],
+        &2,
+    );
+}
+
+// =============================================================================
+// TESTS FOR PER-OPERATION RATE LIMITS
+// =============================================================================
+
+fn setup_with_operation_limits(
+    env: &Env,
+) -> (ProgramEscrowContractClient<'static>, Address, Address) {
+    env.mock_all_auths();
+
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.set_admin(&admin);
+
+    // Tight limit on program creation, loose limit on payouts
+    client.update_operation_rate_limit(&symbol_short!("init_prg"), &3600, &1, &0);
+    client.update_operation_rate_limit(&symbol_short!("batch_pay"), &3600, &5, &0);
+
+    let token_admin = Address::generate(env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(token_admin.clone())
+        .address();
+    token::StellarAssetClient::new(env, &token_id).mint(&contract_id, &100_000);
+    client.init_program(
+        &String::from_str(env, "hack-2026"),
+        &admin,
+        &token_id,
+        &admin,
+        &None,
+        &None,
+    );
+    client.lock_program_funds(&100_000, &None);
+
+    (client, admin, token_id)
+}
+
+#[test]
+fn test_operation_rate_limits_are_independent() {
+    let env = Env::default();
+    let (client, admin, token_id) = setup_with_operation_limits(&env);
+
+    // The init budget is spent, but payouts have their own budget
+    for _ in 0..5 {
+        client.batch_payout(
+            &vec![&env, Address::generate(&env)],
+            &vec![&env, 1_000],
+            &false,
+            &false,
+        );
+    }
+    assert_eq!(client.get_remaining_balance(), 95_000);
+
+    let result = client.try_init_program(
+        &String::from_str(&env, "hack-2027"),
+        &admin,
+        &token_id,
+        &admin,
+        &None,
+        &None,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_operation_rate_limit_blocks_payouts_beyond_budget() {
+    let env = Env::default();
+    let (client, _admin, _token_id) = setup_with_operation_limits(&env);
+
+    for _ in 0..6 {
+        client.batch_payout(
+            &vec![&env, Address::generate(&env)],
+            &vec![&env, 1_000],
+            &false,
+            &false,
+        );
+    }
+}
+
+#[test]
+fn test_operation_rate_limit_falls_back_to_global_config() {
+    let env = Env::default();
+    let (client, _admin, _token_id) = setup_with_operation_limits(&env);
+    let batch_pay = symbol_short!("batch_pay");
+
+    client.update_rate_limit_config(&3600, &2, &0);
+    client.clear_operation_rate_limit(&batch_pay);
+
+    assert_eq!(
+        client.get_operation_rate_limit(&batch_pay),
+        Some(client.get_rate_limit_config())
+    );
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_000],
+        &false,
+        &false,
+    );
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_000],
+        &false,
+        &false,
+    );
+    let result = client.try_batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_000],
+        &false,
+        &false,
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_operations_unlimited_until_a_rate_limit_is_stored() {
+    let env = Env::default();
+    env.ledger().set_timestamp(1_000);
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+
+    // The reported default is not enforced: no cooldown, no window budget
+    assert!(client
+        .get_operation_rate_limit(&symbol_short!("init_prg"))
+        .is_none());
+    assert!(client
+        .get_operation_rate_limit(&symbol_short!("batch_pay"))
+        .is_none());
+    let default_config = client.get_rate_limit_config();
+    for _ in 0..default_config.max_operations + 2 {
+        client.batch_payout(
+            &vec![&env, Address::generate(&env)],
+            &vec![&env, 1_000],
+            &false,
+            &false,
+        );
+    }
+    assert_eq!(client.get_remaining_balance(), 88_000);
+}
+
+#[test]
+fn test_admin_rotation_allowed_after_cooldown() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    env.ledger().set_timestamp(1_000);
+    client.set_admin(&admin);
+    client.set_admin_rotation_cooldown(&3_600);
+    assert_eq!(client.get_admin_rotation_cooldown(), 3_600);
+
+    env.ledger().set_timestamp(1_000 + 3_600);
+    let new_admin = Address::generate(&env);
+    client.set_admin(&new_admin);
+    assert_eq!(client.get_admin(), Some(new_admin));
+}
+
+#[test]
+#[should_panic(expected = "Admin rotation too soon")]
+fn test_admin_rotation_rejected_within_cooldown() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    env.ledger().set_timestamp(1_000);
+    client.set_admin(&admin);
+    client.set_admin_rotation_cooldown(&3_600);
+
+    env.ledger().set_timestamp(1_000 + 3_599);
+    client.set_admin(&Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Admin rotation cooldown active")]
+fn test_admin_rotation_cooldown_cannot_be_dropped_early() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    env.ledger().set_timestamp(1_000);
+    client.set_admin(&admin);
+    client.set_admin_rotation_cooldown(&3_600);
+
+    env.ledger().set_timestamp(1_000 + 60);
+    client.set_admin_rotation_cooldown(&0);
+}
+
+#[test]
+fn test_admin_rotation_cooldown_lowered_after_it_elapses() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    env.ledger().set_timestamp(1_000);
+    client.set_admin(&admin);
+    client.set_admin_rotation_cooldown(&3_600);
+
+    // Raising is never held back
+    client.set_admin_rotation_cooldown(&7_200);
+
+    env.ledger().set_timestamp(1_000 + 7_200);
+    client.set_admin_rotation_cooldown(&0);
+    assert_eq!(client.get_admin_rotation_cooldown(), 0);
+}
+
+// =============================================================================
+// TESTS FOR REQUIRED PAYOUT MEMOS
+// =============================================================================
+
+#[test]
+#[should_panic(expected = "Memo required")]
+fn test_required_memo_rejects_missing_memo() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_require_memo(&program_id, &true);
+
+    client.batch_payout_with_memos(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 10_000, 5_000],
+        &vec![
+            &env,
+            Some(String::from_str(&env, "INV-001")),
+            None::<String>,
+        ],
+    );
+}
+
+#[test]
+fn test_required_memo_accepts_all_memos_present() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner1 = Address::generate(&env);
+    let winner2 = Address::generate(&env);
+    client.set_admin(&admin);
+    client.set_require_memo(&program_id, &true);
+    assert!(client.get_require_memo(&program_id));
+
+    let data = client.batch_payout_with_memos(
+        &vec![&env, winner1.clone(), winner2.clone()],
+        &vec![&env, 10_000, 5_000],
+        &vec![
+            &env,
+            Some(String::from_str(&env, "INV-001")),
+            Some(String::from_str(&env, "INV-002")),
+        ],
+    );
+
+    assert_eq!(data.remaining_balance, 85_000);
+    assert_eq!(token_client.balance(&winner1), 10_000);
+    assert_eq!(token_client.balance(&winner2), 5_000);
+}
+
+#[test]
+fn test_memos_optional_by_default() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert!(!client.get_require_memo(&program_id));
+
+    let data = client.batch_payout_with_memos(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 10_000],
+        &vec![&env, None::<String>],
+    );
+    assert_eq!(data.remaining_balance, 90_000);
+}
+
+// =============================================================================
+// TESTS FOR PAGINATED RELEASE HISTORY
+// =============================================================================
+
+#[test]
+fn test_release_history_pages_in_order() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    for i in 0..7 {
+        client.create_program_release_schedule(
+            &Address::generate(&env),
+            &(1_000 * (i + 1)),
+            &(now + 10),
+        );
+    }
+    env.ledger().set_timestamp(now + 10);
+    assert_eq!(client.trigger_program_releases(), 7);
+    assert_eq!(client.get_release_history_count(), 7);
+
+    let mut seen: u64 = 0;
+    let mut start = 0u32;
+    loop {
+        let page = client.get_program_release_history_page(&program_id, &start, &3);
+        if page.is_empty() {
+            break;
+        }
+        assert!(page.len() <= 3);
+        for entry in page.iter() {
+            seen += 1;
+            assert_eq!(entry.schedule_id, seen);
+            assert_eq!(entry.amount, 1_000 * seen as i128);
+        }
+        start += page.len();
+    }
+    assert_eq!(seen, 7);
+
+    // Final partial page, and nothing past the end
+    assert_eq!(
+        client
+            .get_program_release_history_page(&program_id, &6, &3)
+            .len(),
+        1
+    );
+    assert_eq!(
+        client
+            .get_program_release_history_page(&program_id, &7, &3)
+            .len(),
+        0
+    );
+    assert_eq!(
+        client
+            .get_program_release_history_page(&program_id, &100, &3)
+            .len(),
+        0
+    );
+}
+
+#[test]
+fn test_release_history_page_limit_clamped() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+
+    for _ in 0..3 {
+        client.create_program_release_schedule(&Address::generate(&env), &1_000, &now);
+    }
+    client.trigger_program_releases();
+
+    let page = client.get_program_release_history_page(&program_id, &0, &u32::MAX);
+    assert_eq!(page.len(), 3);
+    assert_eq!(
+        client
+            .get_program_release_history_page(&program_id, &0, &0)
+            .len(),
+        0
+    );
+}
+
+// =============================================================================
+// TESTS FOR OVERFLOW-SAFE MONITORING TOTALS
+// =============================================================================
+
+#[test]
+fn test_payout_volume_tracked_in_analytics() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 10_000);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 500, 250],
+        &false,
+        &false,
+    );
+
+    assert_eq!(client.get_analytics().total_released, 1_750);
+}
+
+#[test]
+fn test_payout_volume_saturates_instead_of_overflowing() {
+    let env = Env::default();
+    let (client, _admin, token, _token_admin) = setup_program(&env, 10_000);
+    let recipient = Address::generate(&env);
+
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, "pay_vol"), &(i128::MAX - 100));
+    });
+
+    client.single_payout(&recipient, &1_000);
+
+    // The payout itself goes through; only the aggregate is capped
+    assert_eq!(token.balance(&recipient), 1_000);
+    assert_eq!(client.get_analytics().total_released, i128::MAX);
+
+    let saturated = env.events().all().iter().any(|(contract, topics, _)| {
+        contract == client.address
+            && topics.len() == 2
+            && Symbol::try_from_val(&env, &topics.get(1).unwrap()) == Ok(symbol_short!("cnt_sat"))
+    });
+    assert!(saturated);
+
+    // Further payouts stay pinned at the cap
+    client.single_payout(&recipient, &1_000);
+    assert_eq!(client.get_analytics().total_released, i128::MAX);
+}
+
+// =============================================================================
+// TESTS FOR ACCRUED PAYOUT FEES
+// =============================================================================
+
+#[test]
+fn test_accrued_fees_match_summed_payout_fees() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let fee_recipient = Address::generate(&env);
+    client.set_admin(&admin);
+    // 2% payout fee
+    client.set_fee_config(&0, &200, &fee_recipient, &true);
+    assert_eq!(client.get_fee_config().payout_fee_rate, 200);
+    assert_eq!(client.get_accrued_fees(&program_id), 0);
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    client.single_payout(&r1, &10_000);
+    client.batch_payout(
+        &vec![&env, r2.clone(), r3.clone()],
+        &vec![&env, 5_000, 2_500],
+        &false,
+        &false,
+    );
+
+    // 200 + 100 + 50
+    assert_eq!(client.get_accrued_fees(&program_id), 350);
+    assert_eq!(token_client.balance(&r1), 9_800);
+    assert_eq!(token_client.balance(&r2), 4_900);
+    assert_eq!(token_client.balance(&r3), 2_450);
+    assert_eq!(token_client.balance(&fee_recipient), 0);
+
+    let withdrawn = client.withdraw_fees(&program_id);
+    assert_eq!(withdrawn, 350);
+    assert_eq!(token_client.balance(&fee_recipient), 350);
+    assert_eq!(client.get_accrued_fees(&program_id), 0);
+}
+
+#[test]
+fn test_payout_fee_clamped_to_cap() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 1_000_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    // 2% payout fee, capped at 1_000 per payout
+    client.set_fee_config(&0, &200, &admin, &true);
+    client.set_max_fee_per_payout(&1_000);
+    assert_eq!(client.get_max_fee_per_payout(), 1_000);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &500_000);
+    // 2% would be 10_000
+    assert_eq!(token_client.balance(&recipient), 499_000);
+    assert_eq!(client.get_accrued_fees(&program_id), 1_000);
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 200_000, 100_000],
+        &false,
+        &false,
+    );
+    assert_eq!(token_client.balance(&r1), 199_000);
+    assert_eq!(token_client.balance(&r2), 99_000);
+    assert_eq!(client.get_accrued_fees(&program_id), 3_000);
+}
+
+#[test]
+fn test_payout_fee_under_cap_unaffected() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_fee_config(&0, &200, &admin, &true);
+    client.set_max_fee_per_payout(&1_000);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &10_000);
+    assert_eq!(token_client.balance(&recipient), 9_800);
+    assert_eq!(client.get_accrued_fees(&program_id), 200);
+}
+
+#[test]
+fn test_no_fees_accrue_when_disabled() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_fee_config(&0, &200, &Address::generate(&env), &false);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &10_000);
+
+    assert_eq!(client.get_accrued_fees(&program_id), 0);
+    assert_eq!(token_client.balance(&recipient), 10_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid fee rate")]
+fn test_set_fee_config_rejects_excessive_rate() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    client.set_fee_config(&0, &1_001, &admin, &true);
+}
+
+// =============================================================================
+// TESTS FOR SELF-PAYOUT REJECTION
+// =============================================================================
+
+#[test]
+#[should_panic(expected = "Cannot pay the contract itself")]
+fn test_single_payout_rejects_contract_address() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+
+    client.single_payout(&client.address, &10_000);
+}
+
+#[test]
+#[should_panic(expected = "Cannot pay the contract itself")]
+fn test_batch_payout_rejects_contract_address_entry() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), client.address.clone()],
+        &vec![&env, 10_000, 5_000],
+        &false,
+        &false,
+    );
+}
+
+#[test]
+fn test_rejected_self_payout_leaves_balance_untouched() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+
+    let res = client.try_batch_payout(
+        &vec![&env, recipient.clone(), client.address.clone()],
+        &vec![&env, 10_000, 5_000],
+        &false,
+        &false,
+    );
+    assert!(res.is_err());
+
+    assert_eq!(client.get_remaining_balance(), 100_000);
+    assert_eq!(token_client.balance(&recipient), 0);
+    assert_eq!(client.get_program_info().payout_history.len(), 0);
+}
+
+// =============================================================================
+// TESTS FOR DUPLICATE RECIPIENT DETECTION
+// =============================================================================
+
+#[test]
+#[should_panic(expected = "Duplicate recipient in batch")]
+fn test_batch_payout_rejects_duplicates_when_enabled() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let winner = Address::generate(&env);
+
+    client.batch_payout(
+        &vec![&env, winner.clone(), Address::generate(&env), winner],
+        &vec![&env, 10_000, 5_000, 10_000],
+        &false,
+        &true,
+    );
+}
+
+#[test]
+fn test_batch_payout_pays_duplicates_when_disabled() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let winner = Address::generate(&env);
+
+    client.batch_payout(
+        &vec![&env, winner.clone(), winner.clone()],
+        &vec![&env, 10_000, 5_000],
+        &false,
+        &false,
+    );
+
+    assert_eq!(token_client.balance(&winner), 15_000);
+    assert_eq!(client.get_remaining_balance(), 85_000);
+}
+
+#[test]
+fn test_batch_payout_unique_recipients_pass_duplicate_check() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 10_000, 5_000],
+        &false,
+        &true,
+    );
+
+    assert_eq!(token_client.balance(&r1), 10_000);
+    assert_eq!(token_client.balance(&r2), 5_000);
+}
+
+// =============================================================================
+// TESTS FOR CLAIMABLE PAYOUTS
+// =============================================================================
+
+#[test]
+fn test_claimable_payout_then_recipient_withdraws() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+
+    let data = client.payout_claimable(&program_id, &winner, &10_000);
+    client.payout_claimable(&program_id, &winner, &2_500);
+
+    // Nothing is pushed; the program balance is reserved for the claim
+    assert_eq!(token_client.balance(&winner), 0);
+    assert_eq!(data.remaining_balance, 90_000);
+    assert_eq!(client.get_remaining_balance(), 87_500);
+    assert_eq!(client.get_claimable_balance(&program_id, &winner), 12_500);
+
+    let withdrawn = client.withdraw_claimable(&program_id, &winner);
+    assert_eq!(withdrawn, 12_500);
+    assert_eq!(token_client.balance(&winner), 12_500);
+    assert_eq!(client.get_claimable_balance(&program_id, &winner), 0);
+    assert_eq!(token_client.balance(&client.address), 87_500);
+}
+
+mod reentrant_token {
+    use super::*;
+
+    #[contracttype]
+    #[derive(Clone)]
+    enum ReentrantTokenKey {
+        Escrow,
+        ProgramId,
+        Recipient,
+        Transferred,
+        ReentryFailed,
+    }
+
+    /// Token that, while paying out, calls back into `withdraw_claimable` for
+    /// the same recipient and records whether the nested withdrawal went
+    /// through.
+    #[contract]
+    pub struct ReentrantToken;
+
+    #[contractimpl]
+    impl ReentrantToken {
+        pub fn setup(env: Env, escrow: Address, program_id: String, recipient: Address) {
+            env.storage()
+                .instance()
+                .set(&ReentrantTokenKey::Escrow, &escrow);
+            env.storage()
+                .instance()
+                .set(&ReentrantTokenKey::ProgramId, &program_id);
+            env.storage()
+                .instance()
+                .set(&ReentrantTokenKey::Recipient, &recipient);
+        }
+
+        pub fn transfer(env: Env, _from: Address, _to: Address, amount: i128) {
+            let transferred: i128 = env
+                .storage()
+                .instance()
+                .get(&ReentrantTokenKey::Transferred)
+                .unwrap_or(0);
+            env.storage()
+                .instance()
+                .set(&ReentrantTokenKey::Transferred, &(transferred + amount));
+
+            let escrow: Address = env
+                .storage()
+                .instance()
+                .get(&ReentrantTokenKey::Escrow)
+                .unwrap();
+            let program_id: String = env
+                .storage()
+                .instance()
+                .get(&ReentrantTokenKey::ProgramId)
+                .unwrap();
+            let recipient: Address = env
+                .storage()
+                .instance()
+                .get(&ReentrantTokenKey::Recipient)
+                .unwrap();
+            let client = ProgramEscrowContractClient::new(&env, &escrow);
+
+            let reentry = client.try_withdraw_claimable(&program_id, &recipient);
+            env.storage()
+                .instance()
+                .set(&ReentrantTokenKey::ReentryFailed, &reentry.is_err());
+        }
+
+        pub fn transferred(env: Env) -> i128 {
+            env.storage()
+                .instance()
+                .get(&ReentrantTokenKey::Transferred)
+                .unwrap_or(0)
+        }
+
+        pub fn reentry_failed(env: Env) -> bool {
+            env.storage()
+                .instance()
+                .get(&ReentrantTokenKey::ReentryFailed)
+                .unwrap_or(false)
+        }
+    }
+}
+use reentrant_token::{ReentrantToken, ReentrantTokenClient};
+
+#[test]
+fn test_withdraw_claimable_clears_balance_before_transfer() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    let token_id = env.register_contract(None, ReentrantToken);
+    let token = ReentrantTokenClient::new(&env, &token_id);
+    let program_id = String::from_str(&env, "reentrant");
+    let winner = Address::generate(&env);
+    client.batch_initialize_programs(&vec![
+        &env,
+        ProgramInitItem {
+            program_id: program_id.clone(),
+            authorized_payout_key: admin.clone(),
+            token_address: token_id.clone(),
+            reference_hash: None,
+        },
+    ]);
+    client.lock_registry_program_funds(&program_id, &10_000);
+    client.payout_claimable(&program_id, &winner, &4_000);
+    token.setup(&client.address, &program_id, &winner);
+
+    assert_eq!(client.withdraw_claimable(&program_id, &winner), 4_000);
+    // The nested withdrawal reverted, so the balance was paid out exactly once
+    assert!(token.reentry_failed());
+    assert_eq!(token.transferred(), 4_000);
+    assert_eq!(client.get_claimable_balance(&program_id, &winner), 0);
+
+    // A follow-up withdrawal finds the zeroed balance and reverts cleanly
+    let again = client.try_withdraw_claimable(&program_id, &winner);
+    assert!(again.is_err());
+    assert_eq!(token.transferred(), 4_000);
+}
+
+#[test]
+#[should_panic(expected = "No claimable balance")]
+fn test_withdraw_claimable_without_balance() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.withdraw_claimable(&program_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic]
+fn test_withdraw_claimable_requires_recipient_auth() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let attacker = Address::generate(&env);
+    client.payout_claimable(&program_id, &winner, &10_000);
+
+    env.mock_auths(&[MockAuth {
+        address: &attacker,
+        invoke: &MockAuthInvoke {
+            contract: &client.address,
+            fn_name: "withdraw_claimable",
+            args: (program_id.clone(), winner.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.withdraw_claimable(&program_id, &winner);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance")]
+fn test_claimable_payout_rejects_overdraw() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 1_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.payout_claimable(&program_id, &Address::generate(&env), &1_001);
+}
+
+#[test]
+fn test_early_claim_is_penalized_into_remaining_balance() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.set_early_claim_penalty(&program_id, &1_000, &(now + 1_000));
+
+    client.payout_claimable(&program_id, &winner, &10_000);
+    assert_eq!(client.get_remaining_balance(), 90_000);
+
+    let withdrawn = client.withdraw_claimable(&program_id, &winner);
+    assert_eq!(withdrawn, 9_000);
+    assert_eq!(token_client.balance(&winner), 9_000);
+    assert_eq!(client.get_remaining_balance(), 91_000);
+    assert_eq!(
+        client.get_program_financials(&program_id).lifetime_paid_out,
+        9_000
+    );
+}
+
+#[test]
+fn test_claim_after_ready_time_is_penalty_free() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.set_early_claim_penalty(&program_id, &1_000, &(now + 1_000));
+
+    client.payout_claimable(&program_id, &winner, &10_000);
+    env.ledger().set_timestamp(now + 1_000);
+
+    assert_eq!(client.withdraw_claimable(&program_id, &winner), 10_000);
+    assert_eq!(token_client.balance(&winner), 10_000);
+    assert_eq!(client.get_remaining_balance(), 90_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid penalty")]
+fn test_early_claim_penalty_rejects_bps_above_100_percent() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_early_claim_penalty(&program_id, &10_001, &0);
+}
+
+// =============================================================================
+// TESTS FOR BATCH PROGRAM EXISTENCE QUERY
+// =============================================================================
+
+#[test]
+fn test_programs_exist_mixed_ids() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+
+    let ids = vec![
+        &env,
+        String::from_str(&env, "hack-2026"),
+        String::from_str(&env, "missing"),
+        String::from_str(&env, "hack-2026"),
+        String::from_str(&env, "also-missing"),
+    ];
+    assert_eq!(
+        client.programs_exist(&ids),
+        vec![&env, true, false, true, false]
+    );
+    assert_eq!(client.programs_exist(&Vec::new(&env)).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Too many program IDs")]
+fn test_programs_exist_rejects_oversized_query() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+
+    let mut ids = Vec::new(&env);
+    for _ in 0..(MAX_BATCH_SIZE + 1) {
+        ids.push_back(String::from_str(&env, "hack-2026"));
+    }
+    client.programs_exist(&ids);
+}
+
+#[test]
+fn test_plan_batch_chunks_even_split() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+
+    assert_eq!(
+        client.plan_batch_chunks(&(MAX_BATCH_SIZE * 2)),
+        vec![&env, MAX_BATCH_SIZE, MAX_BATCH_SIZE]
+    );
+    assert_eq!(client.plan_batch_chunks(&0).len(), 0);
+}
+
+#[test]
+fn test_plan_batch_chunks_uneven_split() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+
+    let chunks = client.plan_batch_chunks(&250);
+    assert_eq!(
+        chunks,
+        vec![
+            &env,
+            MAX_BATCH_SIZE,
+            MAX_BATCH_SIZE,
+            250 - 2 * MAX_BATCH_SIZE
+        ]
+    );
+    assert_eq!(chunks.iter().sum::<u32>(), 250);
+}
+
+// =============================================================================
+// TESTS FOR MINIMUM PAYOUT IN WEIGHTED PAYOUTS
+// =============================================================================
+
+#[test]
+fn test_weighted_payout_redirects_share_below_floor_to_dust() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_min_payout(&program_id, &200);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let tiny = Address::generate(&env);
+    // 7_000 / 2_900 / 100: the last share is under the 200 floor
+    client.batch_payout_weighted(
+        &vec![&env, first.clone(), second.clone(), tiny.clone()],
+        &vec![&env, 70u32, 29u32, 1u32],
+        &10_000,
+    );
+
+    assert_eq!(token_client.balance(&first), 7_100);
+    assert_eq!(token_client.balance(&second), 2_900);
+    assert_eq!(token_client.balance(&tiny), 0);
+    assert_eq!(client.get_remaining_balance(), 90_000);
+    assert_eq!(client.get_program_info().payout_history.len(), 2);
+}
+
+#[test]
+fn test_weighted_payout_without_floor_pays_every_share() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert_eq!(client.get_min_payout(&program_id), 0);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    let third = Address::generate(&env);
+    // 3_333 each, with 1 unit of rounding dust to the first recipient
+    client.batch_payout_weighted(
+        &vec![&env, first.clone(), second.clone(), third.clone()],
+        &vec![&env, 1u32, 1u32, 1u32],
+        &10_000,
+    );
+
+    assert_eq!(token_client.balance(&first), 3_334);
+    assert_eq!(token_client.balance(&second), 3_333);
+    assert_eq!(token_client.balance(&third), 3_333);
+}
+
+#[test]
+#[should_panic(expected = "All shares below minimum payout")]
+fn test_weighted_payout_rejects_when_every_share_is_dust() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_min_payout(&program_id, &5_000);
+
+    client.batch_payout_weighted(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1u32, 1u32],
+        &4_000,
+    );
+}
+
+// =============================================================================
+// TESTS FOR VERIFIED RECIPIENT REGISTRY
+// =============================================================================
+
+#[test]
+fn test_strict_recipients_pays_registered_winner_with_label() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let label = String::from_str(&env, "1st place");
+
+    client.register_winner(&program_id, &winner, &label);
+    client.set_strict_recipients(&program_id, &true);
+    assert_eq!(
+        client.get_winner_label(&program_id, &winner),
+        Some(label.clone())
+    );
+
+    client.single_payout(&winner, &10_000);
+    assert_eq!(token_client.balance(&winner), 10_000);
+
+    let paid: Vec<WinnerPaidEvent> = {
+        let mut paid = Vec::new(&env);
+        for (contract, topics, data) in env.events().all().iter() {
+            if contract != client.address {
+                continue;
+            }
+            let tag = Symbol::try_from_val(&env, &topics.get(0).unwrap());
+            if tag == Ok(WINNER_PAID) {
+                paid.push_back(WinnerPaidEvent::try_from_val(&env, &data).unwrap());
+            }
+        }
+        paid
+    };
+    assert_eq!(paid.len(), 1);
+    let event = paid.get(0).unwrap();
+    assert_eq!(event.recipient, winner);
+    assert_eq!(event.amount, 10_000);
+    assert_eq!(event.label, label);
+}
+
+#[test]
+#[should_panic(expected = "Recipient not registered")]
+fn test_strict_recipients_rejects_unregistered_address() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+
+    client.register_winner(&program_id, &winner, &String::from_str(&env, "1st place"));
+    client.set_strict_recipients(&program_id, &true);
+
+    client.batch_payout(
+        &vec![&env, winner, Address::generate(&env)],
+        &vec![&env, 10_000, 5_000],
+        &false,
+        &false,
+    );
+}
+
+#[test]
+fn test_unregistered_recipient_allowed_when_not_strict() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    assert!(!client.get_strict_recipients(&program_id));
+
+    client.single_payout(&recipient, &10_000);
+    assert_eq!(token_client.balance(&recipient), 10_000);
+}
+
+// =============================================================================
+// TESTS FOR LIFETIME PROGRAM FINANCIALS
+// =============================================================================
+
+#[test]
+fn test_program_financials_track_lifetime_totals() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    token_admin.mint(&client.address, &20_000);
+    client.lock_program_funds(&20_000, &None);
+    client.unlock_program_funds(&program_id, &10_000, &admin);
+
+    client.single_payout(&Address::generate(&env), &5_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 15_000, 5_000],
+        &false,
+        &false,
+    );
+
+    let financials = client.get_program_financials(&program_id);
+    assert_eq!(financials.lifetime_locked, 120_000);
+    assert_eq!(financials.lifetime_refunded, 10_000);
+    assert_eq!(financials.lifetime_paid_out, 25_000);
+    assert_eq!(financials.remaining_balance, 85_000);
+    // total_funds is net of unlocks; lifetime_locked is not
+    assert_eq!(client.get_program_info().total_funds, 110_000);
+}
+
+#[test]
+fn test_program_financials_start_at_zero() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let financials = client.get_program_financials(&program_id);
+    assert_eq!(financials.lifetime_locked, 0);
+    assert_eq!(financials.lifetime_paid_out, 0);
+    assert_eq!(financials.lifetime_refunded, 0);
+    assert_eq!(financials.remaining_balance, 0);
+}
+
+// =============================================================================
+// TESTS FOR RECOVERY MULTISIG EMERGENCY WITHDRAW
+// =============================================================================
+
+fn setup_recovery(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    token::Client<'static>,
+    Vec<Address>,
+    Address,
+) {
+    let (client, admin, token_client, _token_admin) = setup_program(env, 100_000);
+    client.set_admin(&admin);
+    client.set_circuit_admin(&admin, &None);
+
+    let signers = vec![
+        env,
+        Address::generate(env),
+        Address::generate(env),
+        Address::generate(env),
+    ];
+    let recovery = Address::generate(env);
+    client.set_recovery_config(&signers, &2, &recovery);
+
+    (client, token_client, signers, recovery)
+}
+
+#[test]
+fn test_emergency_withdraw_with_recovery_signatures_while_paused() {
+    let env = Env::default();
+    let (client, token_client, signers, recovery) = setup_recovery(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_emergency_withdraw(&program_id, &signers.get(0).unwrap());
+    client.approve_emergency_withdraw(&program_id, &signers.get(2).unwrap());
+    assert_eq!(client.get_recovery_approvals(&program_id).len(), 2);
+
+    // Tokens held for anything other than this program stay put
+    token::StellarAssetClient::new(&env, &token_client.address).mint(&client.address, &25_000);
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&program_id, &recovery);
+
+    assert_eq!(token_client.balance(&recovery), 100_000);
+    assert_eq!(token_client.balance(&client.address), 25_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+    assert_eq!(client.get_recovery_approvals(&program_id).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Recovery not configured")]
+fn test_emergency_withdraw_requires_recovery_config() {
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&program_id, &admin);
+}
+
+#[test]
+#[should_panic(expected = "Not paused")]
+fn test_emergency_withdraw_rejected_when_unpaused() {
+    let env = Env::default();
+    let (client, _token_client, signers, recovery) = setup_recovery(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_emergency_withdraw(&program_id, &signers.get(0).unwrap());
+    client.approve_emergency_withdraw(&program_id, &signers.get(1).unwrap());
+    client.emergency_withdraw(&program_id, &recovery);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient recovery approvals")]
+fn test_emergency_withdraw_rejected_when_under_signed() {
+    let env = Env::default();
+    let (client, _token_client, signers, recovery) = setup_recovery(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_emergency_withdraw(&program_id, &signers.get(0).unwrap());
+    // A repeated approval from the same signer does not count twice.
+    client.approve_emergency_withdraw(&program_id, &signers.get(0).unwrap());
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&program_id, &recovery);
+}
+
+#[test]
+#[should_panic(expected = "Not the recovery address")]
+fn test_emergency_withdraw_rejects_other_target() {
+    let env = Env::default();
+    let (client, _token_client, signers, _recovery) = setup_recovery(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_emergency_withdraw(&program_id, &signers.get(0).unwrap());
+    client.approve_emergency_withdraw(&program_id, &signers.get(1).unwrap());
+
+    client.set_paused(&Some(true), &None, &None, &None);
+    client.emergency_withdraw(&program_id, &Address::generate(&env));
+}
+
+#[test]
+#[should_panic(expected = "Not a recovery signer")]
+fn test_approve_emergency_withdraw_rejects_non_signer() {
+    let env = Env::default();
+    let (client, _token_client, _signers, _recovery) = setup_recovery(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_emergency_withdraw(&program_id, &Address::generate(&env));
+}
+
+// =============================================================================
+// TESTS FOR RELEASE SCHEDULE DEPENDENCY CHAINS
+// =============================================================================
+
+#[test]
+fn test_dependent_schedule_releases_only_after_prerequisite() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+
+    let first = client.create_program_release_schedule(&recipient, &30_000, &100);
+    let second =
+        client.create_dependent_prog_schedule(&recipient, &20_000, &50, &Some(first.schedule_id));
+    let program_id = String::from_str(&env, "hack-2026");
+    assert_eq!(
+        client.get_schedule_dependency(&program_id, &second.schedule_id),
+        Some(first.schedule_id)
+    );
+
+    // The dependent is due first, but must wait for its prerequisite.
+    env.ledger().set_timestamp(60);
+    assert_eq!(client.trigger_program_releases(), 0);
+    assert_eq!(token_client.balance(&recipient), 0);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.trigger_program_releases(), 2);
+    assert_eq!(token_client.balance(&recipient), 50_000);
+}
+
+#[test]
+fn test_manual_release_of_chain_in_order() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+
+    let first = client.create_program_release_schedule(&recipient, &30_000, &100);
+    let second =
+        client.create_dependent_prog_schedule(&recipient, &20_000, &100, &Some(first.schedule_id));
+
+    client.release_program_schedule_manual(&first.schedule_id);
+    client.release_program_schedule_manual(&second.schedule_id);
+
+    assert!(
+        client
+            .get_program_release_schedule(&second.schedule_id)
+            .released
+    );
+    assert_eq!(token_client.balance(&recipient), 50_000);
+}
+
+#[test]
+#[should_panic(expected = "Dependency not yet released")]
+fn test_dependent_schedule_rejects_early_release() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 100_000);
+    let recipient = Address::generate(&env);
+
+    let first = client.create_program_release_schedule(&recipient, &30_000, &100);
+    let second =
+        client.create_dependent_prog_schedule(&recipient, &20_000, &100, &Some(first.schedule_id));
+
+    client.release_program_schedule_manual(&second.schedule_id);
+}
+
+// =============================================================================
+// TESTS FOR COOLDOWN RESET ON SUCCESSFUL PAYOUT
+// =============================================================================
+
+fn pay_one(env: &Env, client: &ProgramEscrowContractClient) {
+    client.batch_payout(
+        &vec![env, Address::generate(env)],
+        &vec![env, 1_000],
+        &false,
+        &false,
+    );
+}
+
+#[test]
+fn test_rapid_payouts_allowed_with_cooldown_reset() {
+    let env = Env::default();
+    let (client, _admin, _token_id) = setup_with_operation_limits(&env);
+    client.update_operation_rate_limit(&symbol_short!("batch_pay"), &3600, &3, &60);
+    client.set_reset_cooldown_on_success(&true);
+    assert!(client.get_reset_cooldown_on_success());
+    env.ledger().set_timestamp(1_000);
+
+    pay_one(&env, &client);
+    pay_one(&env, &client);
+    pay_one(&env, &client);
+    assert_eq!(client.get_remaining_balance(), 97_000);
+}
+
+#[test]
+#[should_panic(expected = "Rate limit exceeded")]
+fn test_cooldown_reset_still_counts_toward_window_cap() {
+    let env = Env::default();
+    let (client, _admin, _token_id) = setup_with_operation_limits(&env);
+    client.update_operation_rate_limit(&symbol_short!("batch_pay"), &3600, &2, &60);
+    client.set_reset_cooldown_on_success(&true);
+    env.ledger().set_timestamp(1_000);
+
+    pay_one(&env, &client);
+    pay_one(&env, &client);
+    pay_one(&env, &client);
+}
+
+#[test]
+#[should_panic(expected = "Operation in cooldown period")]
+fn test_rapid_payouts_blocked_without_cooldown_reset() {
+    let env = Env::default();
+    let (client, _admin, _token_id) = setup_with_operation_limits(&env);
+    client.update_operation_rate_limit(&symbol_short!("batch_pay"), &3600, &3, &60);
+    assert!(!client.get_reset_cooldown_on_success());
+    env.ledger().set_timestamp(1_000);
+
+    pay_one(&env, &client);
+    pay_one(&env, &client);
+}
+
+// =============================================================================
+// TESTS FOR FLAGGED SCHEDULES
+// =============================================================================
+
+#[test]
+fn test_flagged_schedules_lists_only_blacklisted_recipients() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    client.create_program_release_schedule(&alice, &10_000, &100);
+    let flagged_schedule = client.create_program_release_schedule(&bob, &10_000, &100);
+    client.create_program_release_schedule(&alice, &5_000, &200);
+    assert_eq!(client.get_flagged_schedules(&program_id).len(), 0);
+
+    client.add_program_blacklist(&program_id, &bob, &String::from_str(&env, "sanctions"));
+    assert!(!client.is_participant_allowed(&program_id, &bob));
+    assert!(client.is_participant_allowed(&program_id, &alice));
+
+    let flagged = client.get_flagged_schedules(&program_id);
+    assert_eq!(flagged, vec![&env, flagged_schedule.schedule_id]);
+
+    client.remove_program_blacklist(&program_id, &bob);
+    assert_eq!(client.get_flagged_schedules(&program_id).len(), 0);
+}
+
+// =============================================================================
+// TESTS FOR MULTISIG-GATED LOCKS
+// =============================================================================
+
+fn setup_lock_multisig(
+    env: &Env,
+) -> (
+    ProgramEscrowContractClient<'static>,
+    token::StellarAssetClient<'static>,
+    Address,
+    Address,
+) {
+    let (client, admin, _token, token_admin) = setup_program(env, 0);
+    client.set_admin(&admin);
+    let program_id = String::from_str(env, "hack-2026");
+
+    let organizer_a = Address::generate(env);
+    let organizer_b = Address::generate(env);
+    client.set_multisig_config(
+        &program_id,
+        &i128::MAX,
+        &vec![env, organizer_a.clone(), organizer_b.clone()],
+        &2,
+    );
+    client.set_lock_requires_multisig(&program_id, &true);
+    token_admin.mint(&client.address, &50_000);
+
+    (client, token_admin, organizer_a, organizer_b)
+}
+
+#[test]
+fn test_multisig_lock_commits_with_enough_approvals() {
+    let env = Env::default();
+    let (client, _token_admin, organizer_a, organizer_b) = setup_lock_multisig(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_lock(&program_id, &50_000, &organizer_a);
+    let approval = client.approve_lock(&program_id, &50_000, &organizer_b);
+    assert_eq!(approval.approvals.len(), 2);
+
+    let data = client.lock_program_funds(&50_000, &None);
+    assert_eq!(data.remaining_balance, 50_000);
+    // Approvals are consumed by the lock.
+    assert_eq!(client.get_lock_approval(&program_id).approvals.len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient lock approvals")]
+fn test_multisig_lock_reverts_without_enough_approvals() {
+    let env = Env::default();
+    let (client, _token_admin, organizer_a, _organizer_b) = setup_lock_multisig(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_lock(&program_id, &50_000, &organizer_a);
+    client.lock_program_funds(&50_000, &None);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient lock approvals")]
+fn test_multisig_lock_reverts_for_different_amount() {
+    let env = Env::default();
+    let (client, _token_admin, organizer_a, organizer_b) = setup_lock_multisig(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.approve_lock(&program_id, &20_000, &organizer_a);
+    client.approve_lock(&program_id, &20_000, &organizer_b);
+    client.lock_program_funds(&50_000, &None);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient lock approvals")]
+fn test_multisig_lock_from_depositor_requires_approvals() {
+    let env = Env::default();
+    let (client, token_admin, organizer_a, _organizer_b) = setup_lock_multisig(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+    let depositor = Address::generate(&env);
+    token_admin.mint(&depositor, &10_000);
+
+    client.approve_lock(&program_id, &10_000, &organizer_a);
+    client.lock_program_funds_from(&program_id, &depositor, &10_000);
+}
+
+#[test]
+fn test_multisig_lock_gates_registry_program() {
+    let env = Env::default();
+    let (client, admin, other) = setup_two_programs(&env);
+    client.set_admin(&admin);
+    let organizer_a = Address::generate(&env);
+    let organizer_b = Address::generate(&env);
+    client.set_multisig_config(
+        &other,
+        &i128::MAX,
+        &vec![&env, organizer_a.clone(), organizer_b.clone()],
+        &2,
+    );
+    client.set_lock_requires_multisig(&other, &true);
+
+    client.approve_lock(&other, &10_000, &organizer_a);
+    assert!(client
+        .try_lock_registry_program_funds(&other, &10_000)
+        .is_err());
+
+    client.approve_lock(&other, &10_000, &organizer_b);
+    let data = client.lock_registry_program_funds(&other, &10_000);
+    assert_eq!(data.remaining_balance, 10_000);
+    assert_eq!(client.get_lock_approval(&other).approvals.len(), 0);
+}
+
+// =============================================================================
+// TESTS FOR PER-PROGRAM PAUSE
+// =============================================================================
+
+fn setup_two_programs(env: &Env) -> (ProgramEscrowContractClient<'static>, Address, String) {
+    let (client, admin, _token, _token_admin) = setup_program(env, 100_000);
+    let other = String::from_str(env, "other-2026");
+    client.batch_initialize_programs(&vec![
+        env,
+        ProgramInitItem {
+            program_id: other.clone(),
+            authorized_payout_key: admin.clone(),
+            token_address: client.get_program_info().token_address,
+            reference_hash: None,
+        },
+    ]);
+    (client, admin, other)
+}
+
+#[test]
+fn test_pausing_one_program_leaves_other_running() {
+    let env = Env::default();
+    let (client, admin, other) = setup_two_programs(&env);
+
+    client.pause_program(&other, &admin);
+    assert!(client.is_program_paused(&other));
+    assert!(!client.is_program_paused(&String::from_str(&env, "hack-2026")));
+
+    client.single_payout(&Address::generate(&env), &10_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 5_000],
+        &false,
+        &false,
+    );
+    assert_eq!(client.get_remaining_balance(), 85_000);
+
+    let res = client.try_payout_claimable(&other, &Address::generate(&env), &1);
+    assert!(res.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Program paused")]
+fn test_paused_program_rejects_payouts() {
+    let env = Env::default();
+    let (client, admin, _other) = setup_two_programs(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.pause_program(&program_id, &admin);
+    client.single_payout(&Address::generate(&env), &10_000);
+}
+
+#[test]
+fn test_unpause_program_restores_payouts() {
+    let env = Env::default();
+    let (client, admin, _other) = setup_two_programs(&env);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.pause_program(&program_id, &admin);
+    client.unpause_program(&program_id, &admin);
+    client.single_payout(&Address::generate(&env), &10_000);
+    assert_eq!(client.get_remaining_balance(), 90_000);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_pause_program_rejects_unrelated_caller() {
+    let env = Env::default();
+    let (client, _admin, other) = setup_two_programs(&env);
+
+    client.pause_program(&other, &Address::generate(&env));
+}
+
+// =============================================================================
+// TESTS FOR SIGNATURE-BASED BATCH APPROVALS
+// =============================================================================
+
+fn register_signing_key(
+    env: &Env,
+    client: &ProgramEscrowContractClient,
+    signer: &Address,
+    seed: u8,
+) -> ed25519_dalek::SigningKey {
+    let key = ed25519_dalek::SigningKey::from_bytes(&[seed; 32]);
+    let public_key = BytesN::from_array(env, &key.verifying_key().to_bytes());
+    client.set_signer_key(signer, &public_key);
+    key
+}
+
+/// Sign a "hack-2026" payout approval with the signer's current nonce.
+fn sign_approval(
+    client: &ProgramEscrowContractClient,
+    key: &ed25519_dalek::SigningKey,
+    signer: &Address,
+    recipient: &Address,
+    amount: i128,
+    expiry: u64,
+) -> BytesN<64> {
+    use ed25519_dalek::Signer;
+    let env = &client.env;
+    let program_id = String::from_str(env, "hack-2026");
+    let nonce = client.get_signer_nonce(signer);
+    let payload =
+        client.get_payout_approval_payload(&program_id, recipient, &amount, &nonce, &expiry);
+    let mut message = [0u8; 256];
+    let len = payload.len() as usize;
+    payload.copy_into_slice(&mut message[..len]);
+    BytesN::from_array(env, &key.sign(&message[..len]).to_bytes())
+}
+
+#[test]
+fn test_approve_large_payout_signed_records_all_approvals() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 3_600;
+
+    let signers = [
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    client.set_multisig_config(
+        &program_id,
+        &10_000,
+        &vec![
+            &env,
+            signers[0].clone(),
+            signers[1].clone(),
+            signers[2].clone(),
+        ],
+        &3,
+    );
+
+    let mut approvals = Vec::new(&env);
+    for (i, signer) in signers.iter().enumerate() {
+        let key = register_signing_key(&env, &client, signer, i as u8 + 1);
+        let signature = sign_approval(&client, &key, signer, &recipient, 50_000, expiry);
+        approvals.push_back((signer.clone(), signature));
+    }
+
+    let approval =
+        client.approve_large_payout_signed(&program_id, &recipient, &50_000, &expiry, &approvals);
+    assert_eq!(approval.amount, 50_000);
+    assert_eq!(approval.approvals.len(), 3);
+    assert!(
+        approval.approvals.len() >= client.get_multisig_config(&program_id).required_signatures
+    );
+    assert_eq!(client.get_signer_nonce(&signers[0]), 1);
+
+    // The payout consumes the approval
+    client.single_payout(&recipient, &50_000);
+    assert_eq!(token_client.balance(&recipient), 50_000);
+    assert!(client
+        .get_payout_approval(&program_id, &recipient)
+        .approvals
+        .is_empty());
+}
+
+#[test]
+#[should_panic(expected = "Not a multisig signer")]
+fn test_approve_large_payout_signed_rejects_non_signer() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let outsider = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 3_600;
+    client.set_multisig_config(&program_id, &10_000, &vec![&env, signer], &1);
+
+    let key = register_signing_key(&env, &client, &outsider, 9);
+    let signature = sign_approval(&client, &key, &outsider, &recipient, 50_000, expiry);
+    let approvals = vec![&env, (outsider, signature)];
+
+    client.approve_large_payout_signed(&program_id, &recipient, &50_000, &expiry, &approvals);
+}
+
+#[test]
+#[should_panic]
+fn test_approve_large_payout_signed_rejects_bad_signature() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 3_600;
+    client.set_multisig_config(&program_id, &10_000, &vec![&env, signer.clone()], &1);
+
+    // Signed over a different amount than the one submitted
+    let key = register_signing_key(&env, &client, &signer, 7);
+    let signature = sign_approval(&client, &key, &signer, &recipient, 1, expiry);
+    let approvals = vec![&env, (signer, signature)];
+
+    client.approve_large_payout_signed(&program_id, &recipient, &50_000, &expiry, &approvals);
+}
+
+#[test]
+#[should_panic]
+fn test_approve_large_payout_signed_rejects_replayed_signature() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 200_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 3_600;
+    client.set_multisig_config(&program_id, &10_000, &vec![&env, signer.clone()], &1);
+
+    let key = register_signing_key(&env, &client, &signer, 7);
+    let signature = sign_approval(&client, &key, &signer, &recipient, 50_000, expiry);
+    let approvals = vec![&env, (signer, signature)];
+    client.approve_large_payout_signed(&program_id, &recipient, &50_000, &expiry, &approvals);
+    client.single_payout(&recipient, &50_000);
+
+    // The signer's nonce moved on, so the same signature no longer verifies
+    client.approve_large_payout_signed(&program_id, &recipient, &50_000, &expiry, &approvals);
+}
+
+#[test]
+#[should_panic(expected = "Approval signature expired")]
+fn test_approve_large_payout_signed_rejects_expired_signature() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let signer = Address::generate(&env);
+    let expiry = env.ledger().timestamp() + 3_600;
+    client.set_multisig_config(&program_id, &10_000, &vec![&env, signer.clone()], &1);
+
+    let key = register_signing_key(&env, &client, &signer, 7);
+    let signature = sign_approval(&client, &key, &signer, &recipient, 50_000, expiry);
+    let approvals = vec![&env, (signer, signature)];
+
+    env.ledger().with_mut(|li| li.timestamp = expiry + 1);
+    client.approve_large_payout_signed(&program_id, &recipient, &50_000, &expiry, &approvals);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient payout approvals")]
+fn test_large_payout_requires_fresh_approval() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let signer = Address::generate(&env);
+    client.set_multisig_config(&program_id, &10_000, &vec![&env, signer.clone()], &1);
+
+    client.approve_large_payout(&program_id, &recipient, &20_000, &signer);
+    client.single_payout(&recipient, &20_000);
+    // The first payout used up the approval
+    client.single_payout(&recipient, &20_000);
+}
+
+// =============================================================================
+// TESTS FOR DELIVERY VERIFICATION
+// =============================================================================
+
+#[test]
+fn test_single_payout_with_delivery_verification() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert!(!client.get_verify_delivery(&program_id));
+
+    // With verification on, the payout reads the recipient's balance before
+    // and after the transfer; a standard SAC credits the exact amount.
+    client.set_verify_delivery(&program_id, &true);
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &25_000);
+    client.single_payout(&recipient, &5_000);
+
+    assert_eq!(token_client.balance(&recipient), 30_000);
+    assert_eq!(client.get_remaining_balance(), 70_000);
+}
+
+#[test]
+fn test_single_payout_delivery_verification_accounts_for_fee() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_verify_delivery(&program_id, &true);
+    client.set_fee_config(&0, &500, &admin, &true);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &10_000);
+
+    // The check compares against the net amount, after the 5% payout fee
+    assert_eq!(token_client.balance(&recipient), 9_500);
+}
+
+// =============================================================================
+// TESTS FOR SCHEDULES BY RECIPIENT
+// =============================================================================
+
+#[test]
+fn test_query_schedules_by_recipient_filters_in_id_order() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let winner_a = Address::generate(&env);
+    let winner_b = Address::generate(&env);
+    let winner_c = Address::generate(&env);
+    let a1 = client.create_program_release_schedule(&winner_a, &10_000, &100);
+    client.create_program_release_schedule(&winner_b, &10_000, &100);
+    let a2 = client.create_program_release_schedule(&winner_a, &5_000, &50);
+    client.create_program_release_schedule(&winner_c, &10_000, &300);
+    let a3 = client.create_program_release_schedule(&winner_a, &2_000, &400);
+
+    let schedules = client.query_schedules_by_recipient(&program_id, &winner_a, &0, &10);
+    assert_eq!(schedules.len(), 3);
+    assert_eq!(schedules.get(0).unwrap().schedule_id, a1.schedule_id);
+    assert_eq!(schedules.get(1).unwrap().schedule_id, a2.schedule_id);
+    assert_eq!(schedules.get(2).unwrap().schedule_id, a3.schedule_id);
+    assert!(schedules.iter().all(|s| s.recipient == winner_a));
+
+    let none = client.query_schedules_by_recipient(&program_id, &Address::generate(&env), &0, &10);
+    assert_eq!(none.len(), 0);
+}
+
+// =============================================================================
+// TESTS FOR LOCK AND SCHEDULE
+// =============================================================================
+
+#[test]
+fn test_lock_and_schedule_monthly_split_with_remainder() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let month = 30 * 24 * 60 * 60;
+    env.ledger().set_timestamp(1_000);
+
+    token_admin.mint(&client.address, &120_005);
+    let schedules = client.lock_and_schedule(&program_id, &120_005, &recipient, &12, &month);
+
+    assert_eq!(client.get_remaining_balance(), 120_005);
+    assert_eq!(schedules.len(), 12);
+    for (i, schedule) in schedules.iter().enumerate() {
+        assert_eq!(schedule.recipient, recipient);
+        assert_eq!(schedule.release_timestamp, 1_000 + month * i as u64);
+        let expected = if i == 11 { 10_005 } else { 10_000 };
+        assert_eq!(schedule.amount, expected);
+    }
+    let total: i128 = schedules.iter().map(|s| s.amount).sum();
+    assert_eq!(total, 120_005);
+    assert_eq!(client.get_release_schedules().len(), 12);
+}
+
+#[test]
+#[should_panic(expected = "Count must be greater than zero")]
+fn test_lock_and_schedule_rejects_zero_count() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.lock_and_schedule(&program_id, &1_000, &Address::generate(&env), &0, &60);
+}
+
+// =============================================================================
+// TESTS FOR RELEASE ALL DUE ACROSS PROGRAMS
+// =============================================================================
+
+fn register_funded_program(
+    env: &Env,
+    client: &ProgramEscrowContractClient,
+    admin: &Address,
+    token_admin: &token::StellarAssetClient,
+    program_id: &str,
+    amount: i128,
+) -> String {
+    let program_id = String::from_str(env, program_id);
+    client.batch_initialize_programs(&vec![
+        env,
+        ProgramInitItem {
+            program_id: program_id.clone(),
+            authorized_payout_key: admin.clone(),
+            token_address: token_admin.address.clone(),
+            reference_hash: None,
+        },
+    ]);
+    token_admin.mint(&client.address, &amount);
+    client.lock_registry_program_funds(&program_id, &amount);
+    program_id
+}
+
+#[test]
+fn test_release_all_due_spans_programs_and_respects_max() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 25_000);
+    let beta = register_funded_program(&env, &client, &admin, &token_admin, "beta", 15_000);
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+    let r4 = Address::generate(&env);
+    client.create_program_schedule(&alpha, &r1, &10_000, &100);
+    client.create_program_schedule(&alpha, &r2, &10_000, &100);
+    client.create_program_schedule(&beta, &r3, &5_000, &100);
+    client.create_program_schedule(&beta, &r4, &5_000, &5_000);
+
+    env.ledger().set_timestamp(200);
+    assert_eq!(client.release_all_due(&2), 2);
+    assert_eq!(token_client.balance(&r1), 10_000);
+    assert_eq!(token_client.balance(&r2), 10_000);
+    assert_eq!(token_client.balance(&r3), 0);
+
+    assert_eq!(client.release_all_due(&10), 1);
+    assert_eq!(token_client.balance(&r3), 5_000);
+    // Not yet due
+    assert_eq!(token_client.balance(&r4), 0);
+    assert!(client.get_program_schedule(&beta, &1).unwrap().released);
+    assert!(!client.get_program_schedule(&beta, &2).unwrap().released);
+    assert_eq!(
+        client.get_program_financials(&alpha).remaining_balance,
+        5_000
+    );
+}
+
+#[test]
+fn test_release_all_due_skips_paused_program() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    let beta = register_funded_program(&env, &client, &admin, &token_admin, "beta", 10_000);
+
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    client.create_program_schedule(&alpha, &r1, &10_000, &0);
+    client.create_program_schedule(&beta, &r2, &10_000, &0);
+    client.pause_program(&alpha, &admin);
+
+    assert_eq!(client.release_all_due(&10), 1);
+    assert_eq!(token_client.balance(&r1), 0);
+    assert_eq!(token_client.balance(&r2), 10_000);
+}
+
+#[test]
+fn test_batch_create_prog_schedules_sequential_ids() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    client.create_program_schedule(&alpha, &Address::generate(&env), &1_000, &100);
+
+    let mut items = Vec::new(&env);
+    for i in 0..10u64 {
+        items.push_back(ScheduleItem {
+            recipient: Address::generate(&env),
+            amount: 900,
+            release_timestamp: 100 + i,
+        });
+    }
+    let ids = client.batch_create_prog_schedules(&alpha, &items);
+
+    assert_eq!(ids.len(), 10);
+    for (i, id) in ids.iter().enumerate() {
+        assert_eq!(id, 2 + i as u64);
+        let schedule = client.get_program_schedule(&alpha, &id).unwrap();
+        assert_eq!(schedule.amount, 900);
+        assert_eq!(schedule.release_timestamp, 100 + i as u64);
+    }
+    // Pending schedules never exceed the program balance
+    let committed: i128 = (1..12u64)
+        .map(|id| client.get_program_schedule(&alpha, &id).unwrap().amount)
+        .sum();
+    assert_eq!(committed, 10_000);
+    assert!(committed <= client.get_program_financials(&alpha).remaining_balance);
+}
+
+#[test]
+#[should_panic(expected = "Schedules exceed program balance")]
+fn test_batch_create_prog_schedules_rejects_overcommit() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 1_000);
+
+    let items = vec![
+        &env,
+        ScheduleItem {
+            recipient: Address::generate(&env),
+            amount: 600,
+            release_timestamp: 100,
+        },
+        ScheduleItem {
+            recipient: Address::generate(&env),
+            amount: 600,
+            release_timestamp: 200,
+        },
+    ];
+    client.batch_create_prog_schedules(&alpha, &items);
+}
+
+#[test]
+fn test_get_next_release_time_returns_earliest_pending() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    assert_eq!(client.get_next_release_time(&alpha), None);
+
+    client.create_program_schedule(&alpha, &Address::generate(&env), &1_000, &500);
+    client.create_program_schedule(&alpha, &Address::generate(&env), &1_000, &100);
+    client.create_program_schedule(&alpha, &Address::generate(&env), &1_000, &300);
+    assert_eq!(client.get_next_release_time(&alpha), Some(100));
+
+    env.ledger().set_timestamp(150);
+    assert_eq!(client.release_all_due(&10), 1);
+    assert_eq!(client.get_next_release_time(&alpha), Some(300));
+}
+
+#[test]
+fn test_get_next_release_time_none_when_all_released() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    let beta = register_funded_program(&env, &client, &admin, &token_admin, "beta", 10_000);
+    client.create_program_schedule(&alpha, &Address::generate(&env), &2_000, &100);
+    client.create_program_schedule(&alpha, &Address::generate(&env), &2_000, &200);
+    client.create_program_schedule(&beta, &Address::generate(&env), &2_000, &900);
+
+    env.ledger().set_timestamp(1_000);
+    assert_eq!(client.release_all_due(&10), 3);
+    assert_eq!(client.get_next_release_time(&alpha), None);
+    assert_eq!(client.get_next_release_time(&beta), None);
+}
+
+#[test]
+fn test_reschedule_release_moves_pending_schedule() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    let recipient = Address::generate(&env);
+    let schedule = client.create_program_schedule(&alpha, &recipient, &1_000, &100);
+
+    let moved = client.reschedule_release(&alpha, &schedule.schedule_id, &500);
+    assert_eq!(moved.schedule_id, schedule.schedule_id);
+    assert_eq!(moved.release_timestamp, 500);
+    assert_eq!(
+        client
+            .get_program_schedule(&alpha, &schedule.schedule_id)
+            .unwrap()
+            .release_timestamp,
+        500
+    );
+
+    // No longer due at the original time
+    env.ledger().set_timestamp(200);
+    assert_eq!(client.release_all_due(&10), 0);
+    env.ledger().set_timestamp(500);
+    assert_eq!(client.release_all_due(&10), 1);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Schedule already released")]
+fn test_reschedule_release_rejects_released_schedule() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    let schedule = client.create_program_schedule(&alpha, &Address::generate(&env), &1_000, &100);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.release_all_due(&10), 1);
+    client.reschedule_release(&alpha, &schedule.schedule_id, &1_000);
+}
+
+#[test]
+fn test_reassign_schedule_recipient_redirects_release() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    let old_recipient = Address::generate(&env);
+    let new_recipient = Address::generate(&env);
+    let schedule = client.create_program_schedule(&alpha, &old_recipient, &3_000, &100);
+
+    let updated = client.reassign_schedule_recipient(&alpha, &schedule.schedule_id, &new_recipient);
+    assert_eq!(updated.recipient, new_recipient);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.release_all_due(&10), 1);
+    assert_eq!(token_client.balance(&new_recipient), 3_000);
+    assert_eq!(token_client.balance(&old_recipient), 0);
+}
+
+#[test]
+#[should_panic(expected = "Schedule already released")]
+fn test_reassign_schedule_recipient_rejects_released_schedule() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let alpha = register_funded_program(&env, &client, &admin, &token_admin, "alpha", 10_000);
+    let schedule = client.create_program_schedule(&alpha, &Address::generate(&env), &3_000, &100);
+
+    env.ledger().set_timestamp(100);
+    assert_eq!(client.release_all_due(&10), 1);
+    client.reassign_schedule_recipient(&alpha, &schedule.schedule_id, &Address::generate(&env));
+}
+
+/// Token whose transfers always fail, standing in for a recipient that
+/// cannot receive (e.g. a missing trustline).
+#[contract]
+pub struct FailingToken;
+
+#[contractimpl]
+impl FailingToken {
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        i128::MAX
+    }
+
+    pub fn transfer(_env: Env, _from: Address, _to: Address, _amount: i128) {
+        panic!("Recipient cannot receive");
+    }
+}
+
+#[test]
+fn test_release_all_due_records_failures_until_schedule_fails() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let failing_token = env.register_contract(None, FailingToken);
+    let program_id = String::from_str(&env, "broken");
+    client.batch_initialize_programs(&vec![
+        &env,
+        ProgramInitItem {
+            program_id: program_id.clone(),
+            authorized_payout_key: admin.clone(),
+            token_address: failing_token,
+            reference_hash: None,
+        },
+    ]);
+    client.lock_registry_program_funds(&program_id, &10_000);
+    client.set_max_release_failures(&program_id, &2);
+    client.create_program_schedule(&program_id, &Address::generate(&env), &4_000, &0);
+
+    assert_eq!(client.release_all_due(&10), 0);
+    let failure = client.get_schedule_failure(&program_id, &1);
+    assert_eq!(failure.failure_count, 1);
+    assert_eq!(
+        failure.last_failure_reason,
+        Some(Symbol::new(&env, "xfer_fail"))
+    );
+    assert!(!failure.failed);
+    assert!(
+        !client
+            .get_program_schedule(&program_id, &1)
+            .unwrap()
+            .released
+    );
+
+    assert_eq!(client.release_all_due(&10), 0);
+    let failure = client.get_schedule_failure(&program_id, &1);
+    assert_eq!(failure.failure_count, 2);
+    assert!(failure.failed);
+
+    // A failed schedule is no longer retried
+    assert_eq!(client.release_all_due(&10), 0);
+    assert_eq!(
+        client.get_schedule_failure(&program_id, &1).failure_count,
+        2
+    );
+    assert_eq!(client.get_failed_schedules(&program_id).len(), 1);
+    assert_eq!(
+        client.get_program_financials(&program_id).remaining_balance,
+        10_000
+    );
+}
+
+#[test]
+fn test_single_schedule_releases_record_and_honour_failures() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+    let failing_token = env.register_contract(None, FailingToken);
+    let program_id = String::from_str(&env, "broken");
+    client.init_program(&program_id, &admin, &failing_token, &admin, &None, &None);
+    client.set_admin(&admin);
+    client.lock_program_funds(&10_000, &None);
+    client.set_max_release_failures(&program_id, &2);
+    let schedule = client.create_program_release_schedule(&Address::generate(&env), &4_000, &0);
+
+    client.release_prog_schedule_automatic(&schedule.schedule_id);
+    assert_eq!(
+        client
+            .get_schedule_failure(&program_id, &schedule.schedule_id)
+            .failure_count,
+        1
+    );
+    client.release_program_schedule_manual(&schedule.schedule_id);
+
+    let failure = client.get_schedule_failure(&program_id, &schedule.schedule_id);
+    assert_eq!(failure.failure_count, 2);
+    assert!(failure.failed);
+    assert_eq!(client.get_remaining_balance(), 10_000);
+
+    // Once failed, no path retries it
+    assert!(client
+        .try_release_prog_schedule_automatic(&schedule.schedule_id)
+        .is_err());
+    assert!(client
+        .try_release_program_schedule_manual(&schedule.schedule_id)
+        .is_err());
+    assert_eq!(client.trigger_program_releases(), 0);
+    assert_eq!(
+        client
+            .get_schedule_failure(&program_id, &schedule.schedule_id)
+            .failure_count,
+        2
+    );
+}
+
+// =============================================================================
+// TESTS FOR PAYOUT CATEGORIES
+// =============================================================================
+
+fn last_payout_notice_category(env: &Env) -> Symbol {
+    let (_, topics, _) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok())
+                == Some(Symbol::new(env, "PayNotice"))
+        })
+        .last()
+        .expect("PayNotice event");
+    Symbol::try_from_val(env, &topics.get(1).unwrap()).unwrap()
+}
+
+#[test]
+fn test_payout_categories_appear_in_notice_topics_and_history() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let first = Symbol::new(&env, "first");
+    let runnerup = Symbol::new(&env, "runnerup");
+
+    client.single_payout_with_category(&Address::generate(&env), &10_000, &Some(first.clone()));
+    assert_eq!(last_payout_notice_category(&env), first);
+
+    client.single_payout_with_category(&Address::generate(&env), &5_000, &Some(runnerup.clone()));
+    assert_eq!(last_payout_notice_category(&env), runnerup);
+
+    assert_eq!(client.get_payout_category(&program_id, &0), first);
+    assert_eq!(client.get_payout_category(&program_id, &1), runnerup);
+}
+
+#[test]
+fn test_payouts_default_to_generic_category() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let grant = Symbol::new(&env, "grant");
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    assert_eq!(last_payout_notice_category(&env), DEFAULT_PAYOUT_CATEGORY);
+
+    client.batch_payout_with_category(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_000, 2_000],
+        &Some(grant.clone()),
+    );
+    assert_eq!(last_payout_notice_category(&env), grant);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 500],
+        &false,
+        &false,
+    );
+    assert_eq!(last_payout_notice_category(&env), DEFAULT_PAYOUT_CATEGORY);
+
+    assert_eq!(client.get_program_info().payout_history.len(), 4);
+    assert_eq!(
+        client.get_payout_category(&program_id, &0),
+        DEFAULT_PAYOUT_CATEGORY
+    );
+    assert_eq!(client.get_payout_category(&program_id, &1), grant);
+    assert_eq!(client.get_payout_category(&program_id, &2), grant);
+    assert_eq!(
+        client.get_payout_category(&program_id, &3),
+        DEFAULT_PAYOUT_CATEGORY
+    );
+}
+
+// =============================================================================
+// TESTS FOR PAYOUT EVENT PRIVACY
+// =============================================================================
+
+fn contains_bytes(haystack: &Bytes, needle: &Bytes) -> bool {
+    if needle.len() > haystack.len() {
+        return false;
+    }
+    (0..=haystack.len() - needle.len()).any(|i| haystack.slice(i..i + needle.len()) == *needle)
+}
+
+/// Whether any event published by `contract` mentions `recipient` in its
+/// topics or data. Token transfer events are published by the token, not the
+/// escrow, and are out of scope.
+fn escrow_events_mention(env: &Env, contract: &Address, recipient: &Address) -> bool {
+    let needle = recipient.clone().to_xdr(env);
+    env.events()
+        .all()
+        .iter()
+        .filter(|(source, _, _)| source == contract)
+        .any(|(_, topics, data)| {
+            contains_bytes(&topics.to_xdr(env), &needle)
+                || contains_bytes(&data.to_xdr(env), &needle)
+        })
+}
+
+#[test]
+fn test_event_privacy_defaults_to_full_transparency() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert!(!client.get_event_privacy(&program_id));
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &1_000);
+    assert!(escrow_events_mention(&env, &client.address, &recipient));
+}
+
+#[test]
+fn test_event_privacy_hashes_recipient_and_omits_amount() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_event_privacy(&program_id, &true);
+    assert!(client.get_event_privacy(&program_id));
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &12_345);
+    assert!(!escrow_events_mention(&env, &client.address, &recipient));
+
+    let (_, _, data) = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "PrivPay"))
+        })
+        .last()
+        .expect("PrivPay event");
+    let event = PrivatePayoutEvent::try_from_val(&env, &data).unwrap();
+    let salt: BytesN<32> = env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get(&(EVENT_SALT, program_id.clone()))
+            .unwrap()
+    });
+    let mut preimage: Bytes = salt.into();
+    preimage.append(&recipient.clone().to_xdr(&env));
+    let expected: BytesN<32> = env.crypto().sha256(&preimage).into();
+    assert_eq!(event.recipient_hash, expected);
+    // Unsalted hashes of known addresses don't match
+    let unsalted: BytesN<32> = env.crypto().sha256(&recipient.clone().to_xdr(&env)).into();
+    assert_ne!(event.recipient_hash, unsalted);
+    assert_eq!(event.category, DEFAULT_PAYOUT_CATEGORY);
+
+    // Transfers and the payout history are unaffected
+    assert_eq!(token_client.balance(&recipient), 12_345);
+    let record = client.get_program_info().payout_history.get(0).unwrap();
+    assert_eq!(record.recipient, recipient);
+    assert_eq!(record.amount, 12_345);
+}
+
+#[test]
+fn test_event_privacy_applies_to_batch_payouts() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_event_privacy(&program_id, &true);
+
+    let first = Address::generate(&env);
+    let second = Address::generate(&env);
+    client.batch_payout(
+        &vec![&env, first.clone(), second.clone()],
+        &vec![&env, 1_000, 2_000],
+        &false,
+        &false,
+    );
+    assert!(!escrow_events_mention(&env, &client.address, &first));
+    assert!(!escrow_events_mention(&env, &client.address, &second));
+}
+
+// =============================================================================
+// TESTS FOR PAYOUT RESERVES
+// =============================================================================
+
+#[test]
+fn test_payout_within_reserve_headroom_succeeds() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_reserve(&program_id, &20_000);
+    assert_eq!(client.get_reserve(&program_id), 20_000);
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &50_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 30_000],
+        &false,
+        &false,
+    );
+    assert_eq!(token_client.balance(&recipient), 50_000);
+    assert_eq!(client.get_remaining_balance(), 20_000);
+}
+
+#[test]
+#[should_panic(expected = "Payout would breach reserve")]
+fn test_single_payout_breaching_reserve_reverts() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    client.set_reserve(&String::from_str(&env, "hack-2026"), &20_000);
+
+    client.single_payout(&Address::generate(&env), &80_001);
+}
+
+#[test]
+fn test_batch_payout_breaching_reserve_reverts() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_reserve(&program_id, &20_000);
+
+    let result = client.try_batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 40_000, 40_001],
+        &false,
+        &false,
+    );
+    assert!(result.is_err());
+    assert_eq!(client.get_remaining_balance(), 100_000);
+
+    // Lowering the reserve frees the headroom
+    client.set_reserve(&program_id, &0);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 40_000, 40_001],
+        &false,
+        &false,
+    );
+    assert_eq!(client.get_remaining_balance(), 19_999);
+}
+
+// =============================================================================
+// TESTS FOR COMMITTED POOLS
+// =============================================================================
+
+#[test]
+fn test_finalize_pool_matching_commitment_blocks_further_locks() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_committed_pool(&program_id, &100_000);
+    assert_eq!(client.get_committed_pool(&program_id), 100_000);
+    assert!(!client.is_pool_finalized(&program_id));
+
+    let data = client.finalize_pool(&program_id);
+    assert_eq!(data.total_funds, 100_000);
+    assert!(client.is_pool_finalized(&program_id));
+
+    assert!(client.try_lock_program_funds(&1_000, &None).is_err());
+    assert!(client
+        .try_lock_program_funds_v2(&program_id, &1_000)
+        .is_err());
+    assert!(client
+        .try_set_committed_pool(&program_id, &101_000)
+        .is_err());
+    assert_eq!(client.get_program_info().total_funds, 100_000);
+}
+
+#[test]
+#[should_panic(expected = "Pool underfunded")]
+fn test_finalize_pool_rejects_underfunded_pool() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_committed_pool(&program_id, &150_000);
+    client.finalize_pool(&program_id);
+}
+
+#[test]
+#[should_panic(expected = "Pool overfunded")]
+fn test_finalize_pool_rejects_overfunded_pool() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_committed_pool(&program_id, &50_000);
+    client.finalize_pool(&program_id);
+}
+
+#[test]
+fn test_finalize_pool_succeeds_once_topped_up() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_committed_pool(&program_id, &150_000);
+    assert!(client.try_finalize_pool(&program_id).is_err());
+
+    client.lock_program_funds(&50_000, &None);
+    client.finalize_pool(&program_id);
+    assert!(client.is_pool_finalized(&program_id));
+}
+
+// =============================================================================
+// TESTS FOR BATCH RECEIPTS
+// =============================================================================
+
+#[test]
+fn test_batch_receipts_record_each_batch() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    env.ledger().set_timestamp(1_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 10_000, 5_000],
+        &false,
+        &false,
+    );
+    env.ledger().set_timestamp(2_000);
+    let data = client.batch_payout(
+        &vec![
+            &env,
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ],
+        &vec![&env, 1_000, 2_000, 3_000],
+        &false,
+        &false,
+    );
+
+    let first = client.get_batch_receipt(&program_id, &1).unwrap();
+    assert_eq!(first.batch_id, 1);
+    assert_eq!(first.total_amount, 15_000);
+    assert_eq!(first.recipient_count, 2);
+    assert_eq!(first.timestamp, 1_000);
+
+    let second = client.get_batch_receipt(&program_id, &2).unwrap();
+    assert_eq!(second.batch_id, 2);
+    assert_eq!(second.total_amount, 6_000);
+    assert_eq!(second.recipient_count, 3);
+    assert_eq!(second.timestamp, 2_000);
+
+    assert_eq!(client.get_batch_receipt(&program_id, &3), None);
+    // Per-recipient history is still recorded
+    assert_eq!(data.payout_history.len(), 5);
+}
+
+// =============================================================================
+// TESTS FOR LOCK MEMOS
+// =============================================================================
+
+#[test]
+fn test_lock_program_funds_with_memo_stores_and_emits_memo() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+    let memo = String::from_str(&env, "INV-2024-001");
+
+    let data = client.lock_program_funds(&5_000, &Some(memo.clone()));
+    assert_eq!(data.remaining_balance, 5_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert_eq!(client.get_lock_memo(&program_id), Some(memo.clone()));
+
+    let event = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "LockMemo"))
+        })
+        .map(|(_, _, data)| LockMemoEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .expect("LockMemo event");
+    assert_eq!(event.amount, 5_000);
+    assert_eq!(event.memo, memo);
+}
+
+#[test]
+fn test_lock_program_funds_without_memo_clears_memo() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+
+    client.lock_program_funds(&1_000, &Some(String::from_str(&env, "INV-1")));
+    let data = client.lock_program_funds(&1_000, &None);
+    assert_eq!(data.remaining_balance, 2_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert_eq!(client.get_lock_memo(&program_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Memo too long")]
+fn test_lock_program_funds_with_memo_rejects_long_memo() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 0);
+    let memo = String::from_bytes(&env, &[b'x'; 129]);
+
+    client.lock_program_funds(&1_000, &Some(memo));
+}
+
+// =============================================================================
+// TESTS FOR FEE-AWARE PAYOUT EVENTS
+// =============================================================================
+
+#[test]
+fn test_payout_events_carry_gross_fee_and_net_amounts() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    // 2% payout fee
+    client.set_fee_config(&0, &200, &admin, &true);
+
+    client.single_payout(&Address::generate(&env), &10_000);
+    let payout = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "Payout"))
+        })
+        .map(|(_, _, data)| PayoutEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .expect("Payout event");
+    assert_eq!(payout.amount, 10_000);
+    assert_eq!(payout.gross_amount, 10_000);
+    assert_eq!(payout.fee_amount, 200);
+    assert_eq!(payout.net_amount, 9_800);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 5_000, 2_500],
+        &false,
+        &false,
+    );
+    let batch = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "BatchPay"))
+        })
+        .map(|(_, _, data)| BatchPayoutEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .expect("BatchPayout event");
+    assert_eq!(batch.total_amount, 7_500);
+    assert_eq!(batch.fee_amount, 150);
+    assert_eq!(batch.net_amount, 7_350);
+}
+
+#[test]
+fn test_payout_events_carry_usd_equivalent_at_reference_rate() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    // 0.25 USD per token unit
+    client.set_reference_rate(&program_id, &250_000);
+    assert_eq!(client.get_reference_rate(&program_id), Some(250_000));
+
+    let recipient = Address::generate(&env);
+    client.single_payout(&recipient, &4_000);
+    let payout = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "Payout"))
+        })
+        .map(|(_, _, data)| PayoutEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .expect("Payout event");
+    assert_eq!(payout.usd_equivalent, Some(1_000_000_000));
+    // The transfer itself stays in tokens
+    assert_eq!(token_client.balance(&recipient), 4_000);
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_000, 3],
+        &false,
+        &false,
+    );
+    let batch = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "BatchPay"))
+        })
+        .map(|(_, _, data)| BatchPayoutEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .expect("BatchPayout event");
+    assert_eq!(batch.usd_equivalent, Some(1_003 * 250_000));
+}
+
+#[test]
+fn test_tagged_program_payout_events_carry_tag_topic() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let tag = Symbol::new(&env, "high_val");
+    client.tag_program(&program_id, &tag);
+    assert_eq!(client.get_program_tag(&program_id), Some(tag.clone()));
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    let (_, topics, _) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "Payout"))
+        })
+        .expect("Payout event");
+    assert_eq!(topics.len(), 2);
+    assert_eq!(
+        Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+        tag
+    );
+
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 500],
+        &false,
+        &false,
+    );
+    let (_, topics, _) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "BatchPay"))
+        })
+        .expect("BatchPayout event");
+    assert_eq!(topics.len(), 2);
+    assert_eq!(
+        Symbol::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+        tag
+    );
+}
+
+#[test]
+fn test_untagged_program_payout_events_keep_single_topic() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    assert_eq!(
+        client.get_program_tag(&String::from_str(&env, "hack-2026")),
+        None
+    );
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    let (_, topics, _) = env
+        .events()
+        .all()
+        .iter()
+        .find(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "Payout"))
+        })
+        .expect("Payout event");
+    assert_eq!(topics.len(), 1);
+}
+
+#[test]
+fn test_payout_events_omit_usd_equivalent_without_rate() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_reference_rate(&program_id, &1_000_000);
+    client.set_reference_rate(&program_id, &0);
+    assert_eq!(client.get_reference_rate(&program_id), None);
+
+    client.single_payout(&Address::generate(&env), &500);
+    let payout = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(Symbol::new(&env, "Payout"))
+        })
+        .map(|(_, _, data)| PayoutEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .expect("Payout event");
+    assert_eq!(payout.usd_equivalent, None);
+}
+
+// =============================================================================
+// TESTS FOR IDEMPOTENT PROGRAM INIT
+// =============================================================================
+
+#[test]
+fn test_init_program_idempotent_returns_existing_on_matching_params() {
+    let env = Env::default();
+    let (client, admin, token, _token_admin) = setup_program(&env, 5_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    let data =
+        client.init_program_idempotent(&program_id, &admin, &token.address, &admin, &None, &None);
+    assert_eq!(data.program_id, program_id);
+    assert_eq!(data.total_funds, 5_000);
+    assert_eq!(data.remaining_balance, 5_000);
+    assert_eq!(client.get_program_info(), data);
+}
+
+#[test]
+#[should_panic(expected = "Conflicting program configuration")]
+fn test_init_program_idempotent_panics_on_conflicting_params() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 0);
+    let other_token = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    client.init_program_idempotent(
+        &String::from_str(&env, "hack-2026"),
+        &admin,
+        &other_token,
+        &admin,
+        &None,
+        &None,
+    );
+}
+
+// =============================================================================
+// TESTS FOR BALANCE SNAPSHOT EVENTS
+// =============================================================================
+
+fn last_balance_snapshot(env: &Env) -> Option<BalanceSnapshotEvent> {
+    env.events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(env, &t).ok())
+                == Some(Symbol::new(env, "BalSnap"))
+        })
+        .map(|(_, _, data)| BalanceSnapshotEvent::try_from_val(env, &data).unwrap())
+        .last()
+}
+
+#[test]
+fn test_balance_snapshot_matches_state_after_lock_and_payout() {
+    let env = Env::default();
+    let (client, admin, _token, token_admin) = setup_program(&env, 0);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_admin(&admin);
+    client.set_balance_snapshots(&program_id, &true);
+    env.ledger().set_timestamp(1_000);
+
+    token_admin.mint(&client.address, &10_000);
+    let locked = client.lock_program_funds(&10_000, &None);
+    let snapshot = last_balance_snapshot(&env).expect("snapshot after lock");
+    assert_eq!(snapshot.program_id, program_id);
+    assert_eq!(snapshot.remaining_balance, locked.remaining_balance);
+    assert_eq!(snapshot.timestamp, 1_000);
+
+    env.ledger().set_timestamp(2_000);
+    let paid = client.single_payout(&Address::generate(&env), &3_000);
+    let snapshot = last_balance_snapshot(&env).expect("snapshot after payout");
+    assert_eq!(snapshot.remaining_balance, paid.remaining_balance);
+    assert_eq!(snapshot.remaining_balance, 7_000);
+    assert_eq!(snapshot.timestamp, 2_000);
+}
+
+#[test]
+fn test_balance_snapshots_disabled_by_default() {
+    let env = Env::default();
+    let (client, _admin, _token, token_admin) = setup_program(&env, 0);
+
+    token_admin.mint(&client.address, &10_000);
+    client.lock_program_funds(&10_000, &None);
+    assert!(last_balance_snapshot(&env).is_none());
+}
+
+// =============================================================================
+// TESTS FOR PAYOUT BLACKOUT WINDOWS
+// =============================================================================
+
+#[test]
+#[should_panic(expected = "Payouts frozen during blackout window")]
+fn test_payout_inside_blackout_window_reverts() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    client.set_blackout_window(&String::from_str(&env, "hack-2026"), &1_000, &2_000);
+
+    env.ledger().set_timestamp(1_500);
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "Payouts frozen during blackout window")]
+fn test_batch_payout_inside_blackout_window_reverts() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    client.set_blackout_window(&String::from_str(&env, "hack-2026"), &1_000, &2_000);
+
+    env.ledger().set_timestamp(1_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env)],
+        &vec![&env, 1_000],
+        &false,
+        &false,
+    );
+}
+
+#[test]
+fn test_payout_outside_blackout_window_succeeds() {
+    let env = Env::default();
+    let (client, admin, token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    client.set_blackout_window(&String::from_str(&env, "hack-2026"), &1_000, &2_000);
+    let recipient = Address::generate(&env);
+
+    env.ledger().set_timestamp(2_000);
+    client.single_payout(&recipient, &1_000);
+    assert_eq!(token.balance(&recipient), 1_000);
+}
+
+#[test]
+fn test_clearing_blackout_window_reenables_payouts() {
+    let env = Env::default();
+    let (client, admin, token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_blackout_window(&program_id, &1_000, &2_000);
+    let recipient = Address::generate(&env);
+
+    env.ledger().set_timestamp(1_500);
+    assert!(client.try_single_payout(&recipient, &1_000).is_err());
+
+    client.clear_blackout_window(&program_id);
+    assert_eq!(client.get_blackout_window(&program_id), None);
+    client.single_payout(&recipient, &1_000);
+    assert_eq!(token.balance(&recipient), 1_000);
+}
+
+// =============================================================================
+// TESTS FOR PERMANENT FREEZE
+// =============================================================================
+
+fn freeze(env: &Env, client: &ProgramEscrowContractClient, admin: &Address) {
+    client.permanently_freeze(admin, &String::from_str(env, FREEZE_CONFIRMATION));
+}
+
+#[test]
+fn test_permanent_freeze_blocks_payouts_and_locks() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    assert!(!client.is_permanently_frozen());
+
+    freeze(&env, &client, &admin);
+    assert!(client.is_permanently_frozen());
+
+    assert!(client.try_single_payout(&recipient, &1_000).is_err());
+    assert!(client
+        .try_batch_payout(
+            &vec![&env, recipient.clone()],
+            &vec![&env, 1_000],
+            &false,
+            &false
+        )
+        .is_err());
+    assert!(client.try_lock_program_funds(&1_000, &None).is_err());
+    assert!(client
+        .try_lock_program_funds_v2(&program_id, &1_000)
+        .is_err());
+    assert!(client
+        .try_payout_claimable(&program_id, &recipient, &1_000)
+        .is_err());
+
+    // Views stay readable
+    assert_eq!(client.get_remaining_balance(), 100_000);
+    assert_eq!(client.get_program_info().total_funds, 100_000);
+    assert_eq!(token_client.balance(&recipient), 0);
+}
+
+#[test]
+fn test_permanent_freeze_cannot_be_undone() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    freeze(&env, &client, &admin);
+
+    assert!(client
+        .try_set_paused(&Some(false), &Some(false), &Some(false), &None)
+        .is_err());
+    assert!(client.try_set_admin(&Address::generate(&env)).is_err());
+    assert!(client
+        .try_permanently_freeze(&admin, &String::from_str(&env, FREEZE_CONFIRMATION))
+        .is_err());
+    assert!(client.try_set_maintenance_mode(&false).is_err());
+    assert!(client.is_permanently_frozen());
+    assert_eq!(client.get_admin(), Some(admin));
+}
+
+#[test]
+#[should_panic(expected = "Invalid freeze confirmation")]
+fn test_permanent_freeze_requires_exact_confirmation() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    client.permanently_freeze(&admin, &String::from_str(&env, "freeze"));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_permanent_freeze_rejects_non_admin() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 100_000);
+    client.set_admin(&admin);
+    freeze(&env, &client, &Address::generate(&env));
+}
+
+// =============================================================================
+// TESTS FOR PROPORTIONAL REFUNDS
+// =============================================================================
+
+#[test]
+fn test_refund_all_proportional_splits_by_contribution() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mint(&alice, &30_000);
+    token_admin.mint(&bob, &10_000);
+
+    client.lock_program_funds_from(&program_id, &alice, &30_000);
+    client.lock_program_funds_from(&program_id, &bob, &10_000);
+    let contributions = client.get_contributions(&program_id);
+    assert_eq!(contributions.get(alice.clone()), Some(30_000));
+    assert_eq!(contributions.get(bob.clone()), Some(10_000));
+    assert_eq!(token_client.balance(&client.address), 40_000);
+
+    // Part of the pool is already paid out before cancellation
+    client.single_payout(&Address::generate(&env), &8_000);
+    assert_eq!(client.get_remaining_balance(), 32_000);
+
+    let refunds = client.refund_all_proportional(&program_id);
+    assert_eq!(refunds.get(alice.clone()), Some(24_000));
+    assert_eq!(refunds.get(bob.clone()), Some(8_000));
+    assert_eq!(token_client.balance(&alice), 24_000);
+    assert_eq!(token_client.balance(&bob), 8_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_contributions(&program_id).len(), 0);
+}
+
+#[test]
+fn test_refund_all_proportional_sums_to_remaining_balance() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mint(&alice, &1);
+    token_admin.mint(&bob, &2);
+
+    client.lock_program_funds_from(&program_id, &alice, &1);
+    client.lock_program_funds_from(&program_id, &bob, &2);
+    client.single_payout(&Address::generate(&env), &1);
+
+    let refunds = client.refund_all_proportional(&program_id);
+    let total: i128 = refunds.values().iter().sum();
+    assert_eq!(total, 2);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(
+        client.get_program_financials(&program_id).lifetime_refunded,
+        2
+    );
+}
+
+#[test]
+fn test_refund_all_proportional_leaves_untracked_funds() {
+    let env = Env::default();
+    let (client, admin, token_client, token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    token_admin.mint(&alice, &30_000);
+    token_admin.mint(&bob, &10_000);
+
+    client.lock_program_funds_from(&program_id, &alice, &30_000);
+    client.lock_program_funds_from(&program_id, &bob, &10_000);
+    // Top-up outside the contribution tracking
+    token_admin.mint(&client.address, &5_000);
+    client.lock_program_funds(&5_000, &None);
+    assert_eq!(client.get_remaining_balance(), 45_000);
+
+    let refunds = client.refund_all_proportional(&program_id);
+    assert_eq!(refunds.get(alice.clone()), Some(30_000));
+    assert_eq!(refunds.get(bob.clone()), Some(10_000));
+    assert_eq!(token_client.balance(&alice), 30_000);
+    assert_eq!(token_client.balance(&bob), 10_000);
+    assert_eq!(client.get_remaining_balance(), 5_000);
+    assert_eq!(token_client.balance(&client.address), 5_000);
+}
+
+#[test]
+#[should_panic(expected = "No contributions")]
+fn test_refund_all_proportional_without_contributions() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    client.refund_all_proportional(&String::from_str(&env, "hack-2026"));
+}
+
+// =============================================================================
+// TESTS FOR CANCELLATION REFUNDS
+// =============================================================================
+
+#[test]
+fn test_refund_on_cancel_returns_unreleased_schedule_funds() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let funder = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    let released = client.create_program_release_schedule(&recipient, &20_000, &(now + 10));
+    let pending = client.create_program_release_schedule(&recipient, &15_000, &(now + 1_000));
+    env.ledger().set_timestamp(now + 10);
+    client.release_prog_schedule_automatic(&released.schedule_id);
+
+    client.cancel_program(&program_id);
+    assert!(client.is_program_cancelled(&program_id));
+    assert!(client.is_program_paused(&program_id));
+
+    // The unreleased schedule can no longer vest
+    env.ledger().set_timestamp(now + 1_000);
+    assert!(client
+        .try_release_prog_schedule_automatic(&pending.schedule_id)
+        .is_err());
+
+    assert_eq!(client.refund_on_cancel(&program_id, &funder), 30_000);
+    assert_eq!(token_client.balance(&funder), 30_000);
+    assert_eq!(token_client.balance(&recipient), 20_000);
+    assert_eq!(client.get_remaining_balance(), 0);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(
+        client.get_program_financials(&program_id).lifetime_refunded,
+        30_000
+    );
+}
+
+#[test]
+fn test_refund_on_cancel_waits_for_pending_claims() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let winner = Address::generate(&env);
+    let funder = Address::generate(&env);
+
+    let now = env.ledger().timestamp();
+    let claim_id = client.create_pending_claim(&program_id, &winner, &10_000, &(now + 100));
+    client.cancel_program(&program_id);
+
+    assert!(client.try_refund_on_cancel(&program_id, &funder).is_err());
+
+    // Once the claim settles, the rest of the balance is refunded
+    client.execute_claim(&program_id, &claim_id, &winner);
+    assert_eq!(client.refund_on_cancel(&program_id, &funder), 40_000);
+    assert_eq!(token_client.balance(&winner), 10_000);
+    assert_eq!(token_client.balance(&funder), 40_000);
+}
+
+#[test]
+#[should_panic(expected = "Program not cancelled")]
+fn test_refund_on_cancel_requires_cancelled_program() {
+    let env = Env::default();
+    let (client, admin, _token, _token_admin) = setup_program(&env, 10_000);
+    client.set_admin(&admin);
+    client.refund_on_cancel(
+        &String::from_str(&env, "hack-2026"),
+        &Address::generate(&env),
+    );
+}
+
+// =============================================================================
+// TESTS FOR PAYOUT STATS
+// =============================================================================
+
+#[test]
+fn test_payout_stats_for_uneven_payouts() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+
+    client.single_payout(&Address::generate(&env), &5_000);
+    client.batch_payout(
+        &vec![&env, Address::generate(&env), Address::generate(&env)],
+        &vec![&env, 1_000, 20_000],
+        &false,
+        &false,
+    );
+    client.single_payout(&Address::generate(&env), &4_001);
+
+    let stats = client.get_payout_stats(&program_id);
+    assert_eq!(stats.count, 4);
+    assert_eq!(stats.total, 30_001);
+    assert_eq!(stats.average, 7_500);
+    assert_eq!(stats.min, 1_000);
+    assert_eq!(stats.max, 20_000);
+}
+
+#[test]
+fn test_payout_stats_empty_history_is_zero() {
+    let env = Env::default();
+    let (client, _admin, _token, _token_admin) = setup_program(&env, 100_000);
+
+    let stats = client.get_payout_stats(&String::from_str(&env, "hack-2026"));
+    assert_eq!(
+        stats,
+        PayoutStats {
+            count: 0,
+            total: 0,
+            average: 0,
+            min: 0,
+            max: 0,
+        }
+    );
+}
+
+// =============================================================================
+// TESTS FOR CHECKPOINTED BATCH PAYOUTS
+// =============================================================================
+
+#[test]
+fn test_checkpointed_batch_reports_last_committed_index() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 100_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let key = BytesN::from_array(&env, &[1; 32]);
+    let mut recipients = Vec::new(&env);
+    let mut amounts = Vec::new(&env);
+    for i in 1..=5 {
+        recipients.push_back(Address::generate(&env));
+        amounts.push_back(1_000 * i as i128);
+    }
+
+    assert_eq!(client.get_batch_progress(&program_id, &key), 0);
+
+    let progress = client.batch_payout_checkpointed(&program_id, &key, &recipients, &amounts, &2);
+    assert_eq!(progress, 2);
+    assert_eq!(client.get_batch_progress(&program_id, &key), 2);
+    assert_eq!(token_client.balance(&recipients.get(1).unwrap()), 2_000);
+    assert_eq!(token_client.balance(&recipients.get(2).unwrap()), 0);
+
+    client.batch_payout_checkpointed(&program_id, &key, &recipients, &amounts, &2);
+    assert_eq!(client.get_batch_progress(&program_id, &key), 4);
+    assert_eq!(token_client.balance(&recipients.get(4).unwrap()), 0);
+
+    client.batch_payout_checkpointed(&program_id, &key, &recipients, &amounts, &2);
+    assert_eq!(client.get_batch_progress(&program_id, &key), 5);
+    assert_eq!(token_client.balance(&recipients.get(4).unwrap()), 5_000);
+    assert_eq!(client.get_remaining_balance(), 85_000);
+
+    // A completed batch is not paid twice
+    assert_eq!(
+        client.batch_payout_checkpointed(&program_id, &key, &recipients, &amounts, &2),
+        5
+    );
+    assert_eq!(client.get_remaining_balance(), 85_000);
+}
+
+#[test]
+fn test_checkpointed_batch_failed_chunk_keeps_previous_checkpoint() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 3_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let key = BytesN::from_array(&env, &[2; 32]);
+    let recipients = vec![
+        &env,
+        Address::generate(&env),
+        Address::generate(&env),
+        Address::generate(&env),
+    ];
+    let amounts = vec![&env, 2_000, 2_000, 2_000];
+
+    client.batch_payout_checkpointed(&program_id, &key, &recipients, &amounts, &1);
+    assert!(client
+        .try_batch_payout_checkpointed(&program_id, &key, &recipients, &amounts, &1)
+        .is_err());
+    assert_eq!(client.get_batch_progress(&program_id, &key), 1);
+    assert_eq!(client.get_remaining_balance(), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Idempotency key used for a different batch")]
+fn test_checkpointed_batch_rejects_reused_key() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 10_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let key = BytesN::from_array(&env, &[3; 32]);
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+
+    client.batch_payout_checkpointed(&program_id, &key, &recipients, &vec![&env, 100, 200], &1);
+    client.batch_payout_checkpointed(&program_id, &key, &recipients, &vec![&env, 100, 300], &1);
+}
+
+// =============================================================================
+// TESTS FOR STORAGE TTL EXTENSION
+// =============================================================================
+
+#[test]
+fn test_extend_program_ttl_keeps_program_alive() {
+    use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let schedule = client.create_program_schedule(&program_id, &recipient, &5_000, &(now + 10));
+    let claim_id = client.create_pending_claim(&program_id, &recipient, &1_000, &(now + 100));
+    assert_eq!(client.get_last_ttl_extension(&program_id), 0);
+
+    client.extend_program_ttl(&program_id, &100_000);
+    assert_eq!(client.get_last_ttl_extension(&program_id), 100_000);
+    env.as_contract(&client.address, || {
+        let schedule_key = DataKey::ReleaseSchedule(program_id.clone(), schedule.schedule_id);
+        let claim_key = DataKey::PendingClaim(program_id.clone(), claim_id);
+        assert!(env.storage().persistent().get_ttl(&schedule_key) >= 100_000);
+        assert!(env.storage().persistent().get_ttl(&claim_key) >= 100_000);
+        assert!(env.storage().instance().get_ttl() >= 100_000);
+    });
+
+    // Far beyond the default TTL of freshly written entries
+    env.ledger().with_mut(|li| li.sequence_number += 50_000);
+    assert_eq!(client.get_last_ttl_extension(&program_id), 50_000);
+    let stored = client
+        .get_program_schedule(&program_id, &schedule.schedule_id)
+        .unwrap();
+    assert_eq!(stored.amount, 5_000);
+    assert_eq!(client.get_claim(&program_id, &claim_id).amount, 1_000);
+    assert_eq!(client.get_remaining_balance(), 49_000);
+}
+
+#[test]
+fn test_mutations_extend_instance_ttl() {
+    use soroban_sdk::testutils::storage::Instance as _;
+
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    let instance_ttl = || env.as_contract(&client.address, || env.storage().instance().get_ttl());
+
+    // The initial lock already bumped the instance with the default policy
+    let (threshold, extend_to) = client.get_ttl_policy();
+    assert!(threshold < extend_to);
+    assert_eq!(instance_ttl(), extend_to);
+
+    // Re-extend to the full period on every mutation
+    client.set_ttl_policy(&admin, &1_000_000, &1_000_000);
+    assert_eq!(client.get_ttl_policy(), (1_000_000, 1_000_000));
+    assert_eq!(instance_ttl(), 1_000_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    client.single_payout(&Address::generate(&env), &1_000);
+    assert_eq!(instance_ttl(), 1_000_000);
+
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    let now = env.ledger().timestamp();
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &1_000, &(now + 10));
+    assert_eq!(instance_ttl(), 1_000_000);
+
+    env.ledger().with_mut(|li| {
+        li.sequence_number += 1_000;
+        li.timestamp = now + 10;
+    });
+    client.release_program_schedule_manual(&schedule.schedule_id);
+    assert_eq!(instance_ttl(), 1_000_000);
+
+    // Config setters bump the instance as well
+    env.ledger().with_mut(|li| li.sequence_number += 1_000);
+    client.set_release_balance_check(&true);
+    assert_eq!(instance_ttl(), 1_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Invalid TTL policy")]
+fn test_set_ttl_policy_rejects_threshold_above_extension() {
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 0);
+    client.set_admin(&admin);
+    client.set_ttl_policy(&admin, &2_000, &1_000);
+}
+
+#[test]
+fn test_event_seq_increments_per_emitting_operation() {
+    let env = Env::default();
+    let (client, _admin, _token_client, token_admin) = setup_program(&env, 0);
+    assert_eq!(client.get_event_seq(), 0);
+
+    token_admin.mint(&client.address, &50_000);
+    client.lock_program_funds(&50_000, &None);
+    assert_eq!(client.get_event_seq(), 1);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+    assert_eq!(client.get_event_seq(), 2);
+    let payout = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(symbol_short!("Payout"))
+        })
+        .map(|(_, _, data)| PayoutEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .unwrap();
+    assert_eq!(payout.event_seq, 2);
+
+    let recipients = vec![&env, Address::generate(&env), Address::generate(&env)];
+    let amounts = vec![&env, 1_000, 2_000];
+    client.batch_payout(&recipients, &amounts, &false, &false);
+    assert_eq!(client.get_event_seq(), 3);
+
+    token_admin.mint(&client.address, &5_000);
+    client.lock_program_funds(&5_000, &None);
+    assert_eq!(client.get_event_seq(), 4);
+    let locked = env
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| {
+            topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+                == Some(symbol_short!("FndsLock"))
+        })
+        .map(|(_, _, data)| FundsLockedEvent::try_from_val(&env, &data).unwrap())
+        .last()
+        .unwrap();
+    assert_eq!(locked.event_seq, 4);
+}
+
+#[test]
+fn test_event_seq_covers_releases_refunds_and_snapshots() {
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    assert_eq!(client.get_event_seq(), 1);
+
+    let recipient = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    let first = client.create_program_release_schedule(&recipient, &10_000, &(now + 100));
+    let second = client.create_program_release_schedule(&recipient, &10_000, &(now + 100));
+
+    client.release_program_schedule_manual(&first.schedule_id);
+    env.ledger().set_timestamp(now + 100);
+    client.release_prog_schedule_automatic(&second.schedule_id);
+    assert_eq!(client.get_event_seq(), 3);
+
+    let mut released: Vec<ScheduleReleasedEvent> = Vec::new(&env);
+    for (_, topics, data) in env.events().all().iter() {
+        if topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(&env, &t).ok())
+            == Some(Symbol::new(&env, "SchedRel"))
+        {
+            released.push_back(ScheduleReleasedEvent::try_from_val(&env, &data).unwrap());
+        }
+    }
+    assert_eq!(released.len(), 2);
+    let manual = released.get(0).unwrap();
+    assert_eq!(manual.schedule_id, first.schedule_id);
+    assert_eq!(manual.release_type, ReleaseType::Manual);
+    assert_eq!(manual.event_seq, 2);
+    let automatic = released.get(1).unwrap();
+    assert_eq!(automatic.release_type, ReleaseType::Automatic);
+    assert_eq!(automatic.event_seq, 3);
+
+    client.set_admin(&admin);
+    client.set_balance_snapshots(&program_id, &true);
+    client.cancel_program(&program_id);
+    client.refund_on_cancel(&program_id, &admin);
+
+    // The refund and the snapshot that follows it each take a number
+    assert_eq!(client.get_event_seq(), 5);
+    assert_eq!(last_balance_snapshot(&env).unwrap().event_seq, 5);
+}
+
+// =============================================================================
+// TESTS FOR RECIPIENT ALLOW-LIST
+// =============================================================================
+
+#[test]
+fn test_allowlist_enforced_permits_allowed_recipients() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+
+    client.set_program_recipient_allowed(&program_id, &r1, &true);
+    client.set_program_recipient_allowed(&program_id, &r2, &true);
+    client.set_allowlist_enforced(&program_id, &true);
+    assert!(client.get_allowlist_enforced(&program_id));
+    assert!(client.is_program_recipient_allowed(&program_id, &r1));
+
+    client.single_payout(&r1, &1_000);
+    let recipients = vec![&env, r1.clone(), r2.clone()];
+    client.batch_payout(&recipients, &vec![&env, 2_000, 3_000], &false, &false);
+    assert_eq!(token_client.balance(&r1), 3_000);
+    assert_eq!(token_client.balance(&r2), 3_000);
+}
+
+#[test]
+#[should_panic(expected = "RecipientNotAllowed")]
+fn test_allowlist_enforced_rejects_single_payout() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    client.set_allowlist_enforced(&program_id, &true);
+
+    client.single_payout(&Address::generate(&env), &1_000);
+}
+
+#[test]
+#[should_panic(expected = "RecipientNotAllowed")]
+fn test_allowlist_enforced_rejects_batch_with_one_disallowed() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let allowed = Address::generate(&env);
+    client.set_program_recipient_allowed(&program_id, &allowed, &true);
+    client.set_allowlist_enforced(&program_id, &true);
+
+    let recipients = vec![&env, allowed, Address::generate(&env)];
+    client.batch_payout(&recipients, &vec![&env, 1_000, 1_000], &false, &false);
+}
+
+#[test]
+#[should_panic(expected = "RecipientNotAllowed")]
+fn test_allowlist_enforced_rejects_schedule_release() {
+    let env = Env::default();
+    let (client, _admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let now = env.ledger().timestamp();
+    let schedule =
+        client.create_program_release_schedule(&Address::generate(&env), &1_000, &(now + 10));
+    client.set_allowlist_enforced(&program_id, &true);
+
+    env.ledger().set_timestamp(now + 10);
+    client.release_prog_schedule_automatic(&schedule.schedule_id);
+}
+
+#[test]
+fn test_allowlist_enforced_bulk_release_skips_disallowed() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let allowed = Address::generate(&env);
+    let other = Address::generate(&env);
+    let now = env.ledger().timestamp();
+    client.create_program_release_schedule(&other, &1_000, &(now + 10));
+    client.create_program_release_schedule(&allowed, &2_000, &(now + 10));
+    client.set_program_recipient_allowed(&program_id, &allowed, &true);
+    client.set_allowlist_enforced(&program_id, &true);
+
+    env.ledger().set_timestamp(now + 10);
+    assert_eq!(client.trigger_program_releases(), 1);
+    assert_eq!(token_client.balance(&allowed), 2_000);
+    assert_eq!(token_client.balance(&other), 0);
+    assert!(!client.get_release_schedules().get(0).unwrap().released);
+}
+
+#[test]
+fn test_allowlist_not_enforced_pays_anyone() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let program_id = String::from_str(&env, "hack-2026");
+    let listed = Address::generate(&env);
+    let unlisted = Address::generate(&env);
+    client.set_program_recipient_allowed(&program_id, &listed, &true);
+    assert!(!client.get_allowlist_enforced(&program_id));
+
+    client.single_payout(&unlisted, &1_000);
+    let now = env.ledger().timestamp();
+    let schedule = client.create_program_release_schedule(&unlisted, &2_000, &(now + 10));
+    env.ledger().set_timestamp(now + 10);
+    client.release_prog_schedule_automatic(&schedule.schedule_id);
+    assert_eq!(token_client.balance(&unlisted), 3_000);
+
+    // Enforcing and then lifting enforcement restores open payouts
+    client.set_allowlist_enforced(&program_id, &true);
+    client.set_allowlist_enforced(&program_id, &false);
+    client.single_payout(&unlisted, &500);
+    assert_eq!(token_client.balance(&unlisted), 3_500);
+
+    client.set_program_recipient_allowed(&program_id, &listed, &false);
+    assert!(!client.is_program_recipient_allowed(&program_id, &listed));
+}
+
+#[test]
+fn test_heartbeat_emits_timestamp_and_health() {
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    env.ledger().set_timestamp(1_700_000_000);
+    assert_eq!(client.get_last_heartbeat(), None);
+
+    assert_eq!(client.heartbeat(), 1_700_000_000);
+    let (contract, topics, data) = env.events().all().last().unwrap();
+    assert_eq!(contract, client.address);
+    assert_eq!(
+        Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+        Symbol::new(&env, "Heartbeat")
+    );
+    let event = HeartbeatEvent::try_from_val(&env, &data).unwrap();
+    assert_eq!(event.timestamp, 1_700_000_000);
+    assert_eq!(event.healthy, client.get_health_detailed().healthy);
+    assert!(event.healthy);
+    assert_eq!(client.get_last_heartbeat(), Some(1_700_000_000));
+
+    // Without recording, the heartbeat only emits
+    client.set_heartbeat_config(&true, &false);
+    assert_eq!(client.get_heartbeat_config(), (true, false));
+    env.ledger().set_timestamp(1_700_000_060);
+    assert_eq!(client.heartbeat(), 1_700_000_060);
+    assert_eq!(client.get_last_heartbeat(), Some(1_700_000_000));
+}
+
+// =============================================================================
+// TESTS FOR PROGRAM BLACKLIST
+// =============================================================================
+
+#[test]
+fn test_blacklisted_recipient_mid_batch_aborts_whole_batch() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let r1 = Address::generate(&env);
+    let r2 = Address::generate(&env);
+    let r3 = Address::generate(&env);
+
+    let reason = String::from_str(&env, "sanctions match");
+    client.add_program_blacklist(&program_id, &r2, &reason);
+    assert!(client.is_blacklisted(&program_id, &r2));
+    assert_eq!(client.get_blacklist_reason(&program_id, &r2), Some(reason));
+
+    let recipients = vec![&env, r1.clone(), r2.clone(), r3.clone()];
+    let amounts = vec![&env, 1_000, 2_000, 3_000];
+    let result = client.try_batch_payout(&recipients, &amounts, &false, &false);
+    assert!(result.is_err());
+
+    // Nobody was paid, including the recipient ahead of the blacklisted one
+    assert_eq!(token_client.balance(&r1), 0);
+    assert_eq!(token_client.balance(&r3), 0);
+    assert_eq!(client.get_remaining_balance(), 50_000);
+    assert_eq!(token_client.balance(&client.address), 50_000);
+}
+
+#[test]
+#[should_panic(expected = "RecipientBlacklisted")]
+fn test_blacklisted_recipient_rejected_by_single_payout() {
+    let env = Env::default();
+    let (client, admin, _token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    let reason = String::from_str(&env, "sanctions");
+    client.add_program_blacklist(&program_id, &recipient, &reason);
+
+    client.single_payout(&recipient, &1_000);
+}
+
+#[test]
+fn test_removed_from_blacklist_can_be_paid() {
+    let env = Env::default();
+    let (client, admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    client.set_admin(&admin);
+    let program_id = String::from_str(&env, "hack-2026");
+    let recipient = Address::generate(&env);
+    client.add_program_blacklist(&program_id, &recipient, &String::from_str(&env, "review"));
+    client.remove_program_blacklist(&program_id, &recipient);
+    assert!(!client.is_blacklisted(&program_id, &recipient));
+    assert_eq!(client.get_blacklist_reason(&program_id, &recipient), None);
+
+    client.single_payout(&recipient, &1_000);
+    assert_eq!(token_client.balance(&recipient), 1_000);
+}
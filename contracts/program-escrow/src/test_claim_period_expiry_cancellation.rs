@@ -18,7 +18,7 @@
 
 use soroban_sdk::{
     testutils::{Address as _, Ledger, LedgerInfo},
-    token, Address, Env, String,
+    token, vec, Address, Env, String,
 };
 
 use crate::{
@@ -75,7 +75,7 @@ fn setup<'a>() -> TestSetup<'a> {
     );
 
     // lock funds
-    client.lock_program_funds(&500_000_i128);
+    client.lock_program_funds(&500_000_i128, &None);
 
     client.set_admin(&admin);
 
@@ -387,3 +387,299 @@ fn test_wrong_recipient_cannot_execute_claim() {
     // An unrelated address tries to execute the claim — should panic
     t.client.execute_claim(&t.program_id, &claim_id, &impostor);
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 9: Batch cancel skips already-executed claims in lenient mode
+// ═══════════════════════════════════════════════════════════════════════════
+
+fn create_three_claims_one_executed(t: &TestSetup) -> (u64, u64, u64) {
+    let now: u64 = t.env.ledger().timestamp();
+    let first =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &1_000_i128, &(now + 86_400));
+    let second =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &2_000_i128, &(now + 86_400));
+    let third =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &3_000_i128, &(now + 86_400));
+
+    t.client
+        .execute_claim(&t.program_id, &second, &t.contributor);
+
+    (first, second, third)
+}
+
+#[test]
+fn test_batch_cancel_claims_lenient_skips_executed() {
+    let t = setup();
+    let (first, second, third) = create_three_claims_one_executed(&t);
+    let balance_before = t.client.get_remaining_balance();
+
+    let cancelled = t.client.batch_cancel_claims(
+        &t.program_id,
+        &vec![&t.env, first, second, third],
+        &t.admin,
+        &false,
+    );
+
+    assert_eq!(cancelled, 2);
+    assert_eq!(t.client.get_remaining_balance(), balance_before + 4_000);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &first).status,
+        ClaimStatus::Cancelled
+    );
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &second).status,
+        ClaimStatus::Completed
+    );
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &third).status,
+        ClaimStatus::Cancelled
+    );
+    assert_eq!(t.token.balance(&t.contributor), 2_000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 10: Batch cancel rejects the whole batch in strict mode
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_batch_cancel_claims_strict_rejects_executed() {
+    let t = setup();
+    let (first, second, third) = create_three_claims_one_executed(&t);
+    let balance_before = t.client.get_remaining_balance();
+
+    let result = t.client.try_batch_cancel_claims(
+        &t.program_id,
+        &vec![&t.env, first, second, third],
+        &t.admin,
+        &true,
+    );
+    assert!(result.is_err());
+
+    // Nothing was cancelled
+    assert_eq!(t.client.get_remaining_balance(), balance_before);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &first).status,
+        ClaimStatus::Pending
+    );
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &third).status,
+        ClaimStatus::Pending
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 11: Forced expiry blocks a live claim
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+#[should_panic(expected = "ClaimExpired")]
+fn test_expire_claim_now_blocks_execution() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    let claim_id =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &5_000_i128, &(now + 86_400));
+
+    t.client.expire_claim_now(&t.program_id, &claim_id);
+    let claim = t.client.get_claim(&t.program_id, &claim_id);
+    assert!(claim.claim_deadline < now);
+    assert_eq!(claim.status, ClaimStatus::Pending);
+
+    // Same ledger, well within the original window
+    t.client
+        .execute_claim(&t.program_id, &claim_id, &t.contributor);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 12: A force-expired claim can be cancelled back into escrow
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_expired_claim_now_can_be_cancelled() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    let claim_id =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &5_000_i128, &(now + 86_400));
+    let balance_before = t.client.get_remaining_balance();
+
+    t.client.expire_claim_now(&t.program_id, &claim_id);
+    assert!(t
+        .client
+        .try_execute_claim(&t.program_id, &claim_id, &t.contributor)
+        .is_err());
+
+    t.client.cancel_claim(&t.program_id, &claim_id, &t.admin);
+    assert_eq!(t.client.get_remaining_balance(), balance_before + 5_000);
+    assert_eq!(t.token.balance(&t.contributor), 0);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 13: Early-claim penalty applies before the ready time only
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_early_claim_penalty_on_pending_claims() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    t.client
+        .set_early_claim_penalty(&t.program_id, &2_500, &(now + 3_600));
+
+    let early =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &8_000_i128, &(now + 86_400));
+    let late =
+        t.client
+            .create_pending_claim(&t.program_id, &t.contributor, &4_000_i128, &(now + 86_400));
+    assert_eq!(t.client.get_remaining_balance(), 500_000 - 12_000);
+
+    // 25% of the early claim returns to the pool
+    t.client
+        .execute_claim(&t.program_id, &early, &t.contributor);
+    assert_eq!(t.token.balance(&t.contributor), 6_000);
+    assert_eq!(t.client.get_remaining_balance(), 500_000 - 12_000 + 2_000);
+
+    t.env.ledger().set(LedgerInfo {
+        timestamp: now + 3_600,
+        ..t.env.ledger().get()
+    });
+    t.client.execute_claim(&t.program_id, &late, &t.contributor);
+    assert_eq!(t.token.balance(&t.contributor), 10_000);
+    assert_eq!(t.client.get_remaining_balance(), 500_000 - 12_000 + 2_000);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 14: A due schedule is pulled by its recipient within the window
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_schedule_claim_within_window() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    let schedule = t
+        .client
+        .create_program_release_schedule(&t.contributor, &7_000_i128, &now);
+
+    let claim_id = t
+        .client
+        .authorize_schedule_claim(&t.program_id, &schedule.schedule_id);
+    assert_eq!(
+        t.client
+            .get_schedule_claim(&t.program_id, &schedule.schedule_id),
+        Some(claim_id)
+    );
+    let claim = t.client.get_claim(&t.program_id, &claim_id);
+    assert_eq!(claim.status, ClaimStatus::Pending);
+    assert_eq!(claim.claim_deadline, now + t.client.get_claim_window());
+    assert_eq!(t.client.get_remaining_balance(), 500_000 - 7_000);
+    assert!(t.client.get_release_schedules().get(0).unwrap().released);
+
+    t.env.ledger().set(LedgerInfo {
+        timestamp: now + 3_600,
+        ..t.env.ledger().get()
+    });
+    let claimed = t
+        .client
+        .claim_schedule(&t.program_id, &schedule.schedule_id);
+    assert_eq!(claimed, 7_000);
+    assert_eq!(t.token.balance(&t.contributor), 7_000);
+    assert_eq!(t.client.get_remaining_balance(), 500_000 - 7_000);
+    assert_eq!(
+        t.client.get_claim(&t.program_id, &claim_id).status,
+        ClaimStatus::Completed
+    );
+    assert_eq!(t.client.get_program_release_history().len(), 1);
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TEST 15: An unclaimed schedule expires and is cancelled back into escrow
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[test]
+fn test_schedule_claim_expires_and_cancels() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    let schedule = t
+        .client
+        .create_program_release_schedule(&t.contributor, &7_000_i128, &now);
+    let claim_id = t
+        .client
+        .authorize_schedule_claim(&t.program_id, &schedule.schedule_id);
+
+    t.env.ledger().set(LedgerInfo {
+        timestamp: now + t.client.get_claim_window() + 1,
+        ..t.env.ledger().get()
+    });
+    assert!(t
+        .client
+        .try_claim_schedule(&t.program_id, &schedule.schedule_id)
+        .is_err());
+
+    t.client.cancel_claim(&t.program_id, &claim_id, &t.admin);
+    assert_eq!(t.client.get_remaining_balance(), 500_000);
+    assert_eq!(t.token.balance(&t.contributor), 0);
+
+    // The schedule is open again and can be authorized afresh
+    assert!(!t.client.get_release_schedules().get(0).unwrap().released);
+    assert_eq!(
+        t.client
+            .get_schedule_claim(&t.program_id, &schedule.schedule_id),
+        None
+    );
+    let reissued = t
+        .client
+        .authorize_schedule_claim(&t.program_id, &schedule.schedule_id);
+    assert_ne!(reissued, claim_id);
+}
+
+#[test]
+fn test_schedule_claim_records_amount_net_of_penalty() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    t.client
+        .set_early_claim_penalty(&t.program_id, &2_500, &(now + 3_600));
+    let schedule = t
+        .client
+        .create_program_release_schedule(&t.contributor, &8_000_i128, &now);
+    t.client
+        .authorize_schedule_claim(&t.program_id, &schedule.schedule_id);
+
+    let claimed = t
+        .client
+        .claim_schedule(&t.program_id, &schedule.schedule_id);
+    assert_eq!(claimed, 6_000);
+    assert_eq!(t.token.balance(&t.contributor), 6_000);
+    let history = t.client.get_program_release_history();
+    assert_eq!(history.get(0).unwrap().amount, 6_000);
+}
+
+#[test]
+#[should_panic(expected = "Funds Paused")]
+fn test_schedule_claim_respects_release_pause() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    let schedule = t
+        .client
+        .create_program_release_schedule(&t.contributor, &7_000_i128, &now);
+    t.client
+        .authorize_schedule_claim(&t.program_id, &schedule.schedule_id);
+
+    t.client.set_paused(&None, &Some(true), &None, &None);
+    t.client
+        .claim_schedule(&t.program_id, &schedule.schedule_id);
+}
+
+#[test]
+#[should_panic(expected = "Not yet due")]
+fn test_schedule_claim_requires_due_schedule() {
+    let t = setup();
+    let now: u64 = t.env.ledger().timestamp();
+    let schedule =
+        t.client
+            .create_program_release_schedule(&t.contributor, &7_000_i128, &(now + 100));
+    t.client
+        .authorize_schedule_claim(&t.program_id, &schedule.schedule_id);
+}
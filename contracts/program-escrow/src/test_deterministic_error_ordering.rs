@@ -64,7 +64,7 @@ fn setup_initialized(
 fn test_lock_not_initialized_beats_invalid_amount() {
     let (_env, client, _admin, _token_id) = setup_env();
     // Contract not initialized AND amount is invalid (0) — must get NotInitialized first
-    client.lock_program_funds(&0);
+    client.lock_program_funds(&0, &None);
 }
 
 /// Priority 2 beats priority 3: not-initialized is returned even when paused would also apply.
@@ -73,7 +73,7 @@ fn test_lock_not_initialized_beats_invalid_amount() {
 fn test_lock_not_initialized_beats_paused() {
     let (_env, client, _admin, _token_id) = setup_env();
     // No program initialized — must get NotInitialized, not FundsPaused
-    client.lock_program_funds(&1000);
+    client.lock_program_funds(&1000, &None);
 }
 
 /// Priority 3 beats priority 5: paused is returned even when amount is also invalid.
@@ -88,7 +88,7 @@ fn test_lock_paused_beats_invalid_amount() {
     client.set_paused(&Some(true), &None, &None, &None);
 
     // Both paused AND amount=0 are invalid — must get FundsPaused first
-    client.lock_program_funds(&0);
+    client.lock_program_funds(&0, &None);
 }
 
 /// Priority 5 (amount validation) fires after all higher-priority checks pass.
@@ -102,7 +102,7 @@ fn test_lock_invalid_amount_after_all_higher_checks_pass() {
     token_admin.mint(&client.address, &10_000);
 
     // Contract initialized, not paused — only amount is invalid
-    client.lock_program_funds(&0);
+    client.lock_program_funds(&0, &None);
 }
 
 // ── batch_payout ──────────────────────────────────────────────────────────────
@@ -113,7 +113,7 @@ fn test_lock_invalid_amount_after_all_higher_checks_pass() {
 fn test_batch_payout_not_initialized_beats_empty_batch() {
     let (env, client, _admin, _token_id) = setup_env();
     let recipient = Address::generate(&env);
-    client.batch_payout(&vec![&env, recipient], &vec![&env, 0i128]);
+    client.batch_payout(&vec![&env, recipient], &vec![&env, 0i128], &false, &false);
 }
 
 /// Priority 3 beats priority 5: paused is returned even when batch inputs are also invalid.
@@ -124,14 +124,14 @@ fn test_batch_payout_paused_beats_invalid_input() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &10_000);
-    client.lock_program_funds(&10_000);
+    client.lock_program_funds(&10_000, &None);
 
     // Pause release operations
     client.set_paused(&None, &Some(true), &None, &None);
 
     let recipient = Address::generate(&env);
     // Both paused AND amount=0 — must get FundsPaused first
-    client.batch_payout(&vec![&env, recipient], &vec![&env, 0i128]);
+    client.batch_payout(&vec![&env, recipient], &vec![&env, 0i128], &false, &false);
 }
 
 /// Priority 5 (empty batch) fires after all higher-priority checks pass.
@@ -142,9 +142,9 @@ fn test_batch_payout_empty_batch_after_higher_checks_pass() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &10_000);
-    client.lock_program_funds(&10_000);
+    client.lock_program_funds(&10_000, &None);
 
-    client.batch_payout(&vec![&env], &vec![&env]);
+    client.batch_payout(&vec![&env], &vec![&env], &false, &false);
 }
 
 /// Priority 5 (length mismatch) fires after all higher-priority checks pass.
@@ -155,12 +155,12 @@ fn test_batch_payout_length_mismatch_after_higher_checks_pass() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &10_000);
-    client.lock_program_funds(&10_000);
+    client.lock_program_funds(&10_000, &None);
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
     // 2 recipients, 1 amount — length mismatch
-    client.batch_payout(&vec![&env, r1, r2], &vec![&env, 500i128]);
+    client.batch_payout(&vec![&env, r1, r2], &vec![&env, 500i128], &false, &false);
 }
 
 /// Priority 6 (insufficient balance) fires after all higher-priority checks pass.
@@ -171,11 +171,16 @@ fn test_batch_payout_insufficient_balance_after_higher_checks_pass() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &1_000);
-    client.lock_program_funds(&1_000);
+    client.lock_program_funds(&1_000, &None);
 
     let recipient = Address::generate(&env);
     // Amount exceeds balance — insufficient balance
-    client.batch_payout(&vec![&env, recipient], &vec![&env, 999_999i128]);
+    client.batch_payout(
+        &vec![&env, recipient],
+        &vec![&env, 999_999i128],
+        &false,
+        &false,
+    );
 }
 
 // ── single_payout ─────────────────────────────────────────────────────────────
@@ -197,7 +202,7 @@ fn test_single_payout_paused_beats_invalid_amount() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &10_000);
-    client.lock_program_funds(&10_000);
+    client.lock_program_funds(&10_000, &None);
 
     client.set_paused(&None, &Some(true), &None, &None);
 
@@ -214,7 +219,7 @@ fn test_single_payout_invalid_amount_after_higher_checks_pass() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &10_000);
-    client.lock_program_funds(&10_000);
+    client.lock_program_funds(&10_000, &None);
 
     let recipient = Address::generate(&env);
     client.single_payout(&recipient, &0);
@@ -228,7 +233,7 @@ fn test_single_payout_insufficient_balance_after_higher_checks_pass() {
     env.mock_all_auths();
     let (client, _admin, token_admin) = setup_initialized(&env);
     token_admin.mint(&client.address, &500);
-    client.lock_program_funds(&500);
+    client.lock_program_funds(&500, &None);
 
     let recipient = Address::generate(&env);
     // Amount exceeds balance
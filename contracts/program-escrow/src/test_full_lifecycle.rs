@@ -62,16 +62,16 @@ fn test_complex_multi_program_lifecycle_integration() {
     // ── Phase 2: Funding (Lock Funds) ───────────────────────────────────
     // Program A: Lock 500,000 in two chunks
     token_sac.mint(&client.address, &300_000);
-    client.lock_program_funds(&300_000);
+    client.lock_program_funds(&300_000, &None);
     assert_eq!(client.get_remaining_balance(), 300_000);
 
     token_sac.mint(&client.address, &200_000);
-    client.lock_program_funds(&200_000);
+    client.lock_program_funds(&200_000, &None);
     assert_eq!(client.get_remaining_balance(), 500_000);
 
     // Program B: Lock 1,000,000 in one chunk
     token_sac.mint(&client_b.address, &1_000_000);
-    client_b.lock_program_funds(&1_000_000);
+    client_b.lock_program_funds(&1_000_000, &None);
     assert_eq!(client_b.get_remaining_balance(), 1_000_000);
 
     // ── Phase 3: Batch Payouts Round 1 ─────────────────────────────────
@@ -83,6 +83,8 @@ fn test_complex_multi_program_lifecycle_integration() {
     client.batch_payout(
         &vec![&env, r1.clone(), r2.clone()],
         &vec![&env, 100_000, 150_000],
+        &false,
+        &false,
     );
     assert_eq!(client.get_remaining_balance(), 250_000);
     assert_eq!(token_client.balance(&r1), 100_000);
@@ -101,6 +103,8 @@ fn test_complex_multi_program_lifecycle_integration() {
     client.batch_payout(
         &vec![&env, r4.clone(), r5.clone()],
         &vec![&env, 200_000, 50_000],
+        &false,
+        &false,
     );
     assert_eq!(client.get_remaining_balance(), 0);
     assert_eq!(token_client.balance(&r4), 200_000);
@@ -137,7 +141,7 @@ fn test_lifecycle_with_pausing_and_topup() {
     // 1. Init and Fund
     client.init_program(&prog_id, &admin, &token_id, &creator, &None, &None);
     token_sac.mint(&client.address, &100_000);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     // 2. Pause the contract
     client.set_paused(&None, &Some(true), &None, &None); // Pause releases
@@ -155,7 +159,7 @@ fn test_lifecycle_with_pausing_and_topup() {
 
     // 5. Top-up
     token_sac.mint(&client.address, &50_000);
-    client.lock_program_funds(&50_000);
+    client.lock_program_funds(&50_000, &None);
     assert_eq!(client.get_remaining_balance(), 100_000);
     assert_eq!(client.get_program_info().total_funds, 150_000);
 }
@@ -60,7 +60,7 @@ fn setup(
     // Fund the contract with tokens and lock them
     if initial_balance > 0 {
         token_sac.mint(&contract_id, &initial_balance);
-        client.lock_program_funds(&initial_balance);
+        client.lock_program_funds(&initial_balance, &None);
     }
 
     (client, token_client)
@@ -188,7 +188,7 @@ fn test_lock_blocked_when_lock_paused() {
     let (client, _token) = setup(&env, 0);
 
     client.set_paused(&Some(true), &None, &None, &None::<soroban_sdk::String>);
-    client.lock_program_funds(&500);
+    client.lock_program_funds(&500, &None);
 }
 
 /// lock_paused does NOT block single_payout
@@ -215,7 +215,12 @@ fn test_batch_allowed_when_only_lock_paused() {
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
-    let data = client.batch_payout(&vec![&env, r1, r2], &vec![&env, 100i128, 200i128]);
+    let data = client.batch_payout(
+        &vec![&env, r1, r2],
+        &vec![&env, 100i128, 200i128],
+        &false,
+        &false,
+    );
     assert_eq!(data.remaining_balance, 700);
 }
 
@@ -242,7 +247,7 @@ fn test_batch_payout_blocked_when_release_paused() {
 
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
     let r1 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128], &false, &false);
 }
 
 /// release_paused does NOT block lock_program_funds
@@ -254,7 +259,7 @@ fn test_lock_allowed_when_only_release_paused() {
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
 
     // Should succeed — lock_paused is false
-    let data = client.lock_program_funds(&300);
+    let data = client.lock_program_funds(&300, &None);
     assert_eq!(data.remaining_balance, 300);
 }
 
@@ -270,7 +275,7 @@ fn test_lock_allowed_when_only_refund_paused() {
     let (client, _token) = setup(&env, 0);
 
     client.set_paused(&None, &None, &Some(true), &None::<soroban_sdk::String>);
-    let data = client.lock_program_funds(&400);
+    let data = client.lock_program_funds(&400, &None);
     assert_eq!(data.remaining_balance, 400);
 }
 
@@ -294,7 +299,7 @@ fn test_batch_allowed_when_only_refund_paused() {
 
     client.set_paused(&None, &None, &Some(true), &None::<soroban_sdk::String>);
     let r1 = Address::generate(&env);
-    let data = client.batch_payout(&vec![&env, r1], &vec![&env, 100i128]);
+    let data = client.batch_payout(&vec![&env, r1], &vec![&env, 100i128], &false, &false);
     assert_eq!(data.remaining_balance, 900);
 }
 
@@ -314,7 +319,7 @@ fn test_lock_blocked_when_lock_and_release_paused() {
         &None,
         &None::<soroban_sdk::String>,
     );
-    client.lock_program_funds(&100);
+    client.lock_program_funds(&100, &None);
 }
 
 #[test]
@@ -346,7 +351,7 @@ fn test_batch_payout_blocked_when_lock_and_release_paused() {
         &None::<soroban_sdk::String>,
     );
     let r1 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128], &false, &false);
 }
 
 // ---------------------------------------------------------------------------
@@ -365,7 +370,7 @@ fn test_lock_blocked_when_lock_and_refund_paused() {
         &Some(true),
         &None::<soroban_sdk::String>,
     );
-    client.lock_program_funds(&100);
+    client.lock_program_funds(&100, &None);
 }
 
 #[test]
@@ -396,7 +401,7 @@ fn test_batch_allowed_when_lock_and_refund_paused() {
         &None::<soroban_sdk::String>,
     );
     let r1 = Address::generate(&env);
-    let data = client.batch_payout(&vec![&env, r1], &vec![&env, 200i128]);
+    let data = client.batch_payout(&vec![&env, r1], &vec![&env, 200i128], &false, &false);
     assert_eq!(data.remaining_balance, 300);
 }
 
@@ -415,7 +420,7 @@ fn test_lock_allowed_when_release_and_refund_paused() {
         &Some(true),
         &None::<soroban_sdk::String>,
     );
-    let data = client.lock_program_funds(&600);
+    let data = client.lock_program_funds(&600, &None);
     assert_eq!(data.remaining_balance, 600);
 }
 
@@ -448,7 +453,7 @@ fn test_batch_blocked_when_release_and_refund_paused() {
         &None::<soroban_sdk::String>,
     );
     let r1 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128], &false, &false);
 }
 
 // ---------------------------------------------------------------------------
@@ -467,7 +472,7 @@ fn test_lock_blocked_when_all_paused() {
         &Some(true),
         &None::<soroban_sdk::String>,
     );
-    client.lock_program_funds(&100);
+    client.lock_program_funds(&100, &None);
 }
 
 #[test]
@@ -499,7 +504,7 @@ fn test_batch_payout_blocked_when_all_paused() {
         &None::<soroban_sdk::String>,
     );
     let r1 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r1], &vec![&env, 100i128], &false, &false);
 }
 
 // ---------------------------------------------------------------------------
@@ -513,11 +518,11 @@ fn test_lock_restored_after_unpause() {
 
     client.set_paused(&Some(true), &None, &None, &None::<soroban_sdk::String>);
     // Confirm it's blocked
-    assert!(client.try_lock_program_funds(&200).is_err());
+    assert!(client.try_lock_program_funds(&200, &None).is_err());
 
     client.set_paused(&Some(false), &None, &None, &None::<soroban_sdk::String>);
     // Now it should succeed
-    let data = client.lock_program_funds(&200);
+    let data = client.lock_program_funds(&200, &None);
     assert_eq!(data.remaining_balance, 200);
 }
 
@@ -543,11 +548,16 @@ fn test_batch_payout_restored_after_unpause() {
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
     let r1 = Address::generate(&env);
     assert!(client
-        .try_batch_payout(&vec![&env, r1.clone()], &vec![&env, 100i128])
+        .try_batch_payout(
+            &vec![&env, r1.clone()],
+            &vec![&env, 100i128],
+            &false,
+            &false
+        )
         .is_err());
 
     client.set_paused(&None, &Some(false), &None, &None::<soroban_sdk::String>);
-    let data = client.batch_payout(&vec![&env, r1], &vec![&env, 100i128]);
+    let data = client.batch_payout(&vec![&env, r1], &vec![&env, 100i128], &false, &false);
     assert_eq!(data.remaining_balance, 900);
 }
 
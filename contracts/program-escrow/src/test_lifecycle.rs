@@ -87,7 +87,7 @@ fn setup_active_program(
     let program_id = String::from_str(env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
     if amount > 0 {
-        client.lock_program_funds(&amount);
+        client.lock_program_funds(&amount, &None);
     }
     (client, admin, contract_id, token_client)
 }
@@ -103,7 +103,7 @@ fn test_uninitialized_lock_funds_rejected() {
     let env = Env::default();
     env.mock_all_auths();
     let (client, _cid) = make_client(&env);
-    client.lock_program_funds(&1_000);
+    client.lock_program_funds(&1_000, &None);
 }
 
 #[test]
@@ -123,7 +123,7 @@ fn test_uninitialized_batch_payout_rejected() {
     env.mock_all_auths();
     let (client, _cid) = make_client(&env);
     let r = Address::generate(&env);
-    client.batch_payout(&vec![&env, r], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r], &vec![&env, 100i128], &false, &false);
 }
 
 #[test]
@@ -225,7 +225,7 @@ fn test_initialized_batch_payout_zero_balance_rejected() {
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
     let r = Address::generate(&env);
-    client.batch_payout(&vec![&env, r], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r], &vec![&env, 100i128], &false, &false);
 }
 
 /// Locking funds transitions the contract from Initialized to Active.
@@ -243,7 +243,7 @@ fn test_initialized_to_active_via_lock_funds() {
     assert_eq!(client.get_remaining_balance(), 0);
 
     // Transition: Initialized → Active
-    let data = client.lock_program_funds(&50_000);
+    let data = client.lock_program_funds(&50_000, &None);
     assert_eq!(data.total_funds, 50_000);
     assert_eq!(data.remaining_balance, 50_000);
 
@@ -278,6 +278,8 @@ fn test_active_batch_payout_allowed() {
     let data = client.batch_payout(
         &vec![&env, r1.clone(), r2.clone()],
         &vec![&env, 30_000i128, 20_000i128],
+        &false,
+        &false,
     );
     assert_eq!(data.remaining_balance, 50_000);
     assert_eq!(token_client.balance(&r1), 30_000);
@@ -296,10 +298,10 @@ fn test_active_top_up_lock_increases_balance() {
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
 
-    client.lock_program_funds(&80_000);
+    client.lock_program_funds(&80_000, &None);
     assert_eq!(client.get_remaining_balance(), 80_000);
 
-    client.lock_program_funds(&70_000);
+    client.lock_program_funds(&70_000, &None);
     assert_eq!(client.get_remaining_balance(), 150_000);
 
     let info = client.get_program_info();
@@ -318,7 +320,7 @@ fn test_active_negative_lock_amount_rejected() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&-1);
+    client.lock_program_funds(&-1, &None);
 }
 
 /// Payout exceeding balance must be rejected (Active state guard).
@@ -340,7 +342,12 @@ fn test_active_batch_exceeds_balance_rejected() {
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
     // 30_000 + 30_000 = 60_000 > 50_000
-    client.batch_payout(&vec![&env, r1, r2], &vec![&env, 30_000i128, 30_000i128]);
+    client.batch_payout(
+        &vec![&env, r1, r2],
+        &vec![&env, 30_000i128, 30_000i128],
+        &false,
+        &false,
+    );
 }
 
 /// Zero-amount single payout must be rejected.
@@ -361,7 +368,12 @@ fn test_active_zero_amount_in_batch_rejected() {
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1, r2], &vec![&env, 100i128, 0i128]);
+    client.batch_payout(
+        &vec![&env, r1, r2],
+        &vec![&env, 100i128, 0i128],
+        &false,
+        &false,
+    );
 }
 
 /// Mismatched recipients/amounts vectors must be rejected.
@@ -372,7 +384,7 @@ fn test_active_batch_mismatched_lengths_rejected() {
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1, r2], &vec![&env, 100i128]);
+    client.batch_payout(&vec![&env, r1, r2], &vec![&env, 100i128], &false, &false);
 }
 
 /// Empty batch must be rejected.
@@ -381,7 +393,7 @@ fn test_active_batch_mismatched_lengths_rejected() {
 fn test_active_empty_batch_rejected() {
     let env = Env::default();
     let (client, _admin, _cid, _token) = setup_active_program(&env, 50_000);
-    client.batch_payout(&vec![&env], &vec![&env]);
+    client.batch_payout(&vec![&env], &vec![&env], &false, &false);
 }
 
 /// Payout history grows correctly in Active state after multiple operations.
@@ -397,6 +409,8 @@ fn test_active_payout_history_grows() {
     client.batch_payout(
         &vec![&env, r2.clone(), r3.clone()],
         &vec![&env, 15_000i128, 5_000i128],
+        &false,
+        &false,
     );
 
     let info = client.get_program_info();
@@ -424,7 +438,7 @@ fn test_paused_lock_operation_blocked() {
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
     client.set_paused(&Some(true), &None, &None, &None::<soroban_sdk::String>);
 
-    client.lock_program_funds(&10_000);
+    client.lock_program_funds(&10_000, &None);
 }
 
 /// Pausing release prevents single_payout.
@@ -440,7 +454,7 @@ fn test_paused_single_payout_blocked() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
 
     let r = Address::generate(&env);
@@ -460,11 +474,11 @@ fn test_paused_batch_payout_blocked() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
 
     let r = Address::generate(&env);
-    client.batch_payout(&vec![&env, r], &vec![&env, 1_000i128]);
+    client.batch_payout(&vec![&env, r], &vec![&env, 1_000i128], &false, &false);
 }
 
 /// Unpausing restores operations — Active state is fully resumed.
@@ -479,7 +493,7 @@ fn test_paused_to_active_resume_via_unpause() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     // Transition: Active → Paused
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
@@ -508,7 +522,7 @@ fn test_paused_lock_does_not_block_release() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     // Only lock is paused; release must still succeed
     client.set_paused(&Some(true), &None, &None, &None::<soroban_sdk::String>);
@@ -534,14 +548,14 @@ fn test_paused_release_does_not_block_lock() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     // Only release is paused; lock must still succeed
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
     assert!(!client.get_pause_flags().lock_paused);
     assert!(client.get_pause_flags().release_paused);
 
-    let data = client.lock_program_funds(&50_000);
+    let data = client.lock_program_funds(&50_000, &None);
     assert_eq!(data.total_funds, 150_000);
     assert_eq!(data.remaining_balance, 150_000);
 }
@@ -558,7 +572,7 @@ fn test_fully_paused_query_still_works() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     client.set_paused(
         &Some(true),
         &Some(true),
@@ -621,6 +635,8 @@ fn test_drained_after_full_batch_payout() {
     let data = client.batch_payout(
         &vec![&env, r1.clone(), r2.clone(), r3.clone()],
         &vec![&env, 40_000i128, 30_000i128, 20_000i128],
+        &false,
+        &false,
     );
     assert_eq!(data.remaining_balance, 0);
     assert_eq!(token_client.balance(&r1), 40_000);
@@ -651,7 +667,7 @@ fn test_drained_to_active_via_top_up() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     // Drain
     let r = Address::generate(&env);
@@ -659,7 +675,7 @@ fn test_drained_to_active_via_top_up() {
     assert_eq!(client.get_remaining_balance(), 0);
 
     // Re-activate: Drained → Active
-    let data = client.lock_program_funds(&80_000);
+    let data = client.lock_program_funds(&80_000, &None);
     assert_eq!(data.remaining_balance, 80_000);
     assert_eq!(data.total_funds, 180_000); // cumulative total
 
@@ -683,7 +699,7 @@ fn test_payout_history_preserved_across_states() {
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
 
     // Active: first batch of payouts
-    client.lock_program_funds(&200_000);
+    client.lock_program_funds(&200_000, &None);
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
     client.single_payout(&r1, &100_000);
@@ -695,7 +711,7 @@ fn test_payout_history_preserved_across_states() {
     assert_eq!(info.payout_history.len(), 2);
 
     // Re-activate and pay out more
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     let r3 = Address::generate(&env);
     client.single_payout(&r3, &50_000);
 
@@ -811,7 +827,7 @@ fn test_complete_lifecycle_all_transitions() {
     assert_eq!(data.remaining_balance, 0);
 
     // Initialized → Active
-    let data = client.lock_program_funds(&300_000);
+    let data = client.lock_program_funds(&300_000, &None);
     assert_eq!(data.total_funds, 300_000);
     assert_eq!(data.remaining_balance, 300_000);
 
@@ -819,7 +835,12 @@ fn test_complete_lifecycle_all_transitions() {
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
     client.single_payout(&r1, &50_000);
-    client.batch_payout(&vec![&env, r2.clone()], &vec![&env, 50_000i128]);
+    client.batch_payout(
+        &vec![&env, r2.clone()],
+        &vec![&env, 50_000i128],
+        &false,
+        &false,
+    );
     assert_eq!(client.get_remaining_balance(), 200_000);
 
     // Active → Paused
@@ -838,7 +859,7 @@ fn test_complete_lifecycle_all_transitions() {
 
     // Drained → Active (top-up)
     token::StellarAssetClient::new(&env, &token_id).mint(&contract_id, &100_000);
-    let data = client.lock_program_funds(&100_000);
+    let data = client.lock_program_funds(&100_000, &None);
     assert_eq!(data.remaining_balance, 100_000);
 
     // Active: final payout — drains again
@@ -946,7 +967,7 @@ fn test_drained_batch_payout_rejected() {
     assert_eq!(client.get_remaining_balance(), 0);
 
     // Batch payout must fail in Drained state
-    client.batch_payout(&vec![&env, r2], &vec![&env, 1_i128]);
+    client.batch_payout(&vec![&env, r2], &vec![&env, 1_i128], &false, &false);
 }
 
 /// Double initialization remains rejected even after program is drained.
@@ -992,7 +1013,7 @@ fn test_paused_release_allows_schedule_creation() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     client.set_paused(&None, &Some(true), &None, &None::<soroban_sdk::String>);
 
     // Schedule creation should still work while release is paused
@@ -1054,12 +1075,12 @@ fn test_paused_refund_does_not_block_lock_or_release() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     client.set_paused(&None, &None, &Some(true), &None::<soroban_sdk::String>);
 
     // Lock more funds — should succeed
-    let data = client.lock_program_funds(&50_000);
+    let data = client.lock_program_funds(&50_000, &None);
     assert_eq!(data.remaining_balance, 150_000);
 
     // Payout — should succeed
@@ -1085,11 +1106,15 @@ fn test_emergency_withdraw_in_paused_state() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     client.set_paused(&Some(true), &None, &None, &None::<soroban_sdk::String>);
 
     let target = Address::generate(&env);
-    client.emergency_withdraw(&target);
+    let signer = Address::generate(&env);
+    client.set_circuit_admin(&admin, &None);
+    client.set_recovery_config(&vec![&env, signer.clone()], &1, &target);
+    client.approve_emergency_withdraw(&program_id, &signer);
+    client.emergency_withdraw(&program_id, &target);
     assert_eq!(token_client.balance(&target), 100_000);
     assert_eq!(token_client.balance(&contract_id), 0);
 }
@@ -1107,10 +1132,10 @@ fn test_emergency_withdraw_rejected_when_not_paused() {
     client.initialize_contract(&admin);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     let target = Address::generate(&env);
-    client.emergency_withdraw(&target);
+    client.emergency_withdraw(&program_id, &target);
 }
 
 // ---------------------------------------------------------------------------
@@ -1133,7 +1158,7 @@ fn test_multiple_drain_reactivate_cycles() {
     let mut payout_count = 0u32;
 
     // Cycle 1: lock 100k, drain it
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
     cumulative_total += 100_000;
     let r1 = Address::generate(&env);
     client.single_payout(&r1, &100_000);
@@ -1141,7 +1166,7 @@ fn test_multiple_drain_reactivate_cycles() {
     assert_eq!(client.get_remaining_balance(), 0);
 
     // Cycle 2: lock 150k, partial payout, then drain
-    client.lock_program_funds(&150_000);
+    client.lock_program_funds(&150_000, &None);
     cumulative_total += 150_000;
     let r2 = Address::generate(&env);
     let r3 = Address::generate(&env);
@@ -1151,7 +1176,7 @@ fn test_multiple_drain_reactivate_cycles() {
     assert_eq!(client.get_remaining_balance(), 0);
 
     // Cycle 3: lock 250k, batch drain
-    client.lock_program_funds(&250_000);
+    client.lock_program_funds(&250_000, &None);
     cumulative_total += 250_000;
     let r4 = Address::generate(&env);
     let r5 = Address::generate(&env);
@@ -1159,6 +1184,8 @@ fn test_multiple_drain_reactivate_cycles() {
     client.batch_payout(
         &vec![&env, r4.clone(), r5.clone(), r6.clone()],
         &vec![&env, 100_000i128, 100_000i128, 50_000i128],
+        &false,
+        &false,
     );
     payout_count += 3;
     assert_eq!(client.get_remaining_balance(), 0);
@@ -1202,7 +1229,7 @@ fn test_aggregate_stats_across_lifecycle() {
     assert_eq!(stats.payout_count, 0);
 
     // Active: lock and pay
-    client.lock_program_funds(&200_000);
+    client.lock_program_funds(&200_000, &None);
     let r1 = Address::generate(&env);
     client.single_payout(&r1, &80_000);
 
@@ -1333,7 +1360,7 @@ fn test_drained_reactivate_triggers_pending_schedule() {
     let admin = Address::generate(&env);
     let program_id = String::from_str(&env, "hack-2026");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
-    client.lock_program_funds(&100_000);
+    client.lock_program_funds(&100_000, &None);
 
     // Create a future schedule then drain via payout
     let schedule_recipient = Address::generate(&env);
@@ -1345,7 +1372,7 @@ fn test_drained_reactivate_triggers_pending_schedule() {
     assert_eq!(client.get_remaining_balance(), 0); // Drained
 
     // Re-activate with top-up
-    client.lock_program_funds(&50_000);
+    client.lock_program_funds(&50_000, &None);
     assert_eq!(client.get_remaining_balance(), 50_000);
 
     // Trigger the pending schedule
@@ -93,7 +93,7 @@ fn test_lock_fails_in_maintenance_mode() {
     contract.set_maintenance_mode(&true);
 
     // Should panic due to maintenance mode internally reusing `Funds Paused` via `check_paused`
-    contract.lock_program_funds(&1000i128);
+    contract.lock_program_funds(&1000i128, &None);
 }
 
 #[test]
@@ -108,7 +108,7 @@ fn test_release_and_refund_allowed_in_maintenance_mode() {
     token.transfer(&depositor, &contract.address, &5000);
 
     // Lock funds BEFORE maintenance mode
-    contract.lock_program_funds(&5000i128);
+    contract.lock_program_funds(&5000i128, &None);
 
     // Enable maintenance mode
     contract.set_maintenance_mode(&true);
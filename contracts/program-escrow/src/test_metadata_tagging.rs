@@ -343,7 +343,8 @@ fn test_metadata_persists_through_lifecycle() {
     let mut amounts = SdkVec::new(&s.env);
     amounts.push_back(5_000_0000000i128);
 
-    s.escrow.batch_payout(&program_id, &winners, &amounts);
+    s.escrow
+        .batch_payout(&program_id, &winners, &amounts, &false, &false);
 
     // Verify metadata persists after payout
     let after_payout = s.escrow.get_program_metadata(&program_id);
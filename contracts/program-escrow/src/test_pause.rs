@@ -142,7 +142,7 @@ fn test_lock_program_funds_paused() {
     let (contract, _admin, _payout_key, _token) = setup_program_with_admin(&env);
 
     contract.set_paused(&Some(true), &None, &None, &None);
-    contract.lock_program_funds(&1000);
+    contract.lock_program_funds(&1000, &None);
 }
 
 // --- single_payout enforcement ---
@@ -173,7 +173,7 @@ fn test_batch_payout_paused() {
     let amounts = soroban_sdk::vec![&env, 100i128];
 
     contract.set_paused(&None, &Some(true), &None, &None);
-    contract.batch_payout(&recipients, &amounts);
+    contract.batch_payout(&recipients, &amounts, &false, &false);
 }
 
 // --- initialize_contract guard ---
@@ -260,7 +260,7 @@ fn test_operations_resume_after_unpause() {
     contract.set_paused(&Some(false), &None, &None, &None);
 
     // Should succeed now
-    contract.lock_program_funds(&1000);
+    contract.lock_program_funds(&1000, &None);
 }
 
 #[test]
@@ -270,7 +270,7 @@ fn test_emergency_withdraw_non_admin_fails() {
     let (contract, _admin) = setup_with_admin(&env);
 
     let target = Address::generate(&env);
-    contract.emergency_withdraw(&target);
+    contract.emergency_withdraw(&String::from_str(&env, "test-prog"), &target);
 }
 
 #[test]
@@ -281,7 +281,7 @@ fn test_emergency_withdraw_unpaused_fails() {
     let (contract, _admin) = setup_with_admin(&env);
     let target = Address::generate(&env);
 
-    contract.emergency_withdraw(&target);
+    contract.emergency_withdraw(&String::from_str(&env, "test-prog"), &target);
 }
 
 #[test]
@@ -301,18 +301,36 @@ fn test_emergency_withdraw_succeeds() {
     token_client.transfer(&admin, &contract.address, &500);
 
     // Lock some funds to get balance in contract state
-    contract.lock_program_funds(&500);
+    contract.lock_program_funds(&500, &None);
     assert_eq!(token_client.balance(&contract.address), 500);
 
     let reason = soroban_sdk::String::from_str(&env, "Hacked");
     contract.set_paused(&Some(true), &None, &None, &Some(reason));
 
-    contract.emergency_withdraw(&target);
+    let program_id = String::from_str(&env, "test-prog");
+    approve_recovery(&env, &contract, &admin, &program_id, &target);
+    contract.emergency_withdraw(&program_id, &target);
 
     assert_eq!(token_client.balance(&contract.address), 0);
     assert_eq!(token_client.balance(&target), 500);
 }
 
+/// Configure a one-signer recovery multisig sending to `target` and have
+/// the signer approve withdrawing `program_id`. Returns the signer.
+fn approve_recovery(
+    env: &Env,
+    contract: &ProgramEscrowContractClient,
+    admin: &Address,
+    program_id: &String,
+    target: &Address,
+) -> Address {
+    let signer = Address::generate(env);
+    contract.set_circuit_admin(admin, &None);
+    contract.set_recovery_config(&soroban_sdk::vec![env, signer.clone()], &1, target);
+    contract.approve_emergency_withdraw(program_id, &signer);
+    signer
+}
+
 // =========================================================================
 // COMPREHENSIVE RBAC + EMERGENCY WITHDRAW TESTS
 // =========================================================================
@@ -356,7 +374,7 @@ fn setup_rbac_program_env_strict<'a>(
     let depositor = Address::generate(env);
     token_admin_client.mint(&depositor, &1000);
     token_client.transfer(&depositor, &contract_client.address, &500);
-    contract_client.lock_program_funds(&500);
+    contract_client.lock_program_funds(&500, &None);
 
     // Now reset auths - subsequent operations need proper auth
     env.mock_auths(&[]);
@@ -398,7 +416,7 @@ fn setup_rbac_program_env<'a>(
     let depositor = Address::generate(env);
     token_admin_client.mint(&depositor, &1000);
     token_client.transfer(&depositor, &contract_client.address, &500);
-    contract_client.lock_program_funds(&500);
+    contract_client.lock_program_funds(&500, &None);
 
     (admin, operator, token_client, contract_client)
 }
@@ -416,7 +434,9 @@ fn test_rbac_admin_can_emergency_withdraw_when_paused() {
 
     assert_eq!(token_client.balance(&contract_client.address), 500);
 
-    contract_client.emergency_withdraw(&target);
+    let program_id = String::from_str(&env, "rbac-program");
+    approve_recovery(&env, &contract_client, &admin, &program_id, &target);
+    contract_client.emergency_withdraw(&program_id, &target);
 
     assert_eq!(token_client.balance(&contract_client.address), 0);
     assert_eq!(token_client.balance(&target), 500);
@@ -435,7 +455,7 @@ fn test_rbac_operator_cannot_emergency_withdraw() {
     contract_client.set_paused(&Some(true), &None, &None, &None);
 
     // Attempting to call emergency_withdraw without admin auth should fail
-    contract_client.emergency_withdraw(&target);
+    contract_client.emergency_withdraw(&String::from_str(&env, "rbac-program"), &target);
 }
 
 /// emergency_withdraw FAILS even for admin when contract is NOT paused
@@ -449,7 +469,7 @@ fn test_rbac_admin_emergency_withdraw_requires_paused_state() {
     let target = Address::generate(&env);
 
     // Contract is unpaused by default
-    contract_client.emergency_withdraw(&target);
+    contract_client.emergency_withdraw(&String::from_str(&env, "rbac-program"), &target);
 }
 
 /// emergency_withdraw emits correct event with admin address and amount
@@ -466,7 +486,9 @@ fn test_rbac_emergency_withdraw_emits_event() {
     });
 
     contract_client.set_paused(&Some(true), &None, &None, &None);
-    contract_client.emergency_withdraw(&target);
+    let program_id = String::from_str(&env, "rbac-program");
+    approve_recovery(&env, &contract_client, &admin, &program_id, &target);
+    contract_client.emergency_withdraw(&program_id, &target);
 
     let all_events = env.events().all();
     let last_event = all_events.last().unwrap();
@@ -491,15 +513,19 @@ fn test_rbac_emergency_withdraw_on_empty_contract_is_safe() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (_admin, _operator, token_client, contract_client) = setup_rbac_program_env(&env);
+    let (admin, _operator, token_client, contract_client) = setup_rbac_program_env(&env);
     let target = Address::generate(&env);
+    let program_id = String::from_str(&env, "rbac-program");
 
     contract_client.set_paused(&Some(true), &None, &None, &None);
-    contract_client.emergency_withdraw(&target); // drains 500
+    let signer = approve_recovery(&env, &contract_client, &admin, &program_id, &target);
+    contract_client.emergency_withdraw(&program_id, &target); // drains 500
 
     assert_eq!(token_client.balance(&contract_client.address), 0);
 
-    contract_client.emergency_withdraw(&target); // balance = 0, should NOT panic
+    // Approvals are spent by each withdrawal
+    contract_client.approve_emergency_withdraw(&program_id, &signer);
+    contract_client.emergency_withdraw(&program_id, &target); // balance = 0, should NOT panic
 
     assert_eq!(token_client.balance(&contract_client.address), 0);
 }
@@ -510,11 +536,13 @@ fn test_rbac_pause_state_preserved_after_emergency_withdraw() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (_admin, _operator, _token_client, contract_client) = setup_rbac_program_env(&env);
+    let (admin, _operator, _token_client, contract_client) = setup_rbac_program_env(&env);
     let target = Address::generate(&env);
 
     contract_client.set_paused(&Some(true), &None, &None, &None);
-    contract_client.emergency_withdraw(&target);
+    let program_id = String::from_str(&env, "rbac-program");
+    approve_recovery(&env, &contract_client, &admin, &program_id, &target);
+    contract_client.emergency_withdraw(&program_id, &target);
 
     let flags = contract_client.get_pause_flags();
     assert!(
@@ -536,7 +564,7 @@ fn test_rbac_emergency_withdraw_requires_lock_paused_not_release_paused() {
     // Only pause release, not lock
     contract_client.set_paused(&None, &Some(true), &None, &None);
 
-    contract_client.emergency_withdraw(&target);
+    contract_client.emergency_withdraw(&String::from_str(&env, "rbac-program"), &target);
 }
 
 /// Partial pause: only refund paused (not lock) — emergency_withdraw still requires lock_paused
@@ -552,7 +580,7 @@ fn test_rbac_emergency_withdraw_requires_lock_paused_not_refund_paused() {
     // Only pause refund, not lock
     contract_client.set_paused(&None, &None, &Some(true), &None);
 
-    contract_client.emergency_withdraw(&target);
+    contract_client.emergency_withdraw(&String::from_str(&env, "rbac-program"), &target);
 }
 
 /// Admin withdraws all funds in multiple programs from same contract
@@ -596,7 +624,7 @@ fn test_rbac_emergency_withdraw_drains_all_funds() {
 
     // Transfer to contract and lock in each program
     token_client.transfer(&depositor, &contract_client.address, &1500);
-    contract_client.lock_program_funds(&500); // This locks 500 for the current program context
+    contract_client.lock_program_funds(&500, &None); // This locks 500 for the current program context
 
     assert!(
         token_client.balance(&contract_client.address) > 0,
@@ -605,13 +633,12 @@ fn test_rbac_emergency_withdraw_drains_all_funds() {
 
     let target = Address::generate(&env);
     contract_client.set_paused(&Some(true), &None, &None, &None);
-    contract_client.emergency_withdraw(&target);
+    approve_recovery(&env, &contract_client, &admin, &program_id_1, &target);
+    contract_client.emergency_withdraw(&program_id_1, &target);
 
-    assert_eq!(token_client.balance(&contract_client.address), 0);
-    assert!(
-        token_client.balance(&target) > 0,
-        "Target should receive withdrawn funds"
-    );
+    // Only the program's locked balance leaves; unlocked tokens stay put
+    assert_eq!(token_client.balance(&contract_client.address), 1000);
+    assert_eq!(token_client.balance(&target), 500);
 }
 
 /// After emergency_withdraw, admin can unpause and resume normal operations
@@ -620,11 +647,13 @@ fn test_rbac_after_emergency_withdraw_can_unpause_and_reuse() {
     let env = Env::default();
     env.mock_all_auths();
 
-    let (_admin, _operator, token_client, contract_client) = setup_rbac_program_env(&env);
+    let (admin, _operator, token_client, contract_client) = setup_rbac_program_env(&env);
     let target = Address::generate(&env);
 
     contract_client.set_paused(&Some(true), &None, &None, &None);
-    contract_client.emergency_withdraw(&target);
+    let program_id = String::from_str(&env, "rbac-program");
+    approve_recovery(&env, &contract_client, &admin, &program_id, &target);
+    contract_client.emergency_withdraw(&program_id, &target);
 
     // Verify paused state was set
     let flags = contract_client.get_pause_flags();
@@ -645,7 +674,7 @@ fn test_rbac_after_emergency_withdraw_can_unpause_and_reuse() {
     env.mock_all_auths();
     token_sac.mint(&contract_client.address, &200);
 
-    contract_client.lock_program_funds(&200);
+    contract_client.lock_program_funds(&200, &None);
     // Note: this will fail since we drained the contract, but the point is
     // that the pause check passes
     assert_eq!(token_client.balance(&contract_client.address), 200);
@@ -665,5 +694,5 @@ fn test_rbac_emergency_withdraw_ignores_release_and_refund_pause() {
     contract_client.set_paused(&None, &Some(true), &Some(true), &None);
 
     // Should still fail because lock is not paused
-    contract_client.emergency_withdraw(&target);
+    contract_client.emergency_withdraw(&String::from_str(&env, "rbac-program"), &target);
 }
@@ -248,4 +248,42 @@ fn test_preview_split_no_transfer() {
     });
 }
 
+#[test]
+fn test_preview_split_applies_min_payout() {
+    let s = setup();
+    let env = &s.env;
+    let contract_id = env.register_contract(None, crate::ProgramEscrowContract);
+    let r1 = Address::generate(env);
+    let r2 = Address::generate(env);
+
+    env.as_contract(&contract_id, || {
+        let program_data = ProgramData {
+            program_id: s.program_id.clone(),
+            total_funds: 1_000,
+            remaining_balance: 1_000,
+            authorized_payout_key: s.payout_key.clone(),
+            payout_history: vec![env],
+            token_address: s.token.clone(),
+            initial_liquidity: 0,
+        };
+        env.storage().instance().set(&PROGRAM_DATA, &program_data);
+        env.storage()
+            .instance()
+            .set(&DataKey::MinPayout(s.program_id.clone()), &100i128);
+
+        let bens = vec![
+            env,
+            BeneficiarySplit { recipient: r1.clone(), share_bps: 9_500 },
+            BeneficiarySplit { recipient: r2.clone(), share_bps: 500 },
+        ];
+        set_split_config(env, &s.program_id, bens);
+
+        // 50 is below the floor, so it rolls into the first share as dust,
+        // exactly as `execute_split_payout` would pay it
+        let preview = preview_split(env, &s.program_id, 1_000);
+        assert_eq!(preview.get(0).unwrap().share_bps, 1_000);
+        assert_eq!(preview.get(1).unwrap().share_bps, 0);
+    });
+}
+
 // ── Single-beneficiary edge case ─────────────────────────────────────────────
@@ -45,7 +45,7 @@ fn setup_active_program(
     let program_id = String::from_str(env, "rep-test");
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
     if amount > 0 {
-        client.lock_program_funds(&amount);
+        client.lock_program_funds(&amount, &None);
     }
     (client, admin, contract_id, token_client, token_sac)
 }
@@ -89,7 +89,12 @@ fn test_reputation_after_payouts() {
 
     let r1 = Address::generate(&env);
     let r2 = Address::generate(&env);
-    client.batch_payout(&vec![&env, r1.clone(), r2.clone()], &vec![&env, 30_000, 20_000]);
+    client.batch_payout(
+        &vec![&env, r1.clone(), r2.clone()],
+        &vec![&env, 30_000, 20_000],
+        &false,
+        &false,
+    );
 
     let rep = client.get_program_reputation();
     assert_eq!(rep.total_payouts, 2);
@@ -140,32 +140,42 @@ fn serialization_compatibility_public_types_and_events() {
         (
             "FundsLockedEvent",
             FundsLockedEvent {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 program_id: program_id.clone(),
                 amount: 1000,
                 remaining_balance: 9000,
+                event_seq: 1,
             }
             .into_val(&env),
         ),
         (
             "BatchPayoutEvent",
             BatchPayoutEvent {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 program_id: program_id.clone(),
                 recipient_count: 2,
                 total_amount: 500,
                 remaining_balance: 8500,
+                fee_amount: 0,
+                net_amount: 500,
+                usd_equivalent: None,
+                event_seq: 2,
             }
             .into_val(&env),
         ),
         (
             "PayoutEvent",
             PayoutEvent {
-                version: EVENT_VERSION_V2,
+                version: EVENT_VERSION_V3,
                 program_id: program_id.clone(),
                 recipient: recipient.clone(),
                 amount: 200,
                 remaining_balance: 8800,
+                gross_amount: 200,
+                fee_amount: 0,
+                net_amount: 200,
+                usd_equivalent: None,
+                event_seq: 3,
             }
             .into_val(&env),
         ),
@@ -26,7 +26,7 @@ fn setup(env: &Env, initial_lock: i128) -> (ProgramEscrowContractClient<'static>
     client.init_program(&program_id, &admin, &token_id, &admin, &None, &None);
     if initial_lock > 0 {
         token_asset.mint(&client.address, &initial_lock);
-        client.lock_program_funds(&initial_lock);
+        client.lock_program_funds(&initial_lock, &None);
     }
     (client, admin, token_id)
 }
@@ -47,9 +47,9 @@ fn test_time_weighted_metrics_initial_zero() {
 fn test_time_weighted_metrics_after_locks() {
     let env = Env::default();
     let (client, _admin, _token_id) = setup(&env, 0);
-    client.lock_program_funds(&10_000);
-    client.lock_program_funds(&20_000);
-    client.lock_program_funds(&30_000);
+    client.lock_program_funds(&10_000, &None);
+    client.lock_program_funds(&20_000, &None);
+    client.lock_program_funds(&30_000, &None);
     let m = client.get_time_weighted_metrics();
     assert_eq!(m.lock_count, 3);
     assert_eq!(m.avg_lock_size, 20_000); // (10k + 20k + 30k) / 3
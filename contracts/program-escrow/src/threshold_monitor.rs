@@ -193,7 +193,10 @@ pub fn record_operation_success(env: &Env) {
     rotate_window_if_needed(env);
 
     let mut metrics = get_current_metrics(env);
-    metrics.success_count += 1;
+    metrics.success_count = metrics.success_count.checked_add(1).unwrap_or_else(|| {
+        emit_counter_saturated_event(env, symbol_short!("success"), 1);
+        u32::MAX
+    });
 
     env.storage()
         .persistent()
@@ -205,7 +208,10 @@ pub fn record_operation_failure(env: &Env) {
     rotate_window_if_needed(env);
 
     let mut metrics = get_current_metrics(env);
-    metrics.failure_count += 1;
+    metrics.failure_count = metrics.failure_count.checked_add(1).unwrap_or_else(|| {
+        emit_counter_saturated_event(env, symbol_short!("failure"), 1);
+        u32::MAX
+    });
 
     env.storage()
         .persistent()
@@ -217,7 +223,13 @@ pub fn record_outflow(env: &Env, amount: i128) {
     rotate_window_if_needed(env);
 
     let mut metrics = get_current_metrics(env);
-    metrics.total_outflow = metrics.total_outflow.saturating_add(amount);
+    metrics.total_outflow = metrics
+        .total_outflow
+        .checked_add(amount)
+        .unwrap_or_else(|| {
+            emit_counter_saturated_event(env, symbol_short!("outflow"), amount);
+            i128::MAX
+        });
 
     if amount > metrics.max_single_outflow {
         metrics.max_single_outflow = amount;
@@ -462,6 +474,14 @@ fn emit_window_rotation_event(env: &Env, metrics: &WindowMetrics) {
     );
 }
 
+/// Emit counter saturated event when a window total is capped instead of overflowing
+fn emit_counter_saturated_event(env: &Env, counter: Symbol, attempted: i128) {
+    env.events().publish(
+        (symbol_short!("th_sat"), counter),
+        (attempted, env.ledger().timestamp()),
+    );
+}
+
 /// Emit metrics reset event
 fn emit_metrics_reset_event(env: &Env, admin: &Address, timestamp: u64) {
     env.events()
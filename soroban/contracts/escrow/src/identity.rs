@@ -24,7 +24,7 @@ pub enum IdentityTier {
 pub struct IdentityClaim {
     pub address: Address,
     pub tier: IdentityTier,
-    pub risk_score: u32, // 0-100
+    pub risk_score: u32, // 0-max_risk_score
     pub expiry: u64,     // Unix timestamp
     pub issuer: Address, // Issuer public key
 }
@@ -53,10 +53,13 @@ pub struct TierLimits {
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RiskThresholds {
-    pub high_risk_threshold: u32,  // e.g., 70
+    pub high_risk_threshold: u32,  // e.g., 70 (percent of max_risk_score)
     pub high_risk_multiplier: u32, // e.g., 50 (50% of tier limit)
 }
 
+/// Risk score scale used until the admin configures one
+pub const DEFAULT_MAX_RISK_SCORE: u32 = 100;
+
 impl Default for AddressIdentity {
     fn default() -> Self {
         Self {
@@ -138,21 +141,31 @@ pub fn is_claim_expired(env: &Env, expiry: u64) -> bool {
 }
 
 /// Validate claim format and fields
-pub fn validate_claim(claim: &IdentityClaim) -> Result<(), Error> {
-    // Validate risk score is in valid range (0-100)
-    if claim.risk_score > 100 {
+pub fn validate_claim(claim: &IdentityClaim, max_risk_score: u32) -> Result<(), Error> {
+    // Validate risk score is in valid range (0-max_risk_score)
+    if claim.risk_score > max_risk_score {
         return Err(Error::InvalidRiskScore);
     }
 
     Ok(())
 }
 
+/// Express a raw risk score as a percentage (0-100) of `max_risk_score`
+pub fn normalize_risk_score(risk_score: u32, max_risk_score: u32) -> u32 {
+    if max_risk_score == 0 {
+        return 100;
+    }
+    let percent = (risk_score as u64 * 100) / max_risk_score as u64;
+    percent.min(100) as u32
+}
+
 /// Calculate effective transaction limit based on tier and risk score
 pub fn calculate_effective_limit(
     env: &Env,
     identity: &AddressIdentity,
     tier_limits: &TierLimits,
     risk_thresholds: &RiskThresholds,
+    max_risk_score: u32,
 ) -> i128 {
     // Get tier-based limit
     let tier_limit = match identity.tier {
@@ -162,8 +175,10 @@ pub fn calculate_effective_limit(
         IdentityTier::Premium => tier_limits.premium_limit,
     };
 
-    // Apply risk-based adjustment if risk score is high
-    if identity.risk_score >= risk_thresholds.high_risk_threshold {
+    // Apply risk-based adjustment if risk score is high relative to the scale
+    if normalize_risk_score(identity.risk_score, max_risk_score)
+        >= risk_thresholds.high_risk_threshold
+    {
         // Reduce limit by risk multiplier percentage
         let multiplier = risk_thresholds.high_risk_multiplier as i128;
         let risk_adjusted_limit = (tier_limit * multiplier) / 100;
@@ -181,3 +181,142 @@ fn test_lock_funds_within_limits() {
     let escrow = client.get_escrow(&bounty_id);
     assert_eq!(escrow.amount, amount);
 }
+
+#[test]
+fn test_max_risk_score_defaults_and_updates() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    assert_eq!(client.get_max_risk_score(), 100);
+    client.set_max_risk_score(&1000);
+    assert_eq!(client.get_max_risk_score(), 1000);
+
+    let result = client.try_set_max_risk_score(&0);
+    assert_eq!(result, Err(Ok(Error::InvalidRiskScore)));
+}
+
+#[test]
+fn test_validate_claim_against_max_risk_score() {
+    let env = Env::default();
+    let claim = |risk_score: u32| IdentityClaim {
+        address: Address::generate(&env),
+        tier: IdentityTier::Basic,
+        risk_score,
+        expiry: 1000,
+        issuer: Address::generate(&env),
+    };
+
+    assert!(identity::validate_claim(&claim(0), 100).is_ok());
+    assert!(identity::validate_claim(&claim(100), 100).is_ok());
+    assert_eq!(
+        identity::validate_claim(&claim(101), 100),
+        Err(Error::InvalidRiskScore)
+    );
+    assert!(identity::validate_claim(&claim(900), 1000).is_ok());
+}
+
+#[test]
+fn test_risk_score_normalized_against_max() {
+    assert_eq!(identity::normalize_risk_score(70, 100), 70);
+    assert_eq!(identity::normalize_risk_score(700, 1000), 70);
+    assert_eq!(identity::normalize_risk_score(5, 10), 50);
+
+    let identity_data = AddressIdentity {
+        tier: IdentityTier::Basic,
+        risk_score: 700,
+        expiry: 0,
+        last_updated: 0,
+    };
+    let env = Env::default();
+    let limits = TierLimits::default();
+    let thresholds = RiskThresholds::default();
+    // 700 of 1000 is 70%, at the default high-risk threshold
+    assert_eq!(
+        identity::calculate_effective_limit(&env, &identity_data, &limits, &thresholds, 1000),
+        limits.basic_limit / 2
+    );
+    // 700 of 10000 is 7%, well below it
+    assert_eq!(
+        identity::calculate_effective_limit(&env, &identity_data, &limits, &thresholds, 10_000),
+        limits.basic_limit
+    );
+}
+
+#[test]
+fn test_set_risk_thresholds_rejects_out_of_scale() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, _depositor, _contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000i128);
+
+    let result = client.try_set_risk_thresholds(&101, &50);
+    assert_eq!(result, Err(Ok(Error::InvalidRiskScore)));
+}
+
+/// Store an unexpired identity of `tier` for `address` without a signed claim.
+fn set_identity_tier(env: &Env, contract_id: &Address, address: &Address, tier: IdentityTier) {
+    let expiry = env.ledger().timestamp() + 10_000;
+    env.as_contract(contract_id, || {
+        env.storage().persistent().set(
+            &DataKey::AddressIdentity(address.clone()),
+            &AddressIdentity {
+                tier,
+                risk_score: 0,
+                expiry,
+                last_updated: 0,
+            },
+        );
+    });
+}
+
+#[test]
+fn test_min_claim_tier_met_allows_release() {
+    let env = Env::default();
+    let (client, contract_id, _admin, depositor, contributor, _issuer, token_client) =
+        setup_with_identity(&env, 10_000_0000000);
+    let amount = 50_0000000;
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &amount, &deadline);
+    client.set_min_claim_tier(&1, &Some(IdentityTier::Basic));
+    set_identity_tier(&env, &contract_id, &contributor, IdentityTier::Verified);
+
+    let before = token_client.balance(&contributor);
+    client.assign_contributor(&1, &contributor);
+    client.release_funds(&1, &contributor);
+    assert_eq!(token_client.balance(&contributor), before + amount);
+}
+
+#[test]
+fn test_min_claim_tier_rejects_lower_tier() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, depositor, contributor, _issuer, _token_client) =
+        setup_with_identity(&env, 10_000_0000000);
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &50_0000000, &deadline);
+    client.set_min_claim_tier(&1, &Some(IdentityTier::Basic));
+
+    // No stored identity: contributor is Unverified
+    assert_eq!(
+        client.try_assign_contributor(&1, &contributor),
+        Err(Ok(Error::InvalidTier))
+    );
+    assert_eq!(
+        client.try_release_funds(&1, &contributor),
+        Err(Ok(Error::InvalidTier))
+    );
+}
+
+#[test]
+fn test_no_min_claim_tier_allows_unverified() {
+    let env = Env::default();
+    let (client, _contract_id, _admin, depositor, contributor, _issuer, token_client) =
+        setup_with_identity(&env, 10_000_0000000);
+    let amount = 50_0000000;
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &amount, &deadline);
+    assert_eq!(client.get_min_claim_tier(&1), None);
+
+    let before = token_client.balance(&contributor);
+    client.release_funds(&1, &contributor);
+    assert_eq!(token_client.balance(&contributor), before + amount);
+}
@@ -60,6 +60,9 @@ pub enum DataKey {
     TierLimits,
     RiskThresholds,
     ReentrancyGuard,
+    MaxRiskScore,
+    AssignedContributor(u64),
+    MinClaimTier(u64),
 }
 
 #[contract]
@@ -137,6 +140,8 @@ impl EscrowContract {
     }
 
     /// Configure risk-based adjustments (admin only)
+    ///
+    /// `high_risk_threshold` is a percentage (0-100) of the max risk score.
     pub fn set_risk_thresholds(
         env: Env,
         high_risk_threshold: u32,
@@ -149,6 +154,10 @@ impl EscrowContract {
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
 
+        if high_risk_threshold > 100 {
+            return Err(Error::InvalidRiskScore);
+        }
+
         let thresholds = RiskThresholds {
             high_risk_threshold,
             high_risk_multiplier,
@@ -160,6 +169,32 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Set the top of the risk score scale (admin only)
+    pub fn set_max_risk_score(env: Env, max_risk_score: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if max_risk_score == 0 {
+            return Err(Error::InvalidRiskScore);
+        }
+        env.storage()
+            .persistent()
+            .set(&DataKey::MaxRiskScore, &max_risk_score);
+        Ok(())
+    }
+
+    /// Query the top of the risk score scale
+    pub fn get_max_risk_score(env: Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MaxRiskScore)
+            .unwrap_or(identity::DEFAULT_MAX_RISK_SCORE)
+    }
+
     /// Submit an identity claim for verification and storage
     pub fn submit_identity_claim(
         env: Env,
@@ -176,7 +211,7 @@ impl EscrowContract {
         }
 
         // Validate claim format
-        identity::validate_claim(&claim)?;
+        identity::validate_claim(&claim, Self::get_max_risk_score(env.clone()))?;
 
         // Check if claim has expired
         if identity::is_claim_expired(&env, claim.expiry) {
@@ -264,7 +299,13 @@ impl EscrowContract {
             .get(&DataKey::RiskThresholds)
             .unwrap_or_default();
 
-        identity::calculate_effective_limit(&env, &identity, &tier_limits, &risk_thresholds)
+        identity::calculate_effective_limit(
+            &env,
+            &identity,
+            &tier_limits,
+            &risk_thresholds,
+            Self::get_max_risk_score(env.clone()),
+        )
     }
 
     /// Check if an address has a valid (non-expired) claim
@@ -280,6 +321,51 @@ impl EscrowContract {
         }
     }
 
+    /// Require recipients of a bounty to hold at least `tier` (admin only).
+    /// `None` removes the requirement.
+    pub fn set_min_claim_tier(
+        env: Env,
+        bounty_id: u64,
+        tier: Option<IdentityTier>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        match tier {
+            Some(tier) => env
+                .storage()
+                .persistent()
+                .set(&DataKey::MinClaimTier(bounty_id), &tier),
+            None => env
+                .storage()
+                .persistent()
+                .remove(&DataKey::MinClaimTier(bounty_id)),
+        }
+        Ok(())
+    }
+
+    /// Minimum identity tier a bounty's recipient must hold, if any.
+    pub fn get_min_claim_tier(env: Env, bounty_id: u64) -> Option<IdentityTier> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::MinClaimTier(bounty_id))
+    }
+
+    /// Internal: Reject a recipient whose identity tier is below the bounty's minimum
+    fn enforce_min_claim_tier(env: &Env, bounty_id: u64, address: &Address) -> Result<(), Error> {
+        if let Some(min_tier) = Self::get_min_claim_tier(env.clone(), bounty_id) {
+            let identity = Self::get_address_identity(env.clone(), address.clone());
+            if identity.tier < min_tier {
+                return Err(Error::InvalidTier);
+            }
+        }
+        Ok(())
+    }
+
     /// Internal: Enforce transaction limit for an address
     fn enforce_transaction_limit(env: &Env, address: &Address, amount: i128) -> Result<(), Error> {
         let effective_limit = Self::get_effective_limit(env.clone(), address.clone());
@@ -385,9 +471,23 @@ impl EscrowContract {
             return Err(Error::InsufficientBalance);
         }
 
-        // Enforce transaction limit for contributor
+        // Enforce minimum identity tier and transaction limit for contributor
+        Self::enforce_min_claim_tier(&env, bounty_id, &contributor)?;
         Self::enforce_transaction_limit(&env, &contributor, escrow.remaining_amount)?;
-        
+
+        // CHECK: the contract must actually hold what the escrow says it owes;
+        // a shortfall here is an accounting bug, not a valid release.
+        let token = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Token)
+            .unwrap();
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(&env, &token);
+        if token_client.balance(&contract) < escrow.remaining_amount {
+            return Err(Error::InsufficientBalance);
+        }
+
         // EFFECTS: update state before external call (CEI)
         let release_amount = escrow.remaining_amount;
         escrow.remaining_amount = 0;
@@ -397,13 +497,6 @@ impl EscrowContract {
             .set(&DataKey::Escrow(bounty_id), &escrow);
 
         // INTERACTION: external token transfer is last
-        let token = env
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::Token)
-            .unwrap();
-        let contract = env.current_contract_address();
-        let token_client = token::Client::new(&env, &token);
         token_client.transfer(&contract, &contributor, &release_amount);
 
         // GUARD: release reentrancy lock
@@ -464,6 +557,95 @@ impl EscrowContract {
         Ok(())
     }
 
+    /// Assign the contributor working on a bounty. Admin must be authorized.
+    /// Only the assigned contributor may later `decline_bounty`. The contributor
+    /// must meet the bounty's minimum claim tier, if one is set.
+    pub fn assign_contributor(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        Self::enforce_min_claim_tier(&env, bounty_id, &contributor)?;
+
+        env.storage()
+            .persistent()
+            .set(&DataKey::AssignedContributor(bounty_id), &contributor);
+        Ok(())
+    }
+
+    /// Contributor assigned to a bounty, if any.
+    pub fn get_assigned_contributor(env: Env, bounty_id: u64) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::AssignedContributor(bounty_id))
+    }
+
+    /// Contributor declines the bounty: remaining funds go back to the
+    /// depositor immediately, regardless of deadline. Only valid while `Locked`.
+    ///
+    /// # Reentrancy
+    /// Protected by reentrancy guard. Escrow state is updated to
+    /// `Refunded` *before* the outbound token transfer (CEI pattern).
+    pub fn decline_bounty(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        contributor.require_auth();
+
+        let mut escrow: Escrow = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Escrow(bounty_id))
+            .ok_or(Error::BountyNotFound)?;
+        let assigned: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::AssignedContributor(bounty_id));
+        if assigned != Some(contributor) {
+            return Err(Error::Unauthorized);
+        }
+        if escrow.status != EscrowStatus::Locked {
+            return Err(Error::FundsNotLocked);
+        }
+        if escrow.remaining_amount <= 0 {
+            return Err(Error::InsufficientBalance);
+        }
+
+        // EFFECTS: update state before external call (CEI)
+        let amount = escrow.remaining_amount;
+        let depositor = escrow.depositor.clone();
+        escrow.remaining_amount = 0;
+        escrow.status = EscrowStatus::Refunded;
+        env.storage()
+            .persistent()
+            .set(&DataKey::Escrow(bounty_id), &escrow);
+
+        // INTERACTION: external token transfer is last
+        let token = env
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::Token)
+            .unwrap();
+        let contract = env.current_contract_address();
+        let token_client = token::Client::new(&env, &token);
+        token_client.transfer(&contract, &depositor, &amount);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
     /// Read escrow state (for tests).
     pub fn get_escrow(env: Env, bounty_id: u64) -> Result<Escrow, Error> {
         env.storage()